@@ -8,6 +8,7 @@
 
 #![forbid(missing_docs)]
 
+use core::convert::TryFrom;
 use alloc::vec::Vec;
 use alloc::string::String;
 
@@ -16,10 +17,18 @@ use num_bigint::{BigInt,BigUint};
 #[cfg(feature = "bit-vec")]
 use bit_vec::BitVec;
 
-use super::{ASN1Result,BERMode,BERReader,parse_ber_general};
-use super::models::{ObjectIdentifier,TaggedDerValue};
+use super::{ASN1Error,ASN1ErrorKind,ASN1Result,BERMode,BERReader,parse_ber_general};
+use super::models::{ObjectIdentifier,TaggedDerValue,WithRaw,RawDateTime,OidBuf};
+use super::models::BitString;
+use super::models::{NumericString,PrintableString,Ia5String,VisibleString};
+use super::models::{BmpString,Utf8String,DirectoryString};
+use super::models::{Date,TimeOfDay,DateTime,Duration,Time};
+use super::models::GeneralizedTimeFields;
+use super::tags::{TAG_NUMERICSTRING,TAG_PRINTABLESTRING,TAG_IA5STRING};
+use super::tags::{TAG_VISIBLESTRING,TAG_BMPSTRING,TAG_UTF8STRING};
+use super::tags::{TAG_UTCTIME,TAG_GENERALIZEDTIME};
 #[cfg(feature = "time")]
-use super::models::{UTCTime,GeneralizedTime};
+use super::models::{UTCTime,GeneralizedTime,X509Time};
 
 /// Types decodable in BER.
 ///
@@ -46,13 +55,26 @@ use super::models::{UTCTime,GeneralizedTime};
 /// - `()` as NULL decoder.
 /// - Tuples (except `()`) as SEQUENCE decoder.
 /// - `Vec<u8>` as OCTETSTRING decoder.
-/// - `BitVec` as BITSTRING decoder.
+/// - `BitVec`/`BitString` as BITSTRING decoder.
 /// - `String` as UTF8String decoder.
 /// - `i64`, `u64`, `i32`, `u32`, `i16`, `u16`, `BigInt`, `BigUint`
 ///   as INTEGER decoder. (`u8` is avoided because of confliction.)
 /// - `bool` as BOOLEAN decoder.
 /// - `ObjectIdentifier` as OBJECTT IDENTIFIER decoder.
+/// - `OidBuf` as OBJECT IDENTIFIER decoder, failing if the encoding is
+///   longer than `OidBuf::CAPACITY`.
 /// - `UTCTime`/`GeneralizedTime` as UTCTime/GeneralizedTime decoder.
+/// - `X509Time` as a CHOICE of UTCTime and GeneralizedTime decoders.
+/// - `GeneralizedTimeFields` as GeneralizedTime decoder.
+/// - `Date`, `TimeOfDay`, `DateTime`, `Duration`, `Time` as DATE,
+///   TIME-OF-DAY, DATE-TIME, DURATION, TIME decoder.
+/// - `NumericString`, `PrintableString`, `Ia5String`, `VisibleString`,
+///   `BmpString`, `Utf8String` as the corresponding string decoders.
+/// - `DirectoryString` as a CHOICE of the string types above.
+/// - `TaggedDerValue` as any value, keeping its tag.
+/// - `WithRaw<T>` as the decoder of `T`, keeping the original encoding.
+/// - `RawDateTime`, `chrono::DateTime<Utc>` and `jiff::Timestamp` as
+///   a CHOICE of UTCTime and GeneralizedTime decoders.
 pub trait BERDecodable: Sized {
     /// Reads an ASN.1 value from `BERReader` and converts it to `Self`.
     ///
@@ -215,6 +237,13 @@ impl BERDecodable for BitVec {
     }
 }
 
+impl BERDecodable for BitString {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let (bytes, len) = reader.read_bitvec_bytes()?;
+        return Ok(BitString::from_bytes_and_len(bytes, len));
+    }
+}
+
 impl BERDecodable for Vec<u8> {
     fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
         reader.read_bytes()
@@ -233,6 +262,83 @@ impl BERDecodable for ObjectIdentifier {
     }
 }
 
+impl BERDecodable for OidBuf {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let oid = reader.read_oid_ref()?;
+        return OidBuf::from_oid(oid).ok_or_else(
+            || ASN1Error::new(ASN1ErrorKind::Invalid));
+    }
+}
+
+impl BERDecodable for NumericString {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let string = reader.read_numeric_string()?;
+        NumericString::try_from(string)
+            .map_err(|_| ASN1Error::new(ASN1ErrorKind::Invalid))
+    }
+}
+
+impl BERDecodable for PrintableString {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let string = reader.read_printable_string()?;
+        PrintableString::try_from(string)
+            .map_err(|_| ASN1Error::new(ASN1ErrorKind::Invalid))
+    }
+}
+
+impl BERDecodable for Ia5String {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let string = reader.read_ia5_string()?;
+        Ia5String::try_from(string)
+            .map_err(|_| ASN1Error::new(ASN1ErrorKind::Invalid))
+    }
+}
+
+impl BERDecodable for VisibleString {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let string = reader.read_visible_string()?;
+        VisibleString::try_from(string)
+            .map_err(|_| ASN1Error::new(ASN1ErrorKind::Invalid))
+    }
+}
+
+impl BERDecodable for BmpString {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let string = reader.read_bmp_string()?;
+        BmpString::try_from(string)
+            .map_err(|_| ASN1Error::new(ASN1ErrorKind::Invalid))
+    }
+}
+
+impl BERDecodable for Utf8String {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let string = reader.read_utf8string()?;
+        Utf8String::try_from(string)
+            .map_err(|_| ASN1Error::new(ASN1ErrorKind::Invalid))
+    }
+}
+
+impl BERDecodable for DirectoryString {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let tag = reader.lookahead_tag()?;
+        match tag {
+            TAG_NUMERICSTRING => Ok(DirectoryString::NumericString(
+                NumericString::decode_ber(reader)?)),
+            TAG_PRINTABLESTRING => Ok(DirectoryString::PrintableString(
+                PrintableString::decode_ber(reader)?)),
+            TAG_IA5STRING => Ok(DirectoryString::Ia5String(
+                Ia5String::decode_ber(reader)?)),
+            TAG_VISIBLESTRING => Ok(DirectoryString::VisibleString(
+                VisibleString::decode_ber(reader)?)),
+            TAG_BMPSTRING => Ok(DirectoryString::BmpString(
+                BmpString::decode_ber(reader)?)),
+            TAG_UTF8STRING => Ok(DirectoryString::Utf8String(
+                Utf8String::decode_ber(reader)?)),
+            _ => Err(ASN1Error::new(ASN1ErrorKind::Invalid)),
+        }
+    }
+}
+
 #[cfg(feature = "time")]
 impl BERDecodable for UTCTime {
     fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
@@ -247,6 +353,55 @@ impl BERDecodable for GeneralizedTime {
     }
 }
 
+#[cfg(feature = "time")]
+impl BERDecodable for X509Time {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let tag = reader.lookahead_tag()?;
+        match tag {
+            TAG_UTCTIME => Ok(X509Time::UTCTime(reader.read_utctime()?)),
+            TAG_GENERALIZEDTIME => Ok(X509Time::GeneralizedTime(
+                reader.read_generalized_time()?)),
+            _ => Err(ASN1Error::new(ASN1ErrorKind::Invalid)),
+        }
+    }
+}
+
+impl BERDecodable for GeneralizedTimeFields {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_generalized_time_fields()
+    }
+}
+
+impl BERDecodable for Date {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_date()
+    }
+}
+
+impl BERDecodable for TimeOfDay {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_time_of_day()
+    }
+}
+
+impl BERDecodable for DateTime {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_date_time()
+    }
+}
+
+impl BERDecodable for Duration {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_duration()
+    }
+}
+
+impl BERDecodable for Time {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_time()
+    }
+}
+
 impl BERDecodable for () {
     fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
         reader.read_null()
@@ -467,3 +622,56 @@ impl BERDecodable for TaggedDerValue  {
         reader.read_tagged_der()
     }
 }
+
+impl BERDecodable for RawDateTime {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let mode = reader.mode();
+        let tag = reader.lookahead_tag()?;
+        let (datetime, canonical) = if tag == TAG_UTCTIME {
+            let bytes = reader.read_tagged_implicit(TAG_UTCTIME, |reader| {
+                reader.read_bytes()
+            })?;
+            let datetime = RawDateTime::parse_utctime(&bytes).ok_or_else(
+                || ASN1Error::new(ASN1ErrorKind::Invalid))?;
+            (datetime, datetime.to_utctime_bytes() == Some(bytes))
+        } else if tag == TAG_GENERALIZEDTIME {
+            let bytes = reader.read_tagged_implicit(TAG_GENERALIZEDTIME,
+                |reader| reader.read_bytes())?;
+            let datetime = RawDateTime::parse_generalized_time(&bytes)
+                .ok_or_else(|| ASN1Error::new(ASN1ErrorKind::Invalid))?;
+            (datetime, datetime.to_generalized_time_bytes() == bytes)
+        } else {
+            return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+        };
+        if mode == BERMode::Der && !canonical {
+            return Err(ASN1Error::new(ASN1ErrorKind::NonCanonicalTime));
+        }
+        return Ok(datetime);
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl BERDecodable for chrono::DateTime<chrono::Utc> {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let datetime = RawDateTime::decode_ber(reader)?;
+        return Ok(datetime.into());
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl BERDecodable for jiff::Timestamp {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let datetime = RawDateTime::decode_ber(reader)?;
+        return jiff::Timestamp::try_from(datetime)
+            .map_err(|_| ASN1Error::new(ASN1ErrorKind::Invalid));
+    }
+}
+
+impl<T> BERDecodable for WithRaw<T> where T: BERDecodable {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let (value, raw) = reader.read_with_buffer(|reader| {
+            T::decode_ber(reader)
+        })?;
+        return Ok(WithRaw::from_parts(value, raw.to_vec()));
+    }
+}
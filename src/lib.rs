@@ -81,12 +81,17 @@ mod writer;
 mod reader;
 mod deserializer;
 mod serializer;
+pub mod notation;
+pub mod text;
+#[cfg(feature = "pkix")]
+pub mod pkix;
 
 pub use crate::writer::{construct_der,try_construct_der};
 pub use crate::writer::{construct_der_seq,try_construct_der_seq};
 pub use crate::writer::{DERWriter,DERWriterSeq,DERWriterSet};
-pub use crate::reader::{parse_ber_general,parse_ber,parse_der,BERMode};
-pub use crate::reader::{BERReader,BERReaderSeq,BERReaderSet};
+pub use crate::reader::{parse_ber_general,parse_ber,parse_der,BERMode,ber_to_der};
+pub use crate::reader::{BERReader,BERReaderSeq,BERReaderSet,ChoiceAlternative};
+pub use crate::reader::LazySequenceOf;
 pub use crate::reader::{ASN1Error,ASN1ErrorKind,ASN1Result};
 pub use crate::deserializer::{BERDecodable,decode_ber_general,decode_ber,decode_der};
 pub use crate::serializer::{DEREncodable,encode_der};
@@ -0,0 +1,150 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+/// A BIT STRING value, stored as bytes and the number of bits.
+///
+/// Bits are numbered from the most significant bit of the first byte,
+/// as in the ASN.1 encoding. Unused bits in the last byte are always zero.
+///
+/// This is a dependency-free alternative to `BitVec`, usable without
+/// the `bit-vec` feature.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::BitString;
+/// let bits = BitString::from_bytes_and_len(vec![117, 13, 65], 18);
+/// assert_eq!(bits.as_bytes(), &[117, 13, 64]);
+/// assert_eq!(bits.get(1), Some(true));
+/// assert_eq!(yasna::encode_der(&bits), &[3, 4, 6, 117, 13, 64]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct BitString {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitString {
+    /// Constructs a new `BitString` with all the bits in `bytes`.
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        let len = bytes.len() * 8;
+        BitString {
+            bytes,
+            len,
+        }
+    }
+
+    /// Constructs a new `BitString` with the first `len` bits in `bytes`.
+    ///
+    /// The unused bits in the last byte are cleared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` has less than `len` bits or unnecessary bytes.
+    pub fn from_bytes_and_len(mut bytes: Vec<u8>, len: usize) -> Self {
+        assert!(len <= bytes.len() * 8 && bytes.len() * 8 < len + 8,
+            "Invalid length of BitString");
+        if len % 8 != 0 {
+            let last = bytes.len() - 1;
+            bytes[last] &= !(255u8 >> (len % 8));
+        }
+        BitString {
+            bytes,
+            len,
+        }
+    }
+
+    /// Constructs a new `BitString` from the indices of the bits which are
+    /// set, with the trailing zero bits removed.
+    ///
+    /// This is the DER encoding of named bit lists (e.g. KeyUsage).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::BitString;
+    /// let bits = BitString::from_set_bits(&[0, 5]);
+    /// assert_eq!(bits.len(), 6);
+    /// assert_eq!(bits.as_bytes(), &[132]);
+    /// ```
+    pub fn from_set_bits(indices: &[usize]) -> Self {
+        let len = indices.iter().map(|&i| i + 1).max().unwrap_or(0);
+        let mut bytes = alloc::vec![0; (len + 7) / 8];
+        for &i in indices {
+            bytes[i / 8] |= 128 >> (i % 8);
+        }
+        BitString {
+            bytes,
+            len,
+        }
+    }
+
+    /// Returns the bytes, including the unused bits in the last byte.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the bytes if the number of bits is a multiple of 8.
+    ///
+    /// Keys and signatures are usually wrapped in such a BIT STRING.
+    pub fn as_octets(&self) -> Option<&[u8]> {
+        if self.len % 8 == 0 {
+            Some(&self.bytes)
+        } else {
+            None
+        }
+    }
+
+    /// Extracts the bytes, including the unused bits in the last byte.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit at `index`, or `None` if it is out of range.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            Some(self.bytes[index / 8] & (128 >> (index % 8)) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the bit at `index` is set.
+    ///
+    /// Bits out of range are regarded as unset, as in named bit lists.
+    pub fn is_set(&self, index: usize) -> bool {
+        self.get(index) == Some(true)
+    }
+}
+
+#[test]
+fn test_bit_string() {
+    let bits = BitString::from_bytes_and_len(alloc::vec![255, 255], 9);
+    assert_eq!(bits.as_bytes(), &[255, 128]);
+    assert_eq!(bits.as_octets(), None);
+    assert_eq!(bits.get(8), Some(true));
+    assert_eq!(bits.get(9), None);
+    assert!(!bits.is_set(9));
+    let bits = BitString::from_bytes(alloc::vec![1, 2]);
+    assert_eq!(bits.len(), 16);
+    assert_eq!(bits.as_octets(), Some(&[1, 2][..]));
+    assert!(BitString::from_set_bits(&[]).is_empty());
+    assert_eq!(BitString::from_set_bits(&[8]).as_bytes(), &[0, 128]);
+}
@@ -0,0 +1,1511 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "time")]
+use core::convert::TryFrom;
+use core::fmt::{self, Display};
+
+use super::raw_time::{is_leap_year, days_in_month, digits_value, push_digits};
+#[cfg(feature = "time")]
+use super::raw_time::DateTimeRangeError;
+
+struct Cursor<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Cursor {
+            buf,
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.buf.get(self.pos).cloned()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.buf.get(self.pos + offset).cloned()
+    }
+
+    fn is_end(&self) -> bool {
+        self.pos == self.buf.len()
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        if self.peek() == Some(b) {
+            self.pos += 1;
+            return true;
+        }
+        return false;
+    }
+
+    fn digit_run(&mut self) -> &'a [u8] {
+        let start = self.pos;
+        while self.peek().map_or(false, |b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        return &self.buf[start..self.pos];
+    }
+
+    /// Reads exactly `n` digits.
+    fn digits(&mut self, n: usize) -> Option<u32> {
+        let digits = self.buf.get(self.pos..self.pos + n)?;
+        let value = digits_value(digits)?;
+        self.pos += n;
+        return Some(value as u32);
+    }
+}
+
+/// Returns the number of ISO 8601 weeks in the year.
+fn weeks_in_year(year: i64) -> u32 {
+    let p = |y: i64| {
+        (y + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400))
+            .rem_euclid(7)
+    };
+    if p(year) == 4 || p(year - 1) == 3 { 53 } else { 52 }
+}
+
+/// A calendar date between 1582-01-01 and 9999-12-31.
+///
+/// Corresponds to ASN.1 DATE type, which is TIME with the property settings
+/// `Basic=Date Date=YMD Year=Basic`. It is represented as `YYYY-MM-DD`.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::Date;
+/// let date = Date::parse(b"2019-10-19").unwrap();
+/// assert_eq!((date.year(), date.month(), date.day()), (2019, 10, 19));
+/// assert_eq!(yasna::encode_der(&date), b"\x1f\x1f\x0a2019-10-19");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Constructs `Date` from the year, the month and the day.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the fields don't specify a correct date between
+    /// the years 1582 and 9999.
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if !((1582..=9999).contains(&year) && (1..=12).contains(&month) &&
+                (1..=days_in_month(year as i64, month)).contains(&day)) {
+            return None;
+        }
+        return Some(Date {
+            year,
+            month,
+            day,
+        });
+    }
+
+    /// Parses ASN.1 string representation of DATE.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string is not in the form of
+    /// `YYYY-MM-DD` or does not specify a correct date.
+    pub fn parse(buf: &[u8]) -> Option<Self> {
+        let mut cursor = Cursor::new(buf);
+        let date = Self::parse_cursor(&mut cursor)?;
+        if !cursor.is_end() {
+            return None;
+        }
+        return Some(date);
+    }
+
+    fn parse_cursor(cursor: &mut Cursor) -> Option<Self> {
+        let year = cursor.digits(4)?;
+        if !cursor.eat(b'-') {
+            return None;
+        }
+        let month = cursor.digits(2)?;
+        if !cursor.eat(b'-') {
+            return None;
+        }
+        let day = cursor.digits(2)?;
+        return Self::new(year as u16, month as u8, day as u8);
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1 to 31.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn push_bytes(&self, buf: &mut Vec<u8>) {
+        push_digits(buf, self.year as u32, 4);
+        buf.push(b'-');
+        push_digits(buf, self.month as u32, 2);
+        buf.push(b'-');
+        push_digits(buf, self.day as u32, 2);
+    }
+
+    /// Returns ASN.1 representation of the date as `Vec<u8>`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(10);
+        self.push_bytes(&mut buf);
+        return buf;
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&String::from_utf8(self.to_bytes()).unwrap())
+    }
+}
+
+/// A local time of day, with the precision of seconds.
+///
+/// Corresponds to ASN.1 TIME-OF-DAY type, which is TIME with the property
+/// settings `Basic=Time Time=HMS Local-or-UTC=L`. It is represented as
+/// `HH:MM:SS`.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::TimeOfDay;
+/// let time = TimeOfDay::parse(b"09:30:00").unwrap();
+/// assert_eq!((time.hour(), time.minute(), time.second()), (9, 30, 0));
+/// assert_eq!(yasna::encode_der(&time), b"\x1f\x20\x0809:30:00");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TimeOfDay {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl TimeOfDay {
+    /// Constructs `TimeOfDay` from the hour, the minute and the second.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the fields don't specify a correct time.
+    pub fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if !(hour < 24 && minute < 60 && second < 60) {
+            return None;
+        }
+        return Some(TimeOfDay {
+            hour,
+            minute,
+            second,
+        });
+    }
+
+    /// Parses ASN.1 string representation of TIME-OF-DAY.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string is not in the form of
+    /// `HH:MM:SS` or does not specify a correct time.
+    pub fn parse(buf: &[u8]) -> Option<Self> {
+        let mut cursor = Cursor::new(buf);
+        let time = Self::parse_cursor(&mut cursor)?;
+        if !cursor.is_end() {
+            return None;
+        }
+        return Some(time);
+    }
+
+    fn parse_cursor(cursor: &mut Cursor) -> Option<Self> {
+        let hour = cursor.digits(2)?;
+        if !cursor.eat(b':') {
+            return None;
+        }
+        let minute = cursor.digits(2)?;
+        if !cursor.eat(b':') {
+            return None;
+        }
+        let second = cursor.digits(2)?;
+        return Self::new(hour as u8, minute as u8, second as u8);
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59.
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second, from 0 to 59.
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    fn push_bytes(&self, buf: &mut Vec<u8>) {
+        push_digits(buf, self.hour as u32, 2);
+        buf.push(b':');
+        push_digits(buf, self.minute as u32, 2);
+        buf.push(b':');
+        push_digits(buf, self.second as u32, 2);
+    }
+
+    /// Returns ASN.1 representation of the time as `Vec<u8>`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(8);
+        self.push_bytes(&mut buf);
+        return buf;
+    }
+}
+
+impl Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&String::from_utf8(self.to_bytes()).unwrap())
+    }
+}
+
+/// A local date and time, with the precision of seconds.
+///
+/// Corresponds to ASN.1 DATE-TIME type, which is TIME with the property
+/// settings `Basic=Date-Time Date=YMD Year=Basic Time=HMS Local-or-UTC=L`.
+/// It is represented as `YYYY-MM-DDTHH:MM:SS`.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::DateTime;
+/// let datetime = DateTime::parse(b"2019-10-19T09:30:00").unwrap();
+/// assert_eq!(datetime.date().year(), 2019);
+/// assert_eq!(datetime.time().hour(), 9);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DateTime {
+    date: Date,
+    time: TimeOfDay,
+}
+
+impl DateTime {
+    /// Constructs `DateTime` from a date and a time of day.
+    pub fn new(date: Date, time: TimeOfDay) -> Self {
+        DateTime {
+            date,
+            time,
+        }
+    }
+
+    /// Parses ASN.1 string representation of DATE-TIME.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string is not in the form of
+    /// `YYYY-MM-DDTHH:MM:SS` or does not specify a correct datetime.
+    pub fn parse(buf: &[u8]) -> Option<Self> {
+        let mut cursor = Cursor::new(buf);
+        let date = Date::parse_cursor(&mut cursor)?;
+        if !cursor.eat(b'T') {
+            return None;
+        }
+        let time = TimeOfDay::parse_cursor(&mut cursor)?;
+        if !cursor.is_end() {
+            return None;
+        }
+        return Some(DateTime {
+            date,
+            time,
+        });
+    }
+
+    /// Returns the date.
+    pub fn date(&self) -> &Date {
+        &self.date
+    }
+
+    /// Returns the time of day.
+    pub fn time(&self) -> &TimeOfDay {
+        &self.time
+    }
+
+    /// Returns ASN.1 representation of the datetime as `Vec<u8>`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(19);
+        self.date.push_bytes(&mut buf);
+        buf.push(b'T');
+        self.time.push_bytes(&mut buf);
+        return buf;
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&String::from_utf8(self.to_bytes()).unwrap())
+    }
+}
+
+/// A duration in the ISO 8601 form `PnYnMnDTnHnMnS` or `PnW`.
+///
+/// Any of the components may be omitted, but at least one must be present.
+/// The last component may have a decimal fraction.
+///
+/// Corresponds to ASN.1 DURATION type, which is TIME with the property
+/// settings `Basic=Interval Interval-type=D`.
+///
+/// When encoded, the decimal sign is always `.`.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::Duration;
+/// let duration = Duration::parse(b"P1Y2M10DT2H30M").unwrap();
+/// assert_eq!(duration.years(), Some(1));
+/// assert_eq!(duration.seconds(), None);
+/// let duration = Duration::parse(b"PT0,5S").unwrap();
+/// assert_eq!(duration.fraction(), b"5");
+/// assert_eq!(&duration.to_string(), "PT0.5S");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Duration {
+    years: Option<u64>,
+    months: Option<u64>,
+    weeks: Option<u64>,
+    days: Option<u64>,
+    hours: Option<u64>,
+    minutes: Option<u64>,
+    seconds: Option<u64>,
+    fraction: Vec<u8>,
+}
+
+/// The components of a [`Duration`] in the form `PnYnMnDTnHnMnS`, by name.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::{Duration,DurationComponents};
+/// let duration = Duration::from_components(DurationComponents {
+///     months: 1,
+///     minutes: 30,
+///     ..Default::default()
+/// });
+/// assert_eq!(&duration.to_string(), "P1MT30M");
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct DurationComponents {
+    /// The number of years.
+    pub years: u64,
+    /// The number of months.
+    pub months: u64,
+    /// The number of days.
+    pub days: u64,
+    /// The number of hours.
+    pub hours: u64,
+    /// The number of minutes.
+    pub minutes: u64,
+    /// The number of seconds.
+    pub seconds: u64,
+}
+
+impl Duration {
+    /// Constructs `Duration` from its components. Zero components are
+    /// omitted, except that the zero duration is `PT0S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::{Duration,DurationComponents};
+    /// let duration = Duration::from_components(DurationComponents {
+    ///     hours: 1,
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(&duration.to_string(), "PT1H");
+    /// let duration = Duration::from_components(Default::default());
+    /// assert_eq!(&duration.to_string(), "PT0S");
+    /// ```
+    pub fn from_components(components: DurationComponents) -> Self {
+        let some_if_nonzero = |value| if value != 0 { Some(value) } else { None };
+        let mut duration = Duration {
+            years: some_if_nonzero(components.years),
+            months: some_if_nonzero(components.months),
+            weeks: None,
+            days: some_if_nonzero(components.days),
+            hours: some_if_nonzero(components.hours),
+            minutes: some_if_nonzero(components.minutes),
+            seconds: some_if_nonzero(components.seconds),
+            fraction: Vec::new(),
+        };
+        if duration.components().iter().all(|c| c.is_none()) {
+            duration.seconds = Some(0);
+        }
+        return duration;
+    }
+
+    /// Constructs `Duration` in the form of `PnW`.
+    pub fn from_weeks(weeks: u64) -> Self {
+        Duration {
+            years: None,
+            months: None,
+            weeks: Some(weeks),
+            days: None,
+            hours: None,
+            minutes: None,
+            seconds: None,
+            fraction: Vec::new(),
+        }
+    }
+
+    /// Parses ASN.1 string representation of DURATION.
+    ///
+    /// Both `.` and `,` are accepted as the decimal sign.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string is not a correct duration.
+    pub fn parse(buf: &[u8]) -> Option<Self> {
+        let mut cursor = Cursor::new(buf);
+        let duration = Self::parse_cursor(&mut cursor)?;
+        if !cursor.is_end() {
+            return None;
+        }
+        return Some(duration);
+    }
+
+    fn parse_cursor(cursor: &mut Cursor) -> Option<Self> {
+        if !cursor.eat(b'P') {
+            return None;
+        }
+        let mut duration = Duration {
+            years: None,
+            months: None,
+            weeks: None,
+            days: None,
+            hours: None,
+            minutes: None,
+            seconds: None,
+            fraction: Vec::new(),
+        };
+        let mut in_time = false;
+        // The index of the next allowed designator in `designators`.
+        let mut next = 0;
+        let mut has_fraction = false;
+        let mut count = 0;
+        loop {
+            if !in_time && cursor.eat(b'T') {
+                if has_fraction {
+                    return None;
+                }
+                in_time = true;
+                next = 0;
+                if !cursor.peek().map_or(false, |b| b.is_ascii_digit()) {
+                    return None;
+                }
+            }
+            let digits = cursor.digit_run();
+            if digits.is_empty() {
+                break;
+            }
+            if has_fraction {
+                return None;
+            }
+            let value = digits_value(digits)?;
+            if cursor.peek() == Some(b'.') || cursor.peek() == Some(b',') {
+                cursor.pos += 1;
+                let fraction = cursor.digit_run();
+                if fraction.is_empty() {
+                    return None;
+                }
+                duration.fraction = fraction.to_vec();
+                has_fraction = true;
+            }
+            let designators : &[u8] = if in_time { b"HMS" } else { b"YMWD" };
+            let designator = cursor.peek()?;
+            let index = designators[next..].iter()
+                .position(|&d| d == designator)? + next;
+            cursor.pos += 1;
+            next = index + 1;
+            let component = match (in_time, designator) {
+                (false, b'Y') => &mut duration.years,
+                (false, b'M') => &mut duration.months,
+                (false, b'W') => &mut duration.weeks,
+                (false, _) => &mut duration.days,
+                (true, b'H') => &mut duration.hours,
+                (true, b'M') => &mut duration.minutes,
+                (true, _) => &mut duration.seconds,
+            };
+            *component = Some(value);
+            count += 1;
+        }
+        if count == 0 || (duration.weeks.is_some() && count != 1) {
+            return None;
+        }
+        return Some(duration);
+    }
+
+    /// Tells whether `buf` is the canonical representation of the duration
+    /// required in DER: the decimal sign is `.`, and the numbers have
+    /// no leading zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::Duration;
+    /// assert!(Duration::is_canonical_bytes(b"PT0.5S"));
+    /// assert!(!Duration::is_canonical_bytes(b"PT0,5S"));
+    /// assert!(!Duration::is_canonical_bytes(b"P01D"));
+    /// assert!(!Duration::is_canonical_bytes(b"P1X"));
+    /// ```
+    pub fn is_canonical_bytes(buf: &[u8]) -> bool {
+        return Self::parse(buf).map_or(false, |duration| {
+            duration.to_bytes() == buf
+        });
+    }
+
+    fn components(&self) -> [Option<u64>; 7] {
+        [self.years, self.months, self.weeks, self.days,
+            self.hours, self.minutes, self.seconds]
+    }
+
+    /// Returns the number of years, if present.
+    pub fn years(&self) -> Option<u64> {
+        self.years
+    }
+
+    /// Returns the number of months, if present.
+    pub fn months(&self) -> Option<u64> {
+        self.months
+    }
+
+    /// Returns the number of weeks, if present.
+    pub fn weeks(&self) -> Option<u64> {
+        self.weeks
+    }
+
+    /// Returns the number of days, if present.
+    pub fn days(&self) -> Option<u64> {
+        self.days
+    }
+
+    /// Returns the number of hours, if present.
+    pub fn hours(&self) -> Option<u64> {
+        self.hours
+    }
+
+    /// Returns the number of minutes, if present.
+    pub fn minutes(&self) -> Option<u64> {
+        self.minutes
+    }
+
+    /// Returns the number of seconds, if present.
+    pub fn seconds(&self) -> Option<u64> {
+        self.seconds
+    }
+
+    /// Returns the digits of the decimal fraction of the last component.
+    /// It is empty if the last component has no fraction.
+    pub fn fraction(&self) -> &[u8] {
+        &self.fraction
+    }
+
+    /// Returns ASN.1 representation of the duration as `Vec<u8>`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let components = self.components();
+        let last = components.iter().rposition(|c| c.is_some());
+        let mut buf = Vec::new();
+        buf.push(b'P');
+        for (i, (&component, &designator)) in
+                components.iter().zip(b"YMWDHMS").enumerate() {
+            if i == 4 && components[4..].iter().any(|c| c.is_some()) {
+                buf.push(b'T');
+            }
+            if let Some(value) = component {
+                buf.extend_from_slice(value.to_string().as_bytes());
+                if Some(i) == last && !self.fraction.is_empty() {
+                    buf.push(b'.');
+                    buf.extend_from_slice(&self.fraction);
+                }
+                buf.push(designator);
+            }
+        }
+        return buf;
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&String::from_utf8(self.to_bytes()).unwrap())
+    }
+}
+
+/// The `Basic` property of TIME.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TimeBasic {
+    /// `Basic=Date`
+    Date,
+    /// `Basic=Time`
+    Time,
+    /// `Basic=Date-Time`
+    DateTime,
+    /// `Basic=Interval`
+    Interval,
+    /// `Basic=Rec-Interval`
+    RecInterval,
+}
+
+/// The `Date` property of TIME.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DateSetting {
+    /// `Date=C`: a century
+    C,
+    /// `Date=Y`: a year
+    Y,
+    /// `Date=YM`: a year and a month
+    Ym,
+    /// `Date=YMD`: a year, a month and a day
+    Ymd,
+    /// `Date=YD`: a year and a day of the year
+    Yd,
+    /// `Date=YW`: a year and a week
+    Yw,
+    /// `Date=YWD`: a year, a week and a day of the week
+    Ywd,
+}
+
+/// The `Year` property of TIME.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum YearSetting {
+    /// `Year=Basic`: four digits from 1582 to 9999
+    Basic,
+    /// `Year=Proleptic`: four digits from 0000 to 1581
+    Proleptic,
+    /// `Year=Negative`: a sign and four digits
+    Negative,
+    /// `Year=Ln`: a sign and `n` (at least five) digits
+    Large(usize),
+}
+
+/// The `Time` property of TIME. The number in the fractional variants is
+/// the number of digits in the fraction.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TimeSetting {
+    /// `Time=H`
+    H,
+    /// `Time=HM`
+    Hm,
+    /// `Time=HMS`
+    Hms,
+    /// `Time=HFn`
+    Hf(usize),
+    /// `Time=HMFn`
+    Hmf(usize),
+    /// `Time=HMSFn`
+    Hmsf(usize),
+}
+
+/// The `Local-or-UTC` property of TIME.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LocalOrUtc {
+    /// `Local-or-UTC=L`: a local time
+    L,
+    /// `Local-or-UTC=Z`: UTC
+    Z,
+    /// `Local-or-UTC=LD`: a local time and its difference from UTC
+    Ld,
+}
+
+/// The `Interval-type` property of TIME.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum IntervalType {
+    /// `Interval-type=SE`: the start and the end
+    Se,
+    /// `Interval-type=D`: a duration
+    D,
+    /// `Interval-type=SD`: the start and a duration
+    Sd,
+    /// `Interval-type=DE`: a duration and the end
+    De,
+}
+
+/// The `Recurrence` property of TIME.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Recurrence {
+    /// `Recurrence=Unlimited`
+    Unlimited,
+    /// `Recurrence=Rn`: the number of recurrences has `n` digits
+    Digits(usize),
+}
+
+/// The property settings of a TIME value (X.680 38.4), inferred from its
+/// representation.
+///
+/// For intervals, the date and time properties are those of the first
+/// point of time in the value.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TimeSettings {
+    basic: TimeBasic,
+    date: Option<DateSetting>,
+    year: Option<YearSetting>,
+    time: Option<TimeSetting>,
+    local_or_utc: Option<LocalOrUtc>,
+    interval_type: Option<IntervalType>,
+    recurrence: Option<Recurrence>,
+}
+
+impl TimeSettings {
+    /// Returns the `Basic` property.
+    pub fn basic(&self) -> TimeBasic {
+        self.basic
+    }
+
+    /// Returns the `Date` property, if the value has a date.
+    pub fn date(&self) -> Option<DateSetting> {
+        self.date
+    }
+
+    /// Returns the `Year` property, if the value has a year.
+    pub fn year(&self) -> Option<YearSetting> {
+        self.year
+    }
+
+    /// Returns the `Time` property, if the value has a time of day.
+    pub fn time(&self) -> Option<TimeSetting> {
+        self.time
+    }
+
+    /// Returns the `Local-or-UTC` property, if the value has a time of day.
+    pub fn local_or_utc(&self) -> Option<LocalOrUtc> {
+        self.local_or_utc
+    }
+
+    /// Returns the `Interval-type` property, if the value is an interval.
+    pub fn interval_type(&self) -> Option<IntervalType> {
+        self.interval_type
+    }
+
+    /// Returns the `Recurrence` property, if the value is a recurring
+    /// interval.
+    pub fn recurrence(&self) -> Option<Recurrence> {
+        self.recurrence
+    }
+}
+
+impl Display for TimeSettings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(match self.basic {
+            TimeBasic::Date => "Basic=Date",
+            TimeBasic::Time => "Basic=Time",
+            TimeBasic::DateTime => "Basic=Date-Time",
+            TimeBasic::Interval => "Basic=Interval",
+            TimeBasic::RecInterval => "Basic=Rec-Interval",
+        })?;
+        if let Some(date) = self.date {
+            f.write_str(match date {
+                DateSetting::C => " Date=C",
+                DateSetting::Y => " Date=Y",
+                DateSetting::Ym => " Date=YM",
+                DateSetting::Ymd => " Date=YMD",
+                DateSetting::Yd => " Date=YD",
+                DateSetting::Yw => " Date=YW",
+                DateSetting::Ywd => " Date=YWD",
+            })?;
+        }
+        match self.year {
+            Some(YearSetting::Basic) => f.write_str(" Year=Basic")?,
+            Some(YearSetting::Proleptic) => f.write_str(" Year=Proleptic")?,
+            Some(YearSetting::Negative) => f.write_str(" Year=Negative")?,
+            Some(YearSetting::Large(n)) => write!(f, " Year=L{}", n)?,
+            None => {},
+        }
+        match self.time {
+            Some(TimeSetting::H) => f.write_str(" Time=H")?,
+            Some(TimeSetting::Hm) => f.write_str(" Time=HM")?,
+            Some(TimeSetting::Hms) => f.write_str(" Time=HMS")?,
+            Some(TimeSetting::Hf(n)) => write!(f, " Time=HF{}", n)?,
+            Some(TimeSetting::Hmf(n)) => write!(f, " Time=HMF{}", n)?,
+            Some(TimeSetting::Hmsf(n)) => write!(f, " Time=HMSF{}", n)?,
+            None => {},
+        }
+        if let Some(local_or_utc) = self.local_or_utc {
+            f.write_str(match local_or_utc {
+                LocalOrUtc::L => " Local-or-UTC=L",
+                LocalOrUtc::Z => " Local-or-UTC=Z",
+                LocalOrUtc::Ld => " Local-or-UTC=LD",
+            })?;
+        }
+        if let Some(interval_type) = self.interval_type {
+            f.write_str(match interval_type {
+                IntervalType::Se => " Interval-type=SE",
+                IntervalType::D => " Interval-type=D",
+                IntervalType::Sd => " Interval-type=SD",
+                IntervalType::De => " Interval-type=DE",
+            })?;
+        }
+        match self.recurrence {
+            Some(Recurrence::Unlimited) =>
+                f.write_str(" Recurrence=Unlimited")?,
+            Some(Recurrence::Digits(n)) => write!(f, " Recurrence=R{}", n)?,
+            None => {},
+        }
+        return Ok(());
+    }
+}
+
+fn parse_year(cursor: &mut Cursor) -> Option<(i64, YearSetting)> {
+    let sign = cursor.peek();
+    if sign == Some(b'+') || sign == Some(b'-') {
+        cursor.pos += 1;
+        let digits = cursor.digit_run();
+        if digits.len() < 4 {
+            return None;
+        }
+        let value = digits_value(digits)? as i64;
+        let value = if sign == Some(b'-') { -value } else { value };
+        let setting = if digits.len() == 4 {
+            YearSetting::Negative
+        } else {
+            YearSetting::Large(digits.len())
+        };
+        return Some((value, setting));
+    }
+    let value = cursor.digits(4)? as i64;
+    let setting = if value >= 1582 {
+        YearSetting::Basic
+    } else {
+        YearSetting::Proleptic
+    };
+    return Some((value, setting));
+}
+
+fn parse_date(cursor: &mut Cursor, settings: &mut TimeSettings) -> Option<()> {
+    if cursor.peek_at(0).map_or(false, |b| b.is_ascii_digit()) &&
+            cursor.peek_at(1).map_or(false, |b| b.is_ascii_digit()) &&
+            !cursor.peek_at(2).map_or(false, |b| b.is_ascii_digit()) {
+        cursor.digits(2)?;
+        settings.date = Some(DateSetting::C);
+        return Some(());
+    }
+    let (year, year_setting) = parse_year(cursor)?;
+    settings.year = Some(year_setting);
+    if !cursor.eat(b'-') {
+        settings.date = Some(DateSetting::Y);
+        return Some(());
+    }
+    if cursor.eat(b'W') {
+        let week = cursor.digits(2)?;
+        if !(1..=weeks_in_year(year)).contains(&week) {
+            return None;
+        }
+        if cursor.eat(b'-') {
+            let day = cursor.digits(1)?;
+            if !(1..=7).contains(&day) {
+                return None;
+            }
+            settings.date = Some(DateSetting::Ywd);
+        } else {
+            settings.date = Some(DateSetting::Yw);
+        }
+        return Some(());
+    }
+    let digits = cursor.digit_run();
+    if digits.len() == 3 {
+        let day = digits_value(digits)?;
+        let days = if is_leap_year(year) { 366 } else { 365 };
+        if !(1..=days).contains(&day) {
+            return None;
+        }
+        settings.date = Some(DateSetting::Yd);
+        return Some(());
+    }
+    if digits.len() != 2 {
+        return None;
+    }
+    let month = digits_value(digits)? as u8;
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+    if cursor.eat(b'-') {
+        let day = cursor.digits(2)? as u8;
+        if !(1..=days_in_month(year, month)).contains(&day) {
+            return None;
+        }
+        settings.date = Some(DateSetting::Ymd);
+    } else {
+        settings.date = Some(DateSetting::Ym);
+    }
+    return Some(());
+}
+
+fn parse_time(cursor: &mut Cursor, settings: &mut TimeSettings) -> Option<()> {
+    let hour = cursor.digits(2)?;
+    let mut fields = 1;
+    if cursor.eat(b':') {
+        let minute = cursor.digits(2)?;
+        fields = 2;
+        if minute >= 60 {
+            return None;
+        }
+        if cursor.eat(b':') {
+            let second = cursor.digits(2)?;
+            fields = 3;
+            if second >= 60 {
+                return None;
+            }
+        }
+    }
+    if hour >= 24 {
+        return None;
+    }
+    let mut fraction = None;
+    if cursor.peek() == Some(b'.') || cursor.peek() == Some(b',') {
+        cursor.pos += 1;
+        let digits = cursor.digit_run();
+        if digits.is_empty() {
+            return None;
+        }
+        fraction = Some(digits.len());
+    }
+    settings.time = Some(match (fields, fraction) {
+        (1, None) => TimeSetting::H,
+        (2, None) => TimeSetting::Hm,
+        (3, None) => TimeSetting::Hms,
+        (1, Some(n)) => TimeSetting::Hf(n),
+        (2, Some(n)) => TimeSetting::Hmf(n),
+        (_, n) => TimeSetting::Hmsf(n.unwrap_or(0)),
+    });
+    if let Some(TimeSetting::Hmsf(0)) = settings.time {
+        settings.time = Some(TimeSetting::Hms);
+    }
+    let sign = cursor.peek();
+    if cursor.eat(b'Z') {
+        settings.local_or_utc = Some(LocalOrUtc::Z);
+    } else if sign == Some(b'+') || sign == Some(b'-') {
+        cursor.pos += 1;
+        let hour = cursor.digits(2)?;
+        let minute = if cursor.eat(b':') { cursor.digits(2)? } else { 0 };
+        if !(hour < 24 && minute < 60) {
+            return None;
+        }
+        settings.local_or_utc = Some(LocalOrUtc::Ld);
+    } else {
+        settings.local_or_utc = Some(LocalOrUtc::L);
+    }
+    return Some(());
+}
+
+/// Parses a date, a time of day or a date-time.
+fn parse_point(cursor: &mut Cursor, settings: &mut TimeSettings)
+        -> Option<TimeBasic> {
+    if cursor.peek_at(2) == Some(b':') {
+        parse_time(cursor, settings)?;
+        return Some(TimeBasic::Time);
+    }
+    parse_date(cursor, settings)?;
+    if cursor.eat(b'T') {
+        if settings.date == Some(DateSetting::C) {
+            return None;
+        }
+        parse_time(cursor, settings)?;
+        return Some(TimeBasic::DateTime);
+    }
+    return Some(TimeBasic::Date);
+}
+
+/// A value of the generic ASN.1 TIME type, which is an ISO 8601
+/// representation of a date, a time, a date-time, an interval or
+/// a recurring interval.
+///
+/// The value is kept as is, together with the property settings
+/// ([`TimeSettings`]) inferred from it.
+///
+/// Only the extended formats of ISO 8601 (with `-` and `:` separators)
+/// are supported. A time of day must have at least hours and minutes
+/// unless it is a part of a date-time.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::{Time,TimeBasic};
+/// let time = Time::parse(b"2019-10-19T09:30:00.5+09:00").unwrap();
+/// assert_eq!(time.settings().basic(), TimeBasic::DateTime);
+/// assert_eq!(&time.settings().to_string(),
+///     "Basic=Date-Time Date=YMD Year=Basic Time=HMSF1 Local-or-UTC=LD");
+/// let time = Time::parse(b"R/2019-W42/P1W").unwrap();
+/// assert_eq!(&time.settings().to_string(),
+///     "Basic=Rec-Interval Date=YW Year=Basic Interval-type=SD \
+///     Recurrence=Unlimited");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Time {
+    string: String,
+    settings: TimeSettings,
+}
+
+impl Time {
+    /// Parses ASN.1 string representation of TIME.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string is not a correct TIME value.
+    pub fn parse(buf: &[u8]) -> Option<Self> {
+        let mut cursor = Cursor::new(buf);
+        let mut settings = TimeSettings {
+            basic: TimeBasic::Date,
+            date: None,
+            year: None,
+            time: None,
+            local_or_utc: None,
+            interval_type: None,
+            recurrence: None,
+        };
+        let recurring = cursor.eat(b'R');
+        if recurring {
+            let digits = cursor.digit_run();
+            settings.recurrence = Some(if digits.is_empty() {
+                Recurrence::Unlimited
+            } else {
+                Recurrence::Digits(digits.len())
+            });
+            if !cursor.eat(b'/') {
+                return None;
+            }
+        }
+        let first_is_duration = cursor.peek() == Some(b'P');
+        let basic = if first_is_duration {
+            Duration::parse_cursor(&mut cursor)?;
+            None
+        } else {
+            Some(parse_point(&mut cursor, &mut settings)?)
+        };
+        if cursor.eat(b'/') {
+            let second_is_duration = cursor.peek() == Some(b'P');
+            if second_is_duration {
+                Duration::parse_cursor(&mut cursor)?;
+            } else if first_is_duration {
+                parse_point(&mut cursor, &mut settings)?;
+            } else {
+                // Keep the settings of the first point.
+                let mut end_settings = settings;
+                parse_point(&mut cursor, &mut end_settings)?;
+            }
+            settings.interval_type = Some(
+                match (first_is_duration, second_is_duration) {
+                    (false, false) => IntervalType::Se,
+                    (false, true) => IntervalType::Sd,
+                    (true, false) => IntervalType::De,
+                    (true, true) => return None,
+                });
+            settings.basic = TimeBasic::Interval;
+        } else if first_is_duration {
+            settings.interval_type = Some(IntervalType::D);
+            settings.basic = TimeBasic::Interval;
+        } else if recurring {
+            return None;
+        } else {
+            settings.basic = basic?;
+        }
+        if recurring {
+            settings.basic = TimeBasic::RecInterval;
+        }
+        if !cursor.is_end() {
+            return None;
+        }
+        return Some(Time {
+            string: String::from_utf8(buf.to_vec()).ok()?,
+            settings,
+        });
+    }
+
+    /// Tells whether the value is in the canonical form required in DER:
+    /// the decimal sign is `.`, and the durations in it are canonical as in
+    /// [`Duration::is_canonical_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::Time;
+    /// assert!(Time::parse(b"2019-10-19T09:30:00.5Z").unwrap().is_canonical());
+    /// assert!(!Time::parse(b"2019-10-19T09:30:00,5Z").unwrap().is_canonical());
+    /// assert!(!Time::parse(b"R/2019-W42/P01D").unwrap().is_canonical());
+    /// ```
+    pub fn is_canonical(&self) -> bool {
+        let bytes = self.string.as_bytes();
+        // Points don't contain `P`, and durations don't contain `/`.
+        return !bytes.contains(&b',') && bytes.split(|&b| b == b'/')
+            .filter(|part| part.first() == Some(&b'P'))
+            .all(Duration::is_canonical_bytes);
+    }
+
+    /// Returns the value as `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// Returns the property settings of the value.
+    pub fn settings(&self) -> &TimeSettings {
+        &self.settings
+    }
+
+    /// Returns ASN.1 representation of the value as `Vec<u8>`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.string.as_bytes().to_vec()
+    }
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&self.string)
+    }
+}
+
+impl From<Date> for Time {
+    fn from(date: Date) -> Self {
+        Time::parse(&date.to_bytes()).unwrap()
+    }
+}
+
+impl From<TimeOfDay> for Time {
+    fn from(time: TimeOfDay) -> Self {
+        Time::parse(&time.to_bytes()).unwrap()
+    }
+}
+
+impl From<DateTime> for Time {
+    fn from(datetime: DateTime) -> Self {
+        Time::parse(&datetime.to_bytes()).unwrap()
+    }
+}
+
+impl From<Duration> for Time {
+    fn from(duration: Duration) -> Self {
+        Time::parse(&duration.to_bytes()).unwrap()
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Date> for ::time::Date {
+    fn from(date: Date) -> Self {
+        // DATE is always in the range of time
+        let month = ::time::Month::try_from(date.month).unwrap();
+        return ::time::Date::from_calendar_date(
+            date.year as i32, month, date.day).unwrap();
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<::time::Date> for Date {
+    type Error = DateTimeRangeError;
+
+    fn try_from(date: ::time::Date) -> Result<Self, Self::Error> {
+        if !(1582 <= date.year() && date.year() <= 9999) {
+            return Err(DateTimeRangeError(()));
+        }
+        return Ok(Date {
+            year: date.year() as u16,
+            month: date.month() as u8,
+            day: date.day(),
+        });
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<TimeOfDay> for ::time::Time {
+    fn from(time: TimeOfDay) -> Self {
+        ::time::Time::from_hms(time.hour, time.minute, time.second).unwrap()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<::time::Time> for TimeOfDay {
+    type Error = DateTimeRangeError;
+
+    /// Converts `time::Time` into `TimeOfDay`.
+    ///
+    /// It fails if the time has a fraction of a second.
+    fn try_from(time: ::time::Time) -> Result<Self, Self::Error> {
+        if time.nanosecond() != 0 {
+            return Err(DateTimeRangeError(()));
+        }
+        return Ok(TimeOfDay {
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+        });
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<DateTime> for ::time::PrimitiveDateTime {
+    fn from(datetime: DateTime) -> Self {
+        ::time::PrimitiveDateTime::new(
+            datetime.date.into(), datetime.time.into())
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<::time::PrimitiveDateTime> for DateTime {
+    type Error = DateTimeRangeError;
+
+    fn try_from(datetime: ::time::PrimitiveDateTime)
+            -> Result<Self, Self::Error> {
+        return Ok(DateTime {
+            date: Date::try_from(datetime.date())?,
+            time: TimeOfDay::try_from(datetime.time())?,
+        });
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<Duration> for ::time::Duration {
+    type Error = DateTimeRangeError;
+
+    /// Converts `Duration` into `time::Duration`.
+    ///
+    /// It fails if the duration has years or months, whose lengths vary.
+    /// Fractions finer than nanoseconds are truncated.
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        if duration.years.is_some() || duration.months.is_some() {
+            return Err(DateTimeRangeError(()));
+        }
+        let units : [(Option<u64>, u128); 5] = [
+            (duration.weeks, 604800),
+            (duration.days, 86400),
+            (duration.hours, 3600),
+            (duration.minutes, 60),
+            (duration.seconds, 1),
+        ];
+        let mut nanoseconds : u128 = 0;
+        let mut last_unit = 1;
+        for &(value, unit) in units.iter() {
+            if let Some(value) = value {
+                nanoseconds += value as u128 * unit * 1_000_000_000;
+                last_unit = unit;
+            }
+        }
+        // Further digits don't affect nanoseconds.
+        let significant = duration.fraction.len().min(20);
+        let numerator = digits_value(&duration.fraction[..significant])
+            .unwrap_or(0) as u128;
+        nanoseconds += numerator * last_unit * 1_000_000_000
+            / 10u128.pow(significant as u32);
+        let seconds = i64::try_from(nanoseconds / 1_000_000_000)
+            .map_err(|_| DateTimeRangeError(()))?;
+        return Ok(::time::Duration::new(
+            seconds, (nanoseconds % 1_000_000_000) as i32));
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<::time::Duration> for Duration {
+    type Error = DateTimeRangeError;
+
+    /// Converts `time::Duration` into `Duration` in the form of
+    /// `PnDTnHnMnS`.
+    ///
+    /// It fails if the duration is negative.
+    fn try_from(duration: ::time::Duration) -> Result<Self, Self::Error> {
+        if duration.is_negative() {
+            return Err(DateTimeRangeError(()));
+        }
+        let seconds = duration.whole_seconds() as u64;
+        let mut result = Duration::from_components(DurationComponents {
+            days: seconds / 86400,
+            hours: seconds / 3600 % 24,
+            minutes: seconds / 60 % 60,
+            seconds: seconds % 60,
+            ..Default::default()
+        });
+        let nanoseconds = duration.subsec_nanoseconds() as u32;
+        if nanoseconds != 0 {
+            if result.seconds.is_none() {
+                result.seconds = Some(0);
+            }
+            let mut fraction = Vec::with_capacity(9);
+            push_digits(&mut fraction, nanoseconds, 9);
+            while fraction.last() == Some(&b'0') {
+                fraction.pop();
+            }
+            result.fraction = fraction;
+        }
+        return Ok(result);
+    }
+}
+
+#[test]
+fn test_date_parse() {
+    let date = Date::parse(b"2000-02-29").unwrap();
+    assert_eq!((date.year(), date.month(), date.day()), (2000, 2, 29));
+    let tests : &[(&[u8], bool)] = &[
+        (b"2019-10-19", true),
+        (b"1582-01-01", true),
+        (b"1581-12-31", false),
+        (b"1900-02-29", false),
+        (b"2019-1-19", false),
+        (b"20191019", false),
+        (b"2019-10-19T", false),
+    ];
+    for &(buf, ok) in tests {
+        let date = Date::parse(buf);
+        assert_eq!(date.is_some(), ok);
+        if let Some(date) = date {
+            assert_eq!(date.to_bytes(), buf);
+        }
+    }
+    assert!(TimeOfDay::parse(b"23:59:59").is_some());
+    assert!(TimeOfDay::parse(b"24:00:00").is_none());
+    assert!(TimeOfDay::parse(b"23:59").is_none());
+    assert!(TimeOfDay::parse(b"23:59:59Z").is_none());
+    assert!(DateTime::parse(b"2019-10-19T23:59:59").is_some());
+    assert!(DateTime::parse(b"2019-10-19 23:59:59").is_none());
+}
+
+#[test]
+fn test_duration_parse() {
+    let tests : &[(&[u8], Option<&[u8]>)] = &[
+        (b"P1Y2M10DT2H30M", Some(b"P1Y2M10DT2H30M")),
+        (b"P3W", Some(b"P3W")),
+        (b"PT36H", Some(b"PT36H")),
+        (b"P0D", Some(b"P0D")),
+        (b"PT1,50S", Some(b"PT1.50S")),
+        (b"P1.5Y", Some(b"P1.5Y")),
+        (b"P", None),
+        (b"PT", None),
+        (b"P1DT", None),
+        (b"P1W2D", None),
+        (b"P1D1Y", None),
+        (b"P1Y1Y", None),
+        (b"P1.5Y2M", None),
+        (b"P1.5DT1H", None),
+        (b"P1H", None),
+        (b"PT1D", None),
+        (b"P1.D", None),
+        (b"1D", None),
+    ];
+    for &(buf, expected) in tests {
+        assert_eq!(Duration::parse(buf).map(|d| d.to_bytes()).as_deref(),
+            expected);
+    }
+}
+
+#[test]
+fn test_iso_time_der() {
+    use crate::{decode_ber, decode_der, ASN1ErrorKind};
+    let tests : &[(&[u8], bool)] = &[
+        (b"\x1f\x22\x06PT0.5S", true),
+        (b"\x1f\x22\x06PT0,5S", false),
+        (b"\x1f\x22\x04P01D", false),
+        (b"\x0e\x062019-W", false),
+        (b"\x0e\x0f2019-W42/PT0.5S", true),
+        (b"\x0e\x0f2019-W42/PT0,5S", false),
+        (b"\x0e\x0d2019-W42/P01D", false),
+        (b"\x0e\x0812:30,5Z", false),
+    ];
+    for &(der, canonical) in tests {
+        if der[0] == 0x1f {
+            let duration = decode_ber::<Duration>(der).unwrap();
+            let result = decode_der::<Duration>(der);
+            assert_eq!(result.as_ref().ok(), canonical.then(|| &duration));
+            if !canonical {
+                assert_eq!(result.unwrap_err().kind(),
+                    ASN1ErrorKind::NonCanonicalTime);
+            }
+        } else if let Ok(time) = decode_ber::<Time>(der) {
+            let result = decode_der::<Time>(der);
+            assert_eq!(result.as_ref().ok(), canonical.then(|| &time));
+            if !canonical {
+                assert_eq!(result.unwrap_err().kind(),
+                    ASN1ErrorKind::NonCanonicalTime);
+            }
+        } else {
+            assert!(!canonical);
+        }
+    }
+    let date = decode_der::<Date>(b"\x1f\x1f\x0a2019-10-19").unwrap();
+    assert_eq!(date, Date::new(2019, 10, 19).unwrap());
+    let err = decode_ber::<Date>(b"\x1f\x1f\x0820191019").unwrap_err();
+    assert_eq!(err.kind(), ASN1ErrorKind::Invalid);
+}
+
+#[test]
+fn test_time_settings() {
+    let tests : &[(&[u8], &str)] = &[
+        (b"2019-10-19", "Basic=Date Date=YMD Year=Basic"),
+        (b"19", "Basic=Date Date=C"),
+        (b"2019", "Basic=Date Date=Y Year=Basic"),
+        (b"1000-10", "Basic=Date Date=YM Year=Proleptic"),
+        (b"2019-292", "Basic=Date Date=YD Year=Basic"),
+        (b"2020-W53-7", "Basic=Date Date=YWD Year=Basic"),
+        (b"-0044-03-15", "Basic=Date Date=YMD Year=Negative"),
+        (b"+12019-10", "Basic=Date Date=YM Year=L5"),
+        (b"09:30", "Basic=Time Time=HM Local-or-UTC=L"),
+        (b"09:30:00Z", "Basic=Time Time=HMS Local-or-UTC=Z"),
+        (b"09:30,25-05", "Basic=Time Time=HMF2 Local-or-UTC=LD"),
+        (b"2019-10-19T09", "Basic=Date-Time Date=YMD Year=Basic \
+            Time=H Local-or-UTC=L"),
+        (b"P1D", "Basic=Interval Interval-type=D"),
+        (b"2019-10-19/2019-10-20",
+            "Basic=Interval Date=YMD Year=Basic Interval-type=SE"),
+        (b"P1D/2019-10-20T00:00Z", "Basic=Interval Date=YMD Year=Basic \
+            Time=HM Local-or-UTC=Z Interval-type=DE"),
+        (b"R5/09:00/PT1H", "Basic=Rec-Interval Time=HM Local-or-UTC=L \
+            Interval-type=SD Recurrence=R1"),
+    ];
+    for &(buf, settings) in tests {
+        let time = Time::parse(buf).unwrap();
+        assert_eq!(time.settings().to_string(), settings);
+        assert_eq!(time.to_bytes(), buf);
+    }
+    let tests : &[&[u8]] = &[
+        b"", b"2019-13", b"2019-366", b"2019-W53", b"2019-W01-8",
+        b"19T09:00", b"24:00", b"09:60", b"P1D/P1D", b"R/2019-10-19",
+        b"2019-10-19/", b"2019-10-19T09:00Z/", b"R5P1D",
+    ];
+    for &buf in tests {
+        assert!(Time::parse(buf).is_none());
+    }
+    assert_eq!(Time::from(Duration::from_weeks(2)).settings().basic(),
+        TimeBasic::Interval);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_iso_time_conversions() {
+    let date = Date::new(2019, 10, 19).unwrap();
+    let time = TimeOfDay::new(9, 30, 0).unwrap();
+    let datetime = ::time::PrimitiveDateTime::from(DateTime::new(date, time));
+    assert_eq!(DateTime::try_from(datetime).unwrap(), DateTime::new(date, time));
+    assert!(Date::try_from(::time::Date::from_calendar_date(
+        1000, ::time::Month::January, 1).unwrap()).is_err());
+
+    let tests : &[(&[u8], i64, i32)] = &[
+        (b"P1DT1H1M1.5S", 90061, 500_000_000),
+        (b"P1W", 604800, 0),
+        (b"PT0.5H", 1800, 0),
+        (b"PT0S", 0, 0),
+        (b"PT0.000000001S", 0, 1),
+    ];
+    for &(buf, seconds, nanoseconds) in tests {
+        let duration = Duration::parse(buf).unwrap();
+        let converted = ::time::Duration::try_from(duration).unwrap();
+        assert_eq!(converted, ::time::Duration::new(seconds, nanoseconds));
+        if !buf.contains(&b'W') && buf != b"PT0.5H" {
+            assert_eq!(Duration::try_from(converted).unwrap().to_bytes(), buf);
+        }
+    }
+    assert!(::time::Duration::try_from(Duration::parse(b"P1M").unwrap())
+        .is_err());
+    assert!(Duration::try_from(::time::Duration::new(-1, 0)).is_err());
+}
@@ -11,11 +11,36 @@
 #![forbid(missing_docs)]
 
 mod oid;
+mod oid_ref;
 #[cfg(feature = "time")]
 mod time;
 mod der;
+mod strings;
+mod raw;
+mod bit_string;
+mod raw_time;
+mod iso_time;
+mod time_fields;
+#[cfg(feature = "oid-registry")]
+mod oid_registry;
 
 pub use self::oid::{ObjectIdentifier, ParseOidError};
+pub use self::oid_ref::{Oid,OidArcs,OidBuf};
+pub(crate) use self::oid_ref::OidBytesError;
+#[cfg(feature = "oid-registry")]
+pub use self::oid_registry::{OidEntry,OidRegistry,OidDisplay,WellKnownOids};
 #[cfg(feature = "time")]
-pub use self::time::{UTCTime,GeneralizedTime};
+pub use self::time::{UTCTime,GeneralizedTime,TimeStrictness};
+#[cfg(feature = "time")]
+pub use self::time::{UTCTimeWindow,X509Time};
 pub use self::der::TaggedDerValue;
+pub use self::raw::{WithRaw,WithRawRef};
+pub use self::bit_string::BitString;
+pub use self::raw_time::{RawDateTime,DateTimeRangeError};
+pub use self::iso_time::{Date,TimeOfDay,DateTime,Duration,DurationComponents};
+pub use self::iso_time::{Time,TimeSettings};
+pub use self::iso_time::{TimeBasic,DateSetting,YearSetting,TimeSetting};
+pub use self::iso_time::{LocalOrUtc,IntervalType,Recurrence};
+pub use self::time_fields::{GeneralizedTimeFields,GeneralizedTimeZone};
+pub use self::strings::{NumericString,PrintableString,Ia5String,VisibleString};
+pub use self::strings::{BmpString,Utf8String,DirectoryString,InvalidStringError};
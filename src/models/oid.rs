@@ -6,6 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use core::cmp::Ordering;
 use core::fmt::{self, Display};
 #[cfg(feature = "std")]
 use std::error::Error;
@@ -14,7 +15,21 @@ use alloc::vec::Vec;
 
 /// A type that represents object identifiers.
 ///
-/// This is actually a thin wrapper of `Vec<u64>`.
+/// This is actually a thin wrapper of `Vec<u64>`. Arcs which do not fit in
+/// `u64`, such as the UUID-based `2.25.<128-bit>` OIDs, are also supported
+/// up to `u128`; see [`ObjectIdentifier::arcs`].
+///
+/// The arcs are validated on construction: there must be at least two
+/// arcs, the first arc must be 0, 1 or 2, and the second arc must be less
+/// than 40 under the roots 0 and 1. [`ObjectIdentifier::new`] and
+/// [`ObjectIdentifier::from_slice`] panic on invalid arcs, while
+/// [`ObjectIdentifier::try_new`], [`ObjectIdentifier::try_from_slice`],
+/// [`ObjectIdentifier::from_arcs`], `FromStr` and
+/// [`BERReader::read_oid`](crate::BERReader::read_oid) fail.
+///
+/// The accessors of the `u64` components, such as
+/// [`ObjectIdentifier::components`], panic if some of the arcs do not fit
+/// in `u64`. Use [`ObjectIdentifier::arcs`] for such OIDs.
 ///
 /// # Examples
 ///
@@ -24,14 +39,21 @@ use alloc::vec::Vec;
 ///     [1, 2, 840, 113549, 1, 1, 12]);
 /// println!("{}", sha384WithRSAEncryption);
 /// ```
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Eq, PartialEq, Hash)]
 pub struct ObjectIdentifier {
     components: Vec<u64>,
+    // All the arcs if some of them do not fit in `u64`; empty otherwise.
+    large_components: Vec<u128>,
 }
 
 impl ObjectIdentifier {
     /// Constructs a new `ObjectIdentifier` from `Vec<u64>`.
     ///
+    /// # Panics
+    ///
+    /// Panics if the arcs are invalid. Use [`ObjectIdentifier::try_new`]
+    /// to check them instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -41,13 +63,22 @@ impl ObjectIdentifier {
     /// println!("{}", pkcs1);
     /// ```
     pub fn new(components: Vec<u64>) -> Self {
+        assert!(
+            check_arcs(components.iter().map(|&arc| arc as u128)),
+            "Invalid OID {:?}", components);
         return ObjectIdentifier {
             components,
+            large_components: Vec::new(),
         };
     }
 
     /// Constructs a new `ObjectIdentifier` from `&[u64]`.
     ///
+    /// # Panics
+    ///
+    /// Panics if the arcs are invalid. Use
+    /// [`ObjectIdentifier::try_from_slice`] to check them instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -57,13 +88,101 @@ impl ObjectIdentifier {
     /// println!("{}", pkcs1);
     /// ```
     pub fn from_slice(components: &[u64]) -> Self {
+        return Self::new(components.to_vec());
+    }
+
+    /// Constructs a new `ObjectIdentifier` from `Vec<u64>`, returning `None`
+    /// if the arcs are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::ObjectIdentifier;
+    /// assert!(ObjectIdentifier::try_new(vec![1, 2, 840]).is_some());
+    /// assert!(ObjectIdentifier::try_new(vec![3, 1]).is_none());
+    /// ```
+    pub fn try_new(components: Vec<u64>) -> Option<Self> {
+        if !check_arcs(components.iter().map(|&arc| arc as u128)) {
+            return None;
+        }
+        return Some(ObjectIdentifier {
+            components,
+            large_components: Vec::new(),
+        });
+    }
+
+    /// Constructs a new `ObjectIdentifier` from `&[u64]`, returning `None`
+    /// if the arcs are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::ObjectIdentifier;
+    /// assert!(ObjectIdentifier::try_from_slice(&[1, 2, 840]).is_some());
+    /// assert!(ObjectIdentifier::try_from_slice(&[1, 40]).is_none());
+    /// ```
+    pub fn try_from_slice(components: &[u64]) -> Option<Self> {
+        return Self::try_new(components.to_vec());
+    }
+
+    /// Constructs a new `ObjectIdentifier` from `&[u128]`, returning `None`
+    /// if the arcs are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::ObjectIdentifier;
+    /// let uuid = ObjectIdentifier::from_arcs(&
+    ///     [2, 25, 0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6]).unwrap();
+    /// assert_eq!(&uuid.to_string(),
+    ///     "2.25.329800735698586629295641978511506172918");
+    /// assert!(ObjectIdentifier::from_arcs(&[1, 40]).is_none());
+    /// ```
+    pub fn from_arcs(arcs: &[u128]) -> Option<Self> {
+        if !check_arcs(arcs.iter().cloned()) {
+            return None;
+        }
+        return Some(Self::from_arcs_unchecked(arcs.to_vec()));
+    }
+
+    pub(crate) fn from_arcs_unchecked(arcs: Vec<u128>) -> Self {
+        if arcs.iter().all(|&arc| arc <= u64::MAX as u128) {
+            return ObjectIdentifier {
+                components: arcs.iter().map(|&arc| arc as u64).collect(),
+                large_components: Vec::new(),
+            };
+        }
         return ObjectIdentifier {
-            components: components.to_vec(),
+            components: Vec::new(),
+            large_components: arcs,
         };
     }
 
+    /// Returns an iterator over the arcs.
+    ///
+    /// Unlike [`ObjectIdentifier::components`], it also works for arcs
+    /// which do not fit in `u64`.
+    pub fn arcs(&self) -> impl Iterator<Item = u128> + '_ {
+        let components = self.components.iter().map(|&arc| arc as u128);
+        components.chain(self.large_components.iter().cloned())
+    }
+
+    /// Returns the number of arcs.
+    pub fn num_arcs(&self) -> usize {
+        self.components.len() + self.large_components.len()
+    }
+
+    /// Returns `true` if some of the arcs do not fit in `u64`.
+    pub fn has_large_arcs(&self) -> bool {
+        !self.large_components.is_empty()
+    }
+
     /// Borrows its internal vector of components.
     ///
+    /// # Panics
+    ///
+    /// Panics if some of the arcs do not fit in `u64`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -73,11 +192,18 @@ impl ObjectIdentifier {
     /// let components : &Vec<u64> = pkcs1.components();
     /// ```
     pub fn components(&self) -> &Vec<u64> {
+        assert!(!self.has_large_arcs(), "OID arc does not fit in u64");
         &self.components
     }
 
     /// Mutably borrows its internal vector of components.
     ///
+    /// The arcs are not validated again after modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some of the arcs do not fit in `u64`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -87,11 +213,16 @@ impl ObjectIdentifier {
     /// let components : &mut Vec<u64> = pkcs1.components_mut();
     /// ```
     pub fn components_mut(&mut self) -> &mut Vec<u64> {
+        assert!(!self.has_large_arcs(), "OID arc does not fit in u64");
         &mut self.components
     }
 
     /// Extracts its internal vector of components.
     ///
+    /// # Panics
+    ///
+    /// Panics if some of the arcs do not fit in `u64`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -101,14 +232,119 @@ impl ObjectIdentifier {
     /// let mut components : Vec<u64> = pkcs1.into_components();
     /// ```
     pub fn into_components(self) -> Vec<u64> {
+        assert!(!self.has_large_arcs(), "OID arc does not fit in u64");
         self.components
     }
+
+    /// Returns `true` if `prefix` is a prefix of this OID, including the
+    /// case where they are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::ObjectIdentifier;
+    /// let pkcs1 = ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 1]);
+    /// let rsa = ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 1, 1]);
+    /// assert!(rsa.starts_with(&pkcs1));
+    /// assert!(pkcs1.is_prefix_of(&rsa));
+    /// assert!(!pkcs1.starts_with(&rsa));
+    /// ```
+    pub fn starts_with(&self, prefix: &ObjectIdentifier) -> bool {
+        return prefix.num_arcs() <= self.num_arcs() &&
+            prefix.arcs().zip(self.arcs()).all(|(a, b)| a == b);
+    }
+
+    /// Returns `true` if this OID is a prefix of `other`, including the
+    /// case where they are equal.
+    pub fn is_prefix_of(&self, other: &ObjectIdentifier) -> bool {
+        return other.starts_with(self);
+    }
+
+    /// Returns the OID without the last arc, or `None` if it has only two
+    /// arcs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::ObjectIdentifier;
+    /// let rsa = ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 1, 1]);
+    /// assert_eq!(rsa.parent().unwrap().to_string(), "1.2.840.113549.1.1");
+    /// assert!(ObjectIdentifier::from_slice(&[1, 2]).parent().is_none());
+    /// ```
+    pub fn parent(&self) -> Option<ObjectIdentifier> {
+        if self.num_arcs() <= 2 {
+            return None;
+        }
+        let mut arcs = self.arcs().collect::<Vec<_>>();
+        arcs.pop();
+        return Some(Self::from_arcs_unchecked(arcs));
+    }
+
+    /// Returns the OID with `arc` appended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::ObjectIdentifier;
+    /// let pkcs1 = ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 1]);
+    /// assert_eq!(pkcs1.child(11).to_string(), "1.2.840.113549.1.1.11");
+    /// ```
+    pub fn child(&self, arc: u128) -> ObjectIdentifier {
+        let mut arcs = self.arcs().collect::<Vec<_>>();
+        arcs.push(arc);
+        return Self::from_arcs_unchecked(arcs);
+    }
+}
+
+/// Checks the constraints on the first two arcs.
+fn check_arcs<I: Iterator<Item = u128>>(mut arcs: I) -> bool {
+    let (id0, id1) = match (arcs.next(), arcs.next()) {
+        (Some(id0), Some(id1)) => (id0, id1),
+        _ => return false,
+    };
+    return match id0 {
+        0 | 1 => id1 < 40,
+        2 => id1 <= u128::MAX - 80,
+        _ => false,
+    };
+}
+
+impl PartialOrd for ObjectIdentifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ObjectIdentifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.arcs().cmp(other.arcs())
+    }
+}
+
+impl fmt::Debug for ObjectIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        if self.has_large_arcs() {
+            return f.debug_struct("ObjectIdentifier")
+                .field("components", &self.large_components)
+                .finish();
+        }
+        f.debug_struct("ObjectIdentifier")
+            .field("components", &self.components)
+            .finish()
+    }
 }
 
 impl Display for ObjectIdentifier {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        #[cfg(feature = "oid-registry")]
+        if f.alternate() {
+            let arcs = self.arcs().collect::<Vec<_>>();
+            if let Some(oid) = super::OidBuf::from_arcs(&arcs) {
+                return super::oid_registry::fmt_alternate(oid.as_oid(), f);
+            }
+        }
         let mut fst = true;
-        for &component in &self.components {
+        for component in self.arcs() {
             if fst {
                 write!(f, "{}", component)?;
             } else {
@@ -138,20 +374,24 @@ impl FromStr for ObjectIdentifier {
     type Err = ParseOidError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.split(".")
+        let arcs = s.split(".")
             .map(|s| s.parse().map_err(|_| ParseOidError(()) ))
-            .collect::<Result<_, _>>()
-            .map(ObjectIdentifier::new)
+            .collect::<Result<Vec<u128>, _>>()?;
+        ObjectIdentifier::from_arcs(&arcs).ok_or(ParseOidError(()))
     }
 }
 
 impl AsRef<[u64]> for ObjectIdentifier {
+    /// Same as [`ObjectIdentifier::components`], it panics if some of the
+    /// arcs do not fit in `u64`.
     fn as_ref(&self) -> &[u64] {
-        &self.components
+        self.components()
     }
 }
 
 impl From<Vec<u64>> for ObjectIdentifier {
+    /// Same as [`ObjectIdentifier::new`], it panics if the arcs are
+    /// invalid.
     fn from(components: Vec<u64>) -> ObjectIdentifier {
         Self::new(components)
     }
@@ -171,3 +411,79 @@ fn parse_oid() {
     "1.2.840.113549.1.1x".parse::<ObjectIdentifier>().unwrap_err();
     "".parse::<ObjectIdentifier>().unwrap_err();
 }
+
+#[test]
+fn test_oid_validation() {
+    assert!(ObjectIdentifier::from_arcs(&[0, 39]).is_some());
+    assert!(ObjectIdentifier::from_arcs(&[1, 39, 5]).is_some());
+    assert!(ObjectIdentifier::from_arcs(&[2, 999]).is_some());
+    assert!(ObjectIdentifier::from_arcs(&[2, u128::MAX - 80]).is_some());
+    assert!(ObjectIdentifier::from_arcs(&[2, u128::MAX - 79]).is_none());
+    assert!(ObjectIdentifier::from_arcs(&[0, 40]).is_none());
+    assert!(ObjectIdentifier::from_arcs(&[1, 40]).is_none());
+    assert!(ObjectIdentifier::from_arcs(&[3, 1]).is_none());
+    assert!(ObjectIdentifier::from_arcs(&[1]).is_none());
+    assert!(ObjectIdentifier::from_arcs(&[]).is_none());
+    "1".parse::<ObjectIdentifier>().unwrap_err();
+    "3.1".parse::<ObjectIdentifier>().unwrap_err();
+    "1.40".parse::<ObjectIdentifier>().unwrap_err();
+}
+
+#[test]
+fn test_oid_try_new() {
+    assert!(ObjectIdentifier::try_new([1, 40, 1].to_vec()).is_none());
+    assert!(ObjectIdentifier::try_from_slice(&[1, 39, 1]).is_some());
+    assert!(ObjectIdentifier::try_from_slice(&[1]).is_none());
+}
+
+#[test]
+#[should_panic]
+fn test_oid_new_invalid() {
+    ObjectIdentifier::new([1, 40, 1].to_vec());
+}
+
+#[test]
+#[should_panic]
+fn test_oid_from_invalid() {
+    let _ = ObjectIdentifier::from([3, 1].to_vec());
+}
+
+#[test]
+#[should_panic]
+fn test_oid_large_arcs_components() {
+    let oid = ObjectIdentifier::from_arcs(&[2, 25, 1 << 64]).unwrap();
+    oid.components();
+}
+
+#[test]
+fn test_oid_large_arcs() {
+    use alloc::format;
+    let uuid = 0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6_u128;
+    let oid = "2.25.329800735698586629295641978511506172918"
+        .parse::<ObjectIdentifier>().unwrap();
+    assert!(oid.has_large_arcs());
+    assert_eq!(oid.arcs().collect::<Vec<_>>(), [2, 25, uuid]);
+    assert_eq!(oid.num_arcs(), 3);
+    assert_eq!(format!("{}", oid),
+        "2.25.329800735698586629295641978511506172918");
+    let small = ObjectIdentifier::from_arcs(&[2, 25, 1]).unwrap();
+    assert!(!small.has_large_arcs());
+    assert_eq!(small, ObjectIdentifier::from_slice(&[2, 25, 1]));
+    assert!(small < oid);
+    assert_eq!(oid.parent().unwrap(), ObjectIdentifier::from_slice(&[2, 25]));
+    assert_eq!(oid.parent().unwrap().child(uuid), oid);
+    assert!(!oid.parent().unwrap().has_large_arcs());
+}
+
+#[test]
+fn test_oid_hierarchy() {
+    let pkcs = ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1]);
+    let pkcs1 = pkcs.child(1);
+    assert_eq!(pkcs1, ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 1]));
+    assert!(pkcs1.starts_with(&pkcs));
+    assert!(pkcs1.starts_with(&pkcs1));
+    assert!(!pkcs.starts_with(&pkcs1));
+    assert!(pkcs.is_prefix_of(&pkcs1));
+    assert!(!ObjectIdentifier::from_slice(&[1, 2, 841]).is_prefix_of(&pkcs1));
+    assert_eq!(pkcs1.parent().unwrap(), pkcs);
+}
@@ -0,0 +1,552 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::cmp::Ordering;
+use core::fmt::{self, Display};
+use core::hash::{Hash, Hasher};
+
+use super::oid::ObjectIdentifier;
+
+/// Tells why bytes are not the encoding of an [`Oid`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum OidBytesError {
+    Invalid,
+    Overflow,
+}
+
+/// An object identifier which borrows its encoding, i.e. the contents
+/// octets of the DER-encoded OBJECT IDENTIFIER.
+///
+/// Unlike [`ObjectIdentifier`], it doesn't allocate. It can be read by
+/// [`BERReader::read_oid_ref`](crate::BERReader::read_oid_ref) and compared
+/// with [`ObjectIdentifier`] and [`OidBuf`].
+///
+/// Each arc is guaranteed to fit in `u128`, as in [`ObjectIdentifier`].
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::{ObjectIdentifier,Oid};
+/// let oid = Oid::from_der_bytes(&[42, 134, 72, 134, 247, 13, 1, 1]).unwrap();
+/// assert_eq!(oid.arcs().collect::<Vec<_>>(), [1, 2, 840, 113549, 1, 1]);
+/// assert_eq!(oid, ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 1]));
+/// assert_eq!(&oid.to_string(), "1.2.840.113549.1.1");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Oid<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Oid<'a> {
+    /// Constructs `Oid` from the contents octets of an OBJECT IDENTIFIER.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the bytes are not a correct encoding of an
+    /// OBJECT IDENTIFIER, or if an arc doesn't fit in `u128`.
+    pub fn from_der_bytes(bytes: &'a [u8]) -> Option<Self> {
+        Self::check_der_bytes(bytes).ok()
+    }
+
+    pub(crate) fn check_der_bytes(bytes: &'a [u8])
+            -> Result<Self, OidBytesError> {
+        if bytes.is_empty() || bytes[bytes.len() - 1] >= 128 {
+            return Err(OidBytesError::Invalid);
+        }
+        let mut subid : u128 = 0;
+        let mut is_first_byte = true;
+        for &b in bytes {
+            // The subidentifier must be encoded in the fewest bytes.
+            if is_first_byte && b == 128 {
+                return Err(OidBytesError::Invalid);
+            }
+            subid = subid.checked_mul(128)
+                .ok_or(OidBytesError::Overflow)? | (b & 127) as u128;
+            is_first_byte = (b & 128) == 0;
+            if is_first_byte {
+                subid = 0;
+            }
+        }
+        return Ok(Oid {
+            bytes,
+        });
+    }
+
+    /// Returns the contents octets.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns an iterator over the arcs.
+    pub fn arcs(&self) -> OidArcs<'a> {
+        OidArcs {
+            bytes: self.bytes,
+            is_first: true,
+            second_arc: None,
+        }
+    }
+
+    /// Converts it into `ObjectIdentifier`.
+    pub fn to_object_identifier(&self) -> ObjectIdentifier {
+        ObjectIdentifier::from_arcs_unchecked(self.arcs().collect())
+    }
+}
+
+impl<'a> PartialEq for Oid<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<'a> Eq for Oid<'a> {}
+
+impl<'a> PartialOrd for Oid<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Oid<'a> {
+    /// Compares the arcs lexicographically, as `ObjectIdentifier` does.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.arcs().cmp(other.arcs())
+    }
+}
+
+impl<'a> Hash for Oid<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes.hash(state);
+    }
+}
+
+impl<'a> PartialEq<ObjectIdentifier> for Oid<'a> {
+    fn eq(&self, other: &ObjectIdentifier) -> bool {
+        self.arcs().eq(other.arcs())
+    }
+}
+
+impl<'a> PartialEq<Oid<'a>> for ObjectIdentifier {
+    fn eq(&self, other: &Oid<'a>) -> bool {
+        other == self
+    }
+}
+
+impl<'a> From<Oid<'a>> for ObjectIdentifier {
+    fn from(oid: Oid<'a>) -> Self {
+        oid.to_object_identifier()
+    }
+}
+
+impl<'a> Display for Oid<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        #[cfg(feature = "oid-registry")]
+        if f.alternate() {
+            return super::oid_registry::fmt_alternate(*self, f);
+        }
+        for (i, arc) in self.arcs().enumerate() {
+            if i == 0 {
+                write!(f, "{}", arc)?;
+            } else {
+                write!(f, ".{}", arc)?;
+            }
+        }
+        return Ok(());
+    }
+}
+
+impl<'a> fmt::Debug for Oid<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Oid({})", self)
+    }
+}
+
+/// An iterator over the arcs of an [`Oid`].
+#[derive(Debug, Clone)]
+pub struct OidArcs<'a> {
+    bytes: &'a [u8],
+    // Whether the next subidentifier encodes the first two arcs.
+    is_first: bool,
+    second_arc: Option<u128>,
+}
+
+impl<'a> Iterator for OidArcs<'a> {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        if let Some(arc) = self.second_arc.take() {
+            return Some(arc);
+        }
+        if self.bytes.is_empty() {
+            return None;
+        }
+        let mut subid : u128 = 0;
+        let mut i = 0;
+        loop {
+            let b = self.bytes[i];
+            subid = (subid << 7) | (b & 127) as u128;
+            i += 1;
+            if (b & 128) == 0 {
+                break;
+            }
+        }
+        self.bytes = &self.bytes[i..];
+        if self.is_first {
+            self.is_first = false;
+            let arc0 = if subid < 40 { 0 } else if subid < 80 { 1 } else { 2 };
+            self.second_arc = Some(subid - 40 * arc0);
+            return Some(arc0);
+        }
+        return Some(subid);
+    }
+}
+
+const OID_BUF_CAPACITY : usize = 63;
+
+/// Appends the base-128 encoding of `subid`, returning `None` if it
+/// overflows the buffer.
+const fn push_subid(
+    mut bytes: [u8; OID_BUF_CAPACITY],
+    len: usize,
+    subid: u128,
+) -> Option<([u8; OID_BUF_CAPACITY], usize)> {
+    let mut num_bytes = 1;
+    while num_bytes < 19 && (subid >> (7 * num_bytes)) != 0 {
+        num_bytes += 1;
+    }
+    if len + num_bytes > OID_BUF_CAPACITY {
+        return None;
+    }
+    let mut i = 0;
+    while i < num_bytes {
+        let shift = 7 * (num_bytes - 1 - i);
+        let mut b = ((subid >> shift) & 127) as u8;
+        if i + 1 < num_bytes {
+            b |= 128;
+        }
+        bytes[len + i] = b;
+        i += 1;
+    }
+    return Some((bytes, len + num_bytes));
+}
+
+/// An object identifier which owns its encoding in an inline buffer, i.e.
+/// without allocation.
+///
+/// It can be constructed at compile time by [`OidBuf::from_dotted`] or
+/// the [`oid!`](crate::oid!) macro. The encoding is limited to
+/// [`OidBuf::CAPACITY`] bytes, which is enough for OIDs in practice.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::{ObjectIdentifier,OidBuf};
+/// const RSA_ENCRYPTION : OidBuf = OidBuf::from_dotted("1.2.840.113549.1.1.1");
+/// let oid = ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 1, 1]);
+/// assert_eq!(RSA_ENCRYPTION, oid);
+/// assert_eq!(yasna::encode_der(&RSA_ENCRYPTION), yasna::encode_der(&oid));
+/// ```
+#[derive(Clone, Copy)]
+pub struct OidBuf {
+    len: u8,
+    bytes: [u8; OID_BUF_CAPACITY],
+}
+
+impl OidBuf {
+    /// The maximum length of the encoding.
+    pub const CAPACITY : usize = OID_BUF_CAPACITY;
+
+    /// Constructs `OidBuf` from the dotted notation, such as
+    /// `"1.2.840.113549"`. It can be evaluated at compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is not a correct OID, or if the encoding exceeds
+    /// [`OidBuf::CAPACITY`] bytes. In a const context, it is a compile
+    /// error instead.
+    pub const fn from_dotted(s: &str) -> Self {
+        let s = s.as_bytes();
+        let mut bytes = [0; OID_BUF_CAPACITY];
+        let mut len = 0;
+        let mut arc0 = 0;
+        let mut num_arcs = 0;
+        let mut i = 0;
+        while i <= s.len() {
+            let start = i;
+            let mut arc : u128 = 0;
+            while i < s.len() && s[i] != b'.' {
+                assert!(s[i].is_ascii_digit(), "Invalid OID: non-digit");
+                arc = match arc.checked_mul(10) {
+                    Some(arc) => arc,
+                    None => panic!("Invalid OID: arc overflow"),
+                };
+                arc = match arc.checked_add((s[i] - b'0') as u128) {
+                    Some(arc) => arc,
+                    None => panic!("Invalid OID: arc overflow"),
+                };
+                i += 1;
+            }
+            assert!(i > start, "Invalid OID: empty arc");
+            let subid = if num_arcs == 0 {
+                assert!(arc <= 2, "Invalid OID: the first arc is not 0, 1 or 2");
+                arc0 = arc;
+                None
+            } else if num_arcs == 1 {
+                assert!(arc0 == 2 || arc < 40,
+                    "Invalid OID: the second arc is too large");
+                match arc.checked_add(arc0 * 40) {
+                    Some(subid) => Some(subid),
+                    None => panic!("Invalid OID: arc overflow"),
+                }
+            } else {
+                Some(arc)
+            };
+            if let Some(subid) = subid {
+                match push_subid(bytes, len, subid) {
+                    Some((new_bytes, new_len)) => {
+                        bytes = new_bytes;
+                        len = new_len;
+                    },
+                    None => panic!("Invalid OID: too long"),
+                }
+            }
+            num_arcs += 1;
+            // Skip the dot
+            i += 1;
+        }
+        assert!(num_arcs >= 2, "Invalid OID: too short");
+        return OidBuf {
+            len: len as u8,
+            bytes,
+        };
+    }
+
+    /// Constructs `OidBuf` from its arcs.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the arcs are not a correct OID, or if the
+    /// encoding exceeds [`OidBuf::CAPACITY`] bytes.
+    pub fn from_arcs(arcs: &[u128]) -> Option<Self> {
+        if arcs.len() < 2 || arcs[0] > 2 || (arcs[0] < 2 && arcs[1] >= 40) {
+            return None;
+        }
+        let mut bytes = [0; OID_BUF_CAPACITY];
+        let mut len = 0;
+        for (i, &arc) in arcs.iter().enumerate().skip(1) {
+            let subid = if i == 1 { arc.checked_add(arcs[0] * 40)? } else { arc };
+            let (new_bytes, new_len) = push_subid(bytes, len, subid)?;
+            bytes = new_bytes;
+            len = new_len;
+        }
+        return Some(OidBuf {
+            len: len as u8,
+            bytes,
+        });
+    }
+
+    /// Constructs `OidBuf` by copying the encoding of `oid`.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the encoding exceeds [`OidBuf::CAPACITY`] bytes.
+    pub fn from_oid(oid: Oid) -> Option<Self> {
+        let src = oid.as_bytes();
+        if src.len() > OID_BUF_CAPACITY {
+            return None;
+        }
+        let mut bytes = [0; OID_BUF_CAPACITY];
+        bytes[..src.len()].copy_from_slice(src);
+        return Some(OidBuf {
+            len: src.len() as u8,
+            bytes,
+        });
+    }
+
+    /// Borrows it as `Oid`.
+    pub fn as_oid(&self) -> Oid<'_> {
+        Oid {
+            bytes: self.as_bytes(),
+        }
+    }
+
+    /// Returns the contents octets.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+
+    /// Returns an iterator over the arcs.
+    pub fn arcs(&self) -> OidArcs<'_> {
+        self.as_oid().arcs()
+    }
+}
+
+impl PartialEq for OidBuf {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for OidBuf {}
+
+impl PartialOrd for OidBuf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OidBuf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_oid().cmp(&other.as_oid())
+    }
+}
+
+impl Hash for OidBuf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+impl PartialEq<ObjectIdentifier> for OidBuf {
+    fn eq(&self, other: &ObjectIdentifier) -> bool {
+        self.as_oid() == *other
+    }
+}
+
+impl PartialEq<OidBuf> for ObjectIdentifier {
+    fn eq(&self, other: &OidBuf) -> bool {
+        other.as_oid() == *self
+    }
+}
+
+impl<'a> PartialEq<Oid<'a>> for OidBuf {
+    fn eq(&self, other: &Oid<'a>) -> bool {
+        self.as_oid() == *other
+    }
+}
+
+impl<'a> PartialEq<OidBuf> for Oid<'a> {
+    fn eq(&self, other: &OidBuf) -> bool {
+        *self == other.as_oid()
+    }
+}
+
+impl From<OidBuf> for ObjectIdentifier {
+    fn from(oid: OidBuf) -> Self {
+        oid.as_oid().to_object_identifier()
+    }
+}
+
+impl Display for OidBuf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        Display::fmt(&self.as_oid(), f)
+    }
+}
+
+impl fmt::Debug for OidBuf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "OidBuf({})", self)
+    }
+}
+
+/// Constructs an [`OidBuf`](crate::models::OidBuf) from the dotted notation
+/// at compile time.
+///
+/// An incorrect OID is a compile error.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::oid;
+/// use yasna::models::{ObjectIdentifier,OidBuf};
+/// const SHA256 : OidBuf = oid!("2.16.840.1.101.3.4.2.1");
+/// assert_eq!(SHA256.as_bytes(), &[96, 134, 72, 1, 101, 3, 4, 2, 1]);
+/// let oid = ObjectIdentifier::from_slice(&[2, 16, 840, 1, 101, 3, 4, 2, 1]);
+/// assert!(oid == oid!("2.16.840.1.101.3.4.2.1"));
+/// ```
+///
+/// ```compile_fail
+/// let oid = yasna::oid!("3.1");
+/// ```
+#[macro_export]
+macro_rules! oid {
+    ($s:expr) => {{
+        const OID : $crate::models::OidBuf =
+            $crate::models::OidBuf::from_dotted($s);
+        OID
+    }};
+}
+
+#[test]
+fn test_oid_ref_from_der_bytes() {
+    let tests : &[(&[u8], Option<&[u128]>)] = &[
+        (&[42, 134, 72, 134, 247, 13, 1, 1],
+            Some(&[1, 2, 840, 113549, 1, 1])),
+        (&[0], Some(&[0, 0])),
+        (&[39], Some(&[0, 39])),
+        (&[40], Some(&[1, 0])),
+        (&[136, 55], Some(&[2, 999])),
+        (&[129, 128, 1], Some(&[2, 16305])),
+        (&[42, 129, 128, 1], Some(&[1, 2, 16385])),
+        (&[42, 129, 255, 255, 255, 255, 255, 255, 255, 255, 127],
+            Some(&[1, 2, u64::MAX as u128])),
+        (&[42, 130, 128, 128, 128, 128, 128, 128, 128, 128, 0],
+            Some(&[1, 2, 1 << 64])),
+        (&[105, 131, 240, 157, 167, 235, 207, 222, 224, 199, 161, 167,
+            178, 192, 148, 140, 200, 249, 215, 118],
+            Some(&[2, 25, 0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6])),
+        (&[131, 255, 255, 255, 255, 255, 255, 255, 255,
+            255, 255, 255, 255, 255, 255, 255, 255, 255, 127],
+            Some(&[2, u128::MAX - 80])),
+        (&[], None),
+        (&[42, 134], None),
+        (&[42, 128, 1], None),
+        (&[128, 1], None),
+        (&[42, 132, 128, 128, 128, 128, 128, 128, 128, 128,
+            128, 128, 128, 128, 128, 128, 128, 128, 128, 0], None),
+    ];
+    for &(bytes, arcs) in tests {
+        let oid = Oid::from_der_bytes(bytes);
+        let actual = oid.map(|oid| oid.arcs().collect::<alloc::vec::Vec<_>>());
+        assert_eq!(actual.as_deref(), arcs);
+    }
+}
+
+#[test]
+fn test_oid_buf_from_dotted() {
+    use alloc::string::ToString;
+    let tests : &[&[u128]] = &[
+        &[1, 2, 840, 113549, 1, 1],
+        &[0, 0],
+        &[2, 999, 3],
+        &[1, 3, u64::MAX as u128],
+        &[2, 25, 0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6],
+        &[2, u128::MAX - 80],
+    ];
+    for &arcs in tests {
+        let s = ObjectIdentifier::from_arcs(arcs).unwrap().to_string();
+        let oid = OidBuf::from_dotted(&s);
+        assert_eq!(oid, ObjectIdentifier::from_arcs(arcs).unwrap());
+        assert_eq!(oid, OidBuf::from_arcs(arcs).unwrap());
+        assert_eq!(Oid::from_der_bytes(oid.as_bytes()), Some(oid.as_oid()));
+        assert_eq!(oid.to_string(), s);
+    }
+    assert!(OidBuf::from_arcs(&[1]).is_none());
+    assert!(OidBuf::from_arcs(&[1, 40]).is_none());
+    assert!(OidBuf::from_arcs(&[3, 1]).is_none());
+    assert!(OidBuf::from_arcs(&[2, u128::MAX - 79]).is_none());
+    assert!(OidBuf::from_arcs(&[1, 2, u128::MAX, u128::MAX,
+        u128::MAX, u128::MAX]).is_none());
+    assert!(oid!("1.2.3") < oid!("1.2.3.0"));
+    assert!(oid!("1.2.3") < oid!("1.2.128"));
+}
+
+#[test]
+#[should_panic]
+fn test_oid_buf_from_dotted_invalid() {
+    OidBuf::from_dotted("1.2.");
+}
@@ -0,0 +1,448 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt::{self, Display};
+
+use super::oid_ref::{Oid, OidBuf};
+
+/// A pair of an OID and its name, used in registries.
+///
+/// # Features
+///
+/// This struct is enabled by `oid-registry` feature.
+///
+/// ```toml
+/// [dependencies]
+/// yasna = { version = "*", features = ["oid-registry"] }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct OidEntry {
+    oid: OidBuf,
+    name: &'static str,
+}
+
+impl OidEntry {
+    /// Constructs a new `OidEntry`.
+    pub const fn new(oid: OidBuf, name: &'static str) -> Self {
+        OidEntry {
+            oid,
+            name,
+        }
+    }
+
+    /// Returns the OID.
+    pub fn oid(&self) -> Oid<'_> {
+        self.oid.as_oid()
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// A table of names of OIDs.
+///
+/// It is implemented for [`WellKnownOids`] and slices of [`OidEntry`].
+/// Registries can be chained with tuples: `(a, b)` looks up `a` first,
+/// and then `b`.
+///
+/// # Features
+///
+/// This trait is enabled by `oid-registry` feature.
+///
+/// ```toml
+/// [dependencies]
+/// yasna = { version = "*", features = ["oid-registry"] }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use yasna::oid;
+/// use yasna::models::{OidEntry,OidRegistry,WellKnownOids};
+/// static PRIVATE_OIDS : &[OidEntry] = &[
+///     OidEntry::new(oid!("1.3.6.1.4.1.99999.1"), "exampleAttribute"),
+/// ];
+/// let registry = (PRIVATE_OIDS, WellKnownOids);
+/// let oid = oid!("1.3.6.1.4.1.99999.1");
+/// assert_eq!(registry.name(oid.as_oid()), Some("exampleAttribute"));
+/// assert_eq!(registry.lookup("commonName"), Some(oid!("2.5.4.3")));
+/// assert_eq!(&registry.display(oid.as_oid()).to_string(),
+///     "exampleAttribute (1.3.6.1.4.1.99999.1)");
+/// ```
+pub trait OidRegistry {
+    /// Returns the name of `oid`, if it is registered.
+    fn name(&self, oid: Oid) -> Option<&str>;
+
+    /// Returns the OID named `name`, if it is registered.
+    fn lookup(&self, name: &str) -> Option<OidBuf>;
+
+    /// Returns an object to display `oid` as `name (dotted.arcs)`, or as
+    /// dotted arcs only if it is not registered.
+    fn display<'r, 'o>(&'r self, oid: Oid<'o>) -> OidDisplay<'r, 'o, Self>
+            where Self: Sized {
+        OidDisplay {
+            registry: self,
+            oid,
+        }
+    }
+}
+
+impl<R: OidRegistry + ?Sized> OidRegistry for &R {
+    fn name(&self, oid: Oid) -> Option<&str> {
+        (**self).name(oid)
+    }
+
+    fn lookup(&self, name: &str) -> Option<OidBuf> {
+        (**self).lookup(name)
+    }
+}
+
+impl OidRegistry for [OidEntry] {
+    fn name(&self, oid: Oid) -> Option<&str> {
+        self.iter().find(|entry| entry.oid() == oid).map(|entry| entry.name)
+    }
+
+    fn lookup(&self, name: &str) -> Option<OidBuf> {
+        self.iter().find(|entry| entry.name == name).map(|entry| entry.oid)
+    }
+}
+
+impl<A: OidRegistry, B: OidRegistry> OidRegistry for (A, B) {
+    fn name(&self, oid: Oid) -> Option<&str> {
+        self.0.name(oid).or_else(|| self.1.name(oid))
+    }
+
+    fn lookup(&self, name: &str) -> Option<OidBuf> {
+        self.0.lookup(name).or_else(|| self.1.lookup(name))
+    }
+}
+
+/// Displays an OID with its name. Returned by [`OidRegistry::display`].
+///
+/// # Features
+///
+/// This struct is enabled by `oid-registry` feature.
+///
+/// ```toml
+/// [dependencies]
+/// yasna = { version = "*", features = ["oid-registry"] }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OidDisplay<'r, 'o, R> {
+    registry: &'r R,
+    oid: Oid<'o>,
+}
+
+impl<'r, 'o, R: OidRegistry> Display for OidDisplay<'r, 'o, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self.registry.name(self.oid) {
+            Some(name) => write!(f, "{} ({})", name, self.oid),
+            None => write!(f, "{}", self.oid),
+        }
+    }
+}
+
+/// The registry of well-known OIDs.
+///
+/// It covers X.520 attribute types, X.509 and PKIX extensions, extended key
+/// usages and access methods, PKCS #1, #5, #7, #9 and #12, CMS content
+/// types, hash, MAC, cipher and signature algorithms, and NIST/SEC
+/// elliptic curves.
+///
+/// The names are taken from the ASN.1 modules defining them, without
+/// prefixes like `id-` or `id-ce-`.
+///
+/// With `oid-registry` feature, the alternate `Display` format (`{:#}`) of
+/// [`ObjectIdentifier`](crate::models::ObjectIdentifier), [`Oid`] and
+/// [`OidBuf`] uses this registry.
+///
+/// # Features
+///
+/// This struct is enabled by `oid-registry` feature.
+///
+/// ```toml
+/// [dependencies]
+/// yasna = { version = "*", features = ["oid-registry"] }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use yasna::oid;
+/// use yasna::models::{ObjectIdentifier,OidRegistry,WellKnownOids};
+/// assert_eq!(WellKnownOids.name(oid!("2.5.29.19").as_oid()),
+///     Some("basicConstraints"));
+/// let oid = ObjectIdentifier::from_slice(&[2, 5, 4, 3]);
+/// assert_eq!(&format!("{:#}", oid), "commonName (2.5.4.3)");
+/// assert_eq!(&format!("{}", oid), "2.5.4.3");
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct WellKnownOids;
+
+impl WellKnownOids {
+    /// Returns all the entries.
+    pub fn entries(&self) -> &'static [OidEntry] {
+        WELL_KNOWN_OIDS
+    }
+}
+
+impl OidRegistry for WellKnownOids {
+    fn name(&self, oid: Oid) -> Option<&str> {
+        WELL_KNOWN_OIDS.name(oid)
+    }
+
+    fn lookup(&self, name: &str) -> Option<OidBuf> {
+        WELL_KNOWN_OIDS.lookup(name)
+    }
+}
+
+/// Writes `oid` in the alternate format, with its well-known name.
+pub(super) fn fmt_alternate(oid: Oid, f: &mut fmt::Formatter)
+        -> Result<(), fmt::Error> {
+    write!(f, "{}", WellKnownOids.display(oid))
+}
+
+macro_rules! entries {
+    ($($oid:expr => $name:expr,)*) => {
+        &[$(OidEntry::new(crate::oid!($oid), $name),)*]
+    };
+}
+
+static WELL_KNOWN_OIDS : &[OidEntry] = entries! {
+    // X.520 attribute types
+    "2.5.4.3" => "commonName",
+    "2.5.4.4" => "surname",
+    "2.5.4.5" => "serialNumber",
+    "2.5.4.6" => "countryName",
+    "2.5.4.7" => "localityName",
+    "2.5.4.8" => "stateOrProvinceName",
+    "2.5.4.9" => "streetAddress",
+    "2.5.4.10" => "organizationName",
+    "2.5.4.11" => "organizationalUnitName",
+    "2.5.4.12" => "title",
+    "2.5.4.13" => "description",
+    "2.5.4.15" => "businessCategory",
+    "2.5.4.17" => "postalCode",
+    "2.5.4.41" => "name",
+    "2.5.4.42" => "givenName",
+    "2.5.4.43" => "initials",
+    "2.5.4.44" => "generationQualifier",
+    "2.5.4.45" => "x500UniqueIdentifier",
+    "2.5.4.46" => "dnQualifier",
+    "2.5.4.65" => "pseudonym",
+    "2.5.4.97" => "organizationIdentifier",
+    "0.9.2342.19200300.100.1.1" => "userId",
+    "0.9.2342.19200300.100.1.25" => "domainComponent",
+
+    // X.509 certificate and CRL extensions
+    "2.5.29.9" => "subjectDirectoryAttributes",
+    "2.5.29.14" => "subjectKeyIdentifier",
+    "2.5.29.15" => "keyUsage",
+    "2.5.29.16" => "privateKeyUsagePeriod",
+    "2.5.29.17" => "subjectAltName",
+    "2.5.29.18" => "issuerAltName",
+    "2.5.29.19" => "basicConstraints",
+    "2.5.29.20" => "cRLNumber",
+    "2.5.29.21" => "reasonCode",
+    "2.5.29.23" => "holdInstructionCode",
+    "2.5.29.24" => "invalidityDate",
+    "2.5.29.27" => "deltaCRLIndicator",
+    "2.5.29.28" => "issuingDistributionPoint",
+    "2.5.29.29" => "certificateIssuer",
+    "2.5.29.30" => "nameConstraints",
+    "2.5.29.31" => "cRLDistributionPoints",
+    "2.5.29.32" => "certificatePolicies",
+    "2.5.29.32.0" => "anyPolicy",
+    "2.5.29.33" => "policyMappings",
+    "2.5.29.35" => "authorityKeyIdentifier",
+    "2.5.29.36" => "policyConstraints",
+    "2.5.29.37" => "extKeyUsage",
+    "2.5.29.37.0" => "anyExtendedKeyUsage",
+    "2.5.29.46" => "freshestCRL",
+    "2.5.29.54" => "inhibitAnyPolicy",
+
+    // PKIX
+    "1.3.6.1.5.5.7.1.1" => "authorityInfoAccess",
+    "1.3.6.1.5.5.7.1.11" => "subjectInfoAccess",
+    "1.3.6.1.5.5.7.1.24" => "tlsfeature",
+    "1.3.6.1.5.5.7.2.1" => "cps",
+    "1.3.6.1.5.5.7.2.2" => "unotice",
+    "1.3.6.1.5.5.7.3.1" => "serverAuth",
+    "1.3.6.1.5.5.7.3.2" => "clientAuth",
+    "1.3.6.1.5.5.7.3.3" => "codeSigning",
+    "1.3.6.1.5.5.7.3.4" => "emailProtection",
+    "1.3.6.1.5.5.7.3.8" => "timeStamping",
+    "1.3.6.1.5.5.7.3.9" => "OCSPSigning",
+    "1.3.6.1.5.5.7.48.1" => "ocsp",
+    "1.3.6.1.5.5.7.48.1.1" => "pkix-ocsp-basic",
+    "1.3.6.1.5.5.7.48.1.2" => "pkix-ocsp-nonce",
+    "1.3.6.1.5.5.7.48.1.5" => "pkix-ocsp-nocheck",
+    "1.3.6.1.5.5.7.48.2" => "caIssuers",
+    "1.3.6.1.5.5.7.48.3" => "ad-timeStamping",
+    "1.3.6.1.5.5.7.48.5" => "caRepository",
+    "1.3.6.1.4.1.11129.2.4.2" => "ctPrecertificateSCTs",
+    "1.3.6.1.4.1.11129.2.4.3" => "ctPrecertificatePoison",
+
+    // PKCS #1
+    "1.2.840.113549.1.1.1" => "rsaEncryption",
+    "1.2.840.113549.1.1.2" => "md2WithRSAEncryption",
+    "1.2.840.113549.1.1.4" => "md5WithRSAEncryption",
+    "1.2.840.113549.1.1.5" => "sha1WithRSAEncryption",
+    "1.2.840.113549.1.1.7" => "RSAES-OAEP",
+    "1.2.840.113549.1.1.8" => "mgf1",
+    "1.2.840.113549.1.1.9" => "pSpecified",
+    "1.2.840.113549.1.1.10" => "RSASSA-PSS",
+    "1.2.840.113549.1.1.11" => "sha256WithRSAEncryption",
+    "1.2.840.113549.1.1.12" => "sha384WithRSAEncryption",
+    "1.2.840.113549.1.1.13" => "sha512WithRSAEncryption",
+    "1.2.840.113549.1.1.14" => "sha224WithRSAEncryption",
+
+    // PKCS #5
+    "1.2.840.113549.1.5.3" => "pbeWithMD5AndDES-CBC",
+    "1.2.840.113549.1.5.10" => "pbeWithSHA1AndDES-CBC",
+    "1.2.840.113549.1.5.12" => "PBKDF2",
+    "1.2.840.113549.1.5.13" => "PBES2",
+    "1.2.840.113549.1.5.14" => "PBMAC1",
+
+    // PKCS #7 and CMS content types
+    "1.2.840.113549.1.7.1" => "data",
+    "1.2.840.113549.1.7.2" => "signedData",
+    "1.2.840.113549.1.7.3" => "envelopedData",
+    "1.2.840.113549.1.7.4" => "signedAndEnvelopedData",
+    "1.2.840.113549.1.7.5" => "digestedData",
+    "1.2.840.113549.1.7.6" => "encryptedData",
+    "1.2.840.113549.1.9.16.1.2" => "ct-authData",
+    "1.2.840.113549.1.9.16.1.4" => "ct-TSTInfo",
+    "1.2.840.113549.1.9.16.1.9" => "ct-compressedData",
+    "1.2.840.113549.1.9.16.1.23" => "ct-authEnvelopedData",
+
+    // PKCS #9 and S/MIME attributes
+    "1.2.840.113549.1.9.1" => "emailAddress",
+    "1.2.840.113549.1.9.2" => "unstructuredName",
+    "1.2.840.113549.1.9.3" => "contentType",
+    "1.2.840.113549.1.9.4" => "messageDigest",
+    "1.2.840.113549.1.9.5" => "signingTime",
+    "1.2.840.113549.1.9.6" => "countersignature",
+    "1.2.840.113549.1.9.7" => "challengePassword",
+    "1.2.840.113549.1.9.8" => "unstructuredAddress",
+    "1.2.840.113549.1.9.14" => "extensionRequest",
+    "1.2.840.113549.1.9.15" => "smimeCapabilities",
+    "1.2.840.113549.1.9.20" => "friendlyName",
+    "1.2.840.113549.1.9.21" => "localKeyID",
+    "1.2.840.113549.1.9.22.1" => "x509Certificate",
+    "1.2.840.113549.1.9.23.1" => "x509CRL",
+    "1.2.840.113549.1.9.16.2.12" => "aa-signingCertificate",
+    "1.2.840.113549.1.9.16.2.14" => "aa-timeStampToken",
+    "1.2.840.113549.1.9.16.2.47" => "aa-signingCertificateV2",
+
+    // PKCS #12
+    "1.2.840.113549.1.12.1.1" => "pbeWithSHAAnd128BitRC4",
+    "1.2.840.113549.1.12.1.2" => "pbeWithSHAAnd40BitRC4",
+    "1.2.840.113549.1.12.1.3" => "pbeWithSHAAnd3-KeyTripleDES-CBC",
+    "1.2.840.113549.1.12.1.4" => "pbeWithSHAAnd2-KeyTripleDES-CBC",
+    "1.2.840.113549.1.12.1.5" => "pbeWithSHAAnd128BitRC2-CBC",
+    "1.2.840.113549.1.12.1.6" => "pbewithSHAAnd40BitRC2-CBC",
+    "1.2.840.113549.1.12.10.1.1" => "keyBag",
+    "1.2.840.113549.1.12.10.1.2" => "pkcs8ShroudedKeyBag",
+    "1.2.840.113549.1.12.10.1.3" => "certBag",
+    "1.2.840.113549.1.12.10.1.4" => "crlBag",
+    "1.2.840.113549.1.12.10.1.5" => "secretBag",
+    "1.2.840.113549.1.12.10.1.6" => "safeContentsBag",
+
+    // Hash and MAC algorithms
+    "1.2.840.113549.2.2" => "md2",
+    "1.2.840.113549.2.5" => "md5",
+    "1.3.14.3.2.26" => "sha1",
+    "2.16.840.1.101.3.4.2.1" => "sha256",
+    "2.16.840.1.101.3.4.2.2" => "sha384",
+    "2.16.840.1.101.3.4.2.3" => "sha512",
+    "2.16.840.1.101.3.4.2.4" => "sha224",
+    "2.16.840.1.101.3.4.2.5" => "sha512-224",
+    "2.16.840.1.101.3.4.2.6" => "sha512-256",
+    "2.16.840.1.101.3.4.2.7" => "sha3-224",
+    "2.16.840.1.101.3.4.2.8" => "sha3-256",
+    "2.16.840.1.101.3.4.2.9" => "sha3-384",
+    "2.16.840.1.101.3.4.2.10" => "sha3-512",
+    "2.16.840.1.101.3.4.2.11" => "shake128",
+    "2.16.840.1.101.3.4.2.12" => "shake256",
+    "1.2.840.113549.2.7" => "hmacWithSHA1",
+    "1.2.840.113549.2.8" => "hmacWithSHA224",
+    "1.2.840.113549.2.9" => "hmacWithSHA256",
+    "1.2.840.113549.2.10" => "hmacWithSHA384",
+    "1.2.840.113549.2.11" => "hmacWithSHA512",
+
+    // Ciphers
+    "1.3.14.3.2.7" => "desCBC",
+    "1.2.840.113549.3.2" => "rc2CBC",
+    "1.2.840.113549.3.7" => "des-ede3-cbc",
+    "2.16.840.1.101.3.4.1.2" => "aes128-CBC",
+    "2.16.840.1.101.3.4.1.5" => "aes128-wrap",
+    "2.16.840.1.101.3.4.1.6" => "aes128-GCM",
+    "2.16.840.1.101.3.4.1.22" => "aes192-CBC",
+    "2.16.840.1.101.3.4.1.25" => "aes192-wrap",
+    "2.16.840.1.101.3.4.1.26" => "aes192-GCM",
+    "2.16.840.1.101.3.4.1.42" => "aes256-CBC",
+    "2.16.840.1.101.3.4.1.45" => "aes256-wrap",
+    "2.16.840.1.101.3.4.1.46" => "aes256-GCM",
+
+    // Public key and signature algorithms
+    "1.2.840.10040.4.1" => "dsa",
+    "1.2.840.10040.4.3" => "dsa-with-sha1",
+    "2.16.840.1.101.3.4.3.1" => "dsa-with-sha224",
+    "2.16.840.1.101.3.4.3.2" => "dsa-with-sha256",
+    "1.2.840.10046.2.1" => "dhpublicnumber",
+    "1.2.840.10045.2.1" => "ecPublicKey",
+    "1.2.840.10045.4.1" => "ecdsa-with-SHA1",
+    "1.2.840.10045.4.3.1" => "ecdsa-with-SHA224",
+    "1.2.840.10045.4.3.2" => "ecdsa-with-SHA256",
+    "1.2.840.10045.4.3.3" => "ecdsa-with-SHA384",
+    "1.2.840.10045.4.3.4" => "ecdsa-with-SHA512",
+    "1.3.101.110" => "X25519",
+    "1.3.101.111" => "X448",
+    "1.3.101.112" => "Ed25519",
+    "1.3.101.113" => "Ed448",
+
+    // Elliptic curves
+    "1.2.840.10045.3.1.1" => "secp192r1",
+    "1.2.840.10045.3.1.7" => "secp256r1",
+    "1.3.132.0.10" => "secp256k1",
+    "1.3.132.0.33" => "secp224r1",
+    "1.3.132.0.34" => "secp384r1",
+    "1.3.132.0.35" => "secp521r1",
+};
+
+#[test]
+fn test_well_known_oids_unique() {
+    let entries = WellKnownOids.entries();
+    for (i, entry) in entries.iter().enumerate() {
+        for other in &entries[..i] {
+            assert_ne!(entry.oid(), other.oid(), "{}", entry.name());
+            assert_ne!(entry.name(), other.name());
+        }
+        assert_eq!(WellKnownOids.name(entry.oid()), Some(entry.name()));
+        assert_eq!(WellKnownOids.lookup(entry.name()).unwrap(), entry.oid());
+    }
+}
+
+#[test]
+fn test_oid_alternate_display() {
+    use alloc::format;
+    use super::oid::ObjectIdentifier;
+    let oid = ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 1, 11]);
+    assert_eq!(format!("{:#}", oid),
+        "sha256WithRSAEncryption (1.2.840.113549.1.1.11)");
+    let oid = crate::oid!("1.3.101.112");
+    assert_eq!(format!("{:#}", oid), "Ed25519 (1.3.101.112)");
+    assert_eq!(format!("{:#}", oid.as_oid()), "Ed25519 (1.3.101.112)");
+    assert_eq!(format!("{}", oid), "1.3.101.112");
+    let oid = ObjectIdentifier::from_slice(&[1, 2, 3]);
+    assert_eq!(format!("{:#}", oid), "1.2.3");
+    assert_eq!(WellKnownOids.lookup("noSuchName"), None);
+}
@@ -0,0 +1,200 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+use super::super::{DEREncodable, encode_der};
+
+/// A decoded value together with its original encoding.
+///
+/// Signatures in X.509, CMS, OCSP and the like are computed over the exact
+/// bytes of a sub-structure (e.g. TBSCertificate). When decoded, `WithRaw<T>`
+/// keeps the whole TLV (tag, length and contents) it was read from, and
+/// when encoded, it writes back these bytes verbatim instead of re-encoding
+/// the value. Therefore, the signed bytes survive a decode/encode round-trip
+/// even if the original encoding is not DER or `T` loses some information.
+///
+/// Note that, if decoded in BER mode, the encoded output may not be valid
+/// DER.
+///
+/// If you don't need to own the bytes, [`WithRawRef`] borrows them from
+/// the input instead.
+///
+/// # Examples
+///
+/// ```
+/// use yasna;
+/// use yasna::models::WithRaw;
+/// let data = &[48, 6, 2, 1, 10, 1, 1, 255];
+/// let asn : WithRaw<(i64, bool)> = yasna::decode_der(data).unwrap();
+/// assert_eq!(asn.value(), &(10, true));
+/// assert_eq!(asn.raw(), data);
+/// assert_eq!(yasna::encode_der(&asn), data);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct WithRaw<T> {
+    value: T,
+    raw: Vec<u8>,
+}
+
+impl<T> WithRaw<T> {
+    /// Constructs a new `WithRaw` from a value and its encoding.
+    ///
+    /// The caller is responsible for providing the bytes consistent
+    /// with `value`: one complete TLV, including the tag and the length.
+    pub fn from_parts(value: T, raw: Vec<u8>) -> Self {
+        WithRaw {
+            value,
+            raw,
+        }
+    }
+
+    /// Returns the decoded value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the original encoding, including the tag and the length.
+    pub fn raw(&self) -> &[u8] {
+        &self.raw
+    }
+
+    /// Extracts the decoded value, discarding the encoding.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Extracts the decoded value and the encoding.
+    pub fn into_parts(self) -> (T, Vec<u8>) {
+        (self.value, self.raw)
+    }
+}
+
+impl<T: DEREncodable> WithRaw<T> {
+    /// Constructs a new `WithRaw` by encoding `value` in DER.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::WithRaw;
+    /// let asn = WithRaw::new(10i64);
+    /// assert_eq!(asn.raw(), &[2, 1, 10]);
+    /// ```
+    pub fn new(value: T) -> Self {
+        let raw = encode_der(&value);
+        WithRaw {
+            value,
+            raw,
+        }
+    }
+}
+
+/// A decoded value together with its original encoding, borrowed from
+/// the input.
+///
+/// This is the borrowing counterpart of [`WithRaw`]. It can be read by
+/// [`BERReader::read_with_raw_ref`](crate::BERReader::read_with_raw_ref),
+/// and is encoded as the original bytes verbatim. To read the encoding
+/// alongside a value of a type not implementing `BERDecodable`, use
+/// [`BERReader::read_with_buffer`](crate::BERReader::read_with_buffer).
+///
+/// # Examples
+///
+/// ```
+/// use yasna;
+/// let data = &[48, 9, 48, 3, 2, 1, 10, 3, 2, 0, 255];
+/// let (tbs, signature) = yasna::parse_der(data, |reader| {
+///     reader.read_sequence(|reader| {
+///         let tbs = reader.next().read_with_raw_ref::<(i64,)>()?;
+///         let signature = reader.next().read_bitvec_bytes()?;
+///         return Ok((tbs, signature));
+///     })
+/// }).unwrap();
+/// assert_eq!(tbs.value(), &(10,));
+/// assert_eq!(tbs.raw(), &[48, 3, 2, 1, 10]);
+/// assert_eq!(signature, (vec![255], 8));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct WithRawRef<'a, T> {
+    value: T,
+    raw: &'a [u8],
+}
+
+impl<'a, T> WithRawRef<'a, T> {
+    /// Constructs a new `WithRawRef` from a value and its encoding.
+    ///
+    /// The caller is responsible for providing the bytes consistent
+    /// with `value`: one complete TLV, including the tag and the length.
+    pub fn from_parts(value: T, raw: &'a [u8]) -> Self {
+        WithRawRef {
+            value,
+            raw,
+        }
+    }
+
+    /// Returns the decoded value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the original encoding, including the tag and the length.
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Extracts the decoded value, discarding the encoding.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Extracts the decoded value and the encoding.
+    pub fn into_parts(self) -> (T, &'a [u8]) {
+        (self.value, self.raw)
+    }
+
+    /// Copies the encoding into a [`WithRaw`].
+    pub fn into_owned(self) -> WithRaw<T> {
+        WithRaw::from_parts(self.value, self.raw.to_vec())
+    }
+}
+
+#[test]
+fn test_with_raw_roundtrip() {
+    use crate::{decode_ber, decode_der};
+    // DER input is kept as is.
+    let data = &[48, 8, 2, 1, 10, 48, 3, 1, 1, 0];
+    let asn : (WithRaw<i64>, WithRaw<(bool,)>) = decode_der(data).unwrap();
+    assert_eq!(asn.0.value(), &10);
+    assert_eq!(asn.0.raw(), &[2, 1, 10]);
+    assert_eq!(asn.1.value(), &(false,));
+    assert_eq!(asn.1.raw(), &[48, 3, 1, 1, 0]);
+    assert_eq!(encode_der(&asn), data);
+
+    // BER input is written back verbatim, not re-encoded.
+    let data = &[48, 128, 1, 1, 1, 0, 0];
+    let asn : WithRaw<(bool,)> = decode_ber(data).unwrap();
+    assert_eq!(asn.value(), &(true,));
+    assert_eq!(encode_der(&asn), data);
+    assert_eq!(encode_der(asn.value()), &[48, 3, 1, 1, 255]);
+    assert_eq!(WithRaw::new((true,)).raw(), &[48, 3, 1, 1, 255]);
+}
+
+#[test]
+fn test_with_raw_ref() {
+    use crate::parse_ber;
+    // BER input is written back verbatim, not re-encoded.
+    let data = &[48, 128, 1, 1, 1, 0, 0];
+    let asn = parse_ber(data, |reader| {
+        reader.read_with_raw_ref::<(bool,)>()
+    }).unwrap();
+    assert_eq!(asn.value(), &(true,));
+    assert_eq!(asn.raw(), data);
+    assert_eq!(encode_der(&asn), data);
+    let owned = asn.into_owned();
+    assert_eq!(owned.raw(), data);
+    assert_eq!(encode_der(&owned), data);
+}
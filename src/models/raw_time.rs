@@ -0,0 +1,634 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+use core::convert::TryFrom;
+use core::fmt::{self, Display};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use super::super::Tag;
+use super::super::tags::{TAG_GENERALIZEDTIME, TAG_UTCTIME};
+use super::time_fields::{GeneralizedTimeFields, UTCTimeFields};
+
+/// An error indicating that a datetime is out of the range supported
+/// by the destination type.
+#[derive(Debug, Clone)]
+pub struct DateTimeRangeError(pub(super) ());
+
+#[cfg(feature = "std")]
+impl Error for DateTimeRangeError {}
+
+impl Display for DateTimeRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str("Datetime out of range")
+    }
+}
+
+/// Date and time in UTC between 0000-01-01T00:00:00Z and
+/// 9999-12-31T23:59:60.999999999Z, with nanosecond precision.
+///
+/// This is a dependency-free datetime type, usable without
+/// the `time`, `chrono` or `jiff` features. It can contain leap seconds.
+///
+/// As an ASN.1 value, it corresponds to the X.509 `Time` type, a CHOICE of
+/// UTCTime and GeneralizedTime. Following RFC 5280, it is encoded as
+/// UTCTime if the year is between 1950 and 2049, and as GeneralizedTime
+/// otherwise. Fractional seconds are truncated in the encoding. A leap
+/// second is kept in GeneralizedTime, and encoded as the preceding second
+/// in UTCTime, which can't express it.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::RawDateTime;
+/// let datetime = RawDateTime::new(2019, 10, 19, 9, 30, 0, 0).unwrap();
+/// assert_eq!(yasna::encode_der(&datetime), b"\x17\x0d191019093000Z");
+/// let datetime = RawDateTime::new(2050, 1, 1, 0, 0, 0, 0).unwrap();
+/// assert_eq!(yasna::encode_der(&datetime), b"\x18\x0f20500101000000Z");
+/// let datetime : RawDateTime =
+///     yasna::decode_der(b"\x17\x0d191019093000Z").unwrap();
+/// assert_eq!(datetime.unix_timestamp(), 1571477400);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct RawDateTime {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+pub(super) fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(super) fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 => if is_leap_year(year) { 29 } else { 28 },
+        _ => 31,
+    }
+}
+
+/// Counts days from 1970-01-01 to the given date in the proleptic
+/// Gregorian calendar.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100
+        + day_of_year;
+    return era * 146097 + day_of_era - 719468;
+}
+
+/// The inverse of `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524
+        - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era
+        - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u8;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    } as u8;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    return (year, month, day);
+}
+
+/// Parses a run of decimal digits, failing on a non-digit or an overflow.
+pub(super) fn digits_value(digits: &[u8]) -> Option<u64> {
+    let mut value : u64 = 0;
+    for &b in digits {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        value = value.checked_mul(10)?.checked_add((b - b'0') as u64)?;
+    }
+    return Some(value);
+}
+
+/// Writes `value` in `width` decimal digits, padded with zeros.
+pub(super) fn push_digits(buf: &mut Vec<u8>, value: u32, width: u32) {
+    for i in (0..width).rev() {
+        buf.push((value / 10u32.pow(i) % 10) as u8 + b'0');
+    }
+}
+
+impl RawDateTime {
+    /// Constructs `RawDateTime` from the calendar fields in UTC.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the fields don't specify a correct datetime
+    /// between the years 0 and 9999.
+    /// `second` can be 60 only in the last minute of a day.
+    pub fn new(year: u16, month: u8, day: u8,
+            hour: u8, minute: u8, second: u8, nanosecond: u32)
+            -> Option<Self> {
+        if !(year <= 9999 && (1..=12).contains(&month) &&
+                (1..=days_in_month(year as i64, month)).contains(&day) &&
+                hour < 24 && minute < 60 && nanosecond < 1_000_000_000) {
+            return None;
+        }
+        if !(second < 60 || (second == 60 && hour == 23 && minute == 59)) {
+            return None;
+        }
+        return Some(RawDateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        });
+    }
+
+    /// Constructs `RawDateTime` from the number of non-leap seconds since
+    /// 1970-01-01T00:00:00Z and the nanoseconds within the second.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the year is not between 0 and 9999, or
+    /// `nanosecond` is not less than 1,000,000,000.
+    pub fn from_unix_timestamp(seconds: i64, nanosecond: u32) -> Option<Self> {
+        // 0000-01-01 to 9999-12-31
+        if !((-62167219200..253402300800).contains(&seconds) &&
+                nanosecond < 1_000_000_000) {
+            return None;
+        }
+        let days = seconds.div_euclid(86400);
+        let seconds_of_day = seconds.rem_euclid(86400);
+        let (year, month, day) = civil_from_days(days);
+        return Some(RawDateTime {
+            year: year as u16,
+            month,
+            day,
+            hour: (seconds_of_day / 3600) as u8,
+            minute: (seconds_of_day / 60 % 60) as u8,
+            second: (seconds_of_day % 60) as u8,
+            nanosecond,
+        });
+    }
+
+    /// Returns the number of non-leap seconds since 1970-01-01T00:00:00Z.
+    ///
+    /// A leap second is counted as the preceding second.
+    pub fn unix_timestamp(&self) -> i64 {
+        let days = days_from_civil(self.year as i64, self.month, self.day);
+        return days * 86400 + self.hour as i64 * 3600
+            + self.minute as i64 * 60 + self.second.min(59) as i64;
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1 to 31.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59.
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second, from 0 to 60.
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the nanoseconds within the second.
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// Tells whether it is in a leap second.
+    pub fn is_leap_second(&self) -> bool {
+        self.second == 60
+    }
+
+    /// Converts the local time and the offset into UTC.
+    fn from_local(date: (u16, u8, u8), time: (u8, u8, u8),
+            fraction_nanos: u64, offset_minutes: i64) -> Option<Self> {
+        let (year, month, day) = date;
+        let (hour, minute, second) = time;
+        if !((1..=12).contains(&month) &&
+                (1..=days_in_month(year as i64, month)).contains(&day) &&
+                hour < 24 && minute < 60 && second <= 60) {
+            return None;
+        }
+        let is_leap_second = second == 60;
+        let seconds = days_from_civil(year as i64, month, day) * 86400
+            + hour as i64 * 3600 + minute as i64 * 60
+            + second.min(59) as i64
+            + (fraction_nanos / 1_000_000_000) as i64
+            - offset_minutes * 60;
+        let nanosecond = (fraction_nanos % 1_000_000_000) as u32;
+        let mut datetime = Self::from_unix_timestamp(seconds, nanosecond)?;
+        if is_leap_second {
+            if !(datetime.hour == 23 && datetime.minute == 59 &&
+                    datetime.second == 59) {
+                return None;
+            }
+            datetime.second = 60;
+        }
+        return Some(datetime);
+    }
+
+    /// Parses ASN.1 string representation of UTCTime.
+    ///
+    /// Same as [`UTCTime::parse`](crate::models::UTCTime::parse), the year
+    /// is interpreted as between 1950 and 2049.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::RawDateTime;
+    /// let datetime = RawDateTime::parse_utctime(b"8201020700-0500").unwrap();
+    /// assert_eq!(datetime, RawDateTime::new(1982, 1, 2, 12, 0, 0, 0).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string does not specify a correct
+    /// datetime.
+    pub fn parse_utctime(buf: &[u8]) -> Option<Self> {
+        let fields = UTCTimeFields::parse(buf)?;
+        let year = if fields.year_short < 50 {
+            fields.year_short as u16 + 2000
+        } else {
+            fields.year_short as u16 + 1900
+        };
+        let datetime = Self::from_local(
+            (year, fields.month, fields.day),
+            (fields.hour, fields.minute, fields.second.unwrap_or(0)),
+            0, fields.offset_minutes as i64)?;
+        if !(1950..2050).contains(&datetime.year) {
+            return None;
+        }
+        return Some(datetime);
+    }
+
+    /// Parses ASN.1 string representation of GeneralizedTime.
+    ///
+    /// Fractions finer than nanoseconds are truncated.
+    /// Local times without time offsets are not accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::RawDateTime;
+    /// let datetime =
+    ///     RawDateTime::parse_generalized_time(b"1985110621.14159Z").unwrap();
+    /// assert_eq!(datetime,
+    ///     RawDateTime::new(1985, 11, 6, 21, 8, 29, 724_000_000).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string does not specify a correct
+    /// datetime.
+    pub fn parse_generalized_time(buf: &[u8]) -> Option<Self> {
+        let fields = GeneralizedTimeFields::parse(buf)?;
+        let offset = fields.zone().offset_minutes()?;
+        // The unit of the last field, to which the fraction applies.
+        let unit : u64 = match (fields.minute(), fields.second()) {
+            (None, _) => 3_600_000_000_000,
+            (Some(_), None) => 60_000_000_000,
+            (Some(_), Some(_)) => 1_000_000_000,
+        };
+        // Further digits don't affect nanoseconds.
+        let significant = fields.fraction().len().min(19);
+        let numerator = digits_value(&fields.fraction()[..significant])?;
+        let fraction_nanos = (numerator as u128 * unit as u128
+            / 10u128.pow(significant as u32)) as u64;
+        return Self::from_local(
+            (fields.year(), fields.month(), fields.day()),
+            (fields.hour(), fields.minute().unwrap_or(0),
+                fields.second().unwrap_or(0)),
+            fraction_nanos, offset as i64);
+    }
+
+    /// Returns the DER representation as UTCTime, or `None` if UTCTime
+    /// can't express the datetime.
+    ///
+    /// UTCTime can't express years before 1950 or after 2049, fractional
+    /// seconds and leap seconds.
+    pub fn to_utctime_bytes(&self) -> Option<Vec<u8>> {
+        if !((1950..2050).contains(&self.year) &&
+                self.nanosecond == 0 && self.second < 60) {
+            return None;
+        }
+        let mut buf = Vec::with_capacity(13);
+        push_digits(&mut buf, self.year as u32 % 100, 2);
+        push_digits(&mut buf, self.month as u32, 2);
+        push_digits(&mut buf, self.day as u32, 2);
+        push_digits(&mut buf, self.hour as u32, 2);
+        push_digits(&mut buf, self.minute as u32, 2);
+        push_digits(&mut buf, self.second as u32, 2);
+        buf.push(b'Z');
+        return Some(buf);
+    }
+
+    /// Returns the DER representation as GeneralizedTime.
+    pub fn to_generalized_time_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(25);
+        push_digits(&mut buf, self.year as u32, 4);
+        push_digits(&mut buf, self.month as u32, 2);
+        push_digits(&mut buf, self.day as u32, 2);
+        push_digits(&mut buf, self.hour as u32, 2);
+        push_digits(&mut buf, self.minute as u32, 2);
+        push_digits(&mut buf, self.second as u32, 2);
+        if self.nanosecond != 0 {
+            buf.push(b'.');
+            push_digits(&mut buf, self.nanosecond, 9);
+            while buf.last() == Some(&b'0') {
+                buf.pop();
+            }
+        }
+        buf.push(b'Z');
+        return buf;
+    }
+
+    /// Returns the RFC 5280 representation as an X.509 `Time`, with its tag.
+    ///
+    /// The fractional seconds are truncated. A leap second is written as
+    /// the preceding second only in UTCTime, which can't express it.
+    pub(crate) fn to_x509_time(self) -> (Tag, Vec<u8>) {
+        let mut datetime = self;
+        datetime.nanosecond = 0;
+        if (1950..2050).contains(&datetime.year) {
+            datetime.second = datetime.second.min(59);
+            // Always expressible as UTCTime now
+            return (TAG_UTCTIME, datetime.to_utctime_bytes().unwrap());
+        }
+        return (TAG_GENERALIZEDTIME, datetime.to_generalized_time_bytes());
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for RawDateTime {
+    type Error = DateTimeRangeError;
+
+    fn try_from(datetime: chrono::DateTime<chrono::Utc>)
+            -> Result<Self, Self::Error> {
+        let nanosecond = datetime.timestamp_subsec_nanos();
+        let error = || DateTimeRangeError(());
+        if nanosecond >= 1_000_000_000 {
+            // chrono represents a leap second as the preceding second
+            // with an extra second in the nanoseconds.
+            let mut raw = Self::from_unix_timestamp(
+                datetime.timestamp(), nanosecond - 1_000_000_000)
+                .ok_or_else(error)?;
+            if !(raw.hour == 23 && raw.minute == 59 && raw.second == 59) {
+                return Err(error());
+            }
+            raw.second = 60;
+            return Ok(raw);
+        }
+        return Self::from_unix_timestamp(datetime.timestamp(), nanosecond)
+            .ok_or_else(error);
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<RawDateTime> for chrono::DateTime<chrono::Utc> {
+    fn from(datetime: RawDateTime) -> Self {
+        let nanosecond = if datetime.is_leap_second() {
+            datetime.nanosecond + 1_000_000_000
+        } else {
+            datetime.nanosecond
+        };
+        // Always in range of chrono
+        return chrono::DateTime::from_timestamp(
+            datetime.unix_timestamp(), nanosecond).unwrap();
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<jiff::Timestamp> for RawDateTime {
+    type Error = DateTimeRangeError;
+
+    fn try_from(timestamp: jiff::Timestamp) -> Result<Self, Self::Error> {
+        // Timestamps before 1970 have negative nanoseconds.
+        let seconds = timestamp.as_second();
+        let nanosecond = timestamp.subsec_nanosecond();
+        let (seconds, nanosecond) = if nanosecond < 0 {
+            (seconds - 1, (nanosecond + 1_000_000_000) as u32)
+        } else {
+            (seconds, nanosecond as u32)
+        };
+        return Self::from_unix_timestamp(seconds, nanosecond)
+            .ok_or(DateTimeRangeError(()));
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<RawDateTime> for jiff::Timestamp {
+    type Error = DateTimeRangeError;
+
+    /// Converts `RawDateTime` into `jiff::Timestamp`.
+    ///
+    /// As jiff doesn't support leap seconds, it fails in a leap second.
+    fn try_from(datetime: RawDateTime) -> Result<Self, Self::Error> {
+        if datetime.is_leap_second() {
+            return Err(DateTimeRangeError(()));
+        }
+        return jiff::Timestamp::new(
+            datetime.unix_timestamp(), datetime.nanosecond as i32)
+            .map_err(|_| DateTimeRangeError(()));
+    }
+}
+
+#[cfg(test)]
+type Fields = (u16, u8, u8, u8, u8, u8, u32);
+
+#[test]
+fn test_raw_date_time_unix_timestamp() {
+    let tests : &[(i64, Fields)] = &[
+        (0, (1970, 1, 1, 0, 0, 0, 0)),
+        (951782400, (2000, 2, 29, 0, 0, 0, 0)),
+        (-1, (1969, 12, 31, 23, 59, 59, 0)),
+        (-62167219200, (0, 1, 1, 0, 0, 0, 0)),
+        (-62162035201, (0, 2, 29, 23, 59, 59, 0)),
+        (253402300799, (9999, 12, 31, 23, 59, 59, 0)),
+    ];
+    for &(timestamp, (y, mo, d, h, mi, s, n)) in tests {
+        let datetime = RawDateTime::new(y, mo, d, h, mi, s, n).unwrap();
+        assert_eq!(datetime.unix_timestamp(), timestamp);
+        assert_eq!(RawDateTime::from_unix_timestamp(timestamp, 0).unwrap(),
+            datetime);
+    }
+    assert!(RawDateTime::from_unix_timestamp(-62167219201, 0).is_none());
+    assert!(RawDateTime::from_unix_timestamp(253402300800, 0).is_none());
+    assert!(RawDateTime::new(1900, 2, 29, 0, 0, 0, 0).is_none());
+    assert!(RawDateTime::new(2016, 12, 31, 23, 59, 60, 0).is_some());
+    assert!(RawDateTime::new(2016, 12, 31, 23, 58, 60, 0).is_none());
+}
+
+#[test]
+fn test_raw_date_time_parse() {
+    let tests : &[(&[u8], Option<Fields>)] = &[
+        (b"820102120000Z", Some((1982, 1, 2, 12, 0, 0, 0))),
+        (b"8201021200Z", Some((1982, 1, 2, 12, 0, 0, 0))),
+        (b"8201020700-0500", Some((1982, 1, 2, 12, 0, 0, 0))),
+        (b"491231230000-0100", None),
+        (b"000229123456Z", Some((2000, 2, 29, 12, 34, 56, 0))),
+        (b"010229123456Z", None),
+        (b"8201021200", None),
+        (b"820102126000Z", None),
+    ];
+    for &(buf, expected) in tests {
+        let expected = expected.map(|(y, mo, d, h, mi, s, n)| {
+            RawDateTime::new(y, mo, d, h, mi, s, n).unwrap()
+        });
+        assert_eq!(RawDateTime::parse_utctime(buf), expected);
+    }
+    let tests : &[(&[u8], Option<Fields>)] = &[
+        (b"19851106210627.3Z", Some((1985, 11, 6, 21, 6, 27, 300_000_000))),
+        (b"19851106210627.3-0500", Some((1985, 11, 7, 2, 6, 27, 300_000_000))),
+        (b"198511062106.456Z", Some((1985, 11, 6, 21, 6, 27, 360_000_000))),
+        (b"1985110621Z", Some((1985, 11, 6, 21, 0, 0, 0))),
+        (b"1985110621,5+09", Some((1985, 11, 6, 12, 30, 0, 0))),
+        (b"19990101085960.1234+0900",
+            Some((1998, 12, 31, 23, 59, 60, 123_400_000))),
+        (b"20080229033411.3625431984612391672391625532918636000680000-0500",
+            Some((2008, 2, 29, 8, 34, 11, 362_543_198))),
+        (b"19851106210627.Z", None),
+        (b"19851106210627", None),
+        (b"19851106210660Z", None),
+        (b"00000101000000+0001", None),
+    ];
+    for &(buf, expected) in tests {
+        let expected = expected.map(|(y, mo, d, h, mi, s, n)| {
+            RawDateTime::new(y, mo, d, h, mi, s, n).unwrap()
+        });
+        assert_eq!(RawDateTime::parse_generalized_time(buf), expected);
+    }
+}
+
+#[test]
+fn test_raw_date_time_encode() {
+    use crate::{encode_der, decode_der, decode_ber, ASN1ErrorKind};
+    let tests : &[(Fields, &[u8])] = &[
+        ((1950, 1, 1, 0, 0, 0, 0), b"\x17\x0d500101000000Z"),
+        ((2049, 12, 31, 23, 59, 59, 0), b"\x17\x0d491231235959Z"),
+        ((1949, 12, 31, 23, 59, 59, 0), b"\x18\x0f19491231235959Z"),
+        ((2050, 1, 1, 0, 0, 0, 0), b"\x18\x0f20500101000000Z"),
+        ((9999, 12, 31, 23, 59, 59, 0), b"\x18\x0f99991231235959Z"),
+    ];
+    for &((y, mo, d, h, mi, s, n), der) in tests {
+        let datetime = RawDateTime::new(y, mo, d, h, mi, s, n).unwrap();
+        assert_eq!(encode_der(&datetime), der);
+        assert_eq!(decode_der::<RawDateTime>(der).unwrap(), datetime);
+    }
+    // Fractions are truncated, and leap seconds become the preceding second
+    // in UTCTime but are kept in GeneralizedTime.
+    let tests : &[(Fields, &[u8])] = &[
+        ((2016, 12, 31, 23, 59, 60, 0), b"\x17\x0d161231235959Z"),
+        ((2019, 1, 1, 0, 0, 0, 999_999_999), b"\x17\x0d190101000000Z"),
+        ((2050, 1, 1, 0, 0, 0, 120_000_000), b"\x18\x0f20500101000000Z"),
+        ((1949, 12, 31, 23, 59, 60, 500_000_000),
+            b"\x18\x0f19491231235960Z"),
+    ];
+    for &((y, mo, d, h, mi, s, n), der) in tests {
+        let datetime = RawDateTime::new(y, mo, d, h, mi, s, n).unwrap();
+        assert_eq!(encode_der(&datetime), der);
+    }
+    let datetime = decode_ber::<RawDateTime>(b"\x17\x0b8201021200Z").unwrap();
+    assert_eq!(datetime, RawDateTime::new(1982, 1, 2, 12, 0, 0, 0).unwrap());
+    let err = decode_der::<RawDateTime>(b"\x17\x0b8201021200Z").unwrap_err();
+    assert_eq!(err.kind(), ASN1ErrorKind::NonCanonicalTime);
+    let err = decode_der::<RawDateTime>(b"\x04\x00").unwrap_err();
+    assert_eq!(err.kind(), ASN1ErrorKind::Invalid);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_raw_date_time_chrono() {
+    use chrono::{DateTime, NaiveDate, Utc};
+    let tests : &[Fields] = &[
+        (1970, 1, 1, 0, 0, 0, 0),
+        (1969, 12, 31, 23, 59, 59, 999_999_999),
+        (2016, 12, 31, 23, 59, 59, 1_500_000_000),
+        (9999, 12, 31, 23, 59, 59, 0),
+    ];
+    for &(y, mo, d, h, mi, s, n) in tests {
+        let datetime = NaiveDate::from_ymd_opt(y as i32, mo as u32, d as u32)
+            .unwrap()
+            .and_hms_nano_opt(h as u32, mi as u32, s as u32, n).unwrap()
+            .and_utc();
+        let raw = RawDateTime::try_from(datetime).unwrap();
+        assert_eq!(raw.year(), y);
+        assert_eq!(raw.is_leap_second(), n >= 1_000_000_000);
+        assert_eq!(raw.nanosecond(), n % 1_000_000_000);
+        assert_eq!(DateTime::<Utc>::from(raw), datetime);
+    }
+    let datetime = NaiveDate::from_ymd_opt(10000, 1, 1).unwrap()
+        .and_hms_opt(0, 0, 0).unwrap().and_utc();
+    assert!(RawDateTime::try_from(datetime).is_err());
+
+    let datetime = NaiveDate::from_ymd_opt(2019, 10, 19).unwrap()
+        .and_hms_nano_opt(9, 30, 0, 500).unwrap().and_utc();
+    let der = crate::encode_der(&datetime);
+    assert_eq!(der, b"\x17\x0d191019093000Z");
+    assert_eq!(crate::decode_der::<DateTime<Utc>>(&der).unwrap(),
+        datetime - chrono::Duration::nanoseconds(500));
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_raw_date_time_jiff() {
+    use jiff::Timestamp;
+    let tests : &[(i64, i32)] = &[
+        (0, 0),
+        (-1, -1),
+        (-1, -999_999_999),
+        (1571477400, 123),
+        (-62167219200, 0),
+    ];
+    for &(seconds, nanosecond) in tests {
+        let timestamp = Timestamp::new(seconds, nanosecond).unwrap();
+        let raw = RawDateTime::try_from(timestamp).unwrap();
+        assert_eq!(Timestamp::try_from(raw).unwrap(), timestamp);
+    }
+    let raw = RawDateTime::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+    assert!(Timestamp::try_from(raw).is_err());
+    let timestamp = Timestamp::new(-62167219201, 0).unwrap();
+    assert!(RawDateTime::try_from(timestamp).is_err());
+
+    let timestamp = Timestamp::new(2524608000, 0).unwrap();
+    let der = crate::encode_der(&timestamp);
+    assert_eq!(der, b"\x18\x0f20500101000000Z");
+    assert_eq!(crate::decode_der::<Timestamp>(&der).unwrap(), timestamp);
+}
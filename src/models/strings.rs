@@ -0,0 +1,344 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::convert::TryFrom;
+use core::fmt::{self, Display};
+use core::ops::Deref;
+#[cfg(feature = "std")]
+use std::error::Error;
+use alloc::string::String;
+
+/// An error indicating that a string contains a character which is not
+/// allowed in the restricted character string type.
+#[derive(Debug, Clone)]
+pub struct InvalidStringError(());
+
+#[cfg(feature = "std")]
+impl Error for InvalidStringError {}
+
+impl Display for InvalidStringError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str("Invalid character in restricted string")
+    }
+}
+
+fn is_numeric_char(byte: u8) -> bool {
+    byte == b' ' || byte.is_ascii_digit()
+}
+
+fn is_printable_char(byte: u8) -> bool {
+    byte == b' ' ||
+        ((b'\''..=b':').contains(&byte) && byte != b'*') ||
+        byte == b'=' || byte == b'?' ||
+        byte.is_ascii_uppercase() ||
+        byte.is_ascii_lowercase()
+}
+
+fn is_visible_char(byte: u8) -> bool {
+    (b' '..=b'~').contains(&byte)
+}
+
+fn is_numeric_string(string: &str) -> bool {
+    string.bytes().all(is_numeric_char)
+}
+
+fn is_printable_string(string: &str) -> bool {
+    string.bytes().all(is_printable_char)
+}
+
+fn is_ia5_string(string: &str) -> bool {
+    string.is_ascii()
+}
+
+fn is_visible_string(string: &str) -> bool {
+    string.bytes().all(is_visible_char)
+}
+
+fn is_bmp_string(string: &str) -> bool {
+    string.chars().all(|c| (c as u32) < 0x10000)
+}
+
+fn is_utf8_string(_string: &str) -> bool {
+    true
+}
+
+macro_rules! restricted_string {
+    ($(#[$attr:meta])* $name:ident, $check:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub struct $name {
+            string: String,
+        }
+
+        impl $name {
+            /// Returns the string as `&str`.
+            pub fn as_str(&self) -> &str {
+                &self.string
+            }
+
+            /// Extracts the inner `String`.
+            pub fn into_string(self) -> String {
+                self.string
+            }
+        }
+
+        impl<'a> TryFrom<&'a str> for $name {
+            type Error = InvalidStringError;
+
+            fn try_from(string: &'a str) -> Result<Self, Self::Error> {
+                if !$check(string) {
+                    return Err(InvalidStringError(()));
+                }
+                return Ok($name {
+                    string: String::from(string),
+                });
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = InvalidStringError;
+
+            fn try_from(string: String) -> Result<Self, Self::Error> {
+                if !$check(&string) {
+                    return Err(InvalidStringError(()));
+                }
+                return Ok($name {
+                    string,
+                });
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.string
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.string
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+                f.write_str(&self.string)
+            }
+        }
+    };
+}
+
+restricted_string! {
+    /// A string which consists only of digits and spaces.
+    ///
+    /// Corresponds to ASN.1 NumericString type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use yasna::models::NumericString;
+    /// let s = NumericString::try_from("128 256").unwrap();
+    /// assert_eq!(yasna::encode_der(&s), [18, 7, 49, 50, 56, 32, 50, 53, 54]);
+    /// assert!(NumericString::try_from("12a").is_err());
+    /// ```
+    NumericString, is_numeric_string
+}
+
+restricted_string! {
+    /// A string which consists only of the PrintableString characters:
+    /// latin letters, digits, space and `'()+,-./:=?`.
+    ///
+    /// Corresponds to ASN.1 PrintableString type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use yasna::models::PrintableString;
+    /// let s : PrintableString = yasna::decode_der(
+    ///     &[19, 9, 67, 111, 46, 44, 32, 76, 116, 100, 46]).unwrap();
+    /// assert_eq!(&*s, "Co., Ltd.");
+    /// assert!(PrintableString::try_from("Co. & Ltd.").is_err());
+    /// ```
+    PrintableString, is_printable_string
+}
+
+restricted_string! {
+    /// A string which consists only of ASCII characters.
+    ///
+    /// Corresponds to ASN.1 IA5String type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use yasna::models::Ia5String;
+    /// let s = Ia5String::try_from("ASCII plz").unwrap();
+    /// assert_eq!(s.len(), 9);
+    /// assert!(Ia5String::try_from("£").is_err());
+    /// ```
+    Ia5String, is_ia5_string
+}
+
+restricted_string! {
+    /// A string which consists only of printable ASCII characters,
+    /// including space.
+    ///
+    /// Corresponds to ASN.1 VisibleString type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use yasna::models::VisibleString;
+    /// let s = VisibleString::try_from("Hi!").unwrap();
+    /// assert_eq!(yasna::encode_der(&s), [26, 3, 72, 105, 33]);
+    /// assert!(VisibleString::try_from("Hi!\n").is_err());
+    /// ```
+    VisibleString, is_visible_string
+}
+
+restricted_string! {
+    /// A string which consists only of characters in the Basic Multilingual
+    /// Plane.
+    ///
+    /// Corresponds to ASN.1 BMPString type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use yasna::models::BmpString;
+    /// let s = BmpString::try_from("£π quux").unwrap();
+    /// assert_eq!(&*s, "£π quux");
+    /// assert!(BmpString::try_from("𪘂").is_err());
+    /// ```
+    BmpString, is_bmp_string
+}
+
+restricted_string! {
+    /// An arbitrary Unicode string.
+    ///
+    /// Corresponds to ASN.1 UTF8String type. While `String` is already
+    /// encoded as UTF8String, this type is provided for symmetry with
+    /// the other restricted string types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use yasna::models::Utf8String;
+    /// let s = Utf8String::try_from("gnaw ροκανίζω 𪘂る").unwrap();
+    /// assert_eq!(yasna::encode_der(&s), yasna::encode_der(s.as_str()));
+    /// ```
+    Utf8String, is_utf8_string
+}
+
+/// A string of any of the restricted character string types supported
+/// by this library.
+///
+/// This is a CHOICE of NumericString, PrintableString, IA5String,
+/// VisibleString, BMPString and UTF8String. It is a superset of
+/// X.520 DirectoryString (except TeletexString and UniversalString,
+/// which are not supported), and is useful to read strings whose
+/// exact type is decided by the sender.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::DirectoryString;
+/// let s : DirectoryString = yasna::decode_der(&[19, 2, 74, 80]).unwrap();
+/// assert_eq!(s.as_str(), "JP");
+/// assert!(matches!(s, DirectoryString::PrintableString(_)));
+/// let s : DirectoryString = yasna::decode_der(&[12, 3, 230, 157, 177]).unwrap();
+/// assert_eq!(s.as_str(), "東");
+/// assert!(matches!(s, DirectoryString::Utf8String(_)));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum DirectoryString {
+    /// A NumericString
+    NumericString(NumericString),
+    /// A PrintableString
+    PrintableString(PrintableString),
+    /// An IA5String
+    Ia5String(Ia5String),
+    /// A VisibleString
+    VisibleString(VisibleString),
+    /// A BMPString
+    BmpString(BmpString),
+    /// A UTF8String
+    Utf8String(Utf8String),
+}
+
+impl DirectoryString {
+    /// Returns the string as `&str`, regardless of its type.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectoryString::NumericString(ref s) => s.as_str(),
+            DirectoryString::PrintableString(ref s) => s.as_str(),
+            DirectoryString::Ia5String(ref s) => s.as_str(),
+            DirectoryString::VisibleString(ref s) => s.as_str(),
+            DirectoryString::BmpString(ref s) => s.as_str(),
+            DirectoryString::Utf8String(ref s) => s.as_str(),
+        }
+    }
+}
+
+impl Display for DirectoryString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.as_str())
+    }
+}
+
+#[test]
+fn test_restricted_string_validation() {
+    assert!(NumericString::try_from("0123 456789").is_ok());
+    assert!(NumericString::try_from("-1").is_err());
+    assert!(PrintableString::try_from("Example Co. (Tokyo) 1+1=2?").is_ok());
+    assert!(PrintableString::try_from("a*b").is_err());
+    assert!(PrintableString::try_from("a@b").is_err());
+    assert!(Ia5String::try_from("foo@example.com").is_ok());
+    assert!(Ia5String::try_from("ü").is_err());
+    assert!(VisibleString::try_from("~!").is_ok());
+    assert!(VisibleString::try_from("\t").is_err());
+    assert!(BmpString::try_from("\u{FFFD}").is_ok());
+    assert!(BmpString::try_from("\u{10000}").is_err());
+    assert!(Utf8String::try_from(String::from("\u{10000}")).is_ok());
+}
+
+#[test]
+fn test_directory_string_roundtrip() {
+    use crate::{encode_der, decode_der};
+    let tests : &[(DirectoryString, &[u8])] = &[
+        (DirectoryString::NumericString(
+            NumericString::try_from("12").unwrap()), &[18, 2, 49, 50]),
+        (DirectoryString::PrintableString(
+            PrintableString::try_from("JP").unwrap()), &[19, 2, 74, 80]),
+        (DirectoryString::Ia5String(
+            Ia5String::try_from("a@b").unwrap()), &[22, 3, 97, 64, 98]),
+        (DirectoryString::VisibleString(
+            VisibleString::try_from("Hi!").unwrap()), &[26, 3, 72, 105, 33]),
+        (DirectoryString::BmpString(
+            BmpString::try_from("π").unwrap()), &[30, 2, 3, 192]),
+        (DirectoryString::Utf8String(
+            Utf8String::try_from("π").unwrap()), &[12, 2, 207, 128]),
+    ];
+    for &(ref value, data) in tests {
+        assert_eq!(encode_der(value), data);
+        assert_eq!(&decode_der::<DirectoryString>(data).unwrap(), value);
+    }
+    let tests : &[&[u8]] = &[
+        &[4, 2, 74, 80], &[19, 2, 74, 64], &[30, 4, 216, 105, 222, 2],
+    ];
+    for &data in tests {
+        decode_der::<DirectoryString>(data).unwrap_err();
+    }
+}
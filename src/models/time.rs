@@ -7,11 +7,131 @@
 // except according to those terms.
 
 use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
 use core::convert::TryFrom;
 use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 
-/// Date and time between 1950-01-01T00:00:00Z and 2049-12-31T23:59:59Z.
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+use super::raw_time::DateTimeRangeError;
+use super::time_fields::{GeneralizedTimeFields, UTCTimeFields};
+
+/// How strictly the string representations of [`UTCTime`] and
+/// [`GeneralizedTime`] are checked when parsed.
+///
+/// # Features
+///
+/// This enum is enabled by `time` feature.
+///
+/// ```toml
+/// [dependencies]
+/// yasna = { version = "*", features = ["time"] }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TimeStrictness {
+    /// Accepts every form allowed in BER: time offsets, omitted minutes
+    /// and seconds, and fractions of any precision, separated by either
+    /// `.` or `,`.
+    ///
+    /// GeneralizedTime in local time (without `Z` or an offset) is
+    /// accepted only when a default offset is given, as in
+    /// [`GeneralizedTime::parse_with_strictness_and_offset`].
+    Lenient,
+    /// Accepts only the canonical form required in DER (X.690 11.7 and
+    /// 11.8): the time is in UTC with `Z`, seconds are present,
+    /// and the fraction, if any, is separated by `.` and has no trailing
+    /// zeros.
+    Der,
+    /// Accepts only the forms required in the RFC 5280 profile:
+    /// `YYMMDDHHMMSSZ` for UTCTime and `YYYYMMDDHHMMSSZ` for
+    /// GeneralizedTime, without fractions.
+    Rfc5280,
+}
+
+/// The 100-year window which the two-digit years of [`UTCTime`] are
+/// interpreted in.
+///
+/// # Features
+///
+/// This struct is enabled by `time` feature.
+///
+/// ```toml
+/// [dependencies]
+/// yasna = { version = "*", features = ["time"] }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::UTCTimeWindow;
+/// assert_eq!(UTCTimeWindow::X509.expand(49), 2049);
+/// assert_eq!(UTCTimeWindow::X509.expand(50), 1950);
+/// let window = UTCTimeWindow::sliding(2026, 80);
+/// assert_eq!((window.start_year(), window.end_year()), (1946, 2045));
+/// assert_eq!(window.expand(45), 2045);
+/// assert_eq!(window.expand(46), 1946);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct UTCTimeWindow {
+    start_year: i32,
+}
+
+impl UTCTimeWindow {
+    /// The window from 1950 to 2049, specified by X.501 and RFC 5280.
+    pub const X509: UTCTimeWindow = UTCTimeWindow { start_year: 1950 };
+
+    /// Constructs a window from `start_year` to `start_year + 99`.
+    pub fn starting_at(start_year: i32) -> Self {
+        UTCTimeWindow {
+            start_year,
+        }
+    }
+
+    /// Constructs a window which contains `past_years` years before
+    /// `reference_year` (typically the current year) and
+    /// `99 - past_years` years after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `past_years` is greater than 99.
+    pub fn sliding(reference_year: i32, past_years: u8) -> Self {
+        assert!(past_years <= 99,
+            "The window can't contain {} past years", past_years);
+        return UTCTimeWindow {
+            start_year: reference_year - past_years as i32,
+        };
+    }
+
+    /// Returns the first year in the window.
+    pub fn start_year(&self) -> i32 {
+        self.start_year
+    }
+
+    /// Returns the last year in the window.
+    pub fn end_year(&self) -> i32 {
+        self.start_year + 99
+    }
+
+    /// Tells whether the window contains `year`.
+    pub fn contains(&self, year: i32) -> bool {
+        self.start_year <= year && year <= self.end_year()
+    }
+
+    /// Returns the year in the window whose last two digits are
+    /// `year_short`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `year_short` is greater than 99.
+    pub fn expand(&self, year_short: u8) -> i32 {
+        assert!(year_short < 100, "{} is not a two-digit year", year_short);
+        let offset = (year_short as i32 - self.start_year).rem_euclid(100);
+        return self.start_year + offset;
+    }
+}
+
+/// Date and time between 1950-01-01T00:00:00Z and 2049-12-31T23:59:59Z,
+/// or in another 100-year window given by [`UTCTimeWindow`].
 /// It cannot express fractional seconds and leap seconds.
 /// It doesn't carry timezone information.
 ///
@@ -68,66 +188,45 @@ impl UTCTime {
     /// While neither X.680 nor X.690 specify interpretation of 2-digits year,
     /// X.501 specifies that UTCTime in Time shall be interpreted as between
     /// 1950 and 2049. This method parses the string according to the X.501
-    /// rule.
+    /// rule. Use [`parse_with_window`](UTCTime::parse_with_window) for
+    /// other interpretations.
     pub fn parse(buf: &[u8]) -> Option<Self> {
-        if buf.len() < 11 {
-            return None;
-        }
-        // i: a position of [Z+-].
-        let i = if [b'+', b'-', b'Z'].contains(&buf[10]) { 10 } else { 12 };
-        if buf.len() < i+1 || ![b'+', b'-', b'Z'].contains(&buf[i]) {
-            return None;
-        }
-        let len = if buf[i] == b'Z' { i+1 } else { i+5 };
-        if len != buf.len() {
-            return None;
-        }
-        if !buf[..i].iter().all(|&b| b'0' <= b && b <= b'9') ||
-            !buf[i+1..].iter().all(|&b| b'0' <= b && b <= b'9')
-        {
-            return None;
-        }
-        let year_short: i32 =
-            ((buf[0] - b'0') as i32) * 10 + ((buf[1] - b'0') as i32);
-        let year = if year_short < 50 {
-            year_short + 2000
-        } else {
-            year_short + 1900
-        };
-        let month = Month::try_from((buf[2] - b'0') * 10 + (buf[3] - b'0')).ok()?;
-        let day = (buf[4] - b'0') * 10 + (buf[5] - b'0');
-        let hour = (buf[6] - b'0') * 10 + (buf[7] - b'0');
-        let minute = (buf[8] - b'0') * 10 + (buf[9] - b'0');
-        let second = if i == 12 {
-            (buf[10] - b'0') * 10 + (buf[11] - b'0')
-        } else {
-            0
-        };
-        let offset_hour: i8 = if buf[i] == b'Z' {
-            0
-        } else {
-            ((buf[i+1] - b'0') as i8) * 10 + ((buf[i+2] - b'0') as i8)
-        };
-        let offset_minute: i8 = if buf[i] == b'Z' {
-            0
-        } else {
-            ((buf[i+3] - b'0') as i8) * 10 + ((buf[i+4] - b'0') as i8)
-        };
-        let date = Date::from_calendar_date(year, month, day).ok()?;
-        let time = Time::from_hms(hour, minute, second).ok()?;
+        Self::parse_with_window(buf, UTCTimeWindow::X509)
+    }
+
+    /// Parses ASN.1 string representation of UTCTime, interpreting the
+    /// two-digit year in the given window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::{UTCTime,UTCTimeWindow};
+    /// let window = UTCTimeWindow::starting_at(1970);
+    /// let datetime = UTCTime::parse_with_window(b"600101000000Z", window)
+    ///     .unwrap();
+    /// assert_eq!(datetime.datetime().year(), 2060);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string does not specify a correct
+    /// datetime, or if the datetime is out of the window after normalized
+    /// to UTC.
+    pub fn parse_with_window(buf: &[u8], window: UTCTimeWindow)
+            -> Option<Self> {
+        let fields = UTCTimeFields::parse(buf)?;
+        let year = window.expand(fields.year_short);
+        let month = Month::try_from(fields.month).ok()?;
+        let date = Date::from_calendar_date(year, month, fields.day).ok()?;
+        let time = Time::from_hms(
+            fields.hour, fields.minute, fields.second.unwrap_or(0)).ok()?;
         let datetime = PrimitiveDateTime::new(date, time);
-        if !(offset_hour < 24 && offset_minute < 60) {
-            return None;
-        }
-        let offset = if buf[i] == b'+' {
-            UtcOffset::from_hms(offset_hour, offset_minute, 0).ok()?
-        } else {
-            UtcOffset::from_hms(-offset_hour, -offset_minute, 0).ok()?
-        };
+        let offset = UtcOffset::from_whole_seconds(
+            fields.offset_minutes as i32 * 60).ok()?;
         let datetime = datetime.assume_offset(offset).to_offset(UtcOffset::UTC);
-        // While the given local datatime is in [1950, 2050) by definition,
+        // While the given local datatime is in the window by definition,
         // the UTC datetime can be out of bounds. We check this.
-        if !(1950 <= datetime.year() && datetime.year() < 2050) {
+        if !window.contains(datetime.year()) {
             return None;
         }
         return Some(UTCTime {
@@ -135,6 +234,35 @@ impl UTCTime {
         });
     }
 
+    /// Parses ASN.1 string representation of UTCTime, accepting only
+    /// the forms allowed by `strictness`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::{UTCTime,TimeStrictness};
+    /// assert!(UTCTime::parse_with_strictness(
+    ///     b"8201021200Z", TimeStrictness::Lenient).is_some());
+    /// assert!(UTCTime::parse_with_strictness(
+    ///     b"8201021200Z", TimeStrictness::Der).is_none());
+    /// assert!(UTCTime::parse_with_strictness(
+    ///     b"820102120000Z", TimeStrictness::Rfc5280).is_some());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string does not specify a correct
+    /// datetime, or if it is not in the form required by `strictness`.
+    pub fn parse_with_strictness(buf: &[u8], strictness: TimeStrictness)
+            -> Option<Self> {
+        let datetime = Self::parse(buf)?;
+        // In UTCTime, the DER form and the RFC 5280 form are the same.
+        if strictness != TimeStrictness::Lenient && datetime.to_bytes() != buf {
+            return None;
+        }
+        return Some(datetime);
+    }
+
     /// Constructs `UTCTime` from an `OffsetDateTime`.
     ///
     /// # Panics
@@ -167,8 +295,25 @@ impl UTCTime {
     /// - It is in a leap second.
     /// - It has a non-zero nanosecond value.
     pub fn from_datetime_opt(datetime: OffsetDateTime) -> Option<Self> {
+        return Self::from_datetime_with_window(datetime, UTCTimeWindow::X509);
+    }
+
+    /// Constructs `UTCTime` from an `OffsetDateTime`, which is to be
+    /// interpreted in the given window.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` when UTCTime can't represent the datetime. That is:
+    ///
+    /// - The year is not in the window.
+    /// - It is in a leap second.
+    /// - It has a non-zero nanosecond value.
+    pub fn from_datetime_with_window(
+        datetime: OffsetDateTime,
+        window: UTCTimeWindow,
+    ) -> Option<Self> {
         let datetime = datetime.to_offset(UtcOffset::UTC);
-        if !(1950 <= datetime.year() && datetime.year() < 2050) {
+        if !window.contains(datetime.year()) {
             return None;
         }
         if !(datetime.nanosecond() == 0) {
@@ -221,6 +366,9 @@ impl UTCTime {
 /// Corresponds to ASN.1 GeneralizedTime type. Often used in conjunction with
 /// [`UTCTime`].
 ///
+/// To keep the representation exactly as written, use
+/// [`GeneralizedTimeFields`] instead.
+///
 /// # Features
 ///
 /// This struct is enabled by `time` feature.
@@ -255,74 +403,39 @@ pub struct GeneralizedTime {
 }
 
 impl GeneralizedTime {
-    /// Almost same as `parse`. It takes `default_offset` however.
-    /// GeneralizedTime value can omit offset in local time.
-    /// In that case, `default_offset` is used instead.
-    fn parse_general(buf: &[u8], default_offset: Option<UtcOffset>) -> Option<Self> {
-        if buf.len() < 10 {
-            return None;
-        }
-        if !buf[..10].iter().all(|&b| b'0' <= b && b <= b'9') {
-            return None;
-        }
-        let year: i32 =
-            ((buf[0] - b'0') as i32) * 1000 + ((buf[1] - b'0') as i32) * 100
-            + ((buf[2] - b'0') as i32) * 10 + ((buf[3] - b'0') as i32);
-        let month = Month::try_from((buf[4] - b'0') * 10 + (buf[5] - b'0')).ok()?;
-        let day = (buf[6] - b'0') * 10 + (buf[7] - b'0');
-        let hour = (buf[8] - b'0') * 10 + (buf[9] - b'0');
-        // i: current position on `buf`
-        let mut i = 10;
+    /// Converts the calendar fields to `GeneralizedTime`.
+    /// A local time without offset is interpreted with `default_offset`.
+    fn from_fields_general(
+        fields: &GeneralizedTimeFields,
+        default_offset: Option<UtcOffset>,
+    ) -> Option<Self> {
+        let month = Month::try_from(fields.month()).ok()?;
         // The factor to scale the fraction part to nanoseconds.
-        let mut fraction_scale : i64 = 1_000_000_000;
-        let mut minute: u8;
-        if i+2 <= buf.len() &&
-                buf[i..i+2].iter().all(|&b| b'0' <= b && b <= b'9') {
-            minute = (buf[i] - b'0') * 10 + (buf[i + 1] - b'0');
-            i += 2;
-        } else {
-            fraction_scale = 3_600_000_000_000;
-            minute = 0;
-        }
-        let mut second: u8;
-        if i+2 <= buf.len() &&
-                buf[i..i+2].iter().all(|&b| b'0' <= b && b <= b'9') {
-            second = (buf[i] - b'0') * 10 + (buf[i + 1] - b'0');
-            i += 2;
-        } else {
-            if fraction_scale == 1_000_000_000 {
-                fraction_scale = 60_000_000_000;
-            }
-            second = 0;
+        let fraction_scale : i64 = match (fields.minute(), fields.second()) {
+            (None, _) => 3_600_000_000_000,
+            (Some(_), None) => 60_000_000_000,
+            (Some(_), Some(_)) => 1_000_000_000,
+        };
+        let mut minute = fields.minute().unwrap_or(0);
+        let mut second = fields.second().unwrap_or(0);
+        let digits = fields.fraction();
+        let mut sub_nano = vec![b'0'; digits.len()];
+        let mut carry : i64 = 0;
+        for k in (0..digits.len()).rev() {
+            let digit = (digits[k] - b'0') as i64;
+            let sum = digit * fraction_scale + carry;
+            carry = sum / 10;
+            sub_nano[k] = b'0' + ((sum % 10) as u8);
         }
-        let mut nanosecond = 0;
-        let mut sub_nano = Vec::new();
-        if i+2 <= buf.len() && (buf[i] == b'.' || buf[i] == b',')
-                && b'0' <= buf[i+1] && buf[i+1] <= b'9' {
-            i += 1;
-            let mut j = 0;
-            while i+j < buf.len() && b'0' <= buf[i+j] && buf[i+j] <= b'9' {
-                sub_nano.push(b'0');
-                j += 1;
-            }
-            let mut carry : i64 = 0;
-            for k in (0..j).rev() {
-                let digit = (buf[i+k] - b'0') as i64;
-                let sum = digit * fraction_scale + carry;
-                carry = sum / 10;
-                sub_nano[k] = b'0' + ((sum % 10) as u8);
-            }
-            nanosecond = (carry % 1_000_000_000) as u32;
-            second += (carry / 1_000_000_000 % 60) as u8;
-            minute += (carry / 60_000_000_000) as u8;
-            while let Some(&digit) = sub_nano.last() {
-                if digit == b'0' {
-                    sub_nano.pop();
-                } else {
-                    break;
-                }
+        let nanosecond = (carry % 1_000_000_000) as u32;
+        second += (carry / 1_000_000_000 % 60) as u8;
+        minute += (carry / 60_000_000_000) as u8;
+        while let Some(&digit) = sub_nano.last() {
+            if digit == b'0' {
+                sub_nano.pop();
+            } else {
+                break;
             }
-            i += j;
         }
         let mut is_leap_second = false;
         if second == 60 {
@@ -330,56 +443,20 @@ impl GeneralizedTime {
             is_leap_second = true;
             second = 59;
         }
-        let date = Date::from_calendar_date(year, month, day).ok()?;
-        let time = Time::from_hms_nano(hour, minute, second, nanosecond).ok()?;
+        let date = Date::from_calendar_date(
+            fields.year() as i32, month, fields.day()).ok()?;
+        let time = Time::from_hms_nano(
+            fields.hour(), minute, second, nanosecond).ok()?;
         let naive_datetime = PrimitiveDateTime::new(date, time);
-        let datetime: OffsetDateTime;
-        if i == buf.len() {
+        let offset = match fields.zone().offset_minutes() {
             // Local datetime with no timezone information.
-            datetime = naive_datetime
-                .assume_offset(default_offset?)
-                .to_offset(UtcOffset::UTC);
-        } else if i < buf.len() && buf[i] == b'Z' {
-            // UTC time.
-            datetime = naive_datetime.assume_utc();
-            i += 1;
-        } else if i < buf.len() && (buf[i] == b'+' || buf[i] == b'-') {
-            // Local datetime with offset information.
-            let offset_sign = buf[i];
-            i += 1;
-            if !(i+2 <= buf.len() &&
-                    buf[i..i+2].iter().all(|&b| b'0' <= b && b <= b'9')) {
-                return None;
-            }
-            let offset_hour =
-                ((buf[i] - b'0') as i8) * 10 + ((buf[i+1] - b'0') as i8);
-            i += 2;
-            let offset_minute;
-            if i+2 <= buf.len() &&
-                    buf[i..i+2].iter().all(|&b| b'0' <= b && b <= b'9') {
-                offset_minute =
-                    ((buf[i] - b'0') as i8) * 10 + ((buf[i+1] - b'0') as i8);
-                i += 2;
-            } else {
-                offset_minute = 0;
-            }
-            if !(offset_hour < 24 && offset_minute < 60) {
-                return None;
-            }
-            let offset = if offset_sign == b'+' {
-                UtcOffset::from_hms(offset_hour, offset_minute, 0)
-            } else {
-                UtcOffset::from_hms(-offset_hour, -offset_minute, 0)
-            };
-            datetime = naive_datetime
-                .assume_offset(offset.ok()?)
-                .to_offset(UtcOffset::UTC);
-        } else {
-            return None;
-        }
-        if i != buf.len() {
-            return None;
-        }
+            None => default_offset?,
+            Some(offset_minutes) => UtcOffset::from_whole_seconds(
+                offset_minutes * 60).ok()?,
+        };
+        let datetime = naive_datetime
+            .assume_offset(offset)
+            .to_offset(UtcOffset::UTC);
         // While the given local datatime is in [0, 10000) by definition,
         // the UTC datetime can be out of bounds. We check this.
         if !(0 <= datetime.year() && datetime.year() < 10000) {
@@ -392,6 +469,62 @@ impl GeneralizedTime {
         });
     }
 
+    /// Almost same as `parse`. It takes `default_offset` however.
+    /// GeneralizedTime value can omit offset in local time.
+    /// In that case, `default_offset` is used instead.
+    fn parse_general(buf: &[u8], default_offset: Option<UtcOffset>) -> Option<Self> {
+        let fields = GeneralizedTimeFields::parse(buf)?;
+        return Self::from_fields_general(&fields, default_offset);
+    }
+
+    /// Converts the calendar fields of GeneralizedTime to `GeneralizedTime`,
+    /// normalizing it to UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::{GeneralizedTime,GeneralizedTimeFields};
+    /// let fields = GeneralizedTimeFields::parse(b"1985110621.14159Z").unwrap();
+    /// let datetime = GeneralizedTime::from_fields(&fields).unwrap();
+    /// assert_eq!(&datetime.to_string(), "19851106210829.724Z");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the fields are in local time without offset,
+    /// or if the datetime is out of range after normalized to UTC.
+    pub fn from_fields(fields: &GeneralizedTimeFields) -> Option<Self> {
+        Self::from_fields_general(fields, None)
+    }
+
+    /// Converts the calendar fields of GeneralizedTime to `GeneralizedTime`,
+    /// with the default timezone for local time given.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the datetime is out of range after normalized
+    /// to UTC.
+    pub fn from_fields_with_offset(
+        fields: &GeneralizedTimeFields,
+        default_offset: UtcOffset,
+    ) -> Option<Self> {
+        Self::from_fields_general(fields, Some(default_offset))
+    }
+
+    /// Returns the calendar fields of the canonical representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::GeneralizedTime;
+    /// let datetime = GeneralizedTime::parse(b"19990101085960+0900").unwrap();
+    /// let fields = datetime.to_fields();
+    /// assert_eq!((fields.hour(), fields.second()), (23, Some(60)));
+    /// ```
+    pub fn to_fields(&self) -> GeneralizedTimeFields {
+        GeneralizedTimeFields::parse(&self.to_bytes()).unwrap()
+    }
+
     /// Parses ASN.1 string representation of GeneralizedTime.
     ///
     /// # Examples
@@ -429,6 +562,87 @@ impl GeneralizedTime {
         Self::parse_general(buf, Some(default_offset))
     }
 
+    /// Parses ASN.1 string representation of GeneralizedTime, accepting
+    /// only the forms allowed by `strictness`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::{GeneralizedTime,TimeStrictness};
+    /// assert!(GeneralizedTime::parse_with_strictness(
+    ///     b"19851106210627.30Z", TimeStrictness::Lenient).is_some());
+    /// assert!(GeneralizedTime::parse_with_strictness(
+    ///     b"19851106210627.30Z", TimeStrictness::Der).is_none());
+    /// assert!(GeneralizedTime::parse_with_strictness(
+    ///     b"19851106210627.3Z", TimeStrictness::Der).is_some());
+    /// assert!(GeneralizedTime::parse_with_strictness(
+    ///     b"19851106210627.3Z", TimeStrictness::Rfc5280).is_none());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string does not specify a correct
+    /// datetime, or if it is not in the form required by `strictness`.
+    pub fn parse_with_strictness(buf: &[u8], strictness: TimeStrictness)
+            -> Option<Self> {
+        Self::parse_with_strictness_general(buf, strictness, None)
+    }
+
+    /// Parses ASN.1 string representation of GeneralizedTime, accepting
+    /// only the forms allowed by `strictness`, with the default timezone
+    /// for local time given.
+    ///
+    /// Local time is never canonical, so `default_offset` matters only
+    /// for [`TimeStrictness::Lenient`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::{GeneralizedTime,TimeStrictness};
+    /// use time::UtcOffset;
+    /// let offset = UtcOffset::from_hms(9, 0, 0).unwrap();
+    /// let datetime = GeneralizedTime::parse_with_strictness_and_offset(
+    ///     b"19851106210627", TimeStrictness::Lenient, offset).unwrap();
+    /// assert_eq!(&datetime.to_string(), "19851106120627Z");
+    /// assert!(GeneralizedTime::parse_with_strictness_and_offset(
+    ///     b"19851106210627", TimeStrictness::Der, offset).is_none());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string does not specify a correct
+    /// datetime, or if it is not in the form required by `strictness`.
+    pub fn parse_with_strictness_and_offset(
+        buf: &[u8],
+        strictness: TimeStrictness,
+        default_offset: UtcOffset,
+    ) -> Option<Self> {
+        Self::parse_with_strictness_general(buf, strictness,
+            Some(default_offset))
+    }
+
+    fn parse_with_strictness_general(
+        buf: &[u8],
+        strictness: TimeStrictness,
+        default_offset: Option<UtcOffset>,
+    ) -> Option<Self> {
+        let datetime = Self::parse_general(buf, default_offset)?;
+        match strictness {
+            TimeStrictness::Lenient => {},
+            TimeStrictness::Der => {
+                if datetime.to_bytes() != buf {
+                    return None;
+                }
+            },
+            TimeStrictness::Rfc5280 => {
+                if datetime.to_bytes() != buf || buf.len() != 15 {
+                    return None;
+                }
+            },
+        }
+        return Some(datetime);
+    }
+
     /// Constructs `GeneralizedTime` from an `OffsetDateTime`.
     ///
     /// # Panics
@@ -590,6 +804,196 @@ impl GeneralizedTime {
     }
 }
 
+/// A time in X.509 (RFC 5280), defined as
+/// `Time ::= CHOICE { utcTime UTCTime, generalTime GeneralizedTime }`.
+///
+/// # Features
+///
+/// This enum is enabled by `time` feature.
+///
+/// ```toml
+/// [dependencies]
+/// yasna = { version = "*", features = ["time"] }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::X509Time;
+/// use time::OffsetDateTime;
+/// let time = X509Time::from_datetime(
+///     OffsetDateTime::from_unix_timestamp(2524608000).unwrap()).unwrap();
+/// assert!(matches!(time, X509Time::GeneralizedTime(_)));
+/// assert_eq!(yasna::encode_der(&time), b"\x18\x0f20500101000000Z");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum X509Time {
+    /// A time as UTCTime.
+    UTCTime(UTCTime),
+    /// A time as GeneralizedTime.
+    GeneralizedTime(GeneralizedTime),
+}
+
+impl X509Time {
+    /// Constructs `X509Time` from an `OffsetDateTime`, choosing UTCTime
+    /// for the years from 1950 to 2049 and GeneralizedTime otherwise
+    /// (RFC 5280 4.1.2.5).
+    ///
+    /// As RFC 5280 doesn't allow fractional seconds in either type,
+    /// the nanoseconds are dropped.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the year is not between 0 and 9999.
+    pub fn from_datetime(datetime: OffsetDateTime) -> Option<Self> {
+        let datetime = datetime
+            - time::Duration::nanoseconds(datetime.nanosecond() as i64);
+        if let Some(utctime) = UTCTime::from_datetime_opt(datetime) {
+            return Some(X509Time::UTCTime(utctime));
+        }
+        let generalized_time = GeneralizedTime::from_datetime_opt(datetime)?;
+        return Some(X509Time::GeneralizedTime(generalized_time));
+    }
+
+    /// Returns the `OffsetDateTime` it represents.
+    pub fn datetime(&self) -> &OffsetDateTime {
+        match *self {
+            X509Time::UTCTime(ref time) => time.datetime(),
+            X509Time::GeneralizedTime(ref time) => time.datetime(),
+        }
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+fn datetime_from_unix_nanos(nanos: i128)
+        -> Result<OffsetDateTime, DateTimeRangeError> {
+    return OffsetDateTime::from_unix_timestamp_nanos(nanos)
+        .map_err(|_| DateTimeRangeError(()));
+}
+
+#[cfg(feature = "chrono")]
+impl From<UTCTime> for chrono::DateTime<chrono::Utc> {
+    fn from(datetime: UTCTime) -> Self {
+        // Always in range of chrono
+        return chrono::DateTime::from_timestamp(
+            datetime.datetime.unix_timestamp(), 0).unwrap();
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for UTCTime {
+    type Error = DateTimeRangeError;
+
+    /// Converts `chrono::DateTime<Utc>` into `UTCTime`.
+    ///
+    /// It fails if the year is not between 1950 and 2049, or if it has
+    /// a non-zero nanosecond value or is in a leap second.
+    fn try_from(datetime: chrono::DateTime<chrono::Utc>)
+            -> Result<Self, Self::Error> {
+        if datetime.timestamp_subsec_nanos() != 0 {
+            return Err(DateTimeRangeError(()));
+        }
+        let datetime = datetime_from_unix_nanos(
+            datetime.timestamp() as i128 * 1_000_000_000)?;
+        return UTCTime::from_datetime_opt(datetime)
+            .ok_or(DateTimeRangeError(()));
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<GeneralizedTime> for chrono::DateTime<chrono::Utc> {
+    /// Converts `GeneralizedTime` into `chrono::DateTime<Utc>`.
+    ///
+    /// The digits finer than nanoseconds are dropped.
+    fn from(datetime: GeneralizedTime) -> Self {
+        let mut nanosecond = datetime.datetime.nanosecond();
+        if datetime.is_leap_second {
+            // chrono represents a leap second as the preceding second
+            // with an extra second in the nanoseconds.
+            nanosecond += 1_000_000_000;
+        }
+        // Always in range of chrono
+        return chrono::DateTime::from_timestamp(
+            datetime.datetime.unix_timestamp(), nanosecond).unwrap();
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for GeneralizedTime {
+    type Error = DateTimeRangeError;
+
+    /// Converts `chrono::DateTime<Utc>` into `GeneralizedTime`.
+    ///
+    /// It fails if the year is not between 0 and 9999.
+    fn try_from(datetime: chrono::DateTime<chrono::Utc>)
+            -> Result<Self, Self::Error> {
+        let nanosecond = datetime.timestamp_subsec_nanos();
+        let offset_datetime = datetime_from_unix_nanos(
+            datetime.timestamp() as i128 * 1_000_000_000
+                + (nanosecond % 1_000_000_000) as i128)?;
+        let mut datetime = GeneralizedTime::from_datetime_opt(offset_datetime)
+            .ok_or(DateTimeRangeError(()))?;
+        datetime.is_leap_second = nanosecond >= 1_000_000_000;
+        return Ok(datetime);
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<UTCTime> for jiff::Timestamp {
+    fn from(datetime: UTCTime) -> Self {
+        // Always in range of jiff
+        return jiff::Timestamp::from_second(
+            datetime.datetime.unix_timestamp()).unwrap();
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<jiff::Timestamp> for UTCTime {
+    type Error = DateTimeRangeError;
+
+    /// Converts `jiff::Timestamp` into `UTCTime`.
+    ///
+    /// It fails if the year is not between 1950 and 2049, or if it has
+    /// a non-zero nanosecond value.
+    fn try_from(timestamp: jiff::Timestamp) -> Result<Self, Self::Error> {
+        let datetime = datetime_from_unix_nanos(timestamp.as_nanosecond())?;
+        return UTCTime::from_datetime_opt(datetime)
+            .ok_or(DateTimeRangeError(()));
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<GeneralizedTime> for jiff::Timestamp {
+    type Error = DateTimeRangeError;
+
+    /// Converts `GeneralizedTime` into `jiff::Timestamp`.
+    ///
+    /// The digits finer than nanoseconds are dropped. As jiff doesn't
+    /// support leap seconds, it fails in a leap second.
+    fn try_from(datetime: GeneralizedTime) -> Result<Self, Self::Error> {
+        if datetime.is_leap_second {
+            return Err(DateTimeRangeError(()));
+        }
+        return jiff::Timestamp::from_nanosecond(
+            datetime.datetime.unix_timestamp_nanos())
+            .map_err(|_| DateTimeRangeError(()));
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<jiff::Timestamp> for GeneralizedTime {
+    type Error = DateTimeRangeError;
+
+    /// Converts `jiff::Timestamp` into `GeneralizedTime`.
+    ///
+    /// It fails if the year is before 0.
+    fn try_from(timestamp: jiff::Timestamp) -> Result<Self, Self::Error> {
+        let datetime = datetime_from_unix_nanos(timestamp.as_nanosecond())?;
+        return GeneralizedTime::from_datetime_opt(datetime)
+            .ok_or(DateTimeRangeError(()));
+    }
+}
+
 #[test]
 fn test_utctime_parse() {
     let datetime = *UTCTime::parse(b"8201021200Z").unwrap().datetime();
@@ -664,3 +1068,172 @@ fn test_generalized_time_parse() {
     assert_eq!(&datetime.to_string(),
         "20080229083411.362543198461239167239162553291863600068Z");
 }
+
+#[test]
+fn test_time_parse_with_strictness() {
+    use self::TimeStrictness::{Lenient, Der, Rfc5280};
+    let tests : &[(&[u8], bool, bool, bool)] = &[
+        (b"820102120000Z", true, true, true),
+        (b"8201021200Z", true, false, false),
+        (b"820102070000-0500", true, false, false),
+    ];
+    for &(buf, lenient, der, rfc5280) in tests {
+        assert_eq!(UTCTime::parse_with_strictness(buf, Lenient).is_some(),
+            lenient);
+        assert_eq!(UTCTime::parse_with_strictness(buf, Der).is_some(), der);
+        assert_eq!(UTCTime::parse_with_strictness(buf, Rfc5280).is_some(),
+            rfc5280);
+    }
+    let tests : &[(&[u8], bool, bool, bool)] = &[
+        (b"19851106210627Z", true, true, true),
+        (b"19851106210660Z", true, true, true),
+        (b"19851106210627.3Z", true, true, false),
+        (b"19851106210627.30Z", true, false, false),
+        (b"19851106210627,3Z", true, false, false),
+        (b"19851106210627.Z", false, false, false),
+        (b"198511062106Z", true, false, false),
+        (b"1985110621Z", true, false, false),
+        (b"19851106210627+0000", true, false, false),
+        (b"19851106210627", false, false, false),
+    ];
+    for &(buf, lenient, der, rfc5280) in tests {
+        assert_eq!(GeneralizedTime::parse_with_strictness(buf, Lenient)
+            .is_some(), lenient);
+        assert_eq!(GeneralizedTime::parse_with_strictness(buf, Der)
+            .is_some(), der);
+        assert_eq!(GeneralizedTime::parse_with_strictness(buf, Rfc5280)
+            .is_some(), rfc5280);
+    }
+    let offset = UtcOffset::from_hms(-5, 0, 0).unwrap();
+    let datetime = GeneralizedTime::parse_with_strictness_and_offset(
+        b"19851106210627.3", Lenient, offset).unwrap();
+    assert_eq!(&datetime.to_string(), "19851107020627.3Z");
+    assert!(GeneralizedTime::parse_with_strictness_and_offset(
+        b"19851106210627.3", Der, offset).is_none());
+    assert!(GeneralizedTime::parse_with_strictness_and_offset(
+        b"19851106210627Z", Rfc5280, offset).is_some());
+}
+
+#[test]
+fn test_utctime_window() {
+    let window = UTCTimeWindow::starting_at(1970);
+    let datetime = UTCTime::parse_with_window(b"691231235959Z", window)
+        .unwrap();
+    assert_eq!(datetime.datetime().year(), 2069);
+    assert_eq!(&datetime.to_string(), "691231235959Z");
+    let datetime = UTCTime::parse_with_window(b"7001010000Z", window)
+        .unwrap();
+    assert_eq!(datetime.datetime().year(), 1970);
+    // Out of the window after normalized to UTC
+    assert!(UTCTime::parse_with_window(b"7001010000+0100", window).is_none());
+    assert!(UTCTime::parse_with_window(b"6912312300-0100", window).is_none());
+
+    let datetime = OffsetDateTime::from_unix_timestamp(2524608000).unwrap();
+    assert!(UTCTime::from_datetime_opt(datetime).is_none());
+    assert!(UTCTime::from_datetime_with_window(datetime, window).is_some());
+    assert_eq!(UTCTimeWindow::sliding(2026, 0).expand(25), 2125);
+    assert_eq!(UTCTimeWindow::sliding(2026, 99).expand(27), 1927);
+}
+
+#[test]
+fn test_x509_time_from_datetime() {
+    let tests : &[(i64, bool)] = &[
+        (-631152001, false),
+        (-631152000, true),
+        (2524607999, true),
+        (2524608000, false),
+    ];
+    for &(timestamp, is_utctime) in tests {
+        let datetime = OffsetDateTime::from_unix_timestamp(timestamp).unwrap();
+        let time = X509Time::from_datetime(datetime).unwrap();
+        assert_eq!(matches!(time, X509Time::UTCTime(_)), is_utctime);
+        assert_eq!(time.datetime(), &datetime);
+    }
+    let tests : &[(i128, bool)] = &[
+        (1_000_000_000_500_000_000, true),
+        (-631_152_000_000_000_001, false),
+        (2_524_607_999_999_999_999, true),
+        (2_524_608_000_999_999_999, false),
+    ];
+    for &(timestamp_nanos, is_utctime) in tests {
+        let datetime =
+            OffsetDateTime::from_unix_timestamp_nanos(timestamp_nanos).unwrap();
+        let time = X509Time::from_datetime(datetime).unwrap();
+        assert_eq!(matches!(time, X509Time::UTCTime(_)), is_utctime);
+        assert_eq!(time.datetime().unix_timestamp(),
+            timestamp_nanos.div_euclid(1_000_000_000) as i64);
+        assert_eq!(time.datetime().nanosecond(), 0);
+    }
+    let datetime = Date::from_calendar_date(-1, Month::January, 1).unwrap()
+        .midnight().assume_utc();
+    assert!(X509Time::from_datetime(datetime).is_none());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_time_chrono() {
+    use chrono::{DateTime, NaiveDate, Utc};
+    let datetime = NaiveDate::from_ymd_opt(1982, 1, 2).unwrap()
+        .and_hms_opt(12, 0, 0).unwrap().and_utc();
+    let utctime = UTCTime::parse(b"820102120000Z").unwrap();
+    assert_eq!(UTCTime::try_from(datetime).unwrap(), utctime);
+    assert_eq!(DateTime::<Utc>::from(utctime), datetime);
+    let datetime = NaiveDate::from_ymd_opt(2050, 1, 1).unwrap()
+        .and_hms_opt(0, 0, 0).unwrap().and_utc();
+    assert!(UTCTime::try_from(datetime).is_err());
+    let datetime = NaiveDate::from_ymd_opt(1982, 1, 2).unwrap()
+        .and_hms_nano_opt(12, 0, 0, 1).unwrap().and_utc();
+    assert!(UTCTime::try_from(datetime).is_err());
+
+    let tests : &[(&[u8], [u32; 7])] = &[
+        (b"19851106210627.3Z", [1985, 11, 6, 21, 6, 27, 300_000_000]),
+        (b"19981231235960.5Z", [1998, 12, 31, 23, 59, 59, 1_500_000_000]),
+        (b"00000101000000Z", [0, 1, 1, 0, 0, 0, 0]),
+    ];
+    for &(buf, [y, mo, d, h, mi, s, n]) in tests {
+        let datetime = NaiveDate::from_ymd_opt(y as i32, mo, d).unwrap()
+            .and_hms_nano_opt(h, mi, s, n).unwrap().and_utc();
+        let generalized_time = GeneralizedTime::parse(buf).unwrap();
+        assert_eq!(GeneralizedTime::try_from(datetime).unwrap(),
+            generalized_time);
+        assert_eq!(DateTime::<Utc>::from(generalized_time), datetime);
+    }
+    let generalized_time =
+        GeneralizedTime::parse(b"19851106210627.1234567891Z").unwrap();
+    assert_eq!(DateTime::<Utc>::from(generalized_time).timestamp_subsec_nanos(),
+        123_456_789);
+    let datetime = NaiveDate::from_ymd_opt(-1, 12, 31).unwrap()
+        .and_hms_opt(23, 59, 59).unwrap().and_utc();
+    assert!(GeneralizedTime::try_from(datetime).is_err());
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_time_jiff() {
+    use jiff::Timestamp;
+    let timestamp = Timestamp::new(378820800, 0).unwrap();
+    let utctime = UTCTime::parse(b"820102120000Z").unwrap();
+    assert_eq!(UTCTime::try_from(timestamp).unwrap(), utctime);
+    assert_eq!(Timestamp::from(utctime), timestamp);
+    assert!(UTCTime::try_from(Timestamp::new(2524608000, 0).unwrap())
+        .is_err());
+    assert!(UTCTime::try_from(Timestamp::new(378820800, 1).unwrap())
+        .is_err());
+
+    let tests : &[(&[u8], i64, i32)] = &[
+        (b"19851106210627.3Z", 500159187, 300_000_000),
+        (b"19691231235959.9Z", -1, 900_000_000),
+        (b"00000101000000Z", -62167219200, 0),
+    ];
+    for &(buf, seconds, nanosecond) in tests {
+        let timestamp = Timestamp::new(seconds, nanosecond).unwrap();
+        let generalized_time = GeneralizedTime::parse(buf).unwrap();
+        assert_eq!(GeneralizedTime::try_from(timestamp).unwrap(),
+            generalized_time);
+        assert_eq!(Timestamp::try_from(generalized_time).unwrap(), timestamp);
+    }
+    let generalized_time = GeneralizedTime::parse(b"19981231235960Z").unwrap();
+    assert!(Timestamp::try_from(generalized_time).is_err());
+    let timestamp = Timestamp::new(-62167219201, 0).unwrap();
+    assert!(GeneralizedTime::try_from(timestamp).is_err());
+}
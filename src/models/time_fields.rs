@@ -0,0 +1,468 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+use super::raw_time::{days_in_month, digits_value, push_digits};
+
+/// The time zone part of a GeneralizedTime, as written.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GeneralizedTimeZone {
+    /// A local time, without any suffix.
+    Local,
+    /// UTC, written as `Z`.
+    Utc,
+    /// A difference from UTC, such as `+0900` or `-05`.
+    Offset {
+        /// Whether the sign is `-`.
+        negative: bool,
+        /// The hours of the difference, from 0 to 23.
+        hours: u8,
+        /// The minutes of the difference, from 0 to 59, if written.
+        minutes: Option<u8>,
+    },
+}
+
+impl GeneralizedTimeZone {
+    /// Returns the difference from UTC in minutes, or `None` for a local
+    /// time.
+    pub fn offset_minutes(&self) -> Option<i32> {
+        match *self {
+            GeneralizedTimeZone::Local => None,
+            GeneralizedTimeZone::Utc => Some(0),
+            GeneralizedTimeZone::Offset { negative, hours, minutes } => {
+                let offset = hours as i32 * 60 + minutes.unwrap_or(0) as i32;
+                Some(if negative { -offset } else { offset })
+            },
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        match *self {
+            GeneralizedTimeZone::Offset { hours, minutes, .. } =>
+                hours < 24 && minutes.map_or(true, |minutes| minutes < 60),
+            _ => true,
+        }
+    }
+}
+
+/// The calendar fields of a GeneralizedTime, exactly as written.
+///
+/// Unlike [`GeneralizedTime`](crate::models::GeneralizedTime), it doesn't
+/// normalize the value to UTC and keeps every detail of the representation:
+/// omitted minutes and seconds, leap seconds, the fraction of any precision
+/// and its decimal sign, and local times with or without an offset.
+/// Therefore [`to_bytes`](GeneralizedTimeFields::to_bytes) gives back
+/// the parsed string. It doesn't depend on the `time` feature.
+///
+/// With `time` feature, it can be converted to `GeneralizedTime` by
+/// [`GeneralizedTime::from_fields`](crate::models::GeneralizedTime::from_fields),
+/// which fails if the datetime cannot be represented.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::{GeneralizedTimeFields,GeneralizedTimeZone};
+/// let fields =
+///     GeneralizedTimeFields::parse(b"19990101085960,1230+0900").unwrap();
+/// assert_eq!(fields.second(), Some(60));
+/// assert!(fields.is_leap_second());
+/// assert_eq!(fields.fraction(), b"1230");
+/// assert_eq!(fields.zone().offset_minutes(), Some(540));
+/// assert_eq!(&fields.to_string(), "19990101085960,1230+0900");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct GeneralizedTimeFields {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: Option<u8>,
+    second: Option<u8>,
+    fraction: Vec<u8>,
+    decimal_comma: bool,
+    zone: GeneralizedTimeZone,
+}
+
+impl GeneralizedTimeFields {
+    /// Constructs `GeneralizedTimeFields` in UTC without a fraction.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the fields don't specify a correct datetime.
+    /// `second` may be 60 for a leap second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::GeneralizedTimeFields;
+    /// let fields =
+    ///     GeneralizedTimeFields::new(2016, 12, 31, 23, 59, 60).unwrap();
+    /// assert_eq!(&fields.to_string(), "20161231235960Z");
+    /// ```
+    pub fn new(year: u16, month: u8, day: u8,
+            hour: u8, minute: u8, second: u8) -> Option<Self> {
+        let fields = GeneralizedTimeFields {
+            year,
+            month,
+            day,
+            hour,
+            minute: Some(minute),
+            second: Some(second),
+            fraction: Vec::new(),
+            decimal_comma: false,
+            zone: GeneralizedTimeZone::Utc,
+        };
+        if !fields.is_valid() {
+            return None;
+        }
+        return Some(fields);
+    }
+
+    /// Replaces the fraction of the last component with the given digits,
+    /// written after `.`. An empty slice removes the fraction.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if `fraction` contains a non-digit character.
+    pub fn with_fraction(mut self, fraction: &[u8]) -> Option<Self> {
+        if !fraction.iter().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        self.fraction = fraction.to_vec();
+        self.decimal_comma = false;
+        return Some(self);
+    }
+
+    /// Replaces the time zone.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the offset is out of range.
+    pub fn with_zone(mut self, zone: GeneralizedTimeZone) -> Option<Self> {
+        if !zone.is_valid() {
+            return None;
+        }
+        self.zone = zone;
+        return Some(self);
+    }
+
+    fn is_valid(&self) -> bool {
+        return self.year <= 9999 && (1..=12).contains(&self.month) &&
+            (1..=days_in_month(self.year as i64, self.month))
+                .contains(&self.day) &&
+            self.hour < 24 && self.minute.map_or(true, |m| m < 60) &&
+            self.second.map_or(true, |s| s <= 60) &&
+            (self.minute.is_some() || self.second.is_none()) &&
+            self.zone.is_valid();
+    }
+
+    /// Parses ASN.1 string representation of GeneralizedTime.
+    ///
+    /// # Errors
+    ///
+    /// It returns `None` if the given string does not specify a correct
+    /// datetime.
+    pub fn parse(buf: &[u8]) -> Option<Self> {
+        let two_digits = |i: usize| {
+            return Some(digits_value(buf.get(i..i+2)?)? as u8);
+        };
+        if buf.len() < 10 || !buf[..10].iter().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let year = two_digits(0)? as u16 * 100 + two_digits(2)? as u16;
+        let month = two_digits(4)?;
+        let day = two_digits(6)?;
+        let hour = two_digits(8)?;
+        // i: current position on `buf`
+        let mut i = 10;
+        let minute = two_digits(i);
+        if minute.is_some() {
+            i += 2;
+        }
+        let second = if minute.is_some() { two_digits(i) } else { None };
+        if second.is_some() {
+            i += 2;
+        }
+        let mut fraction = Vec::new();
+        let mut decimal_comma = false;
+        if i + 2 <= buf.len() && (buf[i] == b'.' || buf[i] == b',') &&
+                buf[i+1].is_ascii_digit() {
+            decimal_comma = buf[i] == b',';
+            i += 1;
+            while i < buf.len() && buf[i].is_ascii_digit() {
+                fraction.push(buf[i]);
+                i += 1;
+            }
+        }
+        let zone = if i == buf.len() {
+            GeneralizedTimeZone::Local
+        } else if buf[i] == b'Z' {
+            i += 1;
+            GeneralizedTimeZone::Utc
+        } else if buf[i] == b'+' || buf[i] == b'-' {
+            let negative = buf[i] == b'-';
+            let hours = two_digits(i + 1)?;
+            i += 3;
+            let minutes = two_digits(i);
+            if minutes.is_some() {
+                i += 2;
+            }
+            GeneralizedTimeZone::Offset {
+                negative,
+                hours,
+                minutes,
+            }
+        } else {
+            return None;
+        };
+        if i != buf.len() {
+            return None;
+        }
+        let fields = GeneralizedTimeFields {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            fraction,
+            decimal_comma,
+            zone,
+        };
+        if !fields.is_valid() {
+            return None;
+        }
+        return Some(fields);
+    }
+
+    /// Returns the year, from 0 to 9999.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1 to 31.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59, if written.
+    pub fn minute(&self) -> Option<u8> {
+        self.minute
+    }
+
+    /// Returns the second, from 0 to 60, if written.
+    pub fn second(&self) -> Option<u8> {
+        self.second
+    }
+
+    /// Returns the digits of the fraction of the last component (the hour,
+    /// the minute or the second), as written. It is empty if there is no
+    /// fraction.
+    pub fn fraction(&self) -> &[u8] {
+        &self.fraction
+    }
+
+    /// Tells whether the fraction is written after `,` instead of `.`.
+    pub fn has_decimal_comma(&self) -> bool {
+        self.decimal_comma
+    }
+
+    /// Returns the time zone.
+    pub fn zone(&self) -> GeneralizedTimeZone {
+        self.zone
+    }
+
+    /// Tells whether the second is 60.
+    pub fn is_leap_second(&self) -> bool {
+        self.second == Some(60)
+    }
+
+    /// Tells whether the representation is the canonical one required in
+    /// DER: the time is in UTC with `Z`, seconds are present, and the
+    /// fraction, if any, is written after `.` and has no trailing zeros.
+    pub fn is_canonical(&self) -> bool {
+        return self.zone == GeneralizedTimeZone::Utc &&
+            self.second.is_some() && !self.decimal_comma &&
+            self.fraction.last() != Some(&b'0');
+    }
+
+    /// Returns ASN.1 representation of the datetime as `Vec<u8>`, exactly
+    /// as parsed.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(19 + self.fraction.len());
+        push_digits(&mut buf, self.year as u32, 4);
+        push_digits(&mut buf, self.month as u32, 2);
+        push_digits(&mut buf, self.day as u32, 2);
+        push_digits(&mut buf, self.hour as u32, 2);
+        if let Some(minute) = self.minute {
+            push_digits(&mut buf, minute as u32, 2);
+        }
+        if let Some(second) = self.second {
+            push_digits(&mut buf, second as u32, 2);
+        }
+        if !self.fraction.is_empty() {
+            buf.push(if self.decimal_comma { b',' } else { b'.' });
+            buf.extend_from_slice(&self.fraction);
+        }
+        match self.zone {
+            GeneralizedTimeZone::Local => {},
+            GeneralizedTimeZone::Utc => buf.push(b'Z'),
+            GeneralizedTimeZone::Offset { negative, hours, minutes } => {
+                buf.push(if negative { b'-' } else { b'+' });
+                push_digits(&mut buf, hours as u32, 2);
+                if let Some(minutes) = minutes {
+                    push_digits(&mut buf, minutes as u32, 2);
+                }
+            },
+        }
+        return buf;
+    }
+}
+
+/// The fields of a UTCTime, as written.
+///
+/// It is the common parser behind
+/// [`UTCTime`](crate::models::UTCTime) and
+/// [`RawDateTime`](crate::models::RawDateTime). Only the ranges of each
+/// field are checked; the calendar and the window of years are left to
+/// the callers.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(super) struct UTCTimeFields {
+    pub(super) year_short: u8,
+    pub(super) month: u8,
+    pub(super) day: u8,
+    pub(super) hour: u8,
+    pub(super) minute: u8,
+    pub(super) second: Option<u8>,
+    pub(super) offset_minutes: i16,
+}
+
+impl UTCTimeFields {
+    /// Parses `YYMMDDhhmm[ss](Z|(+|-)hhmm)`.
+    pub(super) fn parse(buf: &[u8]) -> Option<Self> {
+        if buf.len() < 11 {
+            return None;
+        }
+        // i: a position of [Z+-].
+        let i = if [b'+', b'-', b'Z'].contains(&buf[10]) { 10 } else { 12 };
+        if buf.len() < i+1 || ![b'+', b'-', b'Z'].contains(&buf[i]) {
+            return None;
+        }
+        let len = if buf[i] == b'Z' { i+1 } else { i+5 };
+        if len != buf.len() {
+            return None;
+        }
+        let two_digits = |i: usize| {
+            return Some(digits_value(&buf[i..i+2])? as u8);
+        };
+        let second = if i == 12 { Some(two_digits(10)?) } else { None };
+        let offset_minutes = if buf[i] == b'Z' {
+            0
+        } else {
+            let offset_hour = two_digits(i+1)?;
+            let offset_minute = two_digits(i+3)?;
+            if !(offset_hour < 24 && offset_minute < 60) {
+                return None;
+            }
+            let offset = offset_hour as i16 * 60 + offset_minute as i16;
+            if buf[i] == b'-' { -offset } else { offset }
+        };
+        let fields = UTCTimeFields {
+            year_short: two_digits(0)?,
+            month: two_digits(2)?,
+            day: two_digits(4)?,
+            hour: two_digits(6)?,
+            minute: two_digits(8)?,
+            second,
+            offset_minutes,
+        };
+        if !((1..=12).contains(&fields.month) &&
+                (1..=31).contains(&fields.day) &&
+                fields.hour < 24 && fields.minute < 60 &&
+                fields.second.map_or(true, |s| s < 60)) {
+            return None;
+        }
+        return Some(fields);
+    }
+}
+
+impl Display for GeneralizedTimeFields {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&String::from_utf8(self.to_bytes()).unwrap())
+    }
+}
+
+#[test]
+fn test_generalized_time_fields_roundtrip() {
+    let tests : &[&[u8]] = &[
+        b"19851106210627.3Z",
+        b"19851106210627,30Z",
+        b"198511062106.456Z",
+        b"1985110621,14159Z",
+        b"1985110621",
+        b"19851106210627-05",
+        b"19851106210627+0000",
+        b"19990101085960.1234+0900",
+        b"20161231235960Z",
+        b"00000229000000Z",
+        b"20080229033411.3625431984612391672391625532918636000680000-0500",
+    ];
+    for &buf in tests {
+        let fields = GeneralizedTimeFields::parse(buf).unwrap();
+        assert_eq!(fields.to_bytes(), buf);
+    }
+    let fields = GeneralizedTimeFields::parse(b"198511062106.456").unwrap();
+    assert_eq!(fields.minute(), Some(6));
+    assert_eq!(fields.second(), None);
+    assert_eq!(fields.fraction(), b"456");
+    assert_eq!(fields.zone(), GeneralizedTimeZone::Local);
+    assert!(!fields.is_canonical());
+}
+
+#[test]
+fn test_generalized_time_fields_invalid() {
+    let tests : &[&[u8]] = &[
+        b"",
+        b"198511062",
+        b"19851306210627Z",
+        b"19850229210627Z",
+        b"19851106240627Z",
+        b"19851106216027Z",
+        b"19851106210661Z",
+        b"19851106210627.Z",
+        b"1985110621062Z",
+        b"19851106210627+2400",
+        b"19851106210627+0060",
+        b"19851106210627+0",
+        b"19851106210627ZZ",
+    ];
+    for &buf in tests {
+        assert!(GeneralizedTimeFields::parse(buf).is_none());
+    }
+    assert!(GeneralizedTimeFields::new(2019, 2, 29, 0, 0, 0).is_none());
+    let fields = GeneralizedTimeFields::new(2019, 10, 19, 0, 0, 0).unwrap();
+    assert!(fields.clone().with_fraction(b"5a").is_none());
+    assert!(fields.with_zone(GeneralizedTimeZone::Offset {
+        negative: false,
+        hours: 24,
+        minutes: None,
+    }).is_none());
+}
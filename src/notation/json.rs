@@ -0,0 +1,593 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Write};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use super::super::{PCBit, Tag};
+use super::super::models::{ObjectIdentifier, TaggedDerValue};
+use super::super::models::{Date, DateTime, Duration, Time, TimeOfDay};
+use super::super::models::{GeneralizedTimeFields, RawDateTime};
+use super::super::models::{NumericString, PrintableString, VisibleString};
+use super::super::reader::BER_READER_STACK_DEPTH;
+use super::super::tags::{TAG_DATE, TAG_DATE_TIME, TAG_DURATION};
+use super::super::tags::{TAG_GENERALIZEDTIME, TAG_NUMERICSTRING};
+use super::super::tags::{TAG_PRINTABLESTRING, TAG_TIME, TAG_TIME_OF_DAY};
+use super::super::tags::{TAG_UTCTIME, TAG_VISIBLESTRING};
+use super::{Kind, Node, Primitive};
+use super::{decimal_to_integer, decode_primitive, encode_string, encode_subid};
+use super::{integer_to_decimal, parse_hex, parse_tag, universal_type_by_name};
+use super::{write_arcs, write_hex, write_node, write_tag};
+
+/// An error indicating failure to convert JSON into DER.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::notation::json_to_der;
+/// let err = json_to_der(r#"{"type":"INTEGER","value":true}"#).unwrap_err();
+/// assert_eq!(err.position(), 26);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct JsonError {
+    position: usize,
+    message: &'static str,
+}
+
+impl JsonError {
+    fn new(position: usize, message: &'static str) -> Self {
+        JsonError {
+            position,
+            message,
+        }
+    }
+
+    /// Returns the byte offset in the input where the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for JsonError {}
+
+impl Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{} at byte {}", self.message, self.position)
+    }
+}
+
+/// Converts JSON, as generated by [`to_json`](super::to_json), into DER.
+///
+/// The values are written in the given order; in particular, the components
+/// of SET values are not sorted. As in BER decoding, values can be nested
+/// up to 100 levels deep.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::notation::json_to_der;
+/// let der = json_to_der(r#"
+///     {"type": "SEQUENCE", "value": [
+///         {"type": "INTEGER", "value": -129},
+///         {"type": "PrintableString", "value": "Hello"},
+///         {"tag": "[1]", "value": "0102"}
+///     ]}"#).unwrap();
+/// assert_eq!(der, &[48, 15, 2, 2, 255, 127, 19, 5, 72, 101, 108, 108, 111,
+///     129, 2, 1, 2]);
+/// ```
+pub fn json_to_der(json: &str) -> Result<Vec<u8>, JsonError> {
+    let mut parser = Parser {
+        s: json.as_bytes(),
+        pos: 0,
+        depth: 0,
+    };
+    parser.skip_ws();
+    let value = parser.value()?;
+    parser.skip_ws();
+    if parser.pos < parser.s.len() {
+        return Err(JsonError::new(parser.pos, "trailing characters"));
+    }
+    let node = to_node(&value)?;
+    return Ok(super::super::construct_der(|writer| write_node(writer, &node)));
+}
+
+pub(super) fn write_json(node: &Node, out: &mut String) {
+    match *node {
+        Node::Primitive(ref value) => {
+            let (name, primitive) = match decode_primitive(value) {
+                Some(decoded) => decoded,
+                None => {
+                    out.push_str("{\"tag\":\"");
+                    write_tag(out, value.tag());
+                    out.push_str("\",\"value\":\"");
+                    write_hex(out, value.value());
+                    out.push_str("\"}");
+                    return;
+                },
+            };
+            write!(out, "{{\"type\":\"{}\",\"value\":", name).unwrap();
+            match primitive {
+                Primitive::Boolean(b) => {
+                    out.push_str(if b { "true" } else { "false" });
+                },
+                Primitive::Integer(bytes) => {
+                    let decimal = integer_to_decimal(bytes);
+                    if bytes.len() <= 8 {
+                        out.push_str(&decimal);
+                    } else {
+                        write_string(out, &decimal);
+                    }
+                },
+                Primitive::BitString(unused, bytes) => {
+                    out.push('"');
+                    write_hex(out, bytes);
+                    out.push('"');
+                    if unused > 0 {
+                        write!(out, ",\"unused\":{}", unused).unwrap();
+                    }
+                },
+                Primitive::OctetString(bytes) => {
+                    out.push('"');
+                    write_hex(out, bytes);
+                    out.push('"');
+                },
+                Primitive::Null => out.push_str("null"),
+                Primitive::Oid(arcs) | Primitive::RelativeOid(arcs) => {
+                    out.push('"');
+                    write_arcs(out, &arcs, ".");
+                    out.push('"');
+                },
+                Primitive::String(s) => write_string(out, &s),
+            }
+            out.push('}');
+        },
+        Node::Constructed(tag, ref children) => {
+            match super::universal_type(tag) {
+                Some((name, Kind::Constructed)) => {
+                    write!(out, "{{\"type\":\"{}\",\"value\":[", name).unwrap();
+                },
+                _ => {
+                    out.push_str("{\"tag\":\"");
+                    write_tag(out, tag);
+                    out.push_str("\",\"value\":[");
+                },
+            }
+            for (i, child) in children.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json(child, out);
+            }
+            out.push_str("]}");
+        },
+    }
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                write!(out, "\\u{:04x}", c as u32).unwrap();
+            },
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// A parsed JSON value with its position in the input.
+struct Json {
+    pos: usize,
+    kind: JsonKind,
+}
+
+enum JsonKind {
+    Null,
+    Bool(bool),
+    // The number is kept as written.
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+fn to_node(value: &Json) -> Result<Node, JsonError> {
+    let fields = match value.kind {
+        JsonKind::Object(ref fields) => fields,
+        _ => return Err(JsonError::new(value.pos, "expected an object")),
+    };
+    let mut type_name = None;
+    let mut tag = None;
+    let mut inner = None;
+    let mut unused = None;
+    for (key, field) in fields {
+        let slot = match key.as_str() {
+            "type" => &mut type_name,
+            "tag" => &mut tag,
+            "value" => &mut inner,
+            "unused" => &mut unused,
+            _ => return Err(JsonError::new(field.pos, "unknown key")),
+        };
+        if slot.is_some() {
+            return Err(JsonError::new(field.pos, "duplicate key"));
+        }
+        *slot = Some(field);
+    }
+    let inner = inner.ok_or(JsonError::new(value.pos, "missing value"))?;
+    let (tag, kind) = match (type_name, tag) {
+        (Some(name), None) => {
+            let name = as_str(name)?;
+            universal_type_by_name(name)
+                .ok_or(JsonError::new(value.pos, "unknown type"))?
+        },
+        (None, Some(tag)) => {
+            let tag = parse_tag(as_str(tag)?)
+                .ok_or(JsonError::new(tag.pos, "invalid tag"))?;
+            return raw_node(tag, inner);
+        },
+        _ => {
+            return Err(JsonError::new(value.pos,
+                "expected either type or tag"));
+        },
+    };
+    if unused.is_some() && kind != Kind::BitString {
+        return Err(JsonError::new(value.pos, "unknown key"));
+    }
+    let invalid = JsonError::new(inner.pos, "invalid value");
+    let bytes = match (kind, &inner.kind) {
+        (Kind::Constructed, JsonKind::Array(_)) => return raw_node(tag, inner),
+        (Kind::Constructed, _) => {
+            return Err(JsonError::new(inner.pos, "expected an array"));
+        },
+        (Kind::Boolean, JsonKind::Bool(b)) => {
+            vec![if *b { 255 } else { 0 }]
+        },
+        (Kind::Integer, JsonKind::Number(s)) |
+        (Kind::Integer, JsonKind::String(s)) => {
+            decimal_to_integer(s).ok_or(invalid)?
+        },
+        (Kind::BitString, JsonKind::String(s)) => {
+            let unused = match unused {
+                Some(&Json { kind: JsonKind::Number(ref n), pos }) => {
+                    n.parse::<u8>().ok().filter(|&n| n < 8)
+                        .ok_or(JsonError::new(pos, "invalid unused bits"))?
+                },
+                Some(json) => {
+                    return Err(JsonError::new(json.pos,
+                        "invalid unused bits"));
+                },
+                None => 0,
+            };
+            let mut bytes = vec![unused];
+            bytes.extend(parse_hex(s).ok_or(invalid.clone())?);
+            let last = *bytes.last().unwrap();
+            if (bytes.len() == 1 && unused > 0) ||
+                    last & ((1u16 << unused) - 1) as u8 != 0 {
+                return Err(invalid);
+            }
+            bytes
+        },
+        (Kind::OctetString, JsonKind::String(s)) => {
+            parse_hex(s).ok_or(invalid)?
+        },
+        (Kind::Null, JsonKind::Null) => Vec::new(),
+        (Kind::Oid, JsonKind::String(s)) => {
+            let oid = ObjectIdentifier::from_str(s).map_err(|_| invalid)?;
+            let arcs = oid.arcs().collect::<Vec<_>>();
+            let mut bytes = Vec::new();
+            encode_subid(&mut bytes, arcs[0] * 40 + arcs[1]);
+            for &arc in &arcs[2..] {
+                encode_subid(&mut bytes, arc);
+            }
+            bytes
+        },
+        (Kind::RelativeOid, JsonKind::String(s)) => {
+            let mut bytes = Vec::new();
+            for arc in s.split('.') {
+                encode_subid(&mut bytes, arc.parse().map_err(|_| invalid.clone())?);
+            }
+            bytes
+        },
+        (Kind::Ascii, JsonKind::String(s)) => {
+            if !is_valid_ascii(tag, s) {
+                return Err(invalid);
+            }
+            encode_string(kind, s).ok_or(invalid)?
+        },
+        (_, JsonKind::String(s)) => {
+            encode_string(kind, s).ok_or(invalid)?
+        },
+        _ => return Err(invalid),
+    };
+    return Ok(Node::Primitive(
+        TaggedDerValue::from_tag_pc_and_bytes(tag, PCBit::Primitive, bytes)));
+}
+
+/// Checks the syntax of the string types and the time types with the
+/// parsers of the corresponding models.
+fn is_valid_ascii(tag: Tag, s: &str) -> bool {
+    let bytes = s.as_bytes();
+    return s.is_ascii() && match tag {
+        TAG_NUMERICSTRING => NumericString::try_from(s).is_ok(),
+        TAG_PRINTABLESTRING => PrintableString::try_from(s).is_ok(),
+        TAG_VISIBLESTRING => VisibleString::try_from(s).is_ok(),
+        TAG_UTCTIME => RawDateTime::parse_utctime(bytes).is_some(),
+        TAG_GENERALIZEDTIME => GeneralizedTimeFields::parse(bytes).is_some(),
+        TAG_DATE => Date::parse(bytes).is_some(),
+        TAG_TIME_OF_DAY => TimeOfDay::parse(bytes).is_some(),
+        TAG_DATE_TIME => DateTime::parse(bytes).is_some(),
+        TAG_DURATION => Duration::parse(bytes).is_some(),
+        TAG_TIME => Time::parse(bytes).is_some(),
+        _ => true,
+    };
+}
+
+fn raw_node(tag: Tag, inner: &Json) -> Result<Node, JsonError> {
+    return match inner.kind {
+        JsonKind::String(ref s) => {
+            let bytes = parse_hex(s)
+                .ok_or(JsonError::new(inner.pos, "invalid hexadecimal"))?;
+            Ok(Node::Primitive(
+                TaggedDerValue::from_tag_pc_and_bytes(
+                    tag, PCBit::Primitive, bytes)))
+        },
+        JsonKind::Array(ref elements) => {
+            let children = elements.iter()
+                .map(to_node)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Node::Constructed(tag, children))
+        },
+        _ => Err(JsonError::new(inner.pos, "expected a string or an array")),
+    };
+}
+
+fn as_str(value: &Json) -> Result<&str, JsonError> {
+    return match value.kind {
+        JsonKind::String(ref s) => Ok(s),
+        _ => Err(JsonError::new(value.pos, "expected a string")),
+    };
+}
+
+// An ASN.1 value nests two levels of JSON values: an object and an array.
+const JSON_PARSER_STACK_DEPTH : usize = 2 * BER_READER_STACK_DEPTH;
+
+struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+    // The number of objects and arrays containing the current value.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).cloned()
+    }
+
+    fn skip_ws(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r')
+                = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, b: u8, message: &'static str)
+            -> Result<(), JsonError> {
+        if self.peek() != Some(b) {
+            return Err(self.error(message));
+        }
+        self.pos += 1;
+        return Ok(());
+    }
+
+    fn error(&self, message: &'static str) -> JsonError {
+        if self.pos >= self.s.len() {
+            return JsonError::new(self.pos, "unexpected end of input");
+        }
+        return JsonError::new(self.pos, message);
+    }
+
+    fn value(&mut self) -> Result<Json, JsonError> {
+        if self.depth >= JSON_PARSER_STACK_DEPTH {
+            return Err(self.error("nested too deeply"));
+        }
+        self.depth += 1;
+        let value = self.value_inner();
+        self.depth -= 1;
+        return value;
+    }
+
+    fn value_inner(&mut self) -> Result<Json, JsonError> {
+        let pos = self.pos;
+        let kind = match self.peek() {
+            Some(b'{') => {
+                self.pos += 1;
+                let mut fields = Vec::new();
+                self.skip_ws();
+                if self.peek() == Some(b'}') {
+                    self.pos += 1;
+                } else {
+                    loop {
+                        self.skip_ws();
+                        let key = self.string()?;
+                        self.skip_ws();
+                        self.expect(b':', "expected ':'")?;
+                        self.skip_ws();
+                        fields.push((key, self.value()?));
+                        self.skip_ws();
+                        if self.peek() == Some(b',') {
+                            self.pos += 1;
+                        } else {
+                            self.expect(b'}', "expected ',' or '}'")?;
+                            break;
+                        }
+                    }
+                }
+                JsonKind::Object(fields)
+            },
+            Some(b'[') => {
+                self.pos += 1;
+                let mut elements = Vec::new();
+                self.skip_ws();
+                if self.peek() == Some(b']') {
+                    self.pos += 1;
+                } else {
+                    loop {
+                        self.skip_ws();
+                        elements.push(self.value()?);
+                        self.skip_ws();
+                        if self.peek() == Some(b',') {
+                            self.pos += 1;
+                        } else {
+                            self.expect(b']', "expected ',' or ']'")?;
+                            break;
+                        }
+                    }
+                }
+                JsonKind::Array(elements)
+            },
+            Some(b'"') => JsonKind::String(self.string()?),
+            Some(b't') => {
+                self.literal("true")?;
+                JsonKind::Bool(true)
+            },
+            Some(b'f') => {
+                self.literal("false")?;
+                JsonKind::Bool(false)
+            },
+            Some(b'n') => {
+                self.literal("null")?;
+                JsonKind::Null
+            },
+            Some(b'-') | Some(b'0'..=b'9') => JsonKind::Number(self.number()?),
+            _ => return Err(self.error("expected a value")),
+        };
+        return Ok(Json {
+            pos,
+            kind,
+        });
+    }
+
+    fn literal(&mut self, literal: &str) -> Result<(), JsonError> {
+        if !self.s[self.pos..].starts_with(literal.as_bytes()) {
+            return Err(self.error("expected a value"));
+        }
+        self.pos += literal.len();
+        return Ok(());
+    }
+
+    fn digits(&mut self) -> Result<(), JsonError> {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(self.error("expected a digit"));
+        }
+        return Ok(());
+    }
+
+    fn number(&mut self) -> Result<String, JsonError> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        self.digits()?;
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            self.digits()?;
+        }
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.pos += 1;
+            }
+            self.digits()?;
+        }
+        let number = core::str::from_utf8(&self.s[start..self.pos]).unwrap();
+        return Ok(String::from(number));
+    }
+
+    fn hex4(&mut self) -> Result<u32, JsonError> {
+        let hex = self.s.get(self.pos..self.pos + 4)
+            .and_then(|hex| core::str::from_utf8(hex).ok())
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .ok_or(self.error("invalid escape"))?;
+        self.pos += 4;
+        return Ok(hex);
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.expect(b'"', "expected a string")?;
+        let mut buf = Vec::new();
+        loop {
+            let b = self.peek().ok_or(self.error("unterminated string"))?;
+            match b {
+                b'"' => {
+                    self.pos += 1;
+                    break;
+                },
+                b'\\' => {
+                    self.pos += 1;
+                    let escape = self.peek()
+                        .ok_or(self.error("unterminated string"))?;
+                    self.pos += 1;
+                    let c = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let mut code = self.hex4()?;
+                            if (0xD800..0xDC00).contains(&code) &&
+                                    self.s[self.pos..].starts_with(b"\\u") {
+                                self.pos += 2;
+                                let low = self.hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(self.error("invalid escape"));
+                                }
+                                code = 0x10000 +
+                                    ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            char::from_u32(code)
+                                .ok_or(self.error("invalid escape"))?
+                        },
+                        _ => {
+                            self.pos -= 1;
+                            return Err(self.error("invalid escape"));
+                        },
+                    };
+                    let mut utf8 = [0; 4];
+                    buf.extend_from_slice(c.encode_utf8(&mut utf8).as_bytes());
+                },
+                0..=0x1F => return Err(self.error("control character in string")),
+                _ => {
+                    buf.push(b);
+                    self.pos += 1;
+                },
+            }
+        }
+        // The input is a `str` and escapes are encoded in UTF-8.
+        return Ok(String::from_utf8(buf).unwrap());
+    }
+}
@@ -0,0 +1,588 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Provides human-readable and machine-readable dumps of ASN.1 data.
+//!
+//! The data is decoded without a schema, so components are not named and
+//! implicitly tagged values are shown as raw contents.
+//!
+//! - [`to_value_notation`] formats data in the X.680 value notation, like
+//!   `{ 2, 1234, { { 1 2 840 113549 1 1 11 }, NULL } }`.
+//! - [`to_json`] formats data as JSON, tagged with ASN.1 types so that
+//!   [`json_to_der`] reproduces the DER encoding.
+//!
+//! # Examples
+//!
+//! ```
+//! use yasna::BERMode;
+//! use yasna::notation::{json_to_der, to_json, to_value_notation};
+//! let der = &[48, 9, 2, 1, 10, 6, 4, 42, 134, 72, 78];
+//! assert_eq!(to_value_notation(der, BERMode::Der).unwrap(),
+//!     "{ 10, { 1 2 840 78 } }");
+//! let json = to_json(der, BERMode::Der).unwrap();
+//! assert_eq!(json, concat!(
+//!     r#"{"type":"SEQUENCE","value":[{"type":"INTEGER","value":10},"#,
+//!     r#"{"type":"OBJECT IDENTIFIER","value":"1.2.840.78"}]}"#));
+//! assert_eq!(json_to_der(&json).unwrap(), der);
+//! ```
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use super::{ASN1Result, BERMode, BERReader, DERWriter, PCBit, Tag, TagClass};
+use super::models::TaggedDerValue;
+use super::tags::*;
+
+mod json;
+
+pub use self::json::{json_to_der, JsonError};
+
+/// Formats BER/DER data in the X.680 value notation.
+///
+/// Universal types are shown in their value notation: `TRUE`, `10`,
+/// `'0A0B'H`, `'0101'B`, `NULL`, `{ 1 2 840 }` and `"text"`. Constructed
+/// values are shown as `{ a, b }`. Other values are preceded by their tags,
+/// as in `[0] { 10 }` or `[UNIVERSAL 9] '80'H`.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::BERMode;
+/// use yasna::notation::to_value_notation;
+/// let der = &[48, 8, 1, 1, 255, 160, 3, 2, 1, 2];
+/// assert_eq!(to_value_notation(der, BERMode::Der).unwrap(),
+///     "{ TRUE, [0] { 2 } }");
+/// ```
+pub fn to_value_notation(buf: &[u8], mode: BERMode) -> ASN1Result<String> {
+    super::parse_ber_general(buf, mode, read_value_notation)
+}
+
+/// Reads a value and formats it in the X.680 value notation.
+///
+/// See [`to_value_notation`] for details.
+pub fn read_value_notation(reader: BERReader) -> ASN1Result<String> {
+    let node = read_node(reader)?;
+    let mut out = String::new();
+    write_value_notation(&node, &mut out);
+    return Ok(out);
+}
+
+/// Formats BER/DER data as JSON.
+///
+/// Each value is an object with either the `"type"` key (for universal
+/// types) or the `"tag"` key (for other values), and the `"value"` key:
+///
+/// - BOOLEAN and NULL values are JSON literals.
+/// - INTEGER and ENUMERATED values are numbers, or strings of decimal
+///   digits if they don't fit in `i64`.
+/// - BIT STRING and OCTET STRING values are hexadecimal strings. BIT STRING
+///   values have the `"unused"` key for the number of unused bits, if any.
+/// - OBJECT IDENTIFIER and RELATIVE-OID values are dotted strings.
+/// - Character strings and times are strings.
+/// - SEQUENCE and SET values are arrays of values.
+/// - Other values are tagged by `"tag"` like `"[0]"` or
+///   `"[UNIVERSAL 9]"`, and have arrays of values if they are constructed
+///   or hexadecimal strings of their contents if they are primitive.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::BERMode;
+/// use yasna::notation::to_json;
+/// let der = &[48, 8, 1, 1, 255, 160, 3, 2, 1, 2];
+/// assert_eq!(to_json(der, BERMode::Der).unwrap(), concat!(
+///     r#"{"type":"SEQUENCE","value":[{"type":"BOOLEAN","value":true},"#,
+///     r#"{"tag":"[0]","value":[{"type":"INTEGER","value":2}]}]}"#));
+/// ```
+pub fn to_json(buf: &[u8], mode: BERMode) -> ASN1Result<String> {
+    super::parse_ber_general(buf, mode, read_json)
+}
+
+/// Reads a value and formats it as JSON.
+///
+/// See [`to_json`] for details.
+pub fn read_json(reader: BERReader) -> ASN1Result<String> {
+    let node = read_node(reader)?;
+    let mut out = String::new();
+    json::write_json(&node, &mut out);
+    return Ok(out);
+}
+
+/// A decoded value without a schema.
+#[derive(Debug, Clone)]
+enum Node {
+    Primitive(TaggedDerValue),
+    Constructed(Tag, Vec<Node>),
+}
+
+fn read_node(reader: BERReader) -> ASN1Result<Node> {
+    let (tag, pcbit) = reader.lookahead_identifier()?;
+    if pcbit == PCBit::Primitive {
+        return Ok(Node::Primitive(reader.read_tagged_der()?));
+    }
+    let mut children = Vec::new();
+    reader.read_tagged_implicit(tag, |reader| {
+        reader.read_sequence_of(|reader| {
+            children.push(read_node(reader)?);
+            return Ok(());
+        })
+    })?;
+    return Ok(Node::Constructed(tag, children));
+}
+
+fn write_node(writer: DERWriter, node: &Node) {
+    match *node {
+        Node::Primitive(ref value) => writer.write_tagged_der(value),
+        Node::Constructed(tag, ref children) => {
+            writer.write_tagged_implicit(tag, |writer| {
+                writer.write_sequence(|writer| {
+                    for child in children {
+                        write_node(writer.next(), child);
+                    }
+                })
+            })
+        },
+    }
+}
+
+/// The kinds of universal primitive types known to this module.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Kind {
+    Boolean,
+    Integer,
+    BitString,
+    OctetString,
+    Null,
+    Oid,
+    RelativeOid,
+    Utf8,
+    Ascii,
+    Bmp,
+    Universal,
+    Constructed,
+}
+
+static UNIVERSAL_TYPES : &[(Tag, &str, Kind)] = &[
+    (TAG_BOOLEAN, "BOOLEAN", Kind::Boolean),
+    (TAG_INTEGER, "INTEGER", Kind::Integer),
+    (TAG_BITSTRING, "BIT STRING", Kind::BitString),
+    (TAG_OCTETSTRING, "OCTET STRING", Kind::OctetString),
+    (TAG_NULL, "NULL", Kind::Null),
+    (TAG_OID, "OBJECT IDENTIFIER", Kind::Oid),
+    (TAG_OBJECT_DESCRIPTOR, "ObjectDescriptor", Kind::Ascii),
+    (TAG_ENUM, "ENUMERATED", Kind::Integer),
+    (TAG_UTF8STRING, "UTF8String", Kind::Utf8),
+    (TAG_RELATIVE_OID, "RELATIVE-OID", Kind::RelativeOid),
+    (TAG_TIME, "TIME", Kind::Ascii),
+    (TAG_SEQUENCE, "SEQUENCE", Kind::Constructed),
+    (TAG_SET, "SET", Kind::Constructed),
+    (TAG_NUMERICSTRING, "NumericString", Kind::Ascii),
+    (TAG_PRINTABLESTRING, "PrintableString", Kind::Ascii),
+    (TAG_IA5STRING, "IA5String", Kind::Ascii),
+    (TAG_UTCTIME, "UTCTime", Kind::Ascii),
+    (TAG_GENERALIZEDTIME, "GeneralizedTime", Kind::Ascii),
+    (TAG_GRAPHICSTRING, "GraphicString", Kind::Ascii),
+    (TAG_VISIBLESTRING, "VisibleString", Kind::Ascii),
+    (TAG_GENERALSTRING, "GeneralString", Kind::Ascii),
+    (TAG_UNIVERSALSTRING, "UniversalString", Kind::Universal),
+    (TAG_BMPSTRING, "BMPString", Kind::Bmp),
+    (TAG_DATE, "DATE", Kind::Ascii),
+    (TAG_TIME_OF_DAY, "TIME-OF-DAY", Kind::Ascii),
+    (TAG_DATE_TIME, "DATE-TIME", Kind::Ascii),
+    (TAG_DURATION, "DURATION", Kind::Ascii),
+];
+
+fn universal_type(tag: Tag) -> Option<(&'static str, Kind)> {
+    UNIVERSAL_TYPES.iter()
+        .find(|&&(t, _, _)| t == tag)
+        .map(|&(_, name, kind)| (name, kind))
+}
+
+fn universal_type_by_name(name: &str) -> Option<(Tag, Kind)> {
+    UNIVERSAL_TYPES.iter()
+        .find(|&&(_, n, _)| n == name)
+        .map(|&(tag, _, kind)| (tag, kind))
+}
+
+/// A primitive value decoded from its canonical encoding.
+enum Primitive<'a> {
+    Boolean(bool),
+    Integer(&'a [u8]),
+    BitString(u8, &'a [u8]),
+    OctetString(&'a [u8]),
+    Null,
+    Oid(Vec<u128>),
+    RelativeOid(Vec<u128>),
+    String(String),
+}
+
+/// Decodes a primitive value of a known universal type, or returns `None`
+/// if it is unknown or not canonically encoded.
+fn decode_primitive(value: &TaggedDerValue) -> Option<(&'static str, Primitive<'_>)> {
+    let (name, kind) = universal_type(value.tag())?;
+    let bytes = value.value();
+    let decoded = match kind {
+        Kind::Boolean => match bytes {
+            [0] => Primitive::Boolean(false),
+            [255] => Primitive::Boolean(true),
+            _ => return None,
+        },
+        Kind::Integer => {
+            if !is_minimal_integer(bytes) {
+                return None;
+            }
+            Primitive::Integer(bytes)
+        },
+        Kind::BitString => {
+            let (&unused, rest) = bytes.split_first()?;
+            let last = rest.last().cloned().unwrap_or(0);
+            if unused >= 8 || (rest.is_empty() && unused > 0) ||
+                    last & ((1 << unused) - 1) != 0 {
+                return None;
+            }
+            Primitive::BitString(unused, rest)
+        },
+        Kind::OctetString => Primitive::OctetString(bytes),
+        Kind::Null => {
+            if !bytes.is_empty() {
+                return None;
+            }
+            Primitive::Null
+        },
+        Kind::Oid => {
+            let subids = decode_subids(bytes)?;
+            let id0 = if subids[0] < 40 {
+                0
+            } else if subids[0] < 80 {
+                1
+            } else {
+                2
+            };
+            let mut arcs = Vec::with_capacity(subids.len() + 1);
+            arcs.push(id0);
+            arcs.push(subids[0] - 40 * id0);
+            arcs.extend_from_slice(&subids[1..]);
+            Primitive::Oid(arcs)
+        },
+        Kind::RelativeOid => Primitive::RelativeOid(decode_subids(bytes)?),
+        Kind::Utf8 | Kind::Ascii | Kind::Bmp | Kind::Universal => {
+            Primitive::String(decode_string(kind, bytes)?)
+        },
+        Kind::Constructed => return None,
+    };
+    return Some((name, decoded));
+}
+
+fn is_minimal_integer(bytes: &[u8]) -> bool {
+    return match bytes {
+        [] => false,
+        [0, b, ..] => *b >= 128,
+        [255, b, ..] => *b < 128,
+        _ => true,
+    };
+}
+
+/// Converts a two's complement big-endian integer to decimal.
+fn integer_to_decimal(bytes: &[u8]) -> String {
+    let negative = bytes.first().map_or(false, |&b| b >= 128);
+    let mut magnitude = bytes.to_vec();
+    if negative {
+        negate(&mut magnitude);
+    }
+    let mut digits = Vec::new();
+    while magnitude.iter().any(|&b| b != 0) {
+        let mut rem = 0;
+        for b in magnitude.iter_mut() {
+            let cur = rem * 256 + *b as u32;
+            *b = (cur / 10) as u8;
+            rem = cur % 10;
+        }
+        digits.push(b'0' + rem as u8);
+    }
+    if digits.is_empty() {
+        digits.push(b'0');
+    }
+    if negative {
+        digits.push(b'-');
+    }
+    digits.reverse();
+    return String::from_utf8(digits).unwrap();
+}
+
+/// Converts a decimal integer to the two's complement big-endian minimal
+/// encoding.
+fn decimal_to_integer(s: &str) -> Option<Vec<u8>> {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, s),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let mut bytes = vec![0];
+    for d in digits.bytes() {
+        let mut carry = (d - b'0') as u32;
+        for b in bytes.iter_mut().rev() {
+            let cur = *b as u32 * 10 + carry;
+            *b = cur as u8;
+            carry = cur >> 8;
+        }
+        if carry > 0 {
+            bytes.insert(0, carry as u8);
+        }
+    }
+    bytes.insert(0, 0);
+    if negative {
+        negate(&mut bytes);
+    }
+    while bytes.len() > 1 && !is_minimal_integer(&bytes) {
+        bytes.remove(0);
+    }
+    return Some(bytes);
+}
+
+/// Negates a two's complement big-endian integer in place.
+fn negate(bytes: &mut [u8]) {
+    for b in bytes.iter_mut() {
+        *b = !*b;
+    }
+    for b in bytes.iter_mut().rev() {
+        let (sum, carry) = b.overflowing_add(1);
+        *b = sum;
+        if !carry {
+            break;
+        }
+    }
+}
+
+fn decode_subids(bytes: &[u8]) -> Option<Vec<u128>> {
+    if bytes.last().map_or(true, |&b| b >= 128) {
+        return None;
+    }
+    let mut subids = Vec::new();
+    let mut subid : u128 = 0;
+    let mut subid_start = true;
+    for &b in bytes {
+        if (subid_start && b == 128) || subid >> 121 != 0 {
+            return None;
+        }
+        subid = subid << 7 | (b & 127) as u128;
+        subid_start = b < 128;
+        if subid_start {
+            subids.push(subid);
+            subid = 0;
+        }
+    }
+    return Some(subids);
+}
+
+fn encode_subid(buf: &mut Vec<u8>, subid: u128) {
+    let mut shift = 126;
+    while shift > 0 && (subid >> shift) == 0 {
+        shift -= 7;
+    }
+    while shift > 0 {
+        buf.push(128 | ((subid >> shift) & 127) as u8);
+        shift -= 7;
+    }
+    buf.push((subid & 127) as u8);
+}
+
+fn decode_string(kind: Kind, bytes: &[u8]) -> Option<String> {
+    return match kind {
+        Kind::Utf8 => String::from_utf8(bytes.to_vec()).ok(),
+        Kind::Ascii => {
+            if !bytes.is_ascii() {
+                return None;
+            }
+            String::from_utf8(bytes.to_vec()).ok()
+        },
+        Kind::Bmp => {
+            if bytes.len() % 2 != 0 {
+                return None;
+            }
+            bytes.chunks(2)
+                .map(|c| char::from_u32((c[0] as u32) << 8 | c[1] as u32))
+                .collect()
+        },
+        Kind::Universal => {
+            if bytes.len() % 4 != 0 {
+                return None;
+            }
+            bytes.chunks(4)
+                .map(|c| char::from_u32(
+                    (c[0] as u32) << 24 | (c[1] as u32) << 16 |
+                    (c[2] as u32) << 8 | c[3] as u32))
+                .collect()
+        },
+        _ => None,
+    };
+}
+
+fn encode_string(kind: Kind, s: &str) -> Option<Vec<u8>> {
+    return match kind {
+        Kind::Utf8 => Some(s.as_bytes().to_vec()),
+        Kind::Ascii => {
+            if !s.is_ascii() {
+                return None;
+            }
+            Some(s.as_bytes().to_vec())
+        },
+        Kind::Bmp => {
+            let mut bytes = Vec::new();
+            for c in s.chars() {
+                if c as u32 >= 0x10000 {
+                    return None;
+                }
+                bytes.extend_from_slice(&(c as u16).to_be_bytes());
+            }
+            Some(bytes)
+        },
+        Kind::Universal => {
+            Some(s.chars().flat_map(|c| (c as u32).to_be_bytes()).collect())
+        },
+        _ => None,
+    };
+}
+
+fn write_hex(out: &mut String, bytes: &[u8]) {
+    for &b in bytes {
+        write!(out, "{:02X}", b).unwrap();
+    }
+}
+
+fn parse_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    return s.as_bytes().chunks(2).map(|c| {
+        let hi = (c[0] as char).to_digit(16)?;
+        let lo = (c[1] as char).to_digit(16)?;
+        Some((hi * 16 + lo) as u8)
+    }).collect();
+}
+
+fn write_tag(out: &mut String, tag: Tag) {
+    let class = match tag.tag_class {
+        TagClass::Universal => "UNIVERSAL ",
+        TagClass::Application => "APPLICATION ",
+        TagClass::ContextSpecific => "",
+        TagClass::Private => "PRIVATE ",
+    };
+    write!(out, "[{}{}]", class, tag.tag_number).unwrap();
+}
+
+/// Writes the name of the universal type of `tag`, or `tag` in brackets.
+pub(crate) fn write_tag_name(out: &mut String, tag: Tag) {
+    match universal_type(tag) {
+        Some((name, _)) => out.push_str(name),
+        None => write_tag(out, tag),
+    }
+}
+
+fn parse_tag(s: &str) -> Option<Tag> {
+    let s = s.strip_prefix('[')?.strip_suffix(']')?;
+    let (tag_class, number) = if let Some(n) = s.strip_prefix("UNIVERSAL ") {
+        (TagClass::Universal, n)
+    } else if let Some(n) = s.strip_prefix("APPLICATION ") {
+        (TagClass::Application, n)
+    } else if let Some(n) = s.strip_prefix("PRIVATE ") {
+        (TagClass::Private, n)
+    } else {
+        (TagClass::ContextSpecific, s)
+    };
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    return Some(Tag {
+        tag_class,
+        tag_number: number.parse().ok()?,
+    });
+}
+
+fn write_arcs(out: &mut String, arcs: &[u128], separator: &str) {
+    for (i, arc) in arcs.iter().enumerate() {
+        if i > 0 {
+            out.push_str(separator);
+        }
+        write!(out, "{}", arc).unwrap();
+    }
+}
+
+fn write_value_notation(node: &Node, out: &mut String) {
+    match *node {
+        Node::Primitive(ref value) => {
+            let (_, primitive) = match decode_primitive(value) {
+                Some(decoded) => decoded,
+                None => {
+                    write_tag(out, value.tag());
+                    out.push_str(" '");
+                    write_hex(out, value.value());
+                    out.push_str("'H");
+                    return;
+                },
+            };
+            match primitive {
+                Primitive::Boolean(b) => {
+                    out.push_str(if b { "TRUE" } else { "FALSE" });
+                },
+                Primitive::Integer(bytes) => {
+                    out.push_str(&integer_to_decimal(bytes));
+                },
+                Primitive::BitString(0, bytes) |
+                Primitive::OctetString(bytes) => {
+                    out.push('\'');
+                    write_hex(out, bytes);
+                    out.push_str("'H");
+                },
+                Primitive::BitString(unused, bytes) => {
+                    out.push('\'');
+                    for i in 0..(bytes.len() * 8 - unused as usize) {
+                        let bit = (bytes[i / 8] >> (7 - i % 8)) & 1;
+                        out.push(if bit == 1 { '1' } else { '0' });
+                    }
+                    out.push_str("'B");
+                },
+                Primitive::Null => out.push_str("NULL"),
+                Primitive::Oid(arcs) | Primitive::RelativeOid(arcs) => {
+                    out.push_str("{ ");
+                    write_arcs(out, &arcs, " ");
+                    out.push_str(" }");
+                },
+                Primitive::String(s) => {
+                    out.push('"');
+                    out.push_str(&s.replace('"', "\"\""));
+                    out.push('"');
+                },
+            }
+        },
+        Node::Constructed(tag, ref children) => {
+            if tag != TAG_SEQUENCE && tag != TAG_SET {
+                write_tag(out, tag);
+                out.push(' ');
+            }
+            if children.is_empty() {
+                out.push_str("{}");
+                return;
+            }
+            out.push_str("{ ");
+            for (i, child) in children.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_value_notation(child, out);
+            }
+            out.push_str(" }");
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests;
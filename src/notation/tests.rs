@@ -0,0 +1,149 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::*;
+
+#[test]
+fn test_value_notation() {
+    let tests : &[(&[u8], &str)] = &[
+        (&[1, 1, 0], "FALSE"),
+        (&[2, 1, 0], "0"),
+        (&[2, 2, 255, 127], "-129"),
+        (&[2, 9, 1, 0, 0, 0, 0, 0, 0, 0, 0], "18446744073709551616"),
+        (&[2, 9, 255, 0, 0, 0, 0, 0, 0, 0, 0], "-18446744073709551616"),
+        (&[3, 3, 0, 10, 11], "'0A0B'H"),
+        (&[3, 2, 4, 80], "'0101'B"),
+        (&[3, 1, 0], "''H"),
+        (&[5, 0], "NULL"),
+        (&[6, 3, 85, 4, 3], "{ 2 5 4 3 }"),
+        (&[13, 2, 134, 72], "{ 840 }"),
+        (&[12, 7, 34, 72, 105, 34, 32, 206, 177], "\"\"\"Hi\"\" \u{3b1}\""),
+        (&[30, 4, 0, 72, 0, 105], "\"Hi\""),
+        (&[23, 13, 57, 57, 48, 49, 48, 49, 48, 48, 48, 48, 48, 48, 90],
+            "\"990101000000Z\""),
+        (&[48, 0], "{}"),
+        (&[49, 6, 2, 1, 1, 2, 1, 2], "{ 1, 2 }"),
+        (&[161, 3, 2, 1, 2], "[1] { 2 }"),
+        (&[128, 2, 1, 2], "[0] '0102'H"),
+        (&[95, 31, 0], "[APPLICATION 31] ''H"),
+        (&[226, 0], "[PRIVATE 2] {}"),
+        (&[9, 1, 64], "[UNIVERSAL 9] '40'H"),
+        (&[2, 2, 0, 1], "[UNIVERSAL 2] '0001'H"),
+        (&[3, 2, 4, 81], "[UNIVERSAL 3] '0451'H"),
+        (&[6, 2, 42, 128], "[UNIVERSAL 6] '2A80'H"),
+    ];
+    for &(data, expected) in tests {
+        let notation = to_value_notation(data, BERMode::Ber).unwrap();
+        assert_eq!(notation, expected);
+    }
+}
+
+#[test]
+fn test_json_round_trip() {
+    let tests : &[(&[u8], &str)] = &[
+        (&[1, 1, 255], r#"{"type":"BOOLEAN","value":true}"#),
+        (&[2, 2, 255, 127], r#"{"type":"INTEGER","value":-129}"#),
+        (&[2, 9, 255, 0, 0, 0, 0, 0, 0, 0, 0],
+            r#"{"type":"INTEGER","value":"-18446744073709551616"}"#),
+        (&[10, 1, 3], r#"{"type":"ENUMERATED","value":3}"#),
+        (&[3, 2, 4, 80], r#"{"type":"BIT STRING","value":"50","unused":4}"#),
+        (&[4, 2, 171, 205], r#"{"type":"OCTET STRING","value":"ABCD"}"#),
+        (&[5, 0], r#"{"type":"NULL","value":null}"#),
+        (&[6, 3, 85, 4, 3], r#"{"type":"OBJECT IDENTIFIER","value":"2.5.4.3"}"#),
+        (&[13, 3, 134, 72, 1], r#"{"type":"RELATIVE-OID","value":"840.1"}"#),
+        (&[12, 4, 34, 92, 10, 1],
+            r#"{"type":"UTF8String","value":"\"\\\n\u0001"}"#),
+        (&[28, 4, 0, 1, 243, 10], r#"{"type":"UniversalString","value":"🌊"}"#),
+        (&[49, 3, 1, 1, 0],
+            r#"{"type":"SET","value":[{"type":"BOOLEAN","value":false}]}"#),
+        (&[162, 2, 5, 0], r#"{"tag":"[2]","value":[{"type":"NULL","value":null}]}"#),
+        (&[159, 100, 1, 1], r#"{"tag":"[100]","value":"01"}"#),
+        (&[1, 1, 1], r#"{"tag":"[UNIVERSAL 1]","value":"01"}"#),
+    ];
+    for &(data, expected) in tests {
+        let json = to_json(data, BERMode::Ber).unwrap();
+        assert_eq!(json, expected);
+        assert_eq!(json_to_der(&json).unwrap(), data);
+    }
+}
+
+#[test]
+fn test_json_to_der() {
+    let tests : &[(&str, &[u8])] = &[
+        (r#" { "value" : 0 , "type" : "INTEGER" } "#, &[2, 1, 0]),
+        (r#"{"type":"INTEGER","value":"00128"}"#, &[2, 2, 0, 128]),
+        (r#"{"type":"INTEGER","value":-128}"#, &[2, 1, 128]),
+        (r#"{"type":"OCTET STRING","value":"abCD"}"#, &[4, 2, 171, 205]),
+        (r#"{"type":"BMPString","value":"é"}"#, &[30, 2, 0, 233]),
+        (r#"{"type":"UTF8String","value":"🌊"}"#,
+            &[12, 4, 240, 159, 140, 138]),
+        (r#"{"type":"OBJECT IDENTIFIER","value":"2.25.340282366920938463463374607431768211455"}"#,
+            &[6, 20, 105, 131, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+            255, 255, 255, 255, 255, 255, 255, 255, 127]),
+        (r#"{"tag":"[APPLICATION 1]","value":[]}"#, &[97, 0]),
+    ];
+    for &(json, expected) in tests {
+        assert_eq!(json_to_der(json).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_json_to_der_errors() {
+    let tests : &[(&str, usize)] = &[
+        ("", 0),
+        ("{", 1),
+        (r#"{"type":"INTEGER","value":1.5}"#, 26),
+        (r#"{"type":"INTEGER","value":1} x"#, 29),
+        (r#"{"type":"INTEGER"}"#, 0),
+        (r#"{"type":"INTEGER","tag":"[0]","value":1}"#, 0),
+        (r#"{"type":"Integer","value":1}"#, 0),
+        (r#"{"type":"INTEGER","value":1,"unused":0}"#, 0),
+        (r#"{"type":"INTEGER","valu":1}"#, 25),
+        (r#"{"tag":"[0","value":"00"}"#, 7),
+        (r#"{"tag":"[0]","value":"0"}"#, 21),
+        (r#"{"type":"BIT STRING","value":"51","unused":4}"#, 29),
+        (r#"{"type":"BIT STRING","value":"50","unused":8}"#, 43),
+        (r#"{"type":"OBJECT IDENTIFIER","value":"1.40"}"#, 36),
+        (r#"{"type":"PrintableString","value":"é"}"#, 34),
+        (r#"{"type":"BMPString","value":"🌊"}"#, 28),
+        (r#"{"type":"UTF8String","value":"\x"}"#, 31),
+        (r#"{"type":"SEQUENCE","value":"0102"}"#, 27),
+        (r#"{"type":"SET","value":null}"#, 22),
+        (r#"{"type":"NumericString","value":"12a"}"#, 32),
+        (r#"{"type":"PrintableString","value":"a@b"}"#, 34),
+        (r#"{"type":"VisibleString","value":"\n"}"#, 32),
+        (r#"{"type":"UTCTime","value":"191332000000Z"}"#, 26),
+        (r#"{"type":"GeneralizedTime","value":"2019101900000Z"}"#, 34),
+        (r#"{"type":"DURATION","value":"P1H"}"#, 27),
+        (r#"["#, 1),
+    ];
+    for &(json, position) in tests {
+        let err = json_to_der(json).unwrap_err();
+        assert_eq!(err.position(), position, "{}", json);
+    }
+}
+
+#[test]
+fn test_json_to_der_depth() {
+    use alloc::string::String;
+    let nested = |depth: usize| {
+        let mut json = String::new();
+        for _ in 0..depth {
+            json.push_str(r#"{"type":"SEQUENCE","value":["#);
+        }
+        for _ in 0..depth {
+            json.push_str("]}");
+        }
+        json
+    };
+    assert!(json_to_der(&nested(100)).is_ok());
+    let err = json_to_der(&nested(100000)).unwrap_err();
+    assert_eq!(err.position(), 2800);
+    let json = "[".repeat(100000);
+    assert_eq!(json_to_der(&json).unwrap_err().position(), 200);
+}
@@ -0,0 +1,116 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::super::{ASN1Result, BERDecodable, BERReader, DEREncodable, DERWriter};
+use super::super::models::{BitString, ObjectIdentifier, TaggedDerValue};
+
+/// An algorithm and its parameters (RFC 5280 4.1.1.2).
+///
+/// ```text
+/// AlgorithmIdentifier  ::=  SEQUENCE  {
+///      algorithm               OBJECT IDENTIFIER,
+///      parameters              ANY DEFINED BY algorithm OPTIONAL  }
+/// ```
+///
+/// The parameters are kept as a raw value, since their type depends on
+/// the algorithm. Note that absent parameters and NULL are distinct:
+/// RSA algorithms use NULL, while ECDSA and EdDSA omit the parameters.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::{ObjectIdentifier, TaggedDerValue};
+/// use yasna::pkix::AlgorithmIdentifier;
+/// use yasna::tags::TAG_NULL;
+/// let sha256_with_rsa = AlgorithmIdentifier {
+///     algorithm: ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 1, 11]),
+///     parameters: Some(TaggedDerValue::from_tag_and_bytes(TAG_NULL, vec![])),
+/// };
+/// assert_eq!(yasna::encode_der(&sha256_with_rsa),
+///     &[48, 13, 6, 9, 42, 134, 72, 134, 247, 13, 1, 1, 11, 5, 0]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct AlgorithmIdentifier {
+    /// The algorithm
+    pub algorithm: ObjectIdentifier,
+    /// The parameters, if any
+    pub parameters: Option<TaggedDerValue>,
+}
+
+impl AlgorithmIdentifier {
+    /// Constructs an `AlgorithmIdentifier` without parameters.
+    pub fn new(algorithm: ObjectIdentifier) -> Self {
+        AlgorithmIdentifier {
+            algorithm,
+            parameters: None,
+        }
+    }
+}
+
+impl BERDecodable for AlgorithmIdentifier {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let algorithm = ObjectIdentifier::decode_ber(reader.next())?;
+            let parameters = reader.read_optional(|reader| {
+                reader.read_tagged_der()
+            })?;
+            return Ok(AlgorithmIdentifier {
+                algorithm,
+                parameters,
+            });
+        })
+    }
+}
+
+impl DEREncodable for AlgorithmIdentifier {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.algorithm.encode_der(writer.next());
+            if let Some(ref parameters) = self.parameters {
+                writer.next().write_tagged_der(parameters);
+            }
+        })
+    }
+}
+
+/// A public key and its algorithm (RFC 5280 4.1.2.7).
+///
+/// ```text
+/// SubjectPublicKeyInfo  ::=  SEQUENCE  {
+///      algorithm            AlgorithmIdentifier,
+///      subjectPublicKey     BIT STRING  }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SubjectPublicKeyInfo {
+    /// The algorithm of the key
+    pub algorithm: AlgorithmIdentifier,
+    /// The encoded key, whose format depends on the algorithm
+    pub subject_public_key: BitString,
+}
+
+impl BERDecodable for SubjectPublicKeyInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let algorithm = AlgorithmIdentifier::decode_ber(reader.next())?;
+            let subject_public_key = BitString::decode_ber(reader.next())?;
+            return Ok(SubjectPublicKeyInfo {
+                algorithm,
+                subject_public_key,
+            });
+        })
+    }
+}
+
+impl DEREncodable for SubjectPublicKeyInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.algorithm.encode_der(writer.next());
+            self.subject_public_key.encode_der(writer.next());
+        })
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use super::super::{ASN1Error, ASN1ErrorKind, ASN1Result};
+use super::super::{BERDecodable, BERReader, DEREncodable, DERWriter};
+use super::super::models::{ObjectIdentifier, TaggedDerValue};
+
+/// An attribute with a set of values (RFC 5280 4.1.2.4).
+///
+/// ```text
+/// Attribute               ::= SEQUENCE {
+///       type             AttributeType,
+///       values    SET OF AttributeValue }
+///             -- at least one value is required
+/// ```
+///
+/// This is used in PKCS#10 requests, PKCS#8 keys, CMS and PKCS#12,
+/// unlike [`AttributeTypeAndValue`](super::AttributeTypeAndValue), which
+/// is used in names. The values are sorted when encoded in DER.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::{ObjectIdentifier, TaggedDerValue};
+/// use yasna::pkix::Attribute;
+/// use yasna::tags::TAG_UTF8STRING;
+/// let attribute = Attribute {
+///     attr_type: ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 9, 7]),
+///     values: vec![
+///         TaggedDerValue::from_tag_and_bytes(TAG_UTF8STRING, b"pw".to_vec()),
+///     ],
+/// };
+/// let der = yasna::encode_der(&attribute);
+/// assert_eq!(der, &[48, 17, 6, 9, 42, 134, 72, 134, 247, 13, 1, 9, 7,
+///     49, 4, 12, 2, 112, 119]);
+/// assert_eq!(yasna::decode_der::<Attribute>(&der).unwrap(), attribute);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Attribute {
+    /// The type of the attribute
+    pub attr_type: ObjectIdentifier,
+    /// The values of the attribute
+    pub values: Vec<TaggedDerValue>,
+}
+
+impl BERDecodable for Attribute {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let attr_type = ObjectIdentifier::decode_ber(reader.next())?;
+            let values = reader.next().collect_set_of(|reader| {
+                reader.read_tagged_der()
+            })?;
+            return Ok(Attribute {
+                attr_type,
+                values,
+            });
+        })
+    }
+}
+
+impl DEREncodable for Attribute {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.attr_type.encode_der(writer.next());
+            writer.next().write_set_of(|writer| {
+                for value in &self.values {
+                    writer.next().write_tagged_der(value);
+                }
+            });
+        })
+    }
+}
+
+/// Returns the value of an attribute which must have exactly one value.
+pub(super) fn single_value(attribute: &Attribute)
+        -> ASN1Result<&TaggedDerValue> {
+    if attribute.values.len() != 1 {
+        return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+    }
+    return Ok(&attribute.values[0]);
+}
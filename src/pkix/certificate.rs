@@ -0,0 +1,419 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use super::super::{ASN1Error, ASN1ErrorKind, ASN1Result, Tag};
+use super::super::{BERDecodable, BERReader, DEREncodable, DERWriter};
+use super::super::models::{BitString, ObjectIdentifier, RawDateTime, WithRaw};
+use super::{AlgorithmIdentifier, Extension, Name, SubjectPublicKeyInfo};
+
+/// The version of a certificate (RFC 5280 4.1.2.1).
+///
+/// ```text
+/// Version  ::=  INTEGER  {  v1(0), v2(1), v3(2)  }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Version {
+    /// Version 1, without unique identifiers or extensions
+    V1 = 0,
+    /// Version 2, with unique identifiers
+    V2 = 1,
+    /// Version 3, with extensions
+    V3 = 2,
+}
+
+impl BERDecodable for Version {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        match reader.read_i64()? {
+            0 => Ok(Version::V1),
+            1 => Ok(Version::V2),
+            2 => Ok(Version::V3),
+            _ => Err(ASN1Error::new(ASN1ErrorKind::Invalid)),
+        }
+    }
+}
+
+impl DEREncodable for Version {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_i64(*self as i64)
+    }
+}
+
+/// A certificate serial number (RFC 5280 4.1.2.2).
+///
+/// Serial numbers may be as long as 20 octets, so they are kept as the
+/// contents octets of the INTEGER, in two's complement. Negative and zero
+/// serial numbers are not allowed by RFC 5280, but are accepted because
+/// they are found in the wild.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::SerialNumber;
+/// let serial = SerialNumber::from_unsigned_bytes(&[0, 0, 128, 1]);
+/// assert_eq!(serial.as_bytes(), &[0, 128, 1]);
+/// assert_eq!(serial.to_u64(), Some(32769));
+/// assert_eq!(yasna::encode_der(&serial), &[2, 3, 0, 128, 1]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SerialNumber {
+    bytes: Vec<u8>,
+}
+
+impl SerialNumber {
+    /// Constructs a nonnegative serial number from its big-endian bytes.
+    pub fn from_unsigned_bytes(bytes: &[u8]) -> Self {
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        let bytes = &bytes[start..];
+        let mut contents = Vec::with_capacity(bytes.len() + 1);
+        if bytes.is_empty() || bytes[0] >= 128 {
+            contents.push(0);
+        }
+        contents.extend_from_slice(bytes);
+        SerialNumber {
+            bytes: contents,
+        }
+    }
+
+    /// Returns the contents octets of the INTEGER, in two's complement.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns `true` if the serial number is negative.
+    pub fn is_negative(&self) -> bool {
+        self.bytes[0] >= 128
+    }
+
+    /// Returns the serial number as `u64`, or `None` if it is negative or
+    /// too large.
+    pub fn to_u64(&self) -> Option<u64> {
+        if self.is_negative() {
+            return None;
+        }
+        let bytes = if self.bytes[0] == 0 {
+            &self.bytes[1..]
+        } else {
+            &self.bytes[..]
+        };
+        if bytes.len() > 8 {
+            return None;
+        }
+        return Some(bytes.iter().fold(0, |acc, &b| acc << 8 | b as u64));
+    }
+}
+
+impl From<u64> for SerialNumber {
+    fn from(value: u64) -> Self {
+        SerialNumber::from_unsigned_bytes(&value.to_be_bytes())
+    }
+}
+
+impl BERDecodable for SerialNumber {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let (bytes, _) = reader.read_bigint_bytes()?;
+        return Ok(SerialNumber {
+            bytes,
+        });
+    }
+}
+
+impl DEREncodable for SerialNumber {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_bigint_bytes(&self.bytes, !self.is_negative())
+    }
+}
+
+/// The validity period of a certificate (RFC 5280 4.1.2.5).
+///
+/// ```text
+/// Validity ::= SEQUENCE {
+///      notBefore      Time,
+///      notAfter       Time  }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Validity {
+    /// The beginning of the period
+    pub not_before: RawDateTime,
+    /// The end of the period, inclusive
+    pub not_after: RawDateTime,
+}
+
+impl Validity {
+    /// Returns `true` if `time` is within the validity period.
+    pub fn contains(&self, time: &RawDateTime) -> bool {
+        self.not_before <= *time && *time <= self.not_after
+    }
+}
+
+impl BERDecodable for Validity {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let not_before = RawDateTime::decode_ber(reader.next())?;
+            let not_after = RawDateTime::decode_ber(reader.next())?;
+            return Ok(Validity {
+                not_before,
+                not_after,
+            });
+        })
+    }
+}
+
+impl DEREncodable for Validity {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.not_before.encode_der(writer.next());
+            self.not_after.encode_der(writer.next());
+        })
+    }
+}
+
+/// The signed part of a certificate (RFC 5280 4.1).
+///
+/// ```text
+/// TBSCertificate  ::=  SEQUENCE  {
+///      version         [0]  EXPLICIT Version DEFAULT v1,
+///      serialNumber         CertificateSerialNumber,
+///      signature            AlgorithmIdentifier,
+///      issuer               Name,
+///      validity             Validity,
+///      subject              Name,
+///      subjectPublicKeyInfo SubjectPublicKeyInfo,
+///      issuerUniqueID  [1]  IMPLICIT UniqueIdentifier OPTIONAL,
+///                           -- If present, version MUST be v2 or v3
+///      subjectUniqueID [2]  IMPLICIT UniqueIdentifier OPTIONAL,
+///                           -- If present, version MUST be v2 or v3
+///      extensions      [3]  EXPLICIT Extensions OPTIONAL
+///                           -- If present, version MUST be v3
+///      }
+/// ```
+///
+/// When decoding, the unique identifiers and the extensions are rejected
+/// if the version doesn't allow them. When encoding, the version is
+/// written as is, and the extensions are omitted if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TBSCertificate {
+    /// The version
+    pub version: Version,
+    /// The serial number, unique for each certificate issued by a CA
+    pub serial_number: SerialNumber,
+    /// The signature algorithm, the same as the one in `Certificate`
+    pub signature: AlgorithmIdentifier,
+    /// The issuer name
+    pub issuer: Name,
+    /// The validity period
+    pub validity: Validity,
+    /// The subject name
+    pub subject: Name,
+    /// The public key of the subject
+    pub subject_public_key_info: SubjectPublicKeyInfo,
+    /// The unique identifier of the issuer (v2 or v3)
+    pub issuer_unique_id: Option<BitString>,
+    /// The unique identifier of the subject (v2 or v3)
+    pub subject_unique_id: Option<BitString>,
+    /// The extensions (v3)
+    pub extensions: Vec<Extension>,
+}
+
+impl TBSCertificate {
+    /// Returns the extension of the given type, if any.
+    pub fn extension(&self, extn_id: &ObjectIdentifier) -> Option<&Extension> {
+        self.extensions.iter().find(|extension| {
+            extension.extn_id == *extn_id
+        })
+    }
+}
+
+impl BERDecodable for TBSCertificate {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let version = reader.read_default(Version::V1, |reader| {
+                reader.read_tagged(Tag::context(0), Version::decode_ber)
+            })?;
+            let serial_number = SerialNumber::decode_ber(reader.next())?;
+            let signature = AlgorithmIdentifier::decode_ber(reader.next())?;
+            let issuer = Name::decode_ber(reader.next())?;
+            let validity = Validity::decode_ber(reader.next())?;
+            let subject = Name::decode_ber(reader.next())?;
+            let subject_public_key_info =
+                SubjectPublicKeyInfo::decode_ber(reader.next())?;
+            let issuer_unique_id = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1),
+                    BitString::decode_ber)
+            })?;
+            let subject_unique_id = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(2),
+                    BitString::decode_ber)
+            })?;
+            let extensions = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(3), Vec::decode_ber)
+            })?;
+            if version == Version::V1 &&
+                    (issuer_unique_id.is_some() || subject_unique_id.is_some()) {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            if version != Version::V3 && extensions.is_some() {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            return Ok(TBSCertificate {
+                version,
+                serial_number,
+                signature,
+                issuer,
+                validity,
+                subject,
+                subject_public_key_info,
+                issuer_unique_id,
+                subject_unique_id,
+                extensions: extensions.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for TBSCertificate {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            if self.version != Version::V1 {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    self.version.encode_der(writer)
+                });
+            }
+            self.serial_number.encode_der(writer.next());
+            self.signature.encode_der(writer.next());
+            self.issuer.encode_der(writer.next());
+            self.validity.encode_der(writer.next());
+            self.subject.encode_der(writer.next());
+            self.subject_public_key_info.encode_der(writer.next());
+            if let Some(ref issuer_unique_id) = self.issuer_unique_id {
+                writer.next().write_tagged_implicit(Tag::context(1), |writer| {
+                    issuer_unique_id.encode_der(writer)
+                });
+            }
+            if let Some(ref subject_unique_id) = self.subject_unique_id {
+                writer.next().write_tagged_implicit(Tag::context(2), |writer| {
+                    subject_unique_id.encode_der(writer)
+                });
+            }
+            if !self.extensions.is_empty() {
+                writer.next().write_tagged(Tag::context(3), |writer| {
+                    self.extensions.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// An X.509 certificate (RFC 5280 4.1).
+///
+/// ```text
+/// Certificate  ::=  SEQUENCE  {
+///      tbsCertificate       TBSCertificate,
+///      signatureAlgorithm   AlgorithmIdentifier,
+///      signatureValue       BIT STRING  }
+/// ```
+///
+/// The TBSCertificate is kept with its original encoding, which is
+/// written back verbatim when encoded. The signature is computed over
+/// [`tbs_bytes`](Self::tbs_bytes).
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::{ObjectIdentifier, RawDateTime, BitString};
+/// use yasna::pkix::*;
+/// let algorithm = AlgorithmIdentifier::new(
+///     ObjectIdentifier::from_slice(&[1, 3, 101, 112]));
+/// let tbs_certificate = TBSCertificate {
+///     version: Version::V1,
+///     serial_number: SerialNumber::from(1),
+///     signature: algorithm.clone(),
+///     issuer: "CN=Example CA".parse().unwrap(),
+///     validity: Validity {
+///         not_before: RawDateTime::new(2026, 1, 1, 0, 0, 0, 0).unwrap(),
+///         not_after: RawDateTime::new(2027, 1, 1, 0, 0, 0, 0).unwrap(),
+///     },
+///     subject: "CN=example.com".parse().unwrap(),
+///     subject_public_key_info: SubjectPublicKeyInfo {
+///         algorithm: algorithm.clone(),
+///         subject_public_key: BitString::from_bytes(vec![0; 32]),
+///     },
+///     issuer_unique_id: None,
+///     subject_unique_id: None,
+///     extensions: vec![],
+/// };
+/// let certificate = Certificate::sign(tbs_certificate, |tbs_bytes| {
+///     // Sign `tbs_bytes` with Ed25519 here.
+///     Ok::<_, ()>(vec![0; 64])
+/// }).unwrap();
+/// let der = yasna::encode_der(&certificate);
+/// let decoded : Certificate = yasna::decode_der(&der).unwrap();
+/// assert_eq!(decoded, certificate);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Certificate {
+    /// The signed part, with its encoding
+    pub tbs_certificate: WithRaw<TBSCertificate>,
+    /// The signature algorithm
+    pub signature_algorithm: AlgorithmIdentifier,
+    /// The signature
+    pub signature_value: BitString,
+}
+
+impl Certificate {
+    /// Constructs a certificate by encoding `tbs_certificate` in DER and
+    /// signing the encoding with `sign`.
+    ///
+    /// The signature algorithm is copied from `tbs_certificate.signature`.
+    pub fn sign<F, E>(tbs_certificate: TBSCertificate, sign: F)
+            -> Result<Self, E>
+            where F: FnOnce(&[u8]) -> Result<Vec<u8>, E> {
+        let signature_algorithm = tbs_certificate.signature.clone();
+        let tbs_certificate = WithRaw::new(tbs_certificate);
+        let signature_value = BitString::from_bytes(
+            sign(tbs_certificate.raw())?);
+        return Ok(Certificate {
+            tbs_certificate,
+            signature_algorithm,
+            signature_value,
+        });
+    }
+
+    /// Returns the encoding of the TBSCertificate, over which the signature
+    /// is computed.
+    pub fn tbs_bytes(&self) -> &[u8] {
+        self.tbs_certificate.raw()
+    }
+}
+
+impl BERDecodable for Certificate {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let tbs_certificate = WithRaw::decode_ber(reader.next())?;
+            let signature_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let signature_value = BitString::decode_ber(reader.next())?;
+            return Ok(Certificate {
+                tbs_certificate,
+                signature_algorithm,
+                signature_value,
+            });
+        })
+    }
+}
+
+impl DEREncodable for Certificate {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.tbs_certificate.encode_der(writer.next());
+            self.signature_algorithm.encode_der(writer.next());
+            self.signature_value.encode_der(writer.next());
+        })
+    }
+}
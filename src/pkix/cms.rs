@@ -0,0 +1,802 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use super::super::{ASN1Error, ASN1ErrorKind, ASN1Result, Tag, TagClass};
+use super::super::{BERDecodable, BERReader, BERReaderSeq};
+use super::super::{DEREncodable, DERWriter, DERWriterSeq};
+use super::super::{construct_der, encode_der, parse_der};
+use super::super::models::{ObjectIdentifier, TaggedDerValue};
+use super::{AlgorithmIdentifier, Attribute, Certificate, CertificateList};
+use super::{EncryptedData, EnvelopedData, Name, SerialNumber};
+use super::attribute::single_value;
+use super::oids::{ID_DATA, ID_ENCRYPTED_DATA, ID_ENVELOPED_DATA};
+use super::oids::{ID_MESSAGE_DIGEST, ID_SIGNED_DATA};
+
+/// The version of a CMS structure (RFC 5652 10.2.5).
+///
+/// ```text
+/// CMSVersion ::= INTEGER  { v0(0), v1(1), v2(2), v3(3), v4(4), v5(5) }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum CMSVersion {
+    /// Version 0
+    V0 = 0,
+    /// Version 1
+    V1 = 1,
+    /// Version 2
+    V2 = 2,
+    /// Version 3
+    V3 = 3,
+    /// Version 4
+    V4 = 4,
+    /// Version 5
+    V5 = 5,
+}
+
+impl BERDecodable for CMSVersion {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        match reader.read_i64()? {
+            0 => Ok(CMSVersion::V0),
+            1 => Ok(CMSVersion::V1),
+            2 => Ok(CMSVersion::V2),
+            3 => Ok(CMSVersion::V3),
+            4 => Ok(CMSVersion::V4),
+            5 => Ok(CMSVersion::V5),
+            _ => Err(ASN1Error::new(ASN1ErrorKind::Invalid)),
+        }
+    }
+}
+
+impl DEREncodable for CMSVersion {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_i64(*self as i64)
+    }
+}
+
+/// A content with its type (RFC 5652 3).
+///
+/// ```text
+/// ContentInfo ::= SEQUENCE {
+///   contentType ContentType,
+///   content [0] EXPLICIT ANY DEFINED BY contentType }
+///
+/// ContentType ::= OBJECT IDENTIFIER
+/// ```
+///
+/// The content is decoded according to its type. The content of the other
+/// types is kept as a raw value, which is not converted to DER if decoded
+/// in BER mode.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::ContentInfo;
+/// // Indefinite-length BER, with the data split into two chunks
+/// let ber = &[48, 128, 6, 9, 42, 134, 72, 134, 247, 13, 1, 7, 1,
+///     160, 128, 36, 128, 4, 2, 72, 101, 4, 3, 108, 108, 111, 0, 0,
+///     0, 0, 0, 0];
+/// let content_info : ContentInfo = yasna::decode_ber(ber).unwrap();
+/// assert_eq!(content_info, ContentInfo::Data(b"Hello".to_vec()));
+/// assert_eq!(content_info.content_type().to_string(), "1.2.840.113549.1.7.1");
+/// assert_eq!(yasna::encode_der(&content_info), &[48, 20,
+///     6, 9, 42, 134, 72, 134, 247, 13, 1, 7, 1,
+///     160, 7, 4, 5, 72, 101, 108, 108, 111]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ContentInfo {
+    /// `id-data`, with the contents of the OCTET STRING
+    Data(Vec<u8>),
+    /// `id-signedData`
+    SignedData(SignedData),
+    /// `id-envelopedData`
+    EnvelopedData(EnvelopedData),
+    /// `id-encryptedData`
+    EncryptedData(EncryptedData),
+    /// Any other type
+    Other {
+        /// The type of the content
+        content_type: ObjectIdentifier,
+        /// The content, without the `[0]` tag
+        content: TaggedDerValue,
+    },
+}
+
+impl ContentInfo {
+    /// Returns the type of the content.
+    pub fn content_type(&self) -> ObjectIdentifier {
+        match *self {
+            ContentInfo::Data(_) => ID_DATA.into(),
+            ContentInfo::SignedData(_) => ID_SIGNED_DATA.into(),
+            ContentInfo::EnvelopedData(_) => ID_ENVELOPED_DATA.into(),
+            ContentInfo::EncryptedData(_) => ID_ENCRYPTED_DATA.into(),
+            ContentInfo::Other { ref content_type, .. } => content_type.clone(),
+        }
+    }
+}
+
+impl BERDecodable for ContentInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let content_type = ObjectIdentifier::decode_ber(reader.next())?;
+            reader.next().read_tagged(Tag::context(0), |reader| {
+                if content_type == ID_DATA {
+                    return Ok(ContentInfo::Data(reader.read_bytes()?));
+                } else if content_type == ID_SIGNED_DATA {
+                    return Ok(ContentInfo::SignedData(
+                        SignedData::decode_ber(reader)?));
+                } else if content_type == ID_ENVELOPED_DATA {
+                    return Ok(ContentInfo::EnvelopedData(
+                        EnvelopedData::decode_ber(reader)?));
+                } else if content_type == ID_ENCRYPTED_DATA {
+                    return Ok(ContentInfo::EncryptedData(
+                        EncryptedData::decode_ber(reader)?));
+                }
+                return Ok(ContentInfo::Other {
+                    content_type,
+                    content: reader.read_tagged_der()?,
+                });
+            })
+        })
+    }
+}
+
+impl DEREncodable for ContentInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.content_type().encode_der(writer.next());
+            writer.next().write_tagged(Tag::context(0), |writer| {
+                match *self {
+                    ContentInfo::Data(ref data) => writer.write_bytes(data),
+                    ContentInfo::SignedData(ref signed_data) => {
+                        signed_data.encode_der(writer)
+                    },
+                    ContentInfo::EnvelopedData(ref enveloped_data) => {
+                        enveloped_data.encode_der(writer)
+                    },
+                    ContentInfo::EncryptedData(ref encrypted_data) => {
+                        encrypted_data.encode_der(writer)
+                    },
+                    ContentInfo::Other { ref content, .. } => {
+                        writer.write_tagged_der(content)
+                    },
+                }
+            });
+        })
+    }
+}
+
+/// A certificate identified by its issuer and serial number
+/// (RFC 5652 10.2.4).
+///
+/// ```text
+/// IssuerAndSerialNumber ::= SEQUENCE {
+///   issuer Name,
+///   serialNumber CertificateSerialNumber }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct IssuerAndSerialNumber {
+    /// The issuer of the certificate
+    pub issuer: Name,
+    /// The serial number of the certificate
+    pub serial_number: SerialNumber,
+}
+
+impl BERDecodable for IssuerAndSerialNumber {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let issuer = Name::decode_ber(reader.next())?;
+            let serial_number = SerialNumber::decode_ber(reader.next())?;
+            return Ok(IssuerAndSerialNumber {
+                issuer,
+                serial_number,
+            });
+        })
+    }
+}
+
+impl DEREncodable for IssuerAndSerialNumber {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.issuer.encode_der(writer.next());
+            self.serial_number.encode_der(writer.next());
+        })
+    }
+}
+
+/// The certificate of a signer (RFC 5652 5.3).
+///
+/// ```text
+/// SignerIdentifier ::= CHOICE {
+///   issuerAndSerialNumber IssuerAndSerialNumber,
+///   subjectKeyIdentifier [0] SubjectKeyIdentifier }
+/// ```
+///
+/// This is also used as `RecipientIdentifier` (RFC 5652 6.2.1), which has
+/// the same definition.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum SignerIdentifier {
+    /// `issuerAndSerialNumber`
+    IssuerAndSerialNumber(IssuerAndSerialNumber),
+    /// `subjectKeyIdentifier`
+    SubjectKeyIdentifier(Vec<u8>),
+}
+
+/// The certificate of a recipient (RFC 5652 6.2.1), an alias of
+/// [`SignerIdentifier`].
+pub type RecipientIdentifier = SignerIdentifier;
+
+impl BERDecodable for SignerIdentifier {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        if reader.lookahead_tag()? == Tag::context(0) {
+            return Ok(SignerIdentifier::SubjectKeyIdentifier(
+                reader.read_tagged_implicit(Tag::context(0),
+                    |reader| reader.read_bytes())?));
+        }
+        return Ok(SignerIdentifier::IssuerAndSerialNumber(
+            IssuerAndSerialNumber::decode_ber(reader)?));
+    }
+}
+
+impl DEREncodable for SignerIdentifier {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            SignerIdentifier::IssuerAndSerialNumber(ref id) => {
+                id.encode_der(writer)
+            },
+            SignerIdentifier::SubjectKeyIdentifier(ref id) => {
+                writer.write_tagged_implicit(Tag::context(0), |writer| {
+                    writer.write_bytes(id)
+                })
+            },
+        }
+    }
+}
+
+/// A certificate in a CMS structure (RFC 5652 10.2.2).
+///
+/// ```text
+/// CertificateChoices ::= CHOICE {
+///   certificate Certificate,
+///   extendedCertificate [0] IMPLICIT ExtendedCertificate,  -- Obsolete
+///   v1AttrCert [1] IMPLICIT AttributeCertificateV1,        -- Obsolete
+///   v2AttrCert [2] IMPLICIT AttributeCertificateV2,
+///   other [3] IMPLICIT OtherCertificateFormat }
+///
+/// CertificateSet ::= SET OF CertificateChoices
+/// ```
+///
+/// `CertificateSet` corresponds to `Vec<CertificateChoices>`, which is
+/// omitted when encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum CertificateChoices {
+    /// `certificate`
+    Certificate(Box<Certificate>),
+    /// Any other choice, as a raw value with its context-specific tag
+    Other(TaggedDerValue),
+}
+
+impl CertificateChoices {
+    /// Returns `true` if this is a raw value with `[tag_number]`.
+    pub(super) fn is_other(&self, tag_number: u64) -> bool {
+        match *self {
+            CertificateChoices::Other(ref value) => {
+                value.tag() == Tag::context(tag_number)
+            },
+            _ => false,
+        }
+    }
+}
+
+impl BERDecodable for CertificateChoices {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let tag = reader.lookahead_tag()?;
+        if tag.tag_class != TagClass::ContextSpecific {
+            return Ok(CertificateChoices::Certificate(
+                Box::new(Certificate::decode_ber(reader)?)));
+        }
+        if tag.tag_number > 3 {
+            return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+        }
+        return Ok(CertificateChoices::Other(reader.read_tagged_der()?));
+    }
+}
+
+impl DEREncodable for CertificateChoices {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            CertificateChoices::Certificate(ref certificate) => {
+                certificate.encode_der(writer)
+            },
+            CertificateChoices::Other(ref value) => {
+                writer.write_tagged_der(value)
+            },
+        }
+    }
+}
+
+/// Revocation information in a CMS structure (RFC 5652 10.2.1).
+///
+/// ```text
+/// RevocationInfoChoices ::= SET OF RevocationInfoChoice
+///
+/// RevocationInfoChoice ::= CHOICE {
+///   crl CertificateList,
+///   other [1] IMPLICIT OtherRevocationInfoFormat }
+/// ```
+///
+/// `RevocationInfoChoices` corresponds to `Vec<RevocationInfoChoice>`,
+/// which is omitted when encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum RevocationInfoChoice {
+    /// `crl`
+    Crl(Box<CertificateList>),
+    /// `other`, as a raw value with the `[1]` tag
+    Other(TaggedDerValue),
+}
+
+impl BERDecodable for RevocationInfoChoice {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        if reader.lookahead_tag()? == Tag::context(1) {
+            return Ok(RevocationInfoChoice::Other(reader.read_tagged_der()?));
+        }
+        return Ok(RevocationInfoChoice::Crl(
+            Box::new(CertificateList::decode_ber(reader)?)));
+    }
+}
+
+impl DEREncodable for RevocationInfoChoice {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            RevocationInfoChoice::Crl(ref crl) => crl.encode_der(writer),
+            RevocationInfoChoice::Other(ref value) => {
+                writer.write_tagged_der(value)
+            },
+        }
+    }
+}
+
+/// Reads `[tag] IMPLICIT CertificateSet` and
+/// `[tag] IMPLICIT RevocationInfoChoices` if present.
+pub(super) fn read_certificates_and_crls(reader: &mut BERReaderSeq)
+        -> ASN1Result<(Vec<CertificateChoices>, Vec<RevocationInfoChoice>)> {
+    let certificates = reader.read_optional(|reader| {
+        reader.read_tagged_implicit(Tag::context(0), |reader| {
+            reader.collect_set_of(CertificateChoices::decode_ber)
+        })
+    })?;
+    let crls = reader.read_optional(|reader| {
+        reader.read_tagged_implicit(Tag::context(1), |reader| {
+            reader.collect_set_of(RevocationInfoChoice::decode_ber)
+        })
+    })?;
+    return Ok((certificates.unwrap_or_default(), crls.unwrap_or_default()));
+}
+
+/// Writes `[0] IMPLICIT CertificateSet` and
+/// `[1] IMPLICIT RevocationInfoChoices` unless empty.
+pub(super) fn write_certificates_and_crls(
+        writer: &mut DERWriterSeq,
+        certificates: &[CertificateChoices], crls: &[RevocationInfoChoice]) {
+    if !certificates.is_empty() {
+        writer.next().write_tagged_implicit(Tag::context(0), |writer| {
+            writer.write_set_of(|writer| {
+                for certificate in certificates {
+                    certificate.encode_der(writer.next());
+                }
+            })
+        });
+    }
+    if !crls.is_empty() {
+        writer.next().write_tagged_implicit(Tag::context(1), |writer| {
+            writer.write_set_of(|writer| {
+                for crl in crls {
+                    crl.encode_der(writer.next());
+                }
+            })
+        });
+    }
+}
+
+/// The signed content in a SignedData (RFC 5652 5.2).
+///
+/// ```text
+/// EncapsulatedContentInfo ::= SEQUENCE {
+///   eContentType ContentType,
+///   eContent [0] EXPLICIT OCTET STRING OPTIONAL }
+/// ```
+///
+/// The content is absent in a detached signature.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct EncapsulatedContentInfo {
+    /// The type of the content
+    pub e_content_type: ObjectIdentifier,
+    /// The content, as the contents of the OCTET STRING
+    pub e_content: Option<Vec<u8>>,
+}
+
+impl BERDecodable for EncapsulatedContentInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let e_content_type = ObjectIdentifier::decode_ber(reader.next())?;
+            let e_content = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0),
+                    |reader| reader.read_bytes())
+            })?;
+            return Ok(EncapsulatedContentInfo {
+                e_content_type,
+                e_content,
+            });
+        })
+    }
+}
+
+impl DEREncodable for EncapsulatedContentInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.e_content_type.encode_der(writer.next());
+            if let Some(ref e_content) = self.e_content {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    writer.write_bytes(e_content)
+                });
+            }
+        })
+    }
+}
+
+/// A content signed by any number of signers (RFC 5652 5.1).
+///
+/// ```text
+/// SignedData ::= SEQUENCE {
+///   version CMSVersion,
+///   digestAlgorithms DigestAlgorithmIdentifiers,
+///   encapContentInfo EncapsulatedContentInfo,
+///   certificates [0] IMPLICIT CertificateSet OPTIONAL,
+///   crls [1] IMPLICIT RevocationInfoChoices OPTIONAL,
+///   signerInfos SignerInfos }
+///
+/// DigestAlgorithmIdentifiers ::= SET OF DigestAlgorithmIdentifier
+///
+/// SignerInfos ::= SET OF SignerInfo
+/// ```
+///
+/// The version is kept as decoded, because PKCS#7 structures in the wild
+/// don't always follow the rules of RFC 5652.
+/// [`required_version`](Self::required_version) returns the version these
+/// rules require.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::ObjectIdentifier;
+/// use yasna::pkix::{AlgorithmIdentifier, CMSVersion, ContentInfo};
+/// use yasna::pkix::{EncapsulatedContentInfo, SignedData, oids};
+/// // A degenerate SignedData, which only conveys certificates
+/// let mut signed_data = SignedData {
+///     version: CMSVersion::V1,
+///     digest_algorithms: vec![],
+///     encap_content_info: EncapsulatedContentInfo {
+///         e_content_type: oids::ID_DATA.into(),
+///         e_content: None,
+///     },
+///     certificates: vec![],
+///     crls: vec![],
+///     signer_infos: vec![],
+/// };
+/// assert_eq!(signed_data.required_version(), CMSVersion::V1);
+/// let der = yasna::encode_der(&ContentInfo::SignedData(signed_data.clone()));
+/// assert_eq!(der, &[48, 35, 6, 9, 42, 134, 72, 134, 247, 13, 1, 7, 2,
+///     160, 22, 48, 20, 2, 1, 1, 49, 0, 48, 11, 6, 9, 42, 134, 72, 134,
+///     247, 13, 1, 7, 1, 49, 0]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SignedData {
+    /// The version
+    pub version: CMSVersion,
+    /// The digest algorithms used by the signers
+    pub digest_algorithms: Vec<AlgorithmIdentifier>,
+    /// The signed content
+    pub encap_content_info: EncapsulatedContentInfo,
+    /// The certificates to help find the signers' certificates
+    pub certificates: Vec<CertificateChoices>,
+    /// The CRLs to help validate the certificates
+    pub crls: Vec<RevocationInfoChoice>,
+    /// The signers
+    pub signer_infos: Vec<SignerInfo>,
+}
+
+impl SignedData {
+    /// Returns the version required by RFC 5652 5.1 for the contents.
+    pub fn required_version(&self) -> CMSVersion {
+        let other_crls = self.crls.iter().any(|crl| {
+            matches!(*crl, RevocationInfoChoice::Other(_))
+        });
+        let has_certificate = |tag_number| {
+            self.certificates.iter().any(|c| c.is_other(tag_number))
+        };
+        if has_certificate(3) || other_crls {
+            return CMSVersion::V5;
+        } else if has_certificate(2) {
+            return CMSVersion::V4;
+        } else if has_certificate(1)
+                || self.signer_infos.iter().any(|signer_info| {
+                    signer_info.version() == CMSVersion::V3
+                })
+                || self.encap_content_info.e_content_type != ID_DATA {
+            return CMSVersion::V3;
+        }
+        return CMSVersion::V1;
+    }
+}
+
+impl BERDecodable for SignedData {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let version = CMSVersion::decode_ber(reader.next())?;
+            let digest_algorithms = reader.next()
+                .collect_set_of(AlgorithmIdentifier::decode_ber)?;
+            let encap_content_info =
+                EncapsulatedContentInfo::decode_ber(reader.next())?;
+            let (certificates, crls) = read_certificates_and_crls(reader)?;
+            let signer_infos = reader.next()
+                .collect_set_of(SignerInfo::decode_ber)?;
+            return Ok(SignedData {
+                version,
+                digest_algorithms,
+                encap_content_info,
+                certificates,
+                crls,
+                signer_infos,
+            });
+        })
+    }
+}
+
+impl DEREncodable for SignedData {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.version.encode_der(writer.next());
+            writer.next().write_set_of(|writer| {
+                for digest_algorithm in &self.digest_algorithms {
+                    digest_algorithm.encode_der(writer.next());
+                }
+            });
+            self.encap_content_info.encode_der(writer.next());
+            write_certificates_and_crls(writer, &self.certificates,
+                &self.crls);
+            writer.next().write_set_of(|writer| {
+                for signer_info in &self.signer_infos {
+                    signer_info.encode_der(writer.next());
+                }
+            });
+        })
+    }
+}
+
+/// A signer and its signature (RFC 5652 5.3).
+///
+/// ```text
+/// SignerInfo ::= SEQUENCE {
+///   version CMSVersion,
+///   sid SignerIdentifier,
+///   digestAlgorithm DigestAlgorithmIdentifier,
+///   signedAttrs [0] IMPLICIT SignedAttributes OPTIONAL,
+///   signatureAlgorithm SignatureAlgorithmIdentifier,
+///   signature SignatureValue,
+///   unsignedAttrs [1] IMPLICIT UnsignedAttributes OPTIONAL }
+///
+/// SignedAttributes ::= SET SIZE (1..MAX) OF Attribute
+///
+/// UnsignedAttributes ::= SET SIZE (1..MAX) OF Attribute
+/// ```
+///
+/// The version is not stored: it is 1 if `sid` is an issuer and serial
+/// number, and 3 if it is a subject key identifier. The attributes are
+/// omitted when encoded if empty.
+///
+/// If there are signed attributes, the signature is computed over their
+/// DER encoding with the universal SET tag in place of `[0]` (RFC 5652
+/// 5.4), which [`signed_attrs_der`](Self::signed_attrs_der) returns.
+/// Otherwise, it is computed over the content.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::{ObjectIdentifier, TaggedDerValue};
+/// use yasna::pkix::{AlgorithmIdentifier, Attribute, SignerIdentifier};
+/// use yasna::pkix::{SignerInfo, oids};
+/// use yasna::tags::TAG_OID;
+/// let digest = vec![0; 32]; // The SHA-256 digest of the content
+/// let signed_attrs = vec![
+///     Attribute {
+///         attr_type: oids::ID_CONTENT_TYPE.into(),
+///         values: vec![TaggedDerValue::from_tag_and_bytes(
+///             TAG_OID,
+///             vec![42, 134, 72, 134, 247, 13, 1, 7, 1])],
+///     },
+///     Attribute {
+///         attr_type: oids::ID_MESSAGE_DIGEST.into(),
+///         values: vec![TaggedDerValue::from_octetstring(digest.clone())],
+///     },
+/// ];
+/// let sha256 = AlgorithmIdentifier::new(
+///     ObjectIdentifier::from_slice(&[2, 16, 840, 1, 101, 3, 4, 2, 1]));
+/// let ed25519 = AlgorithmIdentifier::new(
+///     ObjectIdentifier::from_slice(&[1, 3, 101, 112]));
+/// let signer_info = SignerInfo::sign(
+///     SignerIdentifier::SubjectKeyIdentifier(vec![1, 2, 3, 4]),
+///     sha256, signed_attrs, ed25519, |signed_attrs| {
+///         assert_eq!(signed_attrs[0], 49); // SET
+///         // Sign `signed_attrs` with Ed25519 here.
+///         Ok::<_, ()>(vec![0; 64])
+///     }).unwrap();
+/// assert_eq!(signer_info.message_digest().unwrap(), Some(digest));
+/// let der = yasna::encode_der(&signer_info);
+/// assert_eq!(yasna::decode_der::<SignerInfo>(&der).unwrap(), signer_info);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SignerInfo {
+    /// The certificate of the signer
+    pub sid: SignerIdentifier,
+    /// The digest algorithm
+    pub digest_algorithm: AlgorithmIdentifier,
+    /// The signed attributes
+    pub signed_attrs: Vec<Attribute>,
+    /// The signature algorithm
+    pub signature_algorithm: AlgorithmIdentifier,
+    /// The signature
+    pub signature: Vec<u8>,
+    /// The unsigned attributes, such as countersignatures
+    pub unsigned_attrs: Vec<Attribute>,
+}
+
+impl SignerInfo {
+    /// Constructs a signer info by encoding `signed_attrs` in DER as a SET
+    /// and signing the encoding with `sign`.
+    ///
+    /// `signed_attrs` must contain at least the content-type and
+    /// message-digest attributes.
+    pub fn sign<F, E>(sid: SignerIdentifier,
+            digest_algorithm: AlgorithmIdentifier,
+            signed_attrs: Vec<Attribute>,
+            signature_algorithm: AlgorithmIdentifier, sign: F)
+            -> Result<Self, E>
+            where F: FnOnce(&[u8]) -> Result<Vec<u8>, E> {
+        let signature = sign(&encode_signed_attrs(&signed_attrs))?;
+        return Ok(SignerInfo {
+            sid,
+            digest_algorithm,
+            signed_attrs,
+            signature_algorithm,
+            signature,
+            unsigned_attrs: Vec::new(),
+        });
+    }
+
+    /// Returns the version, which is determined by `sid`.
+    pub fn version(&self) -> CMSVersion {
+        match self.sid {
+            SignerIdentifier::IssuerAndSerialNumber(_) => CMSVersion::V1,
+            SignerIdentifier::SubjectKeyIdentifier(_) => CMSVersion::V3,
+        }
+    }
+
+    /// Returns the DER encoding of the signed attributes as a SET, over
+    /// which the signature is computed, or `None` if there are none.
+    ///
+    /// The attributes are re-encoded, so the result may differ from the
+    /// input if it was decoded in BER mode and was not in DER.
+    pub fn signed_attrs_der(&self) -> Option<Vec<u8>> {
+        if self.signed_attrs.is_empty() {
+            return None;
+        }
+        return Some(encode_signed_attrs(&self.signed_attrs));
+    }
+
+    /// Returns the first signed attribute of the given type.
+    pub fn signed_attribute(&self, attr_type: &ObjectIdentifier)
+            -> Option<&Attribute> {
+        self.signed_attrs.iter().find(|a| a.attr_type == *attr_type)
+    }
+
+    /// Decodes the message-digest attribute (RFC 5652 11.2).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the attribute is not a single OCTET STRING.
+    pub fn message_digest(&self) -> ASN1Result<Option<Vec<u8>>> {
+        let attribute = match self.signed_attribute(&ID_MESSAGE_DIGEST.into()) {
+            Some(attribute) => attribute,
+            None => return Ok(None),
+        };
+        let value = single_value(attribute)?;
+        let digest = parse_der(&encode_der(value),
+            |reader| reader.read_bytes())?;
+        return Ok(Some(digest));
+    }
+}
+
+fn encode_signed_attrs(signed_attrs: &[Attribute]) -> Vec<u8> {
+    construct_der(|writer| {
+        writer.write_set_of(|writer| {
+            for attribute in signed_attrs {
+                attribute.encode_der(writer.next());
+            }
+        })
+    })
+}
+
+impl BERDecodable for SignerInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let version = CMSVersion::decode_ber(reader.next())?;
+            let sid = SignerIdentifier::decode_ber(reader.next())?;
+            let digest_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let signed_attrs = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(0), |reader| {
+                    reader.collect_set_of(Attribute::decode_ber)
+                })
+            })?;
+            let signature_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let signature = reader.next().read_bytes()?;
+            let unsigned_attrs = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1), |reader| {
+                    reader.collect_set_of(Attribute::decode_ber)
+                })
+            })?;
+            let signer_info = SignerInfo {
+                sid,
+                digest_algorithm,
+                signed_attrs: signed_attrs.unwrap_or_default(),
+                signature_algorithm,
+                signature,
+                unsigned_attrs: unsigned_attrs.unwrap_or_default(),
+            };
+            if version != signer_info.version() {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            return Ok(signer_info);
+        })
+    }
+}
+
+impl DEREncodable for SignerInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.version().encode_der(writer.next());
+            self.sid.encode_der(writer.next());
+            self.digest_algorithm.encode_der(writer.next());
+            if !self.signed_attrs.is_empty() {
+                writer.next().write_tagged_implicit(Tag::context(0),
+                    |writer| {
+                        writer.write_set_of(|writer| {
+                            for attribute in &self.signed_attrs {
+                                attribute.encode_der(writer.next());
+                            }
+                        })
+                    });
+            }
+            self.signature_algorithm.encode_der(writer.next());
+            writer.next().write_bytes(&self.signature);
+            if !self.unsigned_attrs.is_empty() {
+                writer.next().write_tagged_implicit(Tag::context(1),
+                    |writer| {
+                        writer.write_set_of(|writer| {
+                            for attribute in &self.unsigned_attrs {
+                                attribute.encode_der(writer.next());
+                            }
+                        })
+                    });
+            }
+        })
+    }
+}
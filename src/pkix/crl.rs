@@ -0,0 +1,433 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use super::super::{ASN1Error, ASN1ErrorKind, ASN1Result, BERMode, Tag};
+use super::super::{BERDecodable, BERReader, DEREncodable, DERWriter};
+use super::super::{LazySequenceOf, parse_ber_general};
+use super::super::models::{BitString, ObjectIdentifier, RawDateTime, WithRaw};
+use super::{AlgorithmIdentifier, Extension, Name, SerialNumber, Version};
+
+/// A revoked certificate in a CRL (RFC 5280 5.1.2.6).
+///
+/// ```text
+/// revokedCertificates     SEQUENCE OF SEQUENCE  {
+///      userCertificate         CertificateSerialNumber,
+///      revocationDate          Time,
+///      crlEntryExtensions      Extensions OPTIONAL
+///                               -- if present, version MUST be v2
+///                           }  OPTIONAL,
+/// ```
+///
+/// The extensions are omitted when encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RevokedCertificate {
+    /// The serial number of the revoked certificate
+    pub user_certificate: SerialNumber,
+    /// When the certificate was revoked
+    pub revocation_date: RawDateTime,
+    /// The CRL entry extensions, such as the reason code
+    pub crl_entry_extensions: Vec<Extension>,
+}
+
+impl RevokedCertificate {
+    /// Returns the entry extension with the given OID.
+    pub fn extension(&self, extn_id: &ObjectIdentifier) -> Option<&Extension> {
+        self.crl_entry_extensions.iter().find(|extension| {
+            extension.extn_id == *extn_id
+        })
+    }
+}
+
+impl BERDecodable for RevokedCertificate {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let user_certificate = SerialNumber::decode_ber(reader.next())?;
+            let revocation_date = RawDateTime::decode_ber(reader.next())?;
+            let crl_entry_extensions = reader.read_optional(Vec::decode_ber)?;
+            return Ok(RevokedCertificate {
+                user_certificate,
+                revocation_date,
+                crl_entry_extensions: crl_entry_extensions.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for RevokedCertificate {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.user_certificate.encode_der(writer.next());
+            self.revocation_date.encode_der(writer.next());
+            if !self.crl_entry_extensions.is_empty() {
+                self.crl_entry_extensions.encode_der(writer.next());
+            }
+        })
+    }
+}
+
+/// The signed part of a CRL (RFC 5280 5.1.2).
+///
+/// ```text
+/// TBSCertList  ::=  SEQUENCE  {
+///      version                 Version OPTIONAL,
+///                                   -- if present, MUST be v2
+///      signature               AlgorithmIdentifier,
+///      issuer                  Name,
+///      thisUpdate              Time,
+///      nextUpdate              Time OPTIONAL,
+///      revokedCertificates     SEQUENCE OF SEQUENCE  { ... }  OPTIONAL,
+///      crlExtensions           [0]  EXPLICIT Extensions OPTIONAL
+///                                   -- if present, version MUST be v2
+///                           }
+/// ```
+///
+/// The version is either `V1`, which is written by omitting it, or `V2`.
+/// When decoding, the extensions are rejected in a v1 CRL. When encoding,
+/// the revoked certificates and the extensions are omitted if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TBSCertList {
+    /// The version, `V1` or `V2`
+    pub version: Version,
+    /// The signature algorithm, the same as the one in `CertificateList`
+    pub signature: AlgorithmIdentifier,
+    /// The issuer name
+    pub issuer: Name,
+    /// When the CRL was issued
+    pub this_update: RawDateTime,
+    /// When the next CRL will be issued
+    pub next_update: Option<RawDateTime>,
+    /// The revoked certificates
+    pub revoked_certificates: Vec<RevokedCertificate>,
+    /// The CRL extensions
+    pub crl_extensions: Vec<Extension>,
+}
+
+impl TBSCertList {
+    /// Returns the CRL extension with the given OID.
+    pub fn extension(&self, extn_id: &ObjectIdentifier) -> Option<&Extension> {
+        self.crl_extensions.iter().find(|extension| {
+            extension.extn_id == *extn_id
+        })
+    }
+
+    /// Reads the TBSCertList, leaving the revoked certificates unparsed.
+    fn read_lazy<'a>(reader: BERReader<'a, '_>)
+            -> ASN1Result<(Self, RevokedCertificates<'a>)> {
+        reader.read_sequence(|reader| {
+            let version = reader.read_optional(Version::decode_ber)?;
+            let signature = AlgorithmIdentifier::decode_ber(reader.next())?;
+            let issuer = Name::decode_ber(reader.next())?;
+            let this_update = RawDateTime::decode_ber(reader.next())?;
+            let next_update = reader.read_optional(RawDateTime::decode_ber)?;
+            let revoked_certificates = reader.read_optional(|reader| {
+                reader.read_sequence_of_lazy()
+            })?;
+            let crl_extensions = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0), Vec::decode_ber)
+            })?;
+            let version = match version {
+                None => Version::V1,
+                Some(Version::V2) => Version::V2,
+                Some(_) => return Err(ASN1Error::new(ASN1ErrorKind::Invalid)),
+            };
+            if version == Version::V1 && crl_extensions.is_some() {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            let tbs_cert_list = TBSCertList {
+                version,
+                signature,
+                issuer,
+                this_update,
+                next_update,
+                revoked_certificates: Vec::new(),
+                crl_extensions: crl_extensions.unwrap_or_default(),
+            };
+            let revoked_certificates = RevokedCertificates {
+                entries: revoked_certificates.unwrap_or_default(),
+                version,
+            };
+            return Ok((tbs_cert_list, revoked_certificates));
+        })
+    }
+}
+
+impl BERDecodable for TBSCertList {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let (mut tbs_cert_list, revoked_certificates) =
+            TBSCertList::read_lazy(reader)?;
+        tbs_cert_list.revoked_certificates =
+            revoked_certificates.collect::<ASN1Result<_>>()?;
+        return Ok(tbs_cert_list);
+    }
+}
+
+impl DEREncodable for TBSCertList {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            if self.version != Version::V1 {
+                self.version.encode_der(writer.next());
+            }
+            self.signature.encode_der(writer.next());
+            self.issuer.encode_der(writer.next());
+            self.this_update.encode_der(writer.next());
+            if let Some(ref next_update) = self.next_update {
+                next_update.encode_der(writer.next());
+            }
+            if !self.revoked_certificates.is_empty() {
+                self.revoked_certificates.encode_der(writer.next());
+            }
+            if !self.crl_extensions.is_empty() {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    self.crl_extensions.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// A certificate revocation list (RFC 5280 5.1).
+///
+/// ```text
+/// CertificateList  ::=  SEQUENCE  {
+///      tbsCertList          TBSCertList,
+///      signatureAlgorithm   AlgorithmIdentifier,
+///      signatureValue       BIT STRING  }
+/// ```
+///
+/// Decoding a `CertificateList` collects all the revoked certificates.
+/// Use [`CertificateListRef`] to look up large CRLs without doing so.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::{ObjectIdentifier, RawDateTime};
+/// use yasna::pkix::{AlgorithmIdentifier, CertificateList, RevokedCertificate};
+/// use yasna::pkix::{SerialNumber, TBSCertList, Version};
+/// let tbs_cert_list = TBSCertList {
+///     version: Version::V2,
+///     signature: AlgorithmIdentifier::new(
+///         ObjectIdentifier::from_slice(&[1, 3, 101, 112])),
+///     issuer: "CN=Example CA".parse().unwrap(),
+///     this_update: RawDateTime::new(2026, 1, 1, 0, 0, 0, 0).unwrap(),
+///     next_update: Some(RawDateTime::new(2026, 1, 8, 0, 0, 0, 0).unwrap()),
+///     revoked_certificates: vec![RevokedCertificate {
+///         user_certificate: SerialNumber::from(0x1234),
+///         revocation_date: RawDateTime::new(2025, 12, 31, 0, 0, 0, 0).unwrap(),
+///         crl_entry_extensions: vec![],
+///     }],
+///     crl_extensions: vec![],
+/// };
+/// let crl = CertificateList::sign(tbs_cert_list, |tbs_bytes| {
+///     // Sign `tbs_bytes` with Ed25519 here.
+///     Ok::<_, ()>(vec![0; 64])
+/// }).unwrap();
+/// let der = yasna::encode_der(&crl);
+/// let decoded : CertificateList = yasna::decode_der(&der).unwrap();
+/// assert_eq!(decoded, crl);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CertificateList {
+    /// The signed part, with its encoding
+    pub tbs_cert_list: WithRaw<TBSCertList>,
+    /// The signature algorithm
+    pub signature_algorithm: AlgorithmIdentifier,
+    /// The signature
+    pub signature_value: BitString,
+}
+
+impl CertificateList {
+    /// Constructs a CRL by encoding `tbs_cert_list` in DER and signing the
+    /// encoding with `sign`.
+    ///
+    /// The signature algorithm is copied from `tbs_cert_list.signature`.
+    pub fn sign<F, E>(tbs_cert_list: TBSCertList, sign: F) -> Result<Self, E>
+            where F: FnOnce(&[u8]) -> Result<Vec<u8>, E> {
+        let signature_algorithm = tbs_cert_list.signature.clone();
+        let tbs_cert_list = WithRaw::new(tbs_cert_list);
+        let signature_value = BitString::from_bytes(
+            sign(tbs_cert_list.raw())?);
+        return Ok(CertificateList {
+            tbs_cert_list,
+            signature_algorithm,
+            signature_value,
+        });
+    }
+
+    /// Returns the encoding of the TBSCertList, over which the signature
+    /// is computed.
+    pub fn tbs_bytes(&self) -> &[u8] {
+        self.tbs_cert_list.raw()
+    }
+}
+
+impl BERDecodable for CertificateList {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let tbs_cert_list = WithRaw::decode_ber(reader.next())?;
+            let signature_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let signature_value = BitString::decode_ber(reader.next())?;
+            return Ok(CertificateList {
+                tbs_cert_list,
+                signature_algorithm,
+                signature_value,
+            });
+        })
+    }
+}
+
+impl DEREncodable for CertificateList {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.tbs_cert_list.encode_der(writer.next());
+            self.signature_algorithm.encode_der(writer.next());
+            self.signature_value.encode_der(writer.next());
+        })
+    }
+}
+
+/// An iterator over the revoked certificates of a CRL, decoded lazily.
+///
+/// This is [`LazySequenceOf`] with the check [`TBSCertList`] makes on each
+/// entry: an entry with extensions in a v1 CRL is an error. As with
+/// [`LazySequenceOf`], the iteration stops after an error.
+#[derive(Debug, Clone)]
+pub struct RevokedCertificates<'a> {
+    entries: LazySequenceOf<'a, RevokedCertificate>,
+    version: Version,
+}
+
+impl<'a> RevokedCertificates<'a> {
+    /// Returns the encoding of the entries.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.entries.as_bytes()
+    }
+
+    /// Returns `true` if there are no more entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<'a> Iterator for RevokedCertificates<'a> {
+    type Item = ASN1Result<RevokedCertificate>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = match self.entries.next()? {
+            Ok(entry) => entry,
+            Err(e) => return Some(Err(e)),
+        };
+        if self.version == Version::V1 &&
+                !entry.crl_entry_extensions.is_empty() {
+            self.entries = LazySequenceOf::default();
+            return Some(Err(ASN1Error::new(ASN1ErrorKind::Invalid)));
+        }
+        return Some(Ok(entry));
+    }
+}
+
+/// A CRL whose revoked certificates are decoded on demand.
+///
+/// This borrows the input, and the revoked certificates are decoded one
+/// by one as [`revoked_certificates`](Self::revoked_certificates) is
+/// iterated, so that large CRLs can be searched without collecting the
+/// whole list. `tbs_cert_list.revoked_certificates` is always empty.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::{ObjectIdentifier, RawDateTime};
+/// use yasna::pkix::{AlgorithmIdentifier, CertificateList, CertificateListRef};
+/// use yasna::pkix::{RevokedCertificate, SerialNumber, TBSCertList, Version};
+/// # let tbs_cert_list = TBSCertList {
+/// #     version: Version::V2,
+/// #     signature: AlgorithmIdentifier::new(
+/// #         ObjectIdentifier::from_slice(&[1, 3, 101, 112])),
+/// #     issuer: "CN=Example CA".parse().unwrap(),
+/// #     this_update: RawDateTime::new(2026, 1, 1, 0, 0, 0, 0).unwrap(),
+/// #     next_update: None,
+/// #     revoked_certificates: (1..=1000).map(|i| RevokedCertificate {
+/// #         user_certificate: SerialNumber::from(i),
+/// #         revocation_date: RawDateTime::new(2025, 1, 1, 0, 0, 0, 0).unwrap(),
+/// #         crl_entry_extensions: vec![],
+/// #     }).collect(),
+/// #     crl_extensions: vec![],
+/// # };
+/// # let der = yasna::encode_der(&CertificateList::sign(tbs_cert_list,
+/// #     |_| Ok::<_, ()>(vec![0; 64])).unwrap());
+/// let crl = CertificateListRef::parse_der(&der).unwrap();
+/// assert_eq!(crl.tbs_cert_list.issuer.to_string(), "CN=Example CA");
+/// let entry = crl.find(&SerialNumber::from(500)).unwrap().unwrap();
+/// assert_eq!(entry.revocation_date.year(), 2025);
+/// assert!(crl.find(&SerialNumber::from(1001)).unwrap().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CertificateListRef<'a> {
+    /// The signed part, except the revoked certificates
+    pub tbs_cert_list: TBSCertList,
+    /// The revoked certificates, decoded lazily
+    pub revoked_certificates: RevokedCertificates<'a>,
+    /// The encoding of the TBSCertList, over which the signature is computed
+    pub tbs_bytes: &'a [u8],
+    /// The signature algorithm
+    pub signature_algorithm: AlgorithmIdentifier,
+    /// The signature
+    pub signature_value: BitString,
+}
+
+impl<'a> CertificateListRef<'a> {
+    /// Parses a DER-encoded CRL.
+    pub fn parse_der(buf: &'a [u8]) -> ASN1Result<Self> {
+        return CertificateListRef::parse_ber_general(buf, BERMode::Der);
+    }
+
+    /// Parses a BER-encoded CRL.
+    pub fn parse_ber(buf: &'a [u8]) -> ASN1Result<Self> {
+        return CertificateListRef::parse_ber_general(buf, BERMode::Ber);
+    }
+
+    /// Parses a CRL in the given mode.
+    pub fn parse_ber_general(buf: &'a [u8], mode: BERMode)
+            -> ASN1Result<Self> {
+        parse_ber_general(buf, mode, |reader| {
+            reader.read_sequence(|reader| {
+                let ((tbs_cert_list, revoked_certificates), tbs_bytes) =
+                    reader.next().read_with_buffer(TBSCertList::read_lazy)?;
+                let signature_algorithm =
+                    AlgorithmIdentifier::decode_ber(reader.next())?;
+                let signature_value = BitString::decode_ber(reader.next())?;
+                return Ok(CertificateListRef {
+                    tbs_cert_list,
+                    revoked_certificates,
+                    tbs_bytes,
+                    signature_algorithm,
+                    signature_value,
+                });
+            })
+        })
+    }
+
+    /// Looks up the entry for the serial number, decoding the entries
+    /// until it is found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an entry before the one found is malformed.
+    pub fn find(&self, serial_number: &SerialNumber)
+            -> ASN1Result<Option<RevokedCertificate>> {
+        for entry in self.revoked_certificates.clone() {
+            let entry = entry?;
+            if entry.user_certificate == *serial_number {
+                return Ok(Some(entry));
+            }
+        }
+        return Ok(None);
+    }
+}
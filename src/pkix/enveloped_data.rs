@@ -0,0 +1,999 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use super::super::{ASN1Error, ASN1ErrorKind, ASN1Result, PCBit, Tag};
+use super::super::{BERDecodable, BERReader, BERReaderSeq, ChoiceAlternative};
+use super::super::{DEREncodable, DERWriter, DERWriterSeq};
+use super::super::models::{GeneralizedTime, ObjectIdentifier, TaggedDerValue};
+use super::super::tags::TAG_SEQUENCE;
+use super::{AlgorithmIdentifier, Attribute, SubjectPublicKeyInfo};
+use super::{CMSVersion, CertificateChoices, IssuerAndSerialNumber};
+use super::{RecipientIdentifier, RevocationInfoChoice};
+use super::cms::{read_certificates_and_crls, write_certificates_and_crls};
+
+/// A content encrypted for any number of recipients (RFC 5652 6.1).
+///
+/// ```text
+/// EnvelopedData ::= SEQUENCE {
+///   version CMSVersion,
+///   originatorInfo [0] IMPLICIT OriginatorInfo OPTIONAL,
+///   recipientInfos RecipientInfos,
+///   encryptedContentInfo EncryptedContentInfo,
+///   unprotectedAttrs [1] IMPLICIT UnprotectedAttributes OPTIONAL }
+///
+/// RecipientInfos ::= SET SIZE (1..MAX) OF RecipientInfo
+///
+/// UnprotectedAttributes ::= SET SIZE (1..MAX) OF Attribute
+/// ```
+///
+/// As in [`SignedData`](super::SignedData), the version is kept as decoded,
+/// and [`required_version`](Self::required_version) returns the version
+/// RFC 5652 requires. The unprotected attributes are omitted when encoded
+/// if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct EnvelopedData {
+    /// The version
+    pub version: CMSVersion,
+    /// The certificates and CRLs of the originator
+    pub originator_info: Option<OriginatorInfo>,
+    /// The recipients, with the content-encryption key encrypted for each
+    pub recipient_infos: Vec<RecipientInfo>,
+    /// The encrypted content
+    pub encrypted_content_info: EncryptedContentInfo,
+    /// The unencrypted attributes
+    pub unprotected_attrs: Vec<Attribute>,
+}
+
+impl EnvelopedData {
+    /// Returns the version required by RFC 5652 6.1 for the contents.
+    pub fn required_version(&self) -> CMSVersion {
+        let originator_info = self.originator_info.as_ref();
+        if let Some(originator_info) = originator_info {
+            if originator_info.certs.iter().any(|c| c.is_other(3))
+                    || originator_info.crls.iter().any(|crl| {
+                        matches!(*crl, RevocationInfoChoice::Other(_))
+                    }) {
+                return CMSVersion::V4;
+            }
+        }
+        let has_v2_attribute_certificate = originator_info.map_or(false,
+            |originator_info| originator_info.certs.iter()
+                .any(|c| c.is_other(2)));
+        if has_v2_attribute_certificate
+                || self.recipient_infos.iter().any(|recipient_info| {
+                    matches!(*recipient_info,
+                        RecipientInfo::Pwri(_) | RecipientInfo::Ori(_))
+                }) {
+            return CMSVersion::V3;
+        } else if originator_info.is_none()
+                && self.unprotected_attrs.is_empty()
+                && self.recipient_infos.iter().all(|recipient_info| {
+                    recipient_info.version() == Some(CMSVersion::V0)
+                }) {
+            return CMSVersion::V0;
+        }
+        return CMSVersion::V2;
+    }
+}
+
+impl BERDecodable for EnvelopedData {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let version = CMSVersion::decode_ber(reader.next())?;
+            let originator_info = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(0),
+                    OriginatorInfo::decode_ber)
+            })?;
+            let recipient_infos = reader.next()
+                .collect_set_of(RecipientInfo::decode_ber)?;
+            if recipient_infos.is_empty() {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            let encrypted_content_info =
+                EncryptedContentInfo::decode_ber(reader.next())?;
+            let unprotected_attrs = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1), |reader| {
+                    reader.collect_set_of(Attribute::decode_ber)
+                })
+            })?;
+            return Ok(EnvelopedData {
+                version,
+                originator_info,
+                recipient_infos,
+                encrypted_content_info,
+                unprotected_attrs: unprotected_attrs.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for EnvelopedData {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.version.encode_der(writer.next());
+            if let Some(ref originator_info) = self.originator_info {
+                writer.next().write_tagged_implicit(Tag::context(0),
+                    |writer| originator_info.encode_der(writer));
+            }
+            writer.next().write_set_of(|writer| {
+                for recipient_info in &self.recipient_infos {
+                    recipient_info.encode_der(writer.next());
+                }
+            });
+            self.encrypted_content_info.encode_der(writer.next());
+            if !self.unprotected_attrs.is_empty() {
+                writer.next().write_tagged_implicit(Tag::context(1),
+                    |writer| {
+                        writer.write_set_of(|writer| {
+                            for attribute in &self.unprotected_attrs {
+                                attribute.encode_der(writer.next());
+                            }
+                        })
+                    });
+            }
+        })
+    }
+}
+
+/// The certificates and CRLs of the originator (RFC 5652 6.1).
+///
+/// ```text
+/// OriginatorInfo ::= SEQUENCE {
+///   certs [0] IMPLICIT CertificateSet OPTIONAL,
+///   crls [1] IMPLICIT RevocationInfoChoices OPTIONAL }
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct OriginatorInfo {
+    /// The certificates
+    pub certs: Vec<CertificateChoices>,
+    /// The CRLs
+    pub crls: Vec<RevocationInfoChoice>,
+}
+
+impl BERDecodable for OriginatorInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let (certs, crls) = read_certificates_and_crls(reader)?;
+            return Ok(OriginatorInfo {
+                certs,
+                crls,
+            });
+        })
+    }
+}
+
+impl DEREncodable for OriginatorInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            write_certificates_and_crls(writer, &self.certs, &self.crls);
+        })
+    }
+}
+
+/// The encrypted content in an EnvelopedData (RFC 5652 6.1).
+///
+/// ```text
+/// EncryptedContentInfo ::= SEQUENCE {
+///   contentType ContentType,
+///   contentEncryptionAlgorithm ContentEncryptionAlgorithmIdentifier,
+///   encryptedContent [0] IMPLICIT EncryptedContent OPTIONAL }
+///
+/// EncryptedContent ::= OCTET STRING
+/// ```
+///
+/// The encryption itself is left to the caller of
+/// [`encrypt`](Self::encrypt) and [`decrypt`](Self::decrypt).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct EncryptedContentInfo {
+    /// The type of the content
+    pub content_type: ObjectIdentifier,
+    /// The content-encryption algorithm
+    pub content_encryption_algorithm: AlgorithmIdentifier,
+    /// The encrypted content, which is absent if conveyed separately
+    pub encrypted_content: Option<Vec<u8>>,
+}
+
+impl EncryptedContentInfo {
+    /// Encrypts `content` with `encrypt`.
+    pub fn encrypt<F, E>(content_type: ObjectIdentifier,
+            content_encryption_algorithm: AlgorithmIdentifier,
+            content: &[u8], encrypt: F) -> Result<Self, E>
+            where F: FnOnce(&[u8]) -> Result<Vec<u8>, E> {
+        let encrypted_content = encrypt(content)?;
+        return Ok(EncryptedContentInfo {
+            content_type,
+            content_encryption_algorithm,
+            encrypted_content: Some(encrypted_content),
+        });
+    }
+
+    /// Decrypts the content with `decrypt`, which is given the algorithm
+    /// and the ciphertext.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `decrypt`, or an error converted into `E` if
+    /// the encrypted content is absent.
+    pub fn decrypt<F, E>(&self, decrypt: F) -> Result<Vec<u8>, E>
+            where F: FnOnce(&AlgorithmIdentifier, &[u8]) -> Result<Vec<u8>, E>,
+                E: From<ASN1Error> {
+        let encrypted_content = match self.encrypted_content {
+            Some(ref encrypted_content) => encrypted_content,
+            None => return Err(ASN1Error::new(ASN1ErrorKind::Invalid).into()),
+        };
+        return decrypt(&self.content_encryption_algorithm, encrypted_content);
+    }
+}
+
+impl BERDecodable for EncryptedContentInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let content_type = ObjectIdentifier::decode_ber(reader.next())?;
+            let content_encryption_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let encrypted_content = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(0),
+                    |reader| reader.read_bytes())
+            })?;
+            return Ok(EncryptedContentInfo {
+                content_type,
+                content_encryption_algorithm,
+                encrypted_content,
+            });
+        })
+    }
+}
+
+impl DEREncodable for EncryptedContentInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.content_type.encode_der(writer.next());
+            self.content_encryption_algorithm.encode_der(writer.next());
+            if let Some(ref encrypted_content) = self.encrypted_content {
+                writer.next().write_tagged_implicit(Tag::context(0),
+                    |writer| writer.write_bytes(encrypted_content));
+            }
+        })
+    }
+}
+
+/// A content encrypted with a key managed by other means (RFC 5652 8).
+///
+/// ```text
+/// EncryptedData ::= SEQUENCE {
+///   version CMSVersion,
+///   encryptedContentInfo EncryptedContentInfo,
+///   unprotectedAttrs [1] IMPLICIT UnprotectedAttributes OPTIONAL }
+/// ```
+///
+/// PKCS#12 uses it for the contents encrypted with a password. As in
+/// [`EnvelopedData`], the version is kept as decoded, and
+/// [`required_version`](Self::required_version) returns the version
+/// RFC 5652 requires. The unprotected attributes are omitted when encoded
+/// if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct EncryptedData {
+    /// The version
+    pub version: CMSVersion,
+    /// The encrypted content
+    pub encrypted_content_info: EncryptedContentInfo,
+    /// The unencrypted attributes
+    pub unprotected_attrs: Vec<Attribute>,
+}
+
+impl EncryptedData {
+    /// Constructs an `EncryptedData` without attributes.
+    pub fn new(encrypted_content_info: EncryptedContentInfo) -> Self {
+        EncryptedData {
+            version: CMSVersion::V0,
+            encrypted_content_info,
+            unprotected_attrs: Vec::new(),
+        }
+    }
+
+    /// Returns the version required by RFC 5652 8 for the contents: v2 if
+    /// there are unprotected attributes, and v0 otherwise.
+    pub fn required_version(&self) -> CMSVersion {
+        if self.unprotected_attrs.is_empty() {
+            return CMSVersion::V0;
+        } else {
+            return CMSVersion::V2;
+        }
+    }
+}
+
+impl BERDecodable for EncryptedData {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let version = CMSVersion::decode_ber(reader.next())?;
+            let encrypted_content_info =
+                EncryptedContentInfo::decode_ber(reader.next())?;
+            let unprotected_attrs = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1), |reader| {
+                    reader.collect_set_of(Attribute::decode_ber)
+                })
+            })?;
+            return Ok(EncryptedData {
+                version,
+                encrypted_content_info,
+                unprotected_attrs: unprotected_attrs.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for EncryptedData {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.version.encode_der(writer.next());
+            self.encrypted_content_info.encode_der(writer.next());
+            if !self.unprotected_attrs.is_empty() {
+                writer.next().write_tagged_implicit(Tag::context(1),
+                    |writer| {
+                        writer.write_set_of(|writer| {
+                            for attribute in &self.unprotected_attrs {
+                                attribute.encode_der(writer.next());
+                            }
+                        })
+                    });
+            }
+        })
+    }
+}
+
+/// A recipient of an EnvelopedData (RFC 5652 6.2).
+///
+/// ```text
+/// RecipientInfo ::= CHOICE {
+///   ktri KeyTransRecipientInfo,
+///   kari [1] KeyAgreeRecipientInfo,
+///   kekri [2] KEKRecipientInfo,
+///   pwri [3] PasswordRecipientInfo,
+///   ori [4] OtherRecipientInfo }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum RecipientInfo {
+    /// `ktri`, with the key encrypted by the recipient's public key
+    Ktri(KeyTransRecipientInfo),
+    /// `kari`, with the key encrypted by a key agreed with the recipient
+    Kari(KeyAgreeRecipientInfo),
+    /// `kekri`, with the key encrypted by a previously shared key
+    Kekri(KEKRecipientInfo),
+    /// `pwri`, with the key encrypted by a key derived from a password
+    Pwri(PasswordRecipientInfo),
+    /// `ori`
+    Ori(OtherRecipientInfo),
+}
+
+impl RecipientInfo {
+    /// Returns the version, or `None` for `ori`, which doesn't have one.
+    pub fn version(&self) -> Option<CMSVersion> {
+        match *self {
+            RecipientInfo::Ktri(ref ktri) => Some(ktri.version()),
+            RecipientInfo::Kari(_) => Some(CMSVersion::V3),
+            RecipientInfo::Kekri(_) => Some(CMSVersion::V4),
+            RecipientInfo::Pwri(_) => Some(CMSVersion::V0),
+            RecipientInfo::Ori(_) => None,
+        }
+    }
+}
+
+impl BERDecodable for RecipientInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_choice([
+            ChoiceAlternative::new(TAG_SEQUENCE, PCBit::Constructed,
+                |reader| Ok(RecipientInfo::Ktri(
+                    KeyTransRecipientInfo::decode_ber(reader)?))),
+            ChoiceAlternative::new(Tag::context(1), PCBit::Constructed,
+                |reader| Ok(RecipientInfo::Kari(reader.read_tagged_implicit(
+                    Tag::context(1), KeyAgreeRecipientInfo::decode_ber)?))),
+            ChoiceAlternative::new(Tag::context(2), PCBit::Constructed,
+                |reader| Ok(RecipientInfo::Kekri(reader.read_tagged_implicit(
+                    Tag::context(2), KEKRecipientInfo::decode_ber)?))),
+            ChoiceAlternative::new(Tag::context(3), PCBit::Constructed,
+                |reader| Ok(RecipientInfo::Pwri(reader.read_tagged_implicit(
+                    Tag::context(3), PasswordRecipientInfo::decode_ber)?))),
+            ChoiceAlternative::new(Tag::context(4), PCBit::Constructed,
+                |reader| Ok(RecipientInfo::Ori(reader.read_tagged_implicit(
+                    Tag::context(4), OtherRecipientInfo::decode_ber)?))),
+        ])
+    }
+}
+
+impl DEREncodable for RecipientInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            RecipientInfo::Ktri(ref ktri) => ktri.encode_der(writer),
+            RecipientInfo::Kari(ref kari) => {
+                writer.write_tagged_implicit(Tag::context(1),
+                    |writer| kari.encode_der(writer))
+            },
+            RecipientInfo::Kekri(ref kekri) => {
+                writer.write_tagged_implicit(Tag::context(2),
+                    |writer| kekri.encode_der(writer))
+            },
+            RecipientInfo::Pwri(ref pwri) => {
+                writer.write_tagged_implicit(Tag::context(3),
+                    |writer| pwri.encode_der(writer))
+            },
+            RecipientInfo::Ori(ref ori) => {
+                writer.write_tagged_implicit(Tag::context(4),
+                    |writer| ori.encode_der(writer))
+            },
+        }
+    }
+}
+
+/// Reads a version which must be equal to `expected`.
+fn read_version(reader: BERReader, expected: CMSVersion) -> ASN1Result<()> {
+    if CMSVersion::decode_ber(reader)? != expected {
+        return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+    }
+    return Ok(());
+}
+
+/// A recipient using key transport, such as RSA (RFC 5652 6.2.1).
+///
+/// ```text
+/// KeyTransRecipientInfo ::= SEQUENCE {
+///   version CMSVersion,  -- always set to 0 or 2
+///   rid RecipientIdentifier,
+///   keyEncryptionAlgorithm KeyEncryptionAlgorithmIdentifier,
+///   encryptedKey EncryptedKey }
+/// ```
+///
+/// The version is not stored: it is 0 if `rid` is an issuer and serial
+/// number, and 2 if it is a subject key identifier.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct KeyTransRecipientInfo {
+    /// The certificate of the recipient
+    pub rid: RecipientIdentifier,
+    /// The key-encryption algorithm
+    pub key_encryption_algorithm: AlgorithmIdentifier,
+    /// The encrypted content-encryption key
+    pub encrypted_key: Vec<u8>,
+}
+
+impl KeyTransRecipientInfo {
+    /// Returns the version, which is determined by `rid`.
+    pub fn version(&self) -> CMSVersion {
+        match self.rid {
+            RecipientIdentifier::IssuerAndSerialNumber(_) => CMSVersion::V0,
+            RecipientIdentifier::SubjectKeyIdentifier(_) => CMSVersion::V2,
+        }
+    }
+}
+
+impl BERDecodable for KeyTransRecipientInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let version = CMSVersion::decode_ber(reader.next())?;
+            let rid = RecipientIdentifier::decode_ber(reader.next())?;
+            let key_encryption_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let encrypted_key = reader.next().read_bytes()?;
+            let ktri = KeyTransRecipientInfo {
+                rid,
+                key_encryption_algorithm,
+                encrypted_key,
+            };
+            if version != ktri.version() {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            return Ok(ktri);
+        })
+    }
+}
+
+impl DEREncodable for KeyTransRecipientInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.version().encode_der(writer.next());
+            self.rid.encode_der(writer.next());
+            self.key_encryption_algorithm.encode_der(writer.next());
+            writer.next().write_bytes(&self.encrypted_key);
+        })
+    }
+}
+
+/// A recipient using key agreement, such as ECDH (RFC 5652 6.2.2).
+///
+/// ```text
+/// KeyAgreeRecipientInfo ::= SEQUENCE {
+///   version CMSVersion,  -- always set to 3
+///   originator [0] EXPLICIT OriginatorIdentifierOrKey,
+///   ukm [1] EXPLICIT UserKeyingMaterial OPTIONAL,
+///   keyEncryptionAlgorithm KeyEncryptionAlgorithmIdentifier,
+///   recipientEncryptedKeys RecipientEncryptedKeys }
+///
+/// RecipientEncryptedKeys ::= SEQUENCE OF RecipientEncryptedKey
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct KeyAgreeRecipientInfo {
+    /// The key of the originator
+    pub originator: OriginatorIdentifierOrKey,
+    /// The user keying material
+    pub ukm: Option<Vec<u8>>,
+    /// The key-encryption algorithm
+    pub key_encryption_algorithm: AlgorithmIdentifier,
+    /// The recipients and the encrypted content-encryption keys
+    pub recipient_encrypted_keys: Vec<RecipientEncryptedKey>,
+}
+
+impl BERDecodable for KeyAgreeRecipientInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            read_version(reader.next(), CMSVersion::V3)?;
+            let originator = reader.next().read_tagged(Tag::context(0),
+                OriginatorIdentifierOrKey::decode_ber)?;
+            let ukm = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(1),
+                    |reader| reader.read_bytes())
+            })?;
+            let key_encryption_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let recipient_encrypted_keys = Vec::decode_ber(reader.next())?;
+            return Ok(KeyAgreeRecipientInfo {
+                originator,
+                ukm,
+                key_encryption_algorithm,
+                recipient_encrypted_keys,
+            });
+        })
+    }
+}
+
+impl DEREncodable for KeyAgreeRecipientInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            CMSVersion::V3.encode_der(writer.next());
+            writer.next().write_tagged(Tag::context(0), |writer| {
+                self.originator.encode_der(writer)
+            });
+            if let Some(ref ukm) = self.ukm {
+                writer.next().write_tagged(Tag::context(1), |writer| {
+                    writer.write_bytes(ukm)
+                });
+            }
+            self.key_encryption_algorithm.encode_der(writer.next());
+            self.recipient_encrypted_keys.encode_der(writer.next());
+        })
+    }
+}
+
+/// The key of the originator in a KeyAgreeRecipientInfo (RFC 5652 6.2.2).
+///
+/// ```text
+/// OriginatorIdentifierOrKey ::= CHOICE {
+///   issuerAndSerialNumber IssuerAndSerialNumber,
+///   subjectKeyIdentifier [0] SubjectKeyIdentifier,
+///   originatorKey [1] OriginatorPublicKey }
+///
+/// OriginatorPublicKey ::= SEQUENCE {
+///   algorithm AlgorithmIdentifier,
+///   publicKey BIT STRING }
+/// ```
+///
+/// `OriginatorPublicKey` has the same definition as SubjectPublicKeyInfo.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum OriginatorIdentifierOrKey {
+    /// `issuerAndSerialNumber`
+    IssuerAndSerialNumber(IssuerAndSerialNumber),
+    /// `subjectKeyIdentifier`
+    SubjectKeyIdentifier(Vec<u8>),
+    /// `originatorKey`, usually an ephemeral key
+    OriginatorKey(SubjectPublicKeyInfo),
+}
+
+impl BERDecodable for OriginatorIdentifierOrKey {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_choice([
+            ChoiceAlternative::new(TAG_SEQUENCE, PCBit::Constructed,
+                |reader| Ok(OriginatorIdentifierOrKey::IssuerAndSerialNumber(
+                    IssuerAndSerialNumber::decode_ber(reader)?))),
+            ChoiceAlternative::nested(
+                &[(Tag::context(0), PCBit::Primitive),
+                    (Tag::context(0), PCBit::Constructed)],
+                |reader| Ok(OriginatorIdentifierOrKey::SubjectKeyIdentifier(
+                    reader.read_tagged_implicit(Tag::context(0),
+                        |reader| reader.read_bytes())?))),
+            ChoiceAlternative::new(Tag::context(1), PCBit::Constructed,
+                |reader| Ok(OriginatorIdentifierOrKey::OriginatorKey(
+                    reader.read_tagged_implicit(Tag::context(1),
+                        SubjectPublicKeyInfo::decode_ber)?))),
+        ])
+    }
+}
+
+impl DEREncodable for OriginatorIdentifierOrKey {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            OriginatorIdentifierOrKey::IssuerAndSerialNumber(ref id) => {
+                id.encode_der(writer)
+            },
+            OriginatorIdentifierOrKey::SubjectKeyIdentifier(ref id) => {
+                writer.write_tagged_implicit(Tag::context(0), |writer| {
+                    writer.write_bytes(id)
+                })
+            },
+            OriginatorIdentifierOrKey::OriginatorKey(ref key) => {
+                writer.write_tagged_implicit(Tag::context(1), |writer| {
+                    key.encode_der(writer)
+                })
+            },
+        }
+    }
+}
+
+/// An encrypted key for a recipient in a KeyAgreeRecipientInfo
+/// (RFC 5652 6.2.2).
+///
+/// ```text
+/// RecipientEncryptedKey ::= SEQUENCE {
+///   rid KeyAgreeRecipientIdentifier,
+///   encryptedKey EncryptedKey }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RecipientEncryptedKey {
+    /// The certificate of the recipient
+    pub rid: KeyAgreeRecipientIdentifier,
+    /// The encrypted content-encryption key
+    pub encrypted_key: Vec<u8>,
+}
+
+impl BERDecodable for RecipientEncryptedKey {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let rid = KeyAgreeRecipientIdentifier::decode_ber(reader.next())?;
+            let encrypted_key = reader.next().read_bytes()?;
+            return Ok(RecipientEncryptedKey {
+                rid,
+                encrypted_key,
+            });
+        })
+    }
+}
+
+impl DEREncodable for RecipientEncryptedKey {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.rid.encode_der(writer.next());
+            writer.next().write_bytes(&self.encrypted_key);
+        })
+    }
+}
+
+/// The certificate of a recipient in a KeyAgreeRecipientInfo
+/// (RFC 5652 6.2.2).
+///
+/// ```text
+/// KeyAgreeRecipientIdentifier ::= CHOICE {
+///   issuerAndSerialNumber IssuerAndSerialNumber,
+///   rKeyId [0] IMPLICIT RecipientKeyIdentifier }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum KeyAgreeRecipientIdentifier {
+    /// `issuerAndSerialNumber`
+    IssuerAndSerialNumber(IssuerAndSerialNumber),
+    /// `rKeyId`
+    RKeyId(RecipientKeyIdentifier),
+}
+
+impl BERDecodable for KeyAgreeRecipientIdentifier {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        if reader.lookahead_tag()? == Tag::context(0) {
+            return Ok(KeyAgreeRecipientIdentifier::RKeyId(
+                reader.read_tagged_implicit(Tag::context(0),
+                    RecipientKeyIdentifier::decode_ber)?));
+        }
+        return Ok(KeyAgreeRecipientIdentifier::IssuerAndSerialNumber(
+            IssuerAndSerialNumber::decode_ber(reader)?));
+    }
+}
+
+impl DEREncodable for KeyAgreeRecipientIdentifier {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            KeyAgreeRecipientIdentifier::IssuerAndSerialNumber(ref id) => {
+                id.encode_der(writer)
+            },
+            KeyAgreeRecipientIdentifier::RKeyId(ref id) => {
+                writer.write_tagged_implicit(Tag::context(0), |writer| {
+                    id.encode_der(writer)
+                })
+            },
+        }
+    }
+}
+
+/// Reads the optional `date` and `other` fields of RecipientKeyIdentifier
+/// and KEKIdentifier.
+fn read_date_and_other(reader: &mut BERReaderSeq)
+        -> ASN1Result<(Option<GeneralizedTime>, Option<OtherKeyAttribute>)> {
+    let date = reader.read_optional(|reader| reader.read_generalized_time())?;
+    let other = reader.read_optional(OtherKeyAttribute::decode_ber)?;
+    return Ok((date, other));
+}
+
+/// Writes the optional `date` and `other` fields of RecipientKeyIdentifier
+/// and KEKIdentifier.
+fn write_date_and_other(writer: &mut DERWriterSeq,
+        date: &Option<GeneralizedTime>,
+        other: &Option<OtherKeyAttribute>) {
+    if let Some(ref date) = *date {
+        writer.next().write_generalized_time(date);
+    }
+    if let Some(ref other) = *other {
+        other.encode_der(writer.next());
+    }
+}
+
+/// A key of a recipient in a KeyAgreeRecipientInfo (RFC 5652 6.2.2).
+///
+/// ```text
+/// RecipientKeyIdentifier ::= SEQUENCE {
+///   subjectKeyIdentifier SubjectKeyIdentifier,
+///   date GeneralizedTime OPTIONAL,
+///   other OtherKeyAttribute OPTIONAL }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RecipientKeyIdentifier {
+    /// The subject key identifier of the certificate
+    pub subject_key_identifier: Vec<u8>,
+    /// The date of the key
+    pub date: Option<GeneralizedTime>,
+    /// Additional information to identify the key
+    pub other: Option<OtherKeyAttribute>,
+}
+
+impl BERDecodable for RecipientKeyIdentifier {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let subject_key_identifier = reader.next().read_bytes()?;
+            let (date, other) = read_date_and_other(reader)?;
+            return Ok(RecipientKeyIdentifier {
+                subject_key_identifier,
+                date,
+                other,
+            });
+        })
+    }
+}
+
+impl DEREncodable for RecipientKeyIdentifier {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_bytes(&self.subject_key_identifier);
+            write_date_and_other(writer, &self.date, &self.other);
+        })
+    }
+}
+
+/// Additional information to identify a key (RFC 5652 10.2.7).
+///
+/// ```text
+/// OtherKeyAttribute ::= SEQUENCE {
+///   keyAttrId OBJECT IDENTIFIER,
+///   keyAttr ANY DEFINED BY keyAttrId OPTIONAL }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct OtherKeyAttribute {
+    /// The type of the attribute
+    pub key_attr_id: ObjectIdentifier,
+    /// The value of the attribute
+    pub key_attr: Option<TaggedDerValue>,
+}
+
+impl BERDecodable for OtherKeyAttribute {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let key_attr_id = ObjectIdentifier::decode_ber(reader.next())?;
+            let key_attr = reader.read_optional(|reader| {
+                reader.read_tagged_der()
+            })?;
+            return Ok(OtherKeyAttribute {
+                key_attr_id,
+                key_attr,
+            });
+        })
+    }
+}
+
+impl DEREncodable for OtherKeyAttribute {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.key_attr_id.encode_der(writer.next());
+            if let Some(ref key_attr) = self.key_attr {
+                writer.next().write_tagged_der(key_attr);
+            }
+        })
+    }
+}
+
+/// A recipient sharing a key-encryption key (RFC 5652 6.2.3).
+///
+/// ```text
+/// KEKRecipientInfo ::= SEQUENCE {
+///   version CMSVersion,  -- always set to 4
+///   kekid KEKIdentifier,
+///   keyEncryptionAlgorithm KeyEncryptionAlgorithmIdentifier,
+///   encryptedKey EncryptedKey }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct KEKRecipientInfo {
+    /// The key-encryption key
+    pub kekid: KEKIdentifier,
+    /// The key-encryption algorithm
+    pub key_encryption_algorithm: AlgorithmIdentifier,
+    /// The encrypted content-encryption key
+    pub encrypted_key: Vec<u8>,
+}
+
+impl BERDecodable for KEKRecipientInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            read_version(reader.next(), CMSVersion::V4)?;
+            let kekid = KEKIdentifier::decode_ber(reader.next())?;
+            let key_encryption_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let encrypted_key = reader.next().read_bytes()?;
+            return Ok(KEKRecipientInfo {
+                kekid,
+                key_encryption_algorithm,
+                encrypted_key,
+            });
+        })
+    }
+}
+
+impl DEREncodable for KEKRecipientInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            CMSVersion::V4.encode_der(writer.next());
+            self.kekid.encode_der(writer.next());
+            self.key_encryption_algorithm.encode_der(writer.next());
+            writer.next().write_bytes(&self.encrypted_key);
+        })
+    }
+}
+
+/// A key-encryption key in a KEKRecipientInfo (RFC 5652 6.2.3).
+///
+/// ```text
+/// KEKIdentifier ::= SEQUENCE {
+///   keyIdentifier OCTET STRING,
+///   date GeneralizedTime OPTIONAL,
+///   other OtherKeyAttribute OPTIONAL }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct KEKIdentifier {
+    /// The identifier of the key
+    pub key_identifier: Vec<u8>,
+    /// The date of the key
+    pub date: Option<GeneralizedTime>,
+    /// Additional information to identify the key
+    pub other: Option<OtherKeyAttribute>,
+}
+
+impl BERDecodable for KEKIdentifier {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let key_identifier = reader.next().read_bytes()?;
+            let (date, other) = read_date_and_other(reader)?;
+            return Ok(KEKIdentifier {
+                key_identifier,
+                date,
+                other,
+            });
+        })
+    }
+}
+
+impl DEREncodable for KEKIdentifier {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_bytes(&self.key_identifier);
+            write_date_and_other(writer, &self.date, &self.other);
+        })
+    }
+}
+
+/// A recipient sharing a password (RFC 5652 6.2.4).
+///
+/// ```text
+/// PasswordRecipientInfo ::= SEQUENCE {
+///   version CMSVersion,   -- always set to 0
+///   keyDerivationAlgorithm [0] KeyDerivationAlgorithmIdentifier
+///                              OPTIONAL,
+///   keyEncryptionAlgorithm KeyEncryptionAlgorithmIdentifier,
+///   encryptedKey EncryptedKey }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PasswordRecipientInfo {
+    /// The algorithm to derive the key-encryption key from the password,
+    /// such as PBKDF2
+    pub key_derivation_algorithm: Option<AlgorithmIdentifier>,
+    /// The key-encryption algorithm
+    pub key_encryption_algorithm: AlgorithmIdentifier,
+    /// The encrypted content-encryption key
+    pub encrypted_key: Vec<u8>,
+}
+
+impl BERDecodable for PasswordRecipientInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            read_version(reader.next(), CMSVersion::V0)?;
+            let key_derivation_algorithm = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(0),
+                    AlgorithmIdentifier::decode_ber)
+            })?;
+            let key_encryption_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let encrypted_key = reader.next().read_bytes()?;
+            return Ok(PasswordRecipientInfo {
+                key_derivation_algorithm,
+                key_encryption_algorithm,
+                encrypted_key,
+            });
+        })
+    }
+}
+
+impl DEREncodable for PasswordRecipientInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            CMSVersion::V0.encode_der(writer.next());
+            if let Some(ref key_derivation_algorithm) =
+                    self.key_derivation_algorithm {
+                writer.next().write_tagged_implicit(Tag::context(0),
+                    |writer| key_derivation_algorithm.encode_der(writer));
+            }
+            self.key_encryption_algorithm.encode_der(writer.next());
+            writer.next().write_bytes(&self.encrypted_key);
+        })
+    }
+}
+
+/// A recipient of another type (RFC 5652 6.2.5).
+///
+/// ```text
+/// OtherRecipientInfo ::= SEQUENCE {
+///   oriType OBJECT IDENTIFIER,
+///   oriValue ANY DEFINED BY oriType }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct OtherRecipientInfo {
+    /// The type of the recipient info
+    pub ori_type: ObjectIdentifier,
+    /// The value of the recipient info
+    pub ori_value: TaggedDerValue,
+}
+
+impl BERDecodable for OtherRecipientInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let ori_type = ObjectIdentifier::decode_ber(reader.next())?;
+            let ori_value = reader.next().read_tagged_der()?;
+            return Ok(OtherRecipientInfo {
+                ori_type,
+                ori_value,
+            });
+        })
+    }
+}
+
+impl DEREncodable for OtherRecipientInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.ori_type.encode_der(writer.next());
+            writer.next().write_tagged_der(&self.ori_value);
+        })
+    }
+}
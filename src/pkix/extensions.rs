@@ -0,0 +1,1070 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use super::super::{ASN1Result, BERDecodable, BERReader, DEREncodable, DERWriter};
+use super::super::{ASN1Error, ASN1ErrorKind, Tag, decode_der, encode_der};
+use super::super::parse_der;
+use super::super::models::{BitString, GeneralizedTime, ObjectIdentifier};
+use super::super::models::TaggedDerValue;
+use super::{GeneralName, RelativeDistinguishedName, SerialNumber};
+use super::oids::*;
+
+/// A certificate extension (RFC 5280 4.1).
+///
+/// ```text
+/// Extension  ::=  SEQUENCE  {
+///      extnID      OBJECT IDENTIFIER,
+///      critical    BOOLEAN DEFAULT FALSE,
+///      extnValue   OCTET STRING
+///                  -- contains the DER encoding of an ASN.1 value
+///                  -- corresponding to the extension type identified
+///                  -- by extnID
+///      }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::ObjectIdentifier;
+/// use yasna::pkix::Extension;
+/// let basic_constraints = Extension {
+///     extn_id: ObjectIdentifier::from_slice(&[2, 5, 29, 19]),
+///     critical: true,
+///     extn_value: vec![48, 3, 1, 1, 255],
+/// };
+/// let der = yasna::encode_der(&basic_constraints);
+/// assert_eq!(der, &[48, 15, 6, 3, 85, 29, 19, 1, 1, 255,
+///     4, 5, 48, 3, 1, 1, 255]);
+/// assert_eq!(yasna::decode_der::<Extension>(&der).unwrap(),
+///     basic_constraints);
+/// ```
+///
+/// The well-known extensions can be decoded with
+/// [`parse_value`](Self::parse_value) and constructed with
+/// [`new`](Self::new).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Extension {
+    /// The type of the extension
+    pub extn_id: ObjectIdentifier,
+    /// Whether the extension must be processed (or the certificate rejected)
+    pub critical: bool,
+    /// The DER encoding of the value
+    pub extn_value: Vec<u8>,
+}
+
+impl BERDecodable for Extension {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let extn_id = ObjectIdentifier::decode_ber(reader.next())?;
+            let critical = reader.read_default(false, |reader| {
+                reader.read_bool()
+            })?;
+            let extn_value = reader.next().read_bytes()?;
+            return Ok(Extension {
+                extn_id,
+                critical,
+                extn_value,
+            });
+        })
+    }
+}
+
+impl DEREncodable for Extension {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.extn_id.encode_der(writer.next());
+            if self.critical {
+                writer.next().write_bool(true);
+            }
+            writer.next().write_bytes(&self.extn_value);
+        })
+    }
+}
+
+impl Extension {
+    /// Constructs an extension from a typed value.
+    pub fn new(value: &ExtensionValue, critical: bool) -> Self {
+        Extension {
+            extn_id: value.extn_id(),
+            critical,
+            extn_value: encode_der(value),
+        }
+    }
+
+    /// Decodes the value if the extension is one of those in
+    /// [`ExtensionValue`], or returns `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::ObjectIdentifier;
+    /// use yasna::pkix::{Extension, ExtensionValue};
+    /// let extension = Extension {
+    ///     extn_id: ObjectIdentifier::from_slice(&[2, 5, 29, 19]),
+    ///     critical: true,
+    ///     extn_value: vec![48, 6, 1, 1, 255, 2, 1, 0],
+    /// };
+    /// match extension.parse_value().unwrap() {
+    ///     Some(ExtensionValue::BasicConstraints(basic_constraints)) => {
+    ///         assert!(basic_constraints.ca);
+    ///         assert_eq!(basic_constraints.path_len_constraint, Some(0));
+    ///     },
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn parse_value(&self) -> ASN1Result<Option<ExtensionValue>> {
+        let id = &self.extn_id;
+        let der = &self.extn_value;
+        let value = if *id == ID_CE_BASIC_CONSTRAINTS {
+            ExtensionValue::BasicConstraints(decode_der(der)?)
+        } else if *id == ID_CE_KEY_USAGE {
+            ExtensionValue::KeyUsage(decode_der(der)?)
+        } else if *id == ID_CE_EXT_KEY_USAGE {
+            ExtensionValue::ExtendedKeyUsage(decode_der(der)?)
+        } else if *id == ID_CE_SUBJECT_ALT_NAME {
+            ExtensionValue::SubjectAltName(decode_der(der)?)
+        } else if *id == ID_CE_ISSUER_ALT_NAME {
+            ExtensionValue::IssuerAltName(decode_der(der)?)
+        } else if *id == ID_CE_AUTHORITY_KEY_IDENTIFIER {
+            ExtensionValue::AuthorityKeyIdentifier(decode_der(der)?)
+        } else if *id == ID_CE_SUBJECT_KEY_IDENTIFIER {
+            ExtensionValue::SubjectKeyIdentifier(decode_der(der)?)
+        } else if *id == ID_CE_CRL_DISTRIBUTION_POINTS {
+            ExtensionValue::CrlDistributionPoints(decode_der(der)?)
+        } else if *id == ID_PE_AUTHORITY_INFO_ACCESS {
+            ExtensionValue::AuthorityInfoAccess(decode_der(der)?)
+        } else if *id == ID_CE_CERTIFICATE_POLICIES {
+            ExtensionValue::CertificatePolicies(decode_der(der)?)
+        } else if *id == ID_CE_NAME_CONSTRAINTS {
+            ExtensionValue::NameConstraints(decode_der(der)?)
+        } else if *id == ID_CE_POLICY_CONSTRAINTS {
+            ExtensionValue::PolicyConstraints(decode_der(der)?)
+        } else if *id == ID_CE_CRL_NUMBER {
+            ExtensionValue::CrlNumber(decode_der(der)?)
+        } else if *id == ID_CE_DELTA_CRL_INDICATOR {
+            ExtensionValue::DeltaCrlIndicator(decode_der(der)?)
+        } else if *id == ID_CE_ISSUING_DISTRIBUTION_POINT {
+            ExtensionValue::IssuingDistributionPoint(decode_der(der)?)
+        } else if *id == ID_CE_CRL_REASONS {
+            ExtensionValue::ReasonCode(decode_der(der)?)
+        } else if *id == ID_CE_INVALIDITY_DATE {
+            ExtensionValue::InvalidityDate(
+                parse_der(der, |reader| reader.read_generalized_time())?)
+        } else if *id == ID_CE_CERTIFICATE_ISSUER {
+            ExtensionValue::CertificateIssuer(decode_der(der)?)
+        } else if *id == ID_PKIX_OCSP_NONCE {
+            ExtensionValue::OcspNonce(decode_der(der)?)
+        } else {
+            return Ok(None);
+        };
+        return Ok(Some(value));
+    }
+}
+
+/// The value of a well-known certificate extension.
+///
+/// The SEQUENCE OF types are represented by `Vec`s: `GeneralNames` by
+/// `Vec<GeneralName>`, `ExtKeyUsageSyntax` by `Vec<ObjectIdentifier>`, etc.
+/// `SubjectKeyIdentifier` is the OCTET STRING as `Vec<u8>`.
+///
+/// The CRL extensions (RFC 5280 5.2) and the CRL entry extensions
+/// (RFC 5280 5.3) are also included. `CRLNumber` and `BaseCRLNumber` are
+/// INTEGERs of up to 20 octets like serial numbers, so they are
+/// represented by [`SerialNumber`].
+///
+/// The nonce extension of OCSP (RFC 6960 4.4.1) is an OCTET STRING,
+/// represented by `Vec<u8>`.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::{Extension, ExtensionValue, GeneralName};
+/// let extension = Extension::new(&ExtensionValue::SubjectAltName(vec![
+///     GeneralName::DnsName("example.com".to_string()),
+/// ]), false);
+/// assert_eq!(extension.extn_id.to_string(), "2.5.29.17");
+/// assert_eq!(extension.extn_value, b"0\x0d\x82\x0bexample.com");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ExtensionValue {
+    /// id-ce-basicConstraints
+    BasicConstraints(BasicConstraints),
+    /// id-ce-keyUsage
+    KeyUsage(KeyUsage),
+    /// id-ce-extKeyUsage
+    ExtendedKeyUsage(Vec<ObjectIdentifier>),
+    /// id-ce-subjectAltName
+    SubjectAltName(Vec<GeneralName>),
+    /// id-ce-issuerAltName
+    IssuerAltName(Vec<GeneralName>),
+    /// id-ce-authorityKeyIdentifier
+    AuthorityKeyIdentifier(AuthorityKeyIdentifier),
+    /// id-ce-subjectKeyIdentifier
+    SubjectKeyIdentifier(Vec<u8>),
+    /// id-ce-cRLDistributionPoints
+    CrlDistributionPoints(Vec<DistributionPoint>),
+    /// id-pe-authorityInfoAccess
+    AuthorityInfoAccess(Vec<AccessDescription>),
+    /// id-ce-certificatePolicies
+    CertificatePolicies(Vec<PolicyInformation>),
+    /// id-ce-nameConstraints
+    NameConstraints(NameConstraints),
+    /// id-ce-policyConstraints
+    PolicyConstraints(PolicyConstraints),
+    /// id-ce-cRLNumber
+    CrlNumber(SerialNumber),
+    /// id-ce-deltaCRLIndicator
+    DeltaCrlIndicator(SerialNumber),
+    /// id-ce-issuingDistributionPoint
+    IssuingDistributionPoint(IssuingDistributionPoint),
+    /// id-ce-cRLReasons, a CRL entry extension
+    ReasonCode(CrlReason),
+    /// id-ce-invalidityDate, a CRL entry extension
+    InvalidityDate(GeneralizedTime),
+    /// id-ce-certificateIssuer, a CRL entry extension
+    CertificateIssuer(Vec<GeneralName>),
+    /// id-pkix-ocsp-nonce, an OCSP request and response extension
+    OcspNonce(Vec<u8>),
+}
+
+impl ExtensionValue {
+    /// Returns the OID of the extension.
+    pub fn extn_id(&self) -> ObjectIdentifier {
+        let id = match *self {
+            ExtensionValue::BasicConstraints(_) => ID_CE_BASIC_CONSTRAINTS,
+            ExtensionValue::KeyUsage(_) => ID_CE_KEY_USAGE,
+            ExtensionValue::ExtendedKeyUsage(_) => ID_CE_EXT_KEY_USAGE,
+            ExtensionValue::SubjectAltName(_) => ID_CE_SUBJECT_ALT_NAME,
+            ExtensionValue::IssuerAltName(_) => ID_CE_ISSUER_ALT_NAME,
+            ExtensionValue::AuthorityKeyIdentifier(_) =>
+                ID_CE_AUTHORITY_KEY_IDENTIFIER,
+            ExtensionValue::SubjectKeyIdentifier(_) =>
+                ID_CE_SUBJECT_KEY_IDENTIFIER,
+            ExtensionValue::CrlDistributionPoints(_) =>
+                ID_CE_CRL_DISTRIBUTION_POINTS,
+            ExtensionValue::AuthorityInfoAccess(_) =>
+                ID_PE_AUTHORITY_INFO_ACCESS,
+            ExtensionValue::CertificatePolicies(_) =>
+                ID_CE_CERTIFICATE_POLICIES,
+            ExtensionValue::NameConstraints(_) => ID_CE_NAME_CONSTRAINTS,
+            ExtensionValue::PolicyConstraints(_) => ID_CE_POLICY_CONSTRAINTS,
+            ExtensionValue::CrlNumber(_) => ID_CE_CRL_NUMBER,
+            ExtensionValue::DeltaCrlIndicator(_) => ID_CE_DELTA_CRL_INDICATOR,
+            ExtensionValue::IssuingDistributionPoint(_) =>
+                ID_CE_ISSUING_DISTRIBUTION_POINT,
+            ExtensionValue::ReasonCode(_) => ID_CE_CRL_REASONS,
+            ExtensionValue::InvalidityDate(_) => ID_CE_INVALIDITY_DATE,
+            ExtensionValue::CertificateIssuer(_) => ID_CE_CERTIFICATE_ISSUER,
+            ExtensionValue::OcspNonce(_) => ID_PKIX_OCSP_NONCE,
+        };
+        return ObjectIdentifier::from(id);
+    }
+}
+
+impl DEREncodable for ExtensionValue {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            ExtensionValue::BasicConstraints(ref value) => value.encode_der(writer),
+            ExtensionValue::KeyUsage(ref value) => value.encode_der(writer),
+            ExtensionValue::ExtendedKeyUsage(ref value) => value.encode_der(writer),
+            ExtensionValue::SubjectAltName(ref value) => value.encode_der(writer),
+            ExtensionValue::IssuerAltName(ref value) => value.encode_der(writer),
+            ExtensionValue::AuthorityKeyIdentifier(ref value) =>
+                value.encode_der(writer),
+            ExtensionValue::SubjectKeyIdentifier(ref value) =>
+                value.encode_der(writer),
+            ExtensionValue::CrlDistributionPoints(ref value) =>
+                value.encode_der(writer),
+            ExtensionValue::AuthorityInfoAccess(ref value) =>
+                value.encode_der(writer),
+            ExtensionValue::CertificatePolicies(ref value) =>
+                value.encode_der(writer),
+            ExtensionValue::NameConstraints(ref value) => value.encode_der(writer),
+            ExtensionValue::PolicyConstraints(ref value) =>
+                value.encode_der(writer),
+            ExtensionValue::CrlNumber(ref value) => value.encode_der(writer),
+            ExtensionValue::DeltaCrlIndicator(ref value) =>
+                value.encode_der(writer),
+            ExtensionValue::IssuingDistributionPoint(ref value) =>
+                value.encode_der(writer),
+            ExtensionValue::ReasonCode(ref value) => value.encode_der(writer),
+            ExtensionValue::InvalidityDate(ref value) =>
+                writer.write_generalized_time(value),
+            ExtensionValue::CertificateIssuer(ref value) =>
+                value.encode_der(writer),
+            ExtensionValue::OcspNonce(ref value) => value.encode_der(writer),
+        }
+    }
+}
+
+/// The basic constraints extension (RFC 5280 4.2.1.9).
+///
+/// ```text
+/// BasicConstraints ::= SEQUENCE {
+///      cA                      BOOLEAN DEFAULT FALSE,
+///      pathLenConstraint       INTEGER (0..MAX) OPTIONAL }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub struct BasicConstraints {
+    /// Whether the subject is a CA
+    pub ca: bool,
+    /// The maximum number of intermediate CAs below the subject
+    pub path_len_constraint: Option<u64>,
+}
+
+impl BERDecodable for BasicConstraints {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let ca = reader.read_default(false, |reader| reader.read_bool())?;
+            let path_len_constraint = reader.read_optional(|reader| {
+                reader.read_u64()
+            })?;
+            return Ok(BasicConstraints {
+                ca,
+                path_len_constraint,
+            });
+        })
+    }
+}
+
+impl DEREncodable for BasicConstraints {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            if self.ca {
+                writer.next().write_bool(true);
+            }
+            if let Some(path_len_constraint) = self.path_len_constraint {
+                writer.next().write_u64(path_len_constraint);
+            }
+        })
+    }
+}
+
+/// The key usage extension (RFC 5280 4.2.1.3).
+///
+/// ```text
+/// KeyUsage ::= BIT STRING {
+///      digitalSignature        (0),
+///      nonRepudiation          (1), -- recent editions of X.509 have
+///                           -- renamed this bit to contentCommitment
+///      keyEncipherment         (2),
+///      dataEncipherment        (3),
+///      keyAgreement            (4),
+///      keyCertSign             (5),
+///      cRLSign                 (6),
+///      encipherOnly            (7),
+///      decipherOnly            (8) }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::KeyUsage;
+/// let key_usage = KeyUsage {
+///     key_cert_sign: true,
+///     crl_sign: true,
+///     ..KeyUsage::default()
+/// };
+/// assert_eq!(yasna::encode_der(&key_usage), &[3, 2, 1, 6]);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub struct KeyUsage {
+    /// `digitalSignature`
+    pub digital_signature: bool,
+    /// `nonRepudiation`, or `contentCommitment`
+    pub non_repudiation: bool,
+    /// `keyEncipherment`
+    pub key_encipherment: bool,
+    /// `dataEncipherment`
+    pub data_encipherment: bool,
+    /// `keyAgreement`
+    pub key_agreement: bool,
+    /// `keyCertSign`
+    pub key_cert_sign: bool,
+    /// `cRLSign`
+    pub crl_sign: bool,
+    /// `encipherOnly`
+    pub encipher_only: bool,
+    /// `decipherOnly`
+    pub decipher_only: bool,
+}
+
+impl KeyUsage {
+    fn bits(&self) -> [bool; 9] {
+        [
+            self.digital_signature,
+            self.non_repudiation,
+            self.key_encipherment,
+            self.data_encipherment,
+            self.key_agreement,
+            self.key_cert_sign,
+            self.crl_sign,
+            self.encipher_only,
+            self.decipher_only,
+        ]
+    }
+}
+
+impl BERDecodable for KeyUsage {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let bits = BitString::decode_ber(reader)?;
+        return Ok(KeyUsage {
+            digital_signature: bits.is_set(0),
+            non_repudiation: bits.is_set(1),
+            key_encipherment: bits.is_set(2),
+            data_encipherment: bits.is_set(3),
+            key_agreement: bits.is_set(4),
+            key_cert_sign: bits.is_set(5),
+            crl_sign: bits.is_set(6),
+            encipher_only: bits.is_set(7),
+            decipher_only: bits.is_set(8),
+        });
+    }
+}
+
+impl DEREncodable for KeyUsage {
+    fn encode_der(&self, writer: DERWriter) {
+        let indices : Vec<usize> = self.bits().iter().enumerate()
+            .filter(|&(_, &bit)| bit)
+            .map(|(i, _)| i)
+            .collect();
+        BitString::from_set_bits(&indices).encode_der(writer)
+    }
+}
+
+/// The authority key identifier extension (RFC 5280 4.2.1.1).
+///
+/// ```text
+/// AuthorityKeyIdentifier ::= SEQUENCE {
+///     keyIdentifier             [0] KeyIdentifier           OPTIONAL,
+///     authorityCertIssuer       [1] GeneralNames            OPTIONAL,
+///     authorityCertSerialNumber [2] CertificateSerialNumber OPTIONAL  }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub struct AuthorityKeyIdentifier {
+    /// The identifier of the issuer's key
+    pub key_identifier: Option<Vec<u8>>,
+    /// The issuer of the issuer's certificate
+    pub authority_cert_issuer: Option<Vec<GeneralName>>,
+    /// The serial number of the issuer's certificate
+    pub authority_cert_serial_number: Option<SerialNumber>,
+}
+
+impl BERDecodable for AuthorityKeyIdentifier {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let key_identifier = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(0),
+                    |reader| reader.read_bytes())
+            })?;
+            let authority_cert_issuer = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1), Vec::decode_ber)
+            })?;
+            let authority_cert_serial_number = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(2),
+                    SerialNumber::decode_ber)
+            })?;
+            return Ok(AuthorityKeyIdentifier {
+                key_identifier,
+                authority_cert_issuer,
+                authority_cert_serial_number,
+            });
+        })
+    }
+}
+
+impl DEREncodable for AuthorityKeyIdentifier {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            if let Some(ref key_identifier) = self.key_identifier {
+                writer.next().write_tagged_implicit(Tag::context(0), |writer| {
+                    writer.write_bytes(key_identifier)
+                });
+            }
+            if let Some(ref issuer) = self.authority_cert_issuer {
+                writer.next().write_tagged_implicit(Tag::context(1), |writer| {
+                    issuer.encode_der(writer)
+                });
+            }
+            if let Some(ref serial_number) = self.authority_cert_serial_number {
+                writer.next().write_tagged_implicit(Tag::context(2), |writer| {
+                    serial_number.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// The name of a distribution point (RFC 5280 4.2.1.13).
+///
+/// ```text
+/// DistributionPointName ::= CHOICE {
+///      fullName                [0]     GeneralNames,
+///      nameRelativeToCRLIssuer [1]     RelativeDistinguishedName }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum DistributionPointName {
+    /// `fullName`
+    FullName(Vec<GeneralName>),
+    /// `nameRelativeToCRLIssuer`
+    NameRelativeToCrlIssuer(RelativeDistinguishedName),
+}
+
+impl BERDecodable for DistributionPointName {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        if reader.lookahead_tag()? == Tag::context(0) {
+            return Ok(DistributionPointName::FullName(
+                reader.read_tagged_implicit(Tag::context(0), Vec::decode_ber)?));
+        }
+        return Ok(DistributionPointName::NameRelativeToCrlIssuer(
+            reader.read_tagged_implicit(Tag::context(1),
+                RelativeDistinguishedName::decode_ber)?));
+    }
+}
+
+impl DEREncodable for DistributionPointName {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            DistributionPointName::FullName(ref names) => {
+                writer.write_tagged_implicit(Tag::context(0), |writer| {
+                    names.encode_der(writer)
+                })
+            },
+            DistributionPointName::NameRelativeToCrlIssuer(ref rdn) => {
+                writer.write_tagged_implicit(Tag::context(1), |writer| {
+                    rdn.encode_der(writer)
+                })
+            },
+        }
+    }
+}
+
+/// A CRL distribution point (RFC 5280 4.2.1.13).
+///
+/// ```text
+/// DistributionPoint ::= SEQUENCE {
+///      distributionPoint       [0]     DistributionPointName OPTIONAL,
+///      reasons                 [1]     ReasonFlags OPTIONAL,
+///      cRLIssuer               [2]     GeneralNames OPTIONAL }
+///
+/// ReasonFlags ::= BIT STRING {
+///      unused                  (0),
+///      keyCompromise           (1),
+///      cACompromise            (2),
+///      affiliationChanged      (3),
+///      superseded              (4),
+///      cessationOfOperation    (5),
+///      certificateHold         (6),
+///      privilegeWithdrawn      (7),
+///      aACompromise            (8) }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub struct DistributionPoint {
+    /// Where the CRL is available
+    pub distribution_point: Option<DistributionPointName>,
+    /// The revocation reasons covered by the CRL, as `ReasonFlags`
+    pub reasons: Option<BitString>,
+    /// The issuer of the CRL, if not the issuer of the certificate
+    pub crl_issuer: Option<Vec<GeneralName>>,
+}
+
+impl BERDecodable for DistributionPoint {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let distribution_point = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0),
+                    DistributionPointName::decode_ber)
+            })?;
+            let reasons = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1),
+                    BitString::decode_ber)
+            })?;
+            let crl_issuer = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(2), Vec::decode_ber)
+            })?;
+            return Ok(DistributionPoint {
+                distribution_point,
+                reasons,
+                crl_issuer,
+            });
+        })
+    }
+}
+
+impl DEREncodable for DistributionPoint {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            if let Some(ref name) = self.distribution_point {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    name.encode_der(writer)
+                });
+            }
+            if let Some(ref reasons) = self.reasons {
+                writer.next().write_tagged_implicit(Tag::context(1), |writer| {
+                    reasons.encode_der(writer)
+                });
+            }
+            if let Some(ref crl_issuer) = self.crl_issuer {
+                writer.next().write_tagged_implicit(Tag::context(2), |writer| {
+                    crl_issuer.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// A description of how to access information of the issuer
+/// (RFC 5280 4.2.2.1).
+///
+/// ```text
+/// AccessDescription  ::=  SEQUENCE {
+///         accessMethod          OBJECT IDENTIFIER,
+///         accessLocation        GeneralName  }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct AccessDescription {
+    /// The type of the information, e.g. `id-ad-ocsp`
+    pub access_method: ObjectIdentifier,
+    /// Where the information is available
+    pub access_location: GeneralName,
+}
+
+impl BERDecodable for AccessDescription {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let access_method = ObjectIdentifier::decode_ber(reader.next())?;
+            let access_location = GeneralName::decode_ber(reader.next())?;
+            return Ok(AccessDescription {
+                access_method,
+                access_location,
+            });
+        })
+    }
+}
+
+impl DEREncodable for AccessDescription {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.access_method.encode_der(writer.next());
+            self.access_location.encode_der(writer.next());
+        })
+    }
+}
+
+/// A certificate policy (RFC 5280 4.2.1.4).
+///
+/// ```text
+/// PolicyInformation ::= SEQUENCE {
+///      policyIdentifier   CertPolicyId,
+///      policyQualifiers   SEQUENCE SIZE (1..MAX) OF
+///                              PolicyQualifierInfo OPTIONAL }
+/// ```
+///
+/// The qualifiers are omitted when encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PolicyInformation {
+    /// The policy
+    pub policy_identifier: ObjectIdentifier,
+    /// The qualifiers
+    pub policy_qualifiers: Vec<PolicyQualifierInfo>,
+}
+
+impl BERDecodable for PolicyInformation {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let policy_identifier = ObjectIdentifier::decode_ber(reader.next())?;
+            let policy_qualifiers = reader.read_optional(Vec::decode_ber)?;
+            return Ok(PolicyInformation {
+                policy_identifier,
+                policy_qualifiers: policy_qualifiers.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for PolicyInformation {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.policy_identifier.encode_der(writer.next());
+            if !self.policy_qualifiers.is_empty() {
+                self.policy_qualifiers.encode_der(writer.next());
+            }
+        })
+    }
+}
+
+/// A qualifier of a certificate policy (RFC 5280 4.2.1.4).
+///
+/// ```text
+/// PolicyQualifierInfo ::= SEQUENCE {
+///      policyQualifierId  PolicyQualifierId,
+///      qualifier          ANY DEFINED BY policyQualifierId }
+/// ```
+///
+/// For `id-qt-cps`, the qualifier is an IA5String of the URI.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PolicyQualifierInfo {
+    /// The type of the qualifier
+    pub policy_qualifier_id: ObjectIdentifier,
+    /// The qualifier
+    pub qualifier: TaggedDerValue,
+}
+
+impl BERDecodable for PolicyQualifierInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let policy_qualifier_id =
+                ObjectIdentifier::decode_ber(reader.next())?;
+            let qualifier = reader.next().read_tagged_der()?;
+            return Ok(PolicyQualifierInfo {
+                policy_qualifier_id,
+                qualifier,
+            });
+        })
+    }
+}
+
+impl DEREncodable for PolicyQualifierInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.policy_qualifier_id.encode_der(writer.next());
+            writer.next().write_tagged_der(&self.qualifier);
+        })
+    }
+}
+
+/// The name constraints extension (RFC 5280 4.2.1.10).
+///
+/// ```text
+/// NameConstraints ::= SEQUENCE {
+///      permittedSubtrees       [0]     GeneralSubtrees OPTIONAL,
+///      excludedSubtrees        [1]     GeneralSubtrees OPTIONAL }
+///
+/// GeneralSubtrees ::= SEQUENCE SIZE (1..MAX) OF GeneralSubtree
+/// ```
+///
+/// The subtrees are omitted when encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub struct NameConstraints {
+    /// The permitted subtrees
+    pub permitted_subtrees: Vec<GeneralSubtree>,
+    /// The excluded subtrees
+    pub excluded_subtrees: Vec<GeneralSubtree>,
+}
+
+impl BERDecodable for NameConstraints {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let permitted_subtrees = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(0), Vec::decode_ber)
+            })?;
+            let excluded_subtrees = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1), Vec::decode_ber)
+            })?;
+            return Ok(NameConstraints {
+                permitted_subtrees: permitted_subtrees.unwrap_or_default(),
+                excluded_subtrees: excluded_subtrees.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for NameConstraints {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            if !self.permitted_subtrees.is_empty() {
+                writer.next().write_tagged_implicit(Tag::context(0), |writer| {
+                    self.permitted_subtrees.encode_der(writer)
+                });
+            }
+            if !self.excluded_subtrees.is_empty() {
+                writer.next().write_tagged_implicit(Tag::context(1), |writer| {
+                    self.excluded_subtrees.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// A subtree of names in name constraints (RFC 5280 4.2.1.10).
+///
+/// ```text
+/// GeneralSubtree ::= SEQUENCE {
+///      base                    GeneralName,
+///      minimum         [0]     BaseDistance DEFAULT 0,
+///      maximum         [1]     BaseDistance OPTIONAL }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct GeneralSubtree {
+    /// The root of the subtree
+    pub base: GeneralName,
+    /// The minimum distance, which must be 0 in PKIX
+    pub minimum: u64,
+    /// The maximum distance, which must be absent in PKIX
+    pub maximum: Option<u64>,
+}
+
+impl BERDecodable for GeneralSubtree {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let base = GeneralName::decode_ber(reader.next())?;
+            let minimum = reader.read_default(0, |reader| {
+                reader.read_tagged_implicit(Tag::context(0),
+                    |reader| reader.read_u64())
+            })?;
+            let maximum = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1),
+                    |reader| reader.read_u64())
+            })?;
+            return Ok(GeneralSubtree {
+                base,
+                minimum,
+                maximum,
+            });
+        })
+    }
+}
+
+impl DEREncodable for GeneralSubtree {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.base.encode_der(writer.next());
+            if self.minimum != 0 {
+                writer.next().write_tagged_implicit(Tag::context(0), |writer| {
+                    writer.write_u64(self.minimum)
+                });
+            }
+            if let Some(maximum) = self.maximum {
+                writer.next().write_tagged_implicit(Tag::context(1), |writer| {
+                    writer.write_u64(maximum)
+                });
+            }
+        })
+    }
+}
+
+/// The policy constraints extension (RFC 5280 4.2.1.11).
+///
+/// ```text
+/// PolicyConstraints ::= SEQUENCE {
+///      requireExplicitPolicy           [0] SkipCerts OPTIONAL,
+///      inhibitPolicyMapping            [1] SkipCerts OPTIONAL }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub struct PolicyConstraints {
+    /// The number of certificates before an explicit policy is required
+    pub require_explicit_policy: Option<u64>,
+    /// The number of certificates before policy mapping is inhibited
+    pub inhibit_policy_mapping: Option<u64>,
+}
+
+impl BERDecodable for PolicyConstraints {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let require_explicit_policy = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(0),
+                    |reader| reader.read_u64())
+            })?;
+            let inhibit_policy_mapping = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1),
+                    |reader| reader.read_u64())
+            })?;
+            return Ok(PolicyConstraints {
+                require_explicit_policy,
+                inhibit_policy_mapping,
+            });
+        })
+    }
+}
+
+impl DEREncodable for PolicyConstraints {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            if let Some(skip_certs) = self.require_explicit_policy {
+                writer.next().write_tagged_implicit(Tag::context(0), |writer| {
+                    writer.write_u64(skip_certs)
+                });
+            }
+            if let Some(skip_certs) = self.inhibit_policy_mapping {
+                writer.next().write_tagged_implicit(Tag::context(1), |writer| {
+                    writer.write_u64(skip_certs)
+                });
+            }
+        })
+    }
+}
+
+/// The reason for revocation (RFC 5280 5.3.1).
+///
+/// ```text
+/// CRLReason ::= ENUMERATED {
+///      unspecified             (0),
+///      keyCompromise           (1),
+///      cACompromise            (2),
+///      affiliationChanged      (3),
+///      superseded              (4),
+///      cessationOfOperation    (5),
+///      certificateHold         (6),
+///           -- value 7 is not used
+///      removeFromCRL           (8),
+///      privilegeWithdrawn      (9),
+///      aACompromise           (10) }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum CrlReason {
+    /// `unspecified`
+    Unspecified = 0,
+    /// `keyCompromise`
+    KeyCompromise = 1,
+    /// `cACompromise`
+    CaCompromise = 2,
+    /// `affiliationChanged`
+    AffiliationChanged = 3,
+    /// `superseded`
+    Superseded = 4,
+    /// `cessationOfOperation`
+    CessationOfOperation = 5,
+    /// `certificateHold`
+    CertificateHold = 6,
+    /// `removeFromCRL`
+    RemoveFromCrl = 8,
+    /// `privilegeWithdrawn`
+    PrivilegeWithdrawn = 9,
+    /// `aACompromise`
+    AaCompromise = 10,
+}
+
+impl BERDecodable for CrlReason {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        match reader.read_enum()? {
+            0 => Ok(CrlReason::Unspecified),
+            1 => Ok(CrlReason::KeyCompromise),
+            2 => Ok(CrlReason::CaCompromise),
+            3 => Ok(CrlReason::AffiliationChanged),
+            4 => Ok(CrlReason::Superseded),
+            5 => Ok(CrlReason::CessationOfOperation),
+            6 => Ok(CrlReason::CertificateHold),
+            8 => Ok(CrlReason::RemoveFromCrl),
+            9 => Ok(CrlReason::PrivilegeWithdrawn),
+            10 => Ok(CrlReason::AaCompromise),
+            _ => Err(ASN1Error::new(ASN1ErrorKind::Invalid)),
+        }
+    }
+}
+
+impl DEREncodable for CrlReason {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_enum(*self as i64)
+    }
+}
+
+/// The issuing distribution point CRL extension (RFC 5280 5.2.5).
+///
+/// ```text
+/// IssuingDistributionPoint ::= SEQUENCE {
+///      distributionPoint          [0] DistributionPointName OPTIONAL,
+///      onlyContainsUserCerts      [1] BOOLEAN DEFAULT FALSE,
+///      onlyContainsCACerts        [2] BOOLEAN DEFAULT FALSE,
+///      onlySomeReasons            [3] ReasonFlags OPTIONAL,
+///      indirectCRL                [4] BOOLEAN DEFAULT FALSE,
+///      onlyContainsAttributeCerts [5] BOOLEAN DEFAULT FALSE }
+/// ```
+///
+/// `ReasonFlags` is represented by `BitString` as in [`DistributionPoint`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub struct IssuingDistributionPoint {
+    /// The distribution point of the CRL
+    pub distribution_point: Option<DistributionPointName>,
+    /// Whether the CRL only covers end-entity certificates
+    pub only_contains_user_certs: bool,
+    /// Whether the CRL only covers CA certificates
+    pub only_contains_ca_certs: bool,
+    /// The revocation reasons covered by the CRL
+    pub only_some_reasons: Option<BitString>,
+    /// Whether the CRL covers certificates issued by other CAs
+    pub indirect_crl: bool,
+    /// Whether the CRL only covers attribute certificates
+    pub only_contains_attribute_certs: bool,
+}
+
+impl BERDecodable for IssuingDistributionPoint {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let distribution_point = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0),
+                    DistributionPointName::decode_ber)
+            })?;
+            let only_contains_user_certs = reader.read_default(false,
+                |reader| {
+                    reader.read_tagged_implicit(Tag::context(1),
+                        |reader| reader.read_bool())
+                })?;
+            let only_contains_ca_certs = reader.read_default(false,
+                |reader| {
+                    reader.read_tagged_implicit(Tag::context(2),
+                        |reader| reader.read_bool())
+                })?;
+            let only_some_reasons = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(3),
+                    BitString::decode_ber)
+            })?;
+            let indirect_crl = reader.read_default(false, |reader| {
+                reader.read_tagged_implicit(Tag::context(4),
+                    |reader| reader.read_bool())
+            })?;
+            let only_contains_attribute_certs = reader.read_default(false,
+                |reader| {
+                    reader.read_tagged_implicit(Tag::context(5),
+                        |reader| reader.read_bool())
+                })?;
+            return Ok(IssuingDistributionPoint {
+                distribution_point,
+                only_contains_user_certs,
+                only_contains_ca_certs,
+                only_some_reasons,
+                indirect_crl,
+                only_contains_attribute_certs,
+            });
+        })
+    }
+}
+
+impl DEREncodable for IssuingDistributionPoint {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            if let Some(ref name) = self.distribution_point {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    name.encode_der(writer)
+                });
+            }
+            if self.only_contains_user_certs {
+                writer.next().write_tagged_implicit(Tag::context(1),
+                    |writer| writer.write_bool(true));
+            }
+            if self.only_contains_ca_certs {
+                writer.next().write_tagged_implicit(Tag::context(2),
+                    |writer| writer.write_bool(true));
+            }
+            if let Some(ref reasons) = self.only_some_reasons {
+                writer.next().write_tagged_implicit(Tag::context(3), |writer| {
+                    reasons.encode_der(writer)
+                });
+            }
+            if self.indirect_crl {
+                writer.next().write_tagged_implicit(Tag::context(4),
+                    |writer| writer.write_bool(true));
+            }
+            if self.only_contains_attribute_certs {
+                writer.next().write_tagged_implicit(Tag::context(5),
+                    |writer| writer.write_bool(true));
+            }
+        })
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::super::{ASN1Error, ASN1ErrorKind, ASN1Result, Tag, TagClass};
+use super::super::{BERDecodable, BERReader, DEREncodable, DERWriter};
+use super::super::models::{DirectoryString, ObjectIdentifier, TaggedDerValue};
+use super::Name;
+
+/// A name of a non-standard form (RFC 5280 4.2.1.6).
+///
+/// ```text
+/// OtherName ::= SEQUENCE {
+///      type-id    OBJECT IDENTIFIER,
+///      value      [0] EXPLICIT ANY DEFINED BY type-id }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct OtherName {
+    /// The type of the name
+    pub type_id: ObjectIdentifier,
+    /// The value, without the `[0]` tag
+    pub value: TaggedDerValue,
+}
+
+/// A name for Electronic Data Interchange (RFC 5280 4.2.1.6).
+///
+/// ```text
+/// EDIPartyName ::= SEQUENCE {
+///      nameAssigner            [0]     DirectoryString OPTIONAL,
+///      partyName               [1]     DirectoryString }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct EdiPartyName {
+    /// The authority which assigned the name
+    pub name_assigner: Option<DirectoryString>,
+    /// The name
+    pub party_name: DirectoryString,
+}
+
+/// A name in one of several forms (RFC 5280 4.2.1.6).
+///
+/// ```text
+/// GeneralName ::= CHOICE {
+///      otherName                       [0]     OtherName,
+///      rfc822Name                      [1]     IA5String,
+///      dNSName                         [2]     IA5String,
+///      x400Address                     [3]     ORAddress,
+///      directoryName                   [4]     Name,
+///      ediPartyName                    [5]     EDIPartyName,
+///      uniformResourceIdentifier       [6]     IA5String,
+///      iPAddress                       [7]     OCTET STRING,
+///      registeredID                    [8]     OBJECT IDENTIFIER }
+///
+/// GeneralNames ::= SEQUENCE SIZE (1..MAX) OF GeneralName
+/// ```
+///
+/// `GeneralNames` corresponds to `Vec<GeneralName>`.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::GeneralName;
+/// let names = vec![
+///     GeneralName::DnsName("example.com".to_string()),
+///     GeneralName::IpAddress(vec![192, 0, 2, 1]),
+/// ];
+/// let der = yasna::encode_der(&names);
+/// assert_eq!(der, &[48, 19, 130, 11, 101, 120, 97, 109, 112, 108, 101, 46,
+///     99, 111, 109, 135, 4, 192, 0, 2, 1]);
+/// assert_eq!(yasna::decode_der::<Vec<GeneralName>>(&der).unwrap(), names);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum GeneralName {
+    /// `otherName`
+    OtherName(OtherName),
+    /// `rfc822Name`, an email address
+    Rfc822Name(String),
+    /// `dNSName`
+    DnsName(String),
+    /// `x400Address`, as the components of the ORAddress SEQUENCE
+    X400Address(Vec<TaggedDerValue>),
+    /// `directoryName`
+    DirectoryName(Name),
+    /// `ediPartyName`
+    EdiPartyName(EdiPartyName),
+    /// `uniformResourceIdentifier`
+    UniformResourceIdentifier(String),
+    /// `iPAddress`, 4 or 16 octets (or 8 or 32 octets with a mask in name
+    /// constraints)
+    IpAddress(Vec<u8>),
+    /// `registeredID`
+    RegisteredId(ObjectIdentifier),
+}
+
+impl BERDecodable for GeneralName {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let tag = reader.lookahead_tag()?;
+        if tag.tag_class != TagClass::ContextSpecific {
+            return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+        }
+        match tag.tag_number {
+            0 => reader.read_tagged_implicit(tag, |reader| {
+                reader.read_sequence(|reader| {
+                    let type_id = ObjectIdentifier::decode_ber(reader.next())?;
+                    let value = reader.next().read_tagged(Tag::context(0),
+                        |reader| reader.read_tagged_der())?;
+                    return Ok(GeneralName::OtherName(OtherName {
+                        type_id,
+                        value,
+                    }));
+                })
+            }),
+            1 => Ok(GeneralName::Rfc822Name(reader.read_tagged_implicit(tag,
+                |reader| reader.read_ia5_string())?)),
+            2 => Ok(GeneralName::DnsName(reader.read_tagged_implicit(tag,
+                |reader| reader.read_ia5_string())?)),
+            3 => Ok(GeneralName::X400Address(reader.read_tagged_implicit(tag,
+                |reader| {
+                    reader.read_sequence(|reader| reader.remaining_elements())
+                })?)),
+            4 => Ok(GeneralName::DirectoryName(reader.read_tagged(tag,
+                Name::decode_ber)?)),
+            5 => reader.read_tagged_implicit(tag, |reader| {
+                reader.read_sequence(|reader| {
+                    let name_assigner = reader.read_optional(|reader| {
+                        reader.read_tagged(Tag::context(0),
+                            DirectoryString::decode_ber)
+                    })?;
+                    let party_name = reader.next().read_tagged(
+                        Tag::context(1), DirectoryString::decode_ber)?;
+                    return Ok(GeneralName::EdiPartyName(EdiPartyName {
+                        name_assigner,
+                        party_name,
+                    }));
+                })
+            }),
+            6 => Ok(GeneralName::UniformResourceIdentifier(
+                reader.read_tagged_implicit(tag,
+                    |reader| reader.read_ia5_string())?)),
+            7 => Ok(GeneralName::IpAddress(reader.read_tagged_implicit(tag,
+                |reader| reader.read_bytes())?)),
+            8 => Ok(GeneralName::RegisteredId(reader.read_tagged_implicit(tag,
+                |reader| reader.read_oid())?)),
+            _ => Err(ASN1Error::new(ASN1ErrorKind::Invalid)),
+        }
+    }
+}
+
+impl DEREncodable for GeneralName {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            GeneralName::OtherName(ref other_name) => {
+                writer.write_tagged_implicit(Tag::context(0), |writer| {
+                    writer.write_sequence(|writer| {
+                        other_name.type_id.encode_der(writer.next());
+                        writer.next().write_tagged(Tag::context(0), |writer| {
+                            writer.write_tagged_der(&other_name.value)
+                        });
+                    })
+                })
+            },
+            GeneralName::Rfc822Name(ref name) => {
+                writer.write_tagged_implicit(Tag::context(1), |writer| {
+                    writer.write_ia5_string(name)
+                })
+            },
+            GeneralName::DnsName(ref name) => {
+                writer.write_tagged_implicit(Tag::context(2), |writer| {
+                    writer.write_ia5_string(name)
+                })
+            },
+            GeneralName::X400Address(ref components) => {
+                writer.write_tagged_implicit(Tag::context(3), |writer| {
+                    writer.write_sequence(|writer| {
+                        for component in components {
+                            writer.next().write_tagged_der(component);
+                        }
+                    })
+                })
+            },
+            GeneralName::DirectoryName(ref name) => {
+                writer.write_tagged(Tag::context(4), |writer| {
+                    name.encode_der(writer)
+                })
+            },
+            GeneralName::EdiPartyName(ref name) => {
+                writer.write_tagged_implicit(Tag::context(5), |writer| {
+                    writer.write_sequence(|writer| {
+                        if let Some(ref name_assigner) = name.name_assigner {
+                            writer.next().write_tagged(Tag::context(0),
+                                |writer| name_assigner.encode_der(writer));
+                        }
+                        writer.next().write_tagged(Tag::context(1), |writer| {
+                            name.party_name.encode_der(writer)
+                        });
+                    })
+                })
+            },
+            GeneralName::UniformResourceIdentifier(ref uri) => {
+                writer.write_tagged_implicit(Tag::context(6), |writer| {
+                    writer.write_ia5_string(uri)
+                })
+            },
+            GeneralName::IpAddress(ref address) => {
+                writer.write_tagged_implicit(Tag::context(7), |writer| {
+                    writer.write_bytes(address)
+                })
+            },
+            GeneralName::RegisteredId(ref oid) => {
+                writer.write_tagged_implicit(Tag::context(8), |writer| {
+                    writer.write_oid(oid)
+                })
+            },
+        }
+    }
+}
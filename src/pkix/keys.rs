@@ -0,0 +1,631 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use super::super::{ASN1Error, ASN1ErrorKind, ASN1Result, Tag};
+use super::super::{BERDecodable, BERReader, DEREncodable, DERWriter};
+use super::super::{decode_ber, decode_der, encode_der};
+use super::super::models::{BitString, ObjectIdentifier, TaggedDerValue};
+use super::super::tags::TAG_NULL;
+use super::{AlgorithmIdentifier, Attribute, SubjectPublicKeyInfo};
+use super::oids::{ID_EC_PUBLIC_KEY, RSA_ENCRYPTION};
+
+/// Reads a non-negative INTEGER as big-endian bytes without leading zeros.
+fn read_unsigned(reader: BERReader) -> ASN1Result<Vec<u8>> {
+    let (bytes, non_negative) = reader.read_bigint_bytes()?;
+    if !non_negative {
+        return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+    }
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    return Ok(bytes[start..].to_vec());
+}
+
+/// A private key with its algorithm, in PKCS#8 (RFC 5958 2).
+///
+/// ```text
+/// OneAsymmetricKey ::= SEQUENCE {
+///   version                   Version,
+///   privateKeyAlgorithm       PrivateKeyAlgorithmIdentifier,
+///   privateKey                PrivateKey,
+///   attributes            [0] Attributes OPTIONAL,
+///   ...,
+///   [[2: publicKey        [1] PublicKey OPTIONAL ]],
+///   ...
+/// }
+///
+/// PrivateKeyInfo ::= OneAsymmetricKey
+///
+/// Version ::= INTEGER { v1(0), v2(1) } (v1, ..., v2)
+/// ```
+///
+/// The version is not stored: v2 is used if and only if there is a
+/// public key. The attributes are omitted when encoded if empty.
+///
+/// The private key is an OCTET STRING whose contents depend on the
+/// algorithm. RSA and EC keys can be converted to and from
+/// [`RSAPrivateKey`] and [`ECPrivateKey`].
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::{OneAsymmetricKey, oids};
+/// let key : OneAsymmetricKey = yasna::decode_der(&[48, 46, 2, 1, 0,
+///     48, 5, 6, 3, 43, 101, 112, 4, 34, 4, 32,
+///     212, 238, 114, 219, 249, 19, 88, 74, 213, 182, 216, 241, 247, 105,
+///     248, 173, 58, 254, 124, 40, 203, 241, 212, 251, 224, 151, 168, 143,
+///     68, 117, 88, 66]).unwrap();
+/// assert!(key.private_key_algorithm.algorithm == oids::ID_ED25519);
+/// assert_eq!(key.private_key.len(), 34);
+/// assert_eq!(key.public_key, None);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct OneAsymmetricKey {
+    /// The algorithm of the key
+    pub private_key_algorithm: AlgorithmIdentifier,
+    /// The encoding of the private key
+    pub private_key: Vec<u8>,
+    /// The attributes, such as friendlyName
+    pub attributes: Vec<Attribute>,
+    /// The public key (v2 only)
+    pub public_key: Option<BitString>,
+}
+
+/// A PKCS#8 private key, an alias of [`OneAsymmetricKey`] (RFC 5958 2).
+pub type PrivateKeyInfo = OneAsymmetricKey;
+
+impl OneAsymmetricKey {
+    /// Wraps an RSA private key, with the `rsaEncryption` algorithm.
+    pub fn from_rsa_private_key(key: &RSAPrivateKey) -> Self {
+        OneAsymmetricKey {
+            private_key_algorithm: rsa_encryption(),
+            private_key: encode_der(key),
+            attributes: Vec::new(),
+            public_key: None,
+        }
+    }
+
+    /// Unwraps an RSA private key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the algorithm is not `rsaEncryption` or the key
+    /// is malformed.
+    pub fn to_rsa_private_key(&self) -> ASN1Result<RSAPrivateKey> {
+        if self.private_key_algorithm.algorithm != RSA_ENCRYPTION {
+            return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+        }
+        return decode_ber(&self.private_key);
+    }
+
+    /// Wraps an EC private key, with the `id-ecPublicKey` algorithm.
+    ///
+    /// The domain parameters are moved to the algorithm, as OpenSSL does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key doesn't have the domain parameters.
+    pub fn from_ec_private_key(key: &ECPrivateKey) -> ASN1Result<Self> {
+        let parameters = key.parameters.clone()
+            .ok_or_else(|| ASN1Error::new(ASN1ErrorKind::Invalid))?;
+        let key = ECPrivateKey {
+            parameters: None,
+            ..key.clone()
+        };
+        return Ok(OneAsymmetricKey {
+            private_key_algorithm: AlgorithmIdentifier {
+                algorithm: ObjectIdentifier::from(ID_EC_PUBLIC_KEY),
+                parameters: Some(parameters),
+            },
+            private_key: encode_der(&key),
+            attributes: Vec::new(),
+            public_key: None,
+        });
+    }
+
+    /// Unwraps an EC private key.
+    ///
+    /// The domain parameters are copied from the algorithm if the key
+    /// doesn't have them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the algorithm is not `id-ecPublicKey` or the key
+    /// is malformed.
+    pub fn to_ec_private_key(&self) -> ASN1Result<ECPrivateKey> {
+        if self.private_key_algorithm.algorithm != ID_EC_PUBLIC_KEY {
+            return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+        }
+        let mut key : ECPrivateKey = decode_ber(&self.private_key)?;
+        if key.parameters.is_none() {
+            key.parameters = self.private_key_algorithm.parameters.clone();
+        }
+        return Ok(key);
+    }
+}
+
+impl BERDecodable for OneAsymmetricKey {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let version = reader.next().read_u64()?;
+            if version > 1 {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            let private_key_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let private_key = reader.next().read_bytes()?;
+            let attributes = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(0), |reader| {
+                    reader.collect_set_of(Attribute::decode_ber)
+                })
+            })?;
+            let public_key = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1),
+                    BitString::decode_ber)
+            })?;
+            reader.skip_remaining()?;
+            if version == 0 && public_key.is_some() {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            return Ok(OneAsymmetricKey {
+                private_key_algorithm,
+                private_key,
+                attributes: attributes.unwrap_or_default(),
+                public_key,
+            });
+        })
+    }
+}
+
+impl DEREncodable for OneAsymmetricKey {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_u64(self.public_key.is_some() as u64);
+            self.private_key_algorithm.encode_der(writer.next());
+            writer.next().write_bytes(&self.private_key);
+            if !self.attributes.is_empty() {
+                writer.next().write_tagged_implicit(Tag::context(0),
+                    |writer| {
+                        writer.write_set_of(|writer| {
+                            for attribute in &self.attributes {
+                                attribute.encode_der(writer.next());
+                            }
+                        })
+                    });
+            }
+            if let Some(ref public_key) = self.public_key {
+                writer.next().write_tagged_implicit(Tag::context(1),
+                    |writer| public_key.encode_der(writer));
+            }
+        })
+    }
+}
+
+/// An encrypted PKCS#8 private key (RFC 5958 3).
+///
+/// ```text
+/// EncryptedPrivateKeyInfo ::= SEQUENCE {
+///   encryptionAlgorithm  EncryptionAlgorithmIdentifier,
+///   encryptedData        EncryptedData }
+/// ```
+///
+/// The encryption itself, usually PBES2 (RFC 8018), is left to the caller
+/// of [`encrypt`](Self::encrypt) and [`decrypt`](Self::decrypt).
+///
+/// # Examples
+///
+/// ```
+/// use yasna::ASN1Error;
+/// use yasna::models::ObjectIdentifier;
+/// use yasna::pkix::{AlgorithmIdentifier, EncryptedPrivateKeyInfo};
+/// use yasna::pkix::OneAsymmetricKey;
+/// let key = OneAsymmetricKey {
+///     private_key_algorithm: AlgorithmIdentifier::new(
+///         ObjectIdentifier::from_slice(&[1, 3, 101, 112])),
+///     private_key: vec![4, 32, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13,
+///         14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29,
+///         30, 31, 32],
+///     attributes: vec![],
+///     public_key: None,
+/// };
+/// let pbes2 = AlgorithmIdentifier::new(
+///     ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 5, 13]));
+/// let encrypted = EncryptedPrivateKeyInfo::encrypt(&key, pbes2, |plaintext| {
+///     // Encrypt `plaintext` here.
+///     Ok::<_, ASN1Error>(plaintext.iter().map(|b| b ^ 0x55).collect())
+/// }).unwrap();
+/// let decrypted = encrypted.decrypt(|_algorithm, ciphertext| {
+///     // Decrypt `ciphertext` here.
+///     Ok::<_, ASN1Error>(ciphertext.iter().map(|b| b ^ 0x55).collect())
+/// }).unwrap();
+/// assert_eq!(decrypted, key);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct EncryptedPrivateKeyInfo {
+    /// The encryption algorithm
+    pub encryption_algorithm: AlgorithmIdentifier,
+    /// The encrypted DER encoding of a `OneAsymmetricKey`
+    pub encrypted_data: Vec<u8>,
+}
+
+impl EncryptedPrivateKeyInfo {
+    /// Encodes `key` in DER and encrypts the encoding with `encrypt`.
+    pub fn encrypt<F, E>(key: &OneAsymmetricKey,
+            encryption_algorithm: AlgorithmIdentifier, encrypt: F)
+            -> Result<Self, E>
+            where F: FnOnce(&[u8]) -> Result<Vec<u8>, E> {
+        let encrypted_data = encrypt(&encode_der(key))?;
+        return Ok(EncryptedPrivateKeyInfo {
+            encryption_algorithm,
+            encrypted_data,
+        });
+    }
+
+    /// Decrypts the data with `decrypt`, which is given the algorithm and
+    /// the ciphertext, and decodes the key.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `decrypt`, or a decoding error converted into
+    /// `E`, which is the most likely result of a wrong password.
+    pub fn decrypt<F, E>(&self, decrypt: F) -> Result<OneAsymmetricKey, E>
+            where F: FnOnce(&AlgorithmIdentifier, &[u8]) -> Result<Vec<u8>, E>,
+                E: From<ASN1Error> {
+        let plaintext = decrypt(&self.encryption_algorithm,
+            &self.encrypted_data)?;
+        return Ok(decode_ber(&plaintext)?);
+    }
+}
+
+impl BERDecodable for EncryptedPrivateKeyInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let encryption_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let encrypted_data = reader.next().read_bytes()?;
+            return Ok(EncryptedPrivateKeyInfo {
+                encryption_algorithm,
+                encrypted_data,
+            });
+        })
+    }
+}
+
+impl DEREncodable for EncryptedPrivateKeyInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.encryption_algorithm.encode_der(writer.next());
+            writer.next().write_bytes(&self.encrypted_data);
+        })
+    }
+}
+
+fn rsa_encryption() -> AlgorithmIdentifier {
+    AlgorithmIdentifier {
+        algorithm: ObjectIdentifier::from(RSA_ENCRYPTION),
+        parameters: Some(TaggedDerValue::from_tag_and_bytes(
+            TAG_NULL, Vec::new())),
+    }
+}
+
+/// An RSA public key in PKCS#1 (RFC 8017 A.1.1).
+///
+/// ```text
+/// RSAPublicKey ::= SEQUENCE {
+///     modulus           INTEGER,  -- n
+///     publicExponent    INTEGER   -- e
+/// }
+/// ```
+///
+/// The integers are unsigned big-endian bytes without leading zeros.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::{RSAPublicKey, SubjectPublicKeyInfo};
+/// let key = RSAPublicKey {
+///     modulus: vec![0xC5, 0x3B],
+///     public_exponent: vec![1, 0, 1],
+/// };
+/// assert_eq!(yasna::encode_der(&key),
+///     &[48, 10, 2, 3, 0, 0xC5, 0x3B, 2, 3, 1, 0, 1]);
+/// let spki = SubjectPublicKeyInfo::from_rsa_public_key(&key);
+/// assert_eq!(spki.to_rsa_public_key().unwrap(), key);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RSAPublicKey {
+    /// The modulus `n`
+    pub modulus: Vec<u8>,
+    /// The public exponent `e`
+    pub public_exponent: Vec<u8>,
+}
+
+impl BERDecodable for RSAPublicKey {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let modulus = read_unsigned(reader.next())?;
+            let public_exponent = read_unsigned(reader.next())?;
+            return Ok(RSAPublicKey {
+                modulus,
+                public_exponent,
+            });
+        })
+    }
+}
+
+impl DEREncodable for RSAPublicKey {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_bigint_bytes(&self.modulus, true);
+            writer.next().write_bigint_bytes(&self.public_exponent, true);
+        })
+    }
+}
+
+impl SubjectPublicKeyInfo {
+    /// Wraps an RSA public key, with the `rsaEncryption` algorithm.
+    pub fn from_rsa_public_key(key: &RSAPublicKey) -> Self {
+        SubjectPublicKeyInfo {
+            algorithm: rsa_encryption(),
+            subject_public_key: BitString::from_bytes(encode_der(key)),
+        }
+    }
+
+    /// Unwraps an RSA public key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the algorithm is not `rsaEncryption` or the key
+    /// is malformed.
+    pub fn to_rsa_public_key(&self) -> ASN1Result<RSAPublicKey> {
+        if self.algorithm.algorithm != RSA_ENCRYPTION {
+            return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+        }
+        let bytes = self.subject_public_key.as_octets()
+            .ok_or_else(|| ASN1Error::new(ASN1ErrorKind::Invalid))?;
+        return decode_der(bytes);
+    }
+}
+
+/// An RSA private key in PKCS#1 (RFC 8017 A.1.2).
+///
+/// ```text
+/// RSAPrivateKey ::= SEQUENCE {
+///     version           Version,
+///     modulus           INTEGER,  -- n
+///     publicExponent    INTEGER,  -- e
+///     privateExponent   INTEGER,  -- d
+///     prime1            INTEGER,  -- p
+///     prime2            INTEGER,  -- q
+///     exponent1         INTEGER,  -- d mod (p-1)
+///     exponent2         INTEGER,  -- d mod (q-1)
+///     coefficient       INTEGER,  -- (inverse of q) mod p
+///     otherPrimeInfos   OtherPrimeInfos OPTIONAL
+/// }
+///
+/// Version ::= INTEGER { two-prime(0), multi(1) }
+///    (CONSTRAINED BY
+///    {-- version must be multi if otherPrimeInfos present --})
+/// ```
+///
+/// The integers are unsigned big-endian bytes without leading zeros. The
+/// version is not stored: `multi` is used if and only if there are other
+/// primes.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RSAPrivateKey {
+    /// The modulus `n`
+    pub modulus: Vec<u8>,
+    /// The public exponent `e`
+    pub public_exponent: Vec<u8>,
+    /// The private exponent `d`
+    pub private_exponent: Vec<u8>,
+    /// The first prime `p`
+    pub prime1: Vec<u8>,
+    /// The second prime `q`
+    pub prime2: Vec<u8>,
+    /// `d mod (p-1)`
+    pub exponent1: Vec<u8>,
+    /// `d mod (q-1)`
+    pub exponent2: Vec<u8>,
+    /// `(inverse of q) mod p`
+    pub coefficient: Vec<u8>,
+    /// The third and subsequent primes of a multi-prime key
+    pub other_prime_infos: Vec<OtherPrimeInfo>,
+}
+
+impl RSAPrivateKey {
+    /// Returns the public part of the key.
+    pub fn public_key(&self) -> RSAPublicKey {
+        RSAPublicKey {
+            modulus: self.modulus.clone(),
+            public_exponent: self.public_exponent.clone(),
+        }
+    }
+}
+
+impl BERDecodable for RSAPrivateKey {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let version = reader.next().read_u64()?;
+            let modulus = read_unsigned(reader.next())?;
+            let public_exponent = read_unsigned(reader.next())?;
+            let private_exponent = read_unsigned(reader.next())?;
+            let prime1 = read_unsigned(reader.next())?;
+            let prime2 = read_unsigned(reader.next())?;
+            let exponent1 = read_unsigned(reader.next())?;
+            let exponent2 = read_unsigned(reader.next())?;
+            let coefficient = read_unsigned(reader.next())?;
+            let other_prime_infos = reader.read_optional(Vec::decode_ber)?
+                .unwrap_or_default();
+            if version != (!other_prime_infos.is_empty()) as u64 {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            return Ok(RSAPrivateKey {
+                modulus,
+                public_exponent,
+                private_exponent,
+                prime1,
+                prime2,
+                exponent1,
+                exponent2,
+                coefficient,
+                other_prime_infos,
+            });
+        })
+    }
+}
+
+impl DEREncodable for RSAPrivateKey {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_u64(!self.other_prime_infos.is_empty() as u64);
+            writer.next().write_bigint_bytes(&self.modulus, true);
+            writer.next().write_bigint_bytes(&self.public_exponent, true);
+            writer.next().write_bigint_bytes(&self.private_exponent, true);
+            writer.next().write_bigint_bytes(&self.prime1, true);
+            writer.next().write_bigint_bytes(&self.prime2, true);
+            writer.next().write_bigint_bytes(&self.exponent1, true);
+            writer.next().write_bigint_bytes(&self.exponent2, true);
+            writer.next().write_bigint_bytes(&self.coefficient, true);
+            if !self.other_prime_infos.is_empty() {
+                self.other_prime_infos.encode_der(writer.next());
+            }
+        })
+    }
+}
+
+/// An additional prime of a multi-prime RSA key (RFC 8017 A.1.2).
+///
+/// ```text
+/// OtherPrimeInfo ::= SEQUENCE {
+///     prime             INTEGER,  -- ri
+///     exponent          INTEGER,  -- di
+///     coefficient       INTEGER   -- ti
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct OtherPrimeInfo {
+    /// The prime `r_i`
+    pub prime: Vec<u8>,
+    /// `d mod (r_i-1)`
+    pub exponent: Vec<u8>,
+    /// The CRT coefficient `t_i`
+    pub coefficient: Vec<u8>,
+}
+
+impl BERDecodable for OtherPrimeInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let prime = read_unsigned(reader.next())?;
+            let exponent = read_unsigned(reader.next())?;
+            let coefficient = read_unsigned(reader.next())?;
+            return Ok(OtherPrimeInfo {
+                prime,
+                exponent,
+                coefficient,
+            });
+        })
+    }
+}
+
+impl DEREncodable for OtherPrimeInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_bigint_bytes(&self.prime, true);
+            writer.next().write_bigint_bytes(&self.exponent, true);
+            writer.next().write_bigint_bytes(&self.coefficient, true);
+        })
+    }
+}
+
+/// An EC private key in SEC1 (RFC 5915 3).
+///
+/// ```text
+/// ECPrivateKey ::= SEQUENCE {
+///   version        INTEGER { ecPrivkeyVer1(1) } (ecPrivkeyVer1),
+///   privateKey     OCTET STRING,
+///   parameters [0] ECParameters {{ NamedCurve }} OPTIONAL,
+///   publicKey  [1] BIT STRING OPTIONAL
+/// }
+/// ```
+///
+/// Both `[0]` and `[1]` are EXPLICIT. The parameters are kept as a raw
+/// value, which is usually the OID of a named curve.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::{ECPrivateKey, OneAsymmetricKey, oids};
+/// let key : ECPrivateKey = yasna::decode_der(&[48, 49, 2, 1, 1,
+///     4, 32, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+///     19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+///     160, 10, 6, 8, 42, 134, 72, 206, 61, 3, 1, 7]).unwrap();
+/// assert!(key.named_curve().unwrap() == oids::SECP256R1);
+/// let pkcs8 = OneAsymmetricKey::from_ec_private_key(&key).unwrap();
+/// assert_eq!(pkcs8.to_ec_private_key().unwrap(), key);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ECPrivateKey {
+    /// The private key, as an unsigned big-endian integer of the size of
+    /// the curve order
+    pub private_key: Vec<u8>,
+    /// The domain parameters
+    pub parameters: Option<TaggedDerValue>,
+    /// The public key, as an encoded point
+    pub public_key: Option<BitString>,
+}
+
+impl ECPrivateKey {
+    /// Returns the curve if the parameters are a named curve.
+    pub fn named_curve(&self) -> Option<ObjectIdentifier> {
+        let parameters = self.parameters.as_ref()?;
+        return decode_der(&encode_der(parameters)).ok();
+    }
+}
+
+impl BERDecodable for ECPrivateKey {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            if reader.next().read_u64()? != 1 {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            let private_key = reader.next().read_bytes()?;
+            let parameters = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0),
+                    |reader| reader.read_tagged_der())
+            })?;
+            let public_key = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(1), BitString::decode_ber)
+            })?;
+            return Ok(ECPrivateKey {
+                private_key,
+                parameters,
+                public_key,
+            });
+        })
+    }
+}
+
+impl DEREncodable for ECPrivateKey {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_u64(1);
+            writer.next().write_bytes(&self.private_key);
+            if let Some(ref parameters) = self.parameters {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    writer.write_tagged_der(parameters)
+                });
+            }
+            if let Some(ref public_key) = self.public_key {
+                writer.next().write_tagged(Tag::context(1), |writer| {
+                    public_key.encode_der(writer)
+                });
+            }
+        })
+    }
+}
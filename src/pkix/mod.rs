@@ -0,0 +1,104 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Provides models of the Internet X.509 Public Key Infrastructure
+//! (PKIX) data structures, and related PKCS structures.
+//!
+//! The types follow the ASN.1 modules in RFC 5280 and implement
+//! [`BERDecodable`](crate::BERDecodable) and
+//! [`DEREncodable`](crate::DEREncodable). Signed structures keep the
+//! original encoding of the signed part, so that signatures can be verified
+//! over the exact bytes. No cryptographic operation is done by this module.
+//!
+//! # Examples
+//!
+//! ```
+//! use yasna::pkix::{Certificate, Version};
+//! # let der = yasna::text::decode_base64("
+//! # MIIBbjCCAROgAwIBAgICEjQwCgYIKoZIzj0EAwIwFTETMBEGA1UEAwwKeWFzbmEg
+//! # dGVzdDAeFw0yNjEwMTkwMzQwNTlaFw0zNjEwMTYwMzQwNTlaMBUxEzARBgNVBAMM
+//! # Cnlhc25hIHRlc3QwWTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAARqbYGR/kr1mDKF
+//! # cwsMFY8no84X2EhfiKrS6xhK2Qv5GOim9MunHv3PJrLZ5HqsoYwn3j2AqLfJ3ceG
+//! # 54YlUDFHo1MwUTAdBgNVHQ4EFgQUbJkAgmk2a234gusI4/cg3nGyMxcwHwYDVR0j
+//! # BBgwFoAUbJkAgmk2a234gusI4/cg3nGyMxcwDwYDVR0TAQH/BAUwAwEB/zAKBggq
+//! # hkjOPQQDAgNJADBGAiEAjEuBoFU955ZrwwUyI5e44/xkgElnP4hZ8XK6ldk+wU4C
+//! # IQDTe5zc47K0boFOuXJ6UgaB/Z2DUQDEm+XVo2L5II6rgw==").unwrap();
+//! let certificate : Certificate = yasna::decode_der(&der).unwrap();
+//! let tbs_certificate = certificate.tbs_certificate.value();
+//! assert_eq!(tbs_certificate.version, Version::V3);
+//! assert_eq!(tbs_certificate.validity.not_after.year(), 2036);
+//! // The signature is verified over these bytes.
+//! assert_eq!(certificate.tbs_bytes(), &der[4..283]);
+//! ```
+//!
+//! # Features
+//!
+//! This module is enabled by `pkix` feature, which also enables `time`
+//! feature for the GeneralizedTime fields.
+//!
+//! ```toml
+//! [dependencies]
+//! yasna = { version = "*", features = ["pkix"] }
+//! ```
+
+mod algorithm;
+mod attribute;
+mod certificate;
+mod cms;
+mod crl;
+mod enveloped_data;
+mod extensions;
+mod general_name;
+mod keys;
+mod name;
+pub mod oids;
+mod ocsp;
+mod pkcs12;
+mod request;
+#[cfg(test)]
+mod tests;
+mod tsp;
+
+pub use self::algorithm::{AlgorithmIdentifier,SubjectPublicKeyInfo};
+pub use self::attribute::Attribute;
+pub use self::certificate::{Certificate,TBSCertificate,Version};
+pub use self::certificate::{SerialNumber,Validity};
+pub use self::crl::{CertificateList,TBSCertList,RevokedCertificate};
+pub use self::crl::{CertificateListRef,RevokedCertificates};
+pub use self::cms::{ContentInfo,CMSVersion,IssuerAndSerialNumber};
+pub use self::cms::{SignerIdentifier,RecipientIdentifier,CertificateChoices};
+pub use self::cms::{RevocationInfoChoice,EncapsulatedContentInfo};
+pub use self::cms::{SignedData,SignerInfo};
+pub use self::enveloped_data::{EnvelopedData,OriginatorInfo,EncryptedContentInfo};
+pub use self::enveloped_data::EncryptedData;
+pub use self::enveloped_data::{RecipientInfo,KeyTransRecipientInfo};
+pub use self::enveloped_data::{KeyAgreeRecipientInfo,OriginatorIdentifierOrKey};
+pub use self::enveloped_data::{RecipientEncryptedKey,KeyAgreeRecipientIdentifier};
+pub use self::enveloped_data::{RecipientKeyIdentifier,OtherKeyAttribute};
+pub use self::enveloped_data::{KEKRecipientInfo,KEKIdentifier};
+pub use self::enveloped_data::{PasswordRecipientInfo,OtherRecipientInfo};
+pub use self::extensions::{Extension,ExtensionValue,BasicConstraints,KeyUsage};
+pub use self::extensions::{AuthorityKeyIdentifier,DistributionPoint};
+pub use self::extensions::{DistributionPointName,AccessDescription};
+pub use self::extensions::{PolicyInformation,PolicyQualifierInfo};
+pub use self::extensions::{NameConstraints,GeneralSubtree,PolicyConstraints};
+pub use self::extensions::{CrlReason,IssuingDistributionPoint};
+pub use self::general_name::{GeneralName,OtherName,EdiPartyName};
+pub use self::keys::{OneAsymmetricKey,PrivateKeyInfo,EncryptedPrivateKeyInfo};
+pub use self::keys::{RSAPublicKey,RSAPrivateKey,OtherPrimeInfo,ECPrivateKey};
+pub use self::name::{Name,RelativeDistinguishedName,AttributeTypeAndValue};
+pub use self::name::ParseNameError;
+pub use self::ocsp::{OCSPRequest,TBSRequest,Request,CertID,OCSPSignature};
+pub use self::ocsp::{OCSPResponse,OCSPResponseStatus,ResponseBytes};
+pub use self::ocsp::{BasicOCSPResponse,ResponseData,ResponderID};
+pub use self::ocsp::{SingleResponse,CertStatus,RevokedInfo};
+pub use self::pkcs12::{PFX,MacData,DigestInfo,SafeBag,SafeBagValue};
+pub use self::pkcs12::{CertBag,CrlBag,SecretBag};
+pub use self::request::{CertificationRequest,CertificationRequestInfo};
+pub use self::tsp::{TimeStampReq,MessageImprint,TimeStampResp};
+pub use self::tsp::{PKIStatus,PKIStatusInfo,TSTInfo,Accuracy};
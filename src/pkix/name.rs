@@ -0,0 +1,573 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Write};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use super::super::{ASN1Result, BERDecodable, BERReader, DEREncodable, DERWriter};
+use super::super::{decode_der, encode_der, oid, parse_der};
+use super::super::models::{DirectoryString, ObjectIdentifier, OidBuf};
+use super::super::models::{PrintableString, TaggedDerValue};
+use super::super::tags::{TAG_IA5STRING, TAG_PRINTABLESTRING};
+use super::super::tags::{TAG_TELETEXSTRING, TAG_UNIVERSALSTRING, TAG_UTF8STRING};
+
+/// The attribute types with a short name in RFC 4514.
+static KEYWORDS : &[(&str, OidBuf)] = &[
+    ("CN", oid!("2.5.4.3")),
+    ("L", oid!("2.5.4.7")),
+    ("ST", oid!("2.5.4.8")),
+    ("O", oid!("2.5.4.10")),
+    ("OU", oid!("2.5.4.11")),
+    ("C", oid!("2.5.4.6")),
+    ("STREET", oid!("2.5.4.9")),
+    ("DC", oid!("0.9.2342.19200300.100.1.25")),
+    ("UID", oid!("0.9.2342.19200300.100.1.1")),
+];
+
+/// The attribute types whose values are PrintableString (RFC 5280
+/// Appendix A.1).
+static PRINTABLE_TYPES : &[OidBuf] = &[
+    oid!("2.5.4.5"),
+    oid!("2.5.4.6"),
+    oid!("2.5.4.46"),
+];
+
+/// The attribute types whose values are IA5String (RFC 5280 Appendix A.1).
+static IA5_TYPES : &[OidBuf] = &[
+    oid!("0.9.2342.19200300.100.1.25"),
+    oid!("1.2.840.113549.1.9.1"),
+];
+
+/// An attribute in a distinguished name (RFC 5280 4.1.2.4).
+///
+/// ```text
+/// AttributeTypeAndValue ::= SEQUENCE {
+///   type     AttributeType,
+///   value    AttributeValue }
+///
+/// AttributeType ::= OBJECT IDENTIFIER
+///
+/// AttributeValue ::= ANY -- DEFINED BY AttributeType
+/// ```
+///
+/// The value is kept as a raw value, since most attributes are
+/// DirectoryStrings but some are not.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::ObjectIdentifier;
+/// use yasna::pkix::AttributeTypeAndValue;
+/// let country = AttributeTypeAndValue::from_string(
+///     ObjectIdentifier::from_slice(&[2, 5, 4, 6]), "JP");
+/// assert_eq!(yasna::encode_der(&country),
+///     &[48, 9, 6, 3, 85, 4, 6, 19, 2, 74, 80]);
+/// assert_eq!(country.value_string().unwrap(), "JP");
+/// assert_eq!(country.to_string(), "C=JP");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct AttributeTypeAndValue {
+    /// The type of the attribute
+    pub attr_type: ObjectIdentifier,
+    /// The value of the attribute
+    pub value: TaggedDerValue,
+}
+
+impl AttributeTypeAndValue {
+    /// Constructs an attribute with a string value.
+    ///
+    /// Following RFC 5280, the value is encoded as PrintableString for
+    /// countryName, serialNumber and dnQualifier, as IA5String for
+    /// domainComponent and emailAddress, and as UTF8String otherwise. It
+    /// falls back to UTF8String if the string has invalid characters for
+    /// the type.
+    pub fn from_string(attr_type: ObjectIdentifier, value: &str) -> Self {
+        let tag = if PRINTABLE_TYPES.iter().any(|&t| t == attr_type) &&
+                PrintableString::try_from(value).is_ok() {
+            TAG_PRINTABLESTRING
+        } else if IA5_TYPES.iter().any(|&t| t == attr_type) &&
+                value.is_ascii() {
+            TAG_IA5STRING
+        } else {
+            TAG_UTF8STRING
+        };
+        AttributeTypeAndValue {
+            attr_type,
+            value: TaggedDerValue::from_tag_and_bytes(
+                tag, value.as_bytes().to_vec()),
+        }
+    }
+
+    /// Returns the value as a string, if it is a character string.
+    ///
+    /// TeletexString is interpreted as Latin-1, as many implementations do.
+    pub fn value_string(&self) -> Option<String> {
+        let bytes = self.value.value();
+        match self.value.tag() {
+            TAG_TELETEXSTRING => {
+                return Some(bytes.iter().map(|&b| b as char).collect());
+            },
+            TAG_UNIVERSALSTRING => {
+                if bytes.len() % 4 != 0 {
+                    return None;
+                }
+                return bytes.chunks(4)
+                    .map(|c| char::from_u32(
+                        (c[0] as u32) << 24 | (c[1] as u32) << 16 |
+                        (c[2] as u32) << 8 | c[3] as u32))
+                    .collect();
+            },
+            _ => {},
+        }
+        let string : DirectoryString =
+            decode_der(&encode_der(&self.value)).ok()?;
+        return Some(String::from(string.as_str()));
+    }
+
+    /// Compares attributes by the rules in RFC 5280 7.1.
+    ///
+    /// Strings are compared case-insensitively, ignoring leading, trailing
+    /// and consecutive spaces, even if their types differ. Other values are
+    /// compared by their encodings.
+    pub fn matches(&self, other: &AttributeTypeAndValue) -> bool {
+        if self.attr_type != other.attr_type {
+            return false;
+        }
+        match (self.value_string(), other.value_string()) {
+            (Some(a), Some(b)) => prepare_string(&a) == prepare_string(&b),
+            _ => self.value == other.value,
+        }
+    }
+}
+
+/// Prepares a string for caseIgnoreMatch, roughly following RFC 4518.
+///
+/// Unicode normalization is not done.
+fn prepare_string(s: &str) -> String {
+    let mut prepared = String::with_capacity(s.len());
+    for word in s.split(' ').filter(|word| !word.is_empty()) {
+        if !prepared.is_empty() {
+            prepared.push(' ');
+        }
+        prepared.extend(word.chars().flat_map(char::to_lowercase));
+    }
+    return prepared;
+}
+
+impl BERDecodable for AttributeTypeAndValue {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let attr_type = ObjectIdentifier::decode_ber(reader.next())?;
+            let value = reader.next().read_tagged_der()?;
+            return Ok(AttributeTypeAndValue {
+                attr_type,
+                value,
+            });
+        })
+    }
+}
+
+impl DEREncodable for AttributeTypeAndValue {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.attr_type.encode_der(writer.next());
+            writer.next().write_tagged_der(&self.value);
+        })
+    }
+}
+
+impl Display for AttributeTypeAndValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match KEYWORDS.iter().find(|&&(_, t)| t == self.attr_type) {
+            Some(&(keyword, _)) => f.write_str(keyword)?,
+            None => write!(f, "{}", self.attr_type)?,
+        }
+        f.write_char('=')?;
+        let value = match self.value_string() {
+            Some(value) => value,
+            None => {
+                f.write_char('#')?;
+                for b in encode_der(&self.value) {
+                    write!(f, "{:02X}", b)?;
+                }
+                return Ok(());
+            },
+        };
+        let last = value.chars().count().saturating_sub(1);
+        for (i, c) in value.chars().enumerate() {
+            match c {
+                '"' | '+' | ',' | ';' | '<' | '>' | '\\' => {
+                    write!(f, "\\{}", c)?;
+                },
+                '\0' => f.write_str("\\00")?,
+                ' ' if i == 0 || i == last => f.write_str("\\ ")?,
+                '#' if i == 0 => f.write_str("\\#")?,
+                _ => f.write_char(c)?,
+            }
+        }
+        return Ok(());
+    }
+}
+
+/// A relative distinguished name (RFC 5280 4.1.2.4).
+///
+/// ```text
+/// RelativeDistinguishedName ::=
+///   SET SIZE (1..MAX) OF AttributeTypeAndValue
+/// ```
+///
+/// The attributes are sorted by their encodings when encoded in DER.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub struct RelativeDistinguishedName {
+    /// The attributes
+    pub attributes: Vec<AttributeTypeAndValue>,
+}
+
+impl RelativeDistinguishedName {
+    /// Compares RDNs by the rules in RFC 5280 7.1, regardless of the order
+    /// of the attributes.
+    pub fn matches(&self, other: &RelativeDistinguishedName) -> bool {
+        self.attributes.len() == other.attributes.len() &&
+            self.attributes.iter().all(|a| {
+                other.attributes.iter().any(|b| a.matches(b))
+            }) &&
+            other.attributes.iter().all(|b| {
+                self.attributes.iter().any(|a| a.matches(b))
+            })
+    }
+}
+
+impl BERDecodable for RelativeDistinguishedName {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let attributes = reader.collect_set_of(
+            AttributeTypeAndValue::decode_ber)?;
+        return Ok(RelativeDistinguishedName {
+            attributes,
+        });
+    }
+}
+
+impl DEREncodable for RelativeDistinguishedName {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_set_of(|writer| {
+            for attribute in &self.attributes {
+                attribute.encode_der(writer.next());
+            }
+        })
+    }
+}
+
+impl Display for RelativeDistinguishedName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        for (i, attribute) in self.attributes.iter().enumerate() {
+            if i > 0 {
+                f.write_char('+')?;
+            }
+            write!(f, "{}", attribute)?;
+        }
+        return Ok(());
+    }
+}
+
+/// A distinguished name (RFC 5280 4.1.2.4).
+///
+/// ```text
+/// Name ::= CHOICE { -- only one possibility for now --
+///   rdnSequence  RDNSequence }
+///
+/// RDNSequence ::= SEQUENCE OF RelativeDistinguishedName
+/// ```
+///
+/// A name is formatted and parsed as a string by RFC 4514, where the RDNs
+/// are in the reverse order. Attributes are written by the short names in
+/// RFC 4514 (`CN`, `O`, `C`, etc.) or by OIDs, and values which are not
+/// strings are written in the hexadecimal DER encoding after `#`. When
+/// parsing, spaces around `,` and `+` are ignored, and string values are
+/// encoded as described in
+/// [`AttributeTypeAndValue::from_string`].
+///
+/// `==` compares names exactly. Use [`matches`](Self::matches) to compare
+/// them as in certificate path validation.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::Name;
+/// let name : Name = "CN=Steve Kille,O=Isode Limited,C=GB".parse().unwrap();
+/// assert_eq!(name.rdns.len(), 3);
+/// assert_eq!(name.rdns[0].to_string(), "C=GB");
+/// let der = yasna::encode_der(&name);
+/// let decoded : Name = yasna::decode_der(&der).unwrap();
+/// assert_eq!(decoded.to_string(), "CN=Steve Kille,O=Isode Limited,C=GB");
+///
+/// let name : Name = r"CN=Before\0dAfter,DC=example,DC=net".parse().unwrap();
+/// assert_eq!(name.to_string(), "CN=Before\rAfter,DC=example,DC=net");
+/// let name : Name = "1.3.6.1.4.1.1466.0=#04024869,O=Test".parse().unwrap();
+/// assert_eq!(name.to_string(), "1.3.6.1.4.1.1466.0=#04024869,O=Test");
+/// assert!(name.matches(&"1.3.6.1.4.1.1466.0=#04024869, o=TEST".parse().unwrap()));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub struct Name {
+    /// The RDNs, from the most significant one
+    pub rdns: Vec<RelativeDistinguishedName>,
+}
+
+impl Name {
+    /// Returns `true` if the name has no RDNs.
+    pub fn is_empty(&self) -> bool {
+        self.rdns.is_empty()
+    }
+
+    /// Iterates over all the attributes, from the most significant RDN.
+    pub fn attributes(&self) -> impl Iterator<Item = &AttributeTypeAndValue> {
+        self.rdns.iter().flat_map(|rdn| rdn.attributes.iter())
+    }
+
+    /// Compares names by the rules in RFC 5280 7.1.
+    pub fn matches(&self, other: &Name) -> bool {
+        self.rdns.len() == other.rdns.len() &&
+            self.rdns.iter().zip(&other.rdns).all(|(a, b)| a.matches(b))
+    }
+}
+
+impl BERDecodable for Name {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        let rdns = reader.collect_sequence_of(
+            RelativeDistinguishedName::decode_ber)?;
+        return Ok(Name {
+            rdns,
+        });
+    }
+}
+
+impl DEREncodable for Name {
+    fn encode_der(&self, writer: DERWriter) {
+        self.rdns.encode_der(writer)
+    }
+}
+
+impl Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        for (i, rdn) in self.rdns.iter().rev().enumerate() {
+            if i > 0 {
+                f.write_char(',')?;
+            }
+            write!(f, "{}", rdn)?;
+        }
+        return Ok(());
+    }
+}
+
+/// An error indicating failure to parse a distinguished name.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::Name;
+/// let err = "CN=foo,O".parse::<Name>().unwrap_err();
+/// assert_eq!(err.position(), 8);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseNameError {
+    position: usize,
+    message: &'static str,
+}
+
+impl ParseNameError {
+    fn new(position: usize, message: &'static str) -> Self {
+        ParseNameError {
+            position,
+            message,
+        }
+    }
+
+    /// Returns the byte offset in the input where the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseNameError {}
+
+impl Display for ParseNameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{} at byte {}", self.message, self.position)
+    }
+}
+
+impl FromStr for Name {
+    type Err = ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            bytes: s.as_bytes(),
+            pos: 0,
+        };
+        let mut rdns = Vec::new();
+        parser.skip_spaces();
+        if parser.peek().is_none() {
+            return Ok(Name::default());
+        }
+        loop {
+            rdns.push(parser.parse_rdn()?);
+            match parser.peek() {
+                Some(b',') => parser.pos += 1,
+                None => break,
+                Some(_) => {
+                    return Err(ParseNameError::new(parser.pos,
+                        "expected ','"));
+                },
+            }
+            parser.skip_spaces();
+        }
+        rdns.reverse();
+        return Ok(Name {
+            rdns,
+        });
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).cloned()
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek() == Some(b' ') {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_rdn(&mut self) -> Result<RelativeDistinguishedName, ParseNameError> {
+        let mut attributes = Vec::new();
+        loop {
+            attributes.push(self.parse_attribute()?);
+            if self.peek() != Some(b'+') {
+                break;
+            }
+            self.pos += 1;
+            self.skip_spaces();
+        }
+        return Ok(RelativeDistinguishedName {
+            attributes,
+        });
+    }
+
+    fn parse_attribute(&mut self) -> Result<AttributeTypeAndValue, ParseNameError> {
+        let start = self.pos;
+        while let Some(b) = self.peek() {
+            if !(b.is_ascii_alphanumeric() || b == b'-' || b == b'.') {
+                break;
+            }
+            self.pos += 1;
+        }
+        // The input is ASCII up to here.
+        let name = core::str::from_utf8(&self.bytes[start..self.pos]).unwrap();
+        let attr_type = if name.starts_with(|c: char| c.is_ascii_digit()) {
+            ObjectIdentifier::from_str(name).map_err(|_| {
+                ParseNameError::new(start, "invalid OID")
+            })?
+        } else {
+            let &(_, oid) = KEYWORDS.iter()
+                .find(|&&(keyword, _)| keyword.eq_ignore_ascii_case(name))
+                .ok_or(ParseNameError::new(start, "unknown attribute type"))?;
+            ObjectIdentifier::from(oid)
+        };
+        if self.peek() != Some(b'=') {
+            return Err(ParseNameError::new(self.pos, "expected '='"));
+        }
+        self.pos += 1;
+        if self.peek() == Some(b'#') {
+            self.pos += 1;
+            let value = self.parse_hex_value()?;
+            return Ok(AttributeTypeAndValue {
+                attr_type,
+                value,
+            });
+        }
+        let value = self.parse_string_value()?;
+        return Ok(AttributeTypeAndValue::from_string(attr_type, &value));
+    }
+
+    fn parse_hex_value(&mut self) -> Result<TaggedDerValue, ParseNameError> {
+        let start = self.pos;
+        let mut der = Vec::new();
+        while let Some(high) = self.peek().and_then(hex_digit) {
+            let low = self.bytes.get(self.pos + 1).cloned().and_then(hex_digit)
+                .ok_or(ParseNameError::new(self.pos + 1,
+                    "invalid hexadecimal digit"))?;
+            der.push(high << 4 | low);
+            self.pos += 2;
+        }
+        let value = parse_der(&der, |reader| reader.read_tagged_der())
+            .map_err(|_| ParseNameError::new(start, "invalid DER value"))?;
+        self.skip_spaces();
+        return Ok(value);
+    }
+
+    fn parse_string_value(&mut self) -> Result<String, ParseNameError> {
+        let start = self.pos;
+        let mut bytes = Vec::new();
+        // The length of the value without unescaped trailing spaces.
+        let mut len = 0;
+        while let Some(b) = self.peek() {
+            match b {
+                b',' | b'+' => break,
+                b'\\' => {
+                    self.pos += 1;
+                    let c = self.peek().ok_or(ParseNameError::new(self.pos,
+                        "unterminated escape"))?;
+                    if let Some(high) = hex_digit(c) {
+                        let low = self.bytes.get(self.pos + 1).cloned()
+                            .and_then(hex_digit)
+                            .ok_or(ParseNameError::new(self.pos + 1,
+                                "invalid hexadecimal digit"))?;
+                        bytes.push(high << 4 | low);
+                        self.pos += 2;
+                    } else if b"\"+,;<>\\ #=".contains(&c) {
+                        bytes.push(c);
+                        self.pos += 1;
+                    } else {
+                        return Err(ParseNameError::new(self.pos,
+                            "invalid escape"));
+                    }
+                    len = bytes.len();
+                },
+                b'"' | b';' | b'<' | b'>' | b'\0' => {
+                    return Err(ParseNameError::new(self.pos,
+                        "unescaped special character"));
+                },
+                _ => {
+                    bytes.push(b);
+                    self.pos += 1;
+                    if b != b' ' {
+                        len = bytes.len();
+                    }
+                },
+            }
+        }
+        bytes.truncate(len);
+        return String::from_utf8(bytes).map_err(|_| {
+            ParseNameError::new(start, "invalid UTF-8")
+        });
+    }
+}
+
+fn hex_digit(b: u8) -> Option<u8> {
+    (b as char).to_digit(16).map(|d| d as u8)
+}
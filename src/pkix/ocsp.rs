@@ -0,0 +1,1009 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec::Vec;
+
+use super::super::{ASN1Error, ASN1ErrorKind, ASN1Result, PCBit, Tag};
+use super::super::{BERDecodable, BERReader, BERReaderSeq, ChoiceAlternative};
+use super::super::{DEREncodable, DERWriter};
+use super::super::{decode_der, encode_der};
+use super::super::models::{BitString, GeneralizedTime, ObjectIdentifier, WithRaw};
+use super::{AlgorithmIdentifier, Certificate, CrlReason, Extension};
+use super::{ExtensionValue, GeneralName, Name, SerialNumber};
+use super::oids::{ID_PKIX_OCSP_BASIC, ID_PKIX_OCSP_NONCE};
+
+/// Reads `version [0] EXPLICIT Version DEFAULT v1`, where v1 is the only
+/// version defined.
+fn read_version(reader: &mut BERReaderSeq) -> ASN1Result<()> {
+    let version = reader.read_default(0, |reader| {
+        reader.read_tagged(Tag::context(0), |reader| reader.read_i64())
+    })?;
+    if version != 0 {
+        return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+    }
+    return Ok(());
+}
+
+/// Decodes the nonce extension among `extensions`, if any.
+fn find_nonce(extensions: &[Extension]) -> ASN1Result<Option<Vec<u8>>> {
+    let extension = match extensions.iter().find(|extension| {
+        extension.extn_id == ID_PKIX_OCSP_NONCE
+    }) {
+        Some(extension) => extension,
+        None => return Ok(None),
+    };
+    match extension.parse_value()? {
+        Some(ExtensionValue::OcspNonce(nonce)) => return Ok(Some(nonce)),
+        _ => unreachable!(),
+    }
+}
+
+/// Replaces the nonce extension among `extensions` with `nonce`.
+fn replace_nonce(extensions: &mut Vec<Extension>, nonce: &[u8]) {
+    extensions.retain(|extension| extension.extn_id != ID_PKIX_OCSP_NONCE);
+    extensions.push(Extension::new(
+        &ExtensionValue::OcspNonce(nonce.to_vec()), false));
+}
+
+/// The identifier of a certificate whose status is requested
+/// (RFC 6960 4.1.1).
+///
+/// ```text
+/// CertID          ::=     SEQUENCE {
+///     hashAlgorithm       AlgorithmIdentifier,
+///     issuerNameHash      OCTET STRING, -- Hash of issuer's DN
+///     issuerKeyHash       OCTET STRING, -- Hash of issuer's public key
+///     serialNumber        CertificateSerialNumber }
+/// ```
+///
+/// Responders match the hashes exactly, so a `CertID` in a response is
+/// compared with the one in the request by `==`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CertID {
+    /// The hash algorithm used for the hashes below
+    pub hash_algorithm: AlgorithmIdentifier,
+    /// The hash of the DER encoding of the issuer's name
+    pub issuer_name_hash: Vec<u8>,
+    /// The hash of the issuer's public key, excluding the tag, the length
+    /// and the number of unused bits of the BIT STRING
+    pub issuer_key_hash: Vec<u8>,
+    /// The serial number of the certificate
+    pub serial_number: SerialNumber,
+}
+
+impl CertID {
+    /// Constructs a certificate identifier from the issuer's certificate,
+    /// computing the hashes with `hash`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::models::ObjectIdentifier;
+    /// use yasna::pkix::{AlgorithmIdentifier, Certificate, CertID};
+    /// # let der = yasna::text::decode_base64("
+    /// # MIIBbjCCAROgAwIBAgICEjQwCgYIKoZIzj0EAwIwFTETMBEGA1UEAwwKeWFzbmEg
+    /// # dGVzdDAeFw0yNjEwMTkwMzQwNTlaFw0zNjEwMTYwMzQwNTlaMBUxEzARBgNVBAMM
+    /// # Cnlhc25hIHRlc3QwWTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAARqbYGR/kr1mDKF
+    /// # cwsMFY8no84X2EhfiKrS6xhK2Qv5GOim9MunHv3PJrLZ5HqsoYwn3j2AqLfJ3ceG
+    /// # 54YlUDFHo1MwUTAdBgNVHQ4EFgQUbJkAgmk2a234gusI4/cg3nGyMxcwHwYDVR0j
+    /// # BBgwFoAUbJkAgmk2a234gusI4/cg3nGyMxcwDwYDVR0TAQH/BAUwAwEB/zAKBggq
+    /// # hkjOPQQDAgNJADBGAiEAjEuBoFU955ZrwwUyI5e44/xkgElnP4hZ8XK6ldk+wU4C
+    /// # IQDTe5zc47K0boFOuXJ6UgaB/Z2DUQDEm+XVo2L5II6rgw==").unwrap();
+    /// let issuer : Certificate = yasna::decode_der(&der).unwrap();
+    /// let sha1 = AlgorithmIdentifier::new(
+    ///     ObjectIdentifier::from_slice(&[1, 3, 14, 3, 2, 26]));
+    /// let cert_id = CertID::new(sha1, &issuer, 0x1235.into(), |bytes| {
+    ///     // Compute the SHA-1 digest of `bytes` here.
+    ///     vec![0; 20]
+    /// });
+    /// assert_eq!(cert_id.serial_number.to_u64(), Some(0x1235));
+    /// ```
+    pub fn new<F>(hash_algorithm: AlgorithmIdentifier, issuer: &Certificate,
+            serial_number: SerialNumber, mut hash: F) -> Self
+            where F: FnMut(&[u8]) -> Vec<u8> {
+        let issuer = issuer.tbs_certificate.value();
+        let issuer_name_hash = hash(&encode_der(&issuer.subject));
+        let issuer_key_hash = hash(
+            issuer.subject_public_key_info.subject_public_key.as_bytes());
+        return CertID {
+            hash_algorithm,
+            issuer_name_hash,
+            issuer_key_hash,
+            serial_number,
+        };
+    }
+}
+
+impl BERDecodable for CertID {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let hash_algorithm = AlgorithmIdentifier::decode_ber(reader.next())?;
+            let issuer_name_hash = reader.next().read_bytes()?;
+            let issuer_key_hash = reader.next().read_bytes()?;
+            let serial_number = SerialNumber::decode_ber(reader.next())?;
+            return Ok(CertID {
+                hash_algorithm,
+                issuer_name_hash,
+                issuer_key_hash,
+                serial_number,
+            });
+        })
+    }
+}
+
+impl DEREncodable for CertID {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.hash_algorithm.encode_der(writer.next());
+            writer.next().write_bytes(&self.issuer_name_hash);
+            writer.next().write_bytes(&self.issuer_key_hash);
+            self.serial_number.encode_der(writer.next());
+        })
+    }
+}
+
+/// A single certificate whose status is requested (RFC 6960 4.1.1).
+///
+/// ```text
+/// Request         ::=     SEQUENCE {
+///     reqCert                     CertID,
+///     singleRequestExtensions     [0] EXPLICIT Extensions OPTIONAL }
+/// ```
+///
+/// The extensions are omitted when encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Request {
+    /// The certificate
+    pub req_cert: CertID,
+    /// The extensions for this certificate
+    pub single_request_extensions: Vec<Extension>,
+}
+
+impl BERDecodable for Request {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let req_cert = CertID::decode_ber(reader.next())?;
+            let single_request_extensions = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0), Vec::decode_ber)
+            })?;
+            return Ok(Request {
+                req_cert,
+                single_request_extensions:
+                    single_request_extensions.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for Request {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.req_cert.encode_der(writer.next());
+            if !self.single_request_extensions.is_empty() {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    self.single_request_extensions.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// The signed part of an OCSP request (RFC 6960 4.1.1).
+///
+/// ```text
+/// TBSRequest      ::=     SEQUENCE {
+///     version             [0]     EXPLICIT Version DEFAULT v1,
+///     requestorName       [1]     EXPLICIT GeneralName OPTIONAL,
+///     requestList                 SEQUENCE OF Request,
+///     requestExtensions   [2]     EXPLICIT Extensions OPTIONAL }
+///
+/// Version         ::=             INTEGER  {  v1(0) }
+/// ```
+///
+/// The version is not stored, since v1 is the only version. The extensions
+/// are omitted when encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TBSRequest {
+    /// The name of the requestor, required if the request is signed
+    pub requestor_name: Option<GeneralName>,
+    /// The certificates whose statuses are requested
+    pub request_list: Vec<Request>,
+    /// The extensions, such as the nonce
+    pub request_extensions: Vec<Extension>,
+}
+
+impl TBSRequest {
+    /// Returns the request extension with the given OID.
+    pub fn extension(&self, extn_id: &ObjectIdentifier) -> Option<&Extension> {
+        self.request_extensions.iter().find(|extension| {
+            extension.extn_id == *extn_id
+        })
+    }
+
+    /// Decodes the nonce extension (RFC 6960 4.4.1).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the extension is not an OCTET STRING.
+    pub fn nonce(&self) -> ASN1Result<Option<Vec<u8>>> {
+        find_nonce(&self.request_extensions)
+    }
+
+    /// Sets the nonce extension, replacing the existing one if any.
+    pub fn set_nonce(&mut self, nonce: &[u8]) {
+        replace_nonce(&mut self.request_extensions, nonce)
+    }
+}
+
+impl BERDecodable for TBSRequest {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            read_version(reader)?;
+            let requestor_name = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(1), GeneralName::decode_ber)
+            })?;
+            let request_list = Vec::decode_ber(reader.next())?;
+            let request_extensions = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(2), Vec::decode_ber)
+            })?;
+            return Ok(TBSRequest {
+                requestor_name,
+                request_list,
+                request_extensions: request_extensions.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for TBSRequest {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            if let Some(ref requestor_name) = self.requestor_name {
+                writer.next().write_tagged(Tag::context(1), |writer| {
+                    requestor_name.encode_der(writer)
+                });
+            }
+            self.request_list.encode_der(writer.next());
+            if !self.request_extensions.is_empty() {
+                writer.next().write_tagged(Tag::context(2), |writer| {
+                    self.request_extensions.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// The signature of an OCSP request (RFC 6960 4.1.1).
+///
+/// ```text
+/// Signature       ::=     SEQUENCE {
+///     signatureAlgorithm      AlgorithmIdentifier,
+///     signature               BIT STRING,
+///     certs               [0] EXPLICIT SEQUENCE OF Certificate OPTIONAL }
+/// ```
+///
+/// The certificates are omitted when encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct OCSPSignature {
+    /// The signature algorithm
+    pub signature_algorithm: AlgorithmIdentifier,
+    /// The signature
+    pub signature: BitString,
+    /// The certificates to help the responder verify the signature
+    pub certs: Vec<Certificate>,
+}
+
+impl BERDecodable for OCSPSignature {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let signature_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let signature = BitString::decode_ber(reader.next())?;
+            let certs = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0), Vec::decode_ber)
+            })?;
+            return Ok(OCSPSignature {
+                signature_algorithm,
+                signature,
+                certs: certs.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for OCSPSignature {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.signature_algorithm.encode_der(writer.next());
+            self.signature.encode_der(writer.next());
+            if !self.certs.is_empty() {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    self.certs.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// An OCSP request (RFC 6960 4.1.1).
+///
+/// ```text
+/// OCSPRequest     ::=     SEQUENCE {
+///     tbsRequest                  TBSRequest,
+///     optionalSignature   [0]     EXPLICIT Signature OPTIONAL }
+/// ```
+///
+/// The TBSRequest is kept with its original encoding, which is written
+/// back verbatim when encoded. The signature, if any, is computed over
+/// [`tbs_bytes`](Self::tbs_bytes).
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::ObjectIdentifier;
+/// use yasna::pkix::{AlgorithmIdentifier, CertID, OCSPRequest, Request};
+/// use yasna::pkix::TBSRequest;
+/// let sha1 = AlgorithmIdentifier::new(
+///     ObjectIdentifier::from_slice(&[1, 3, 14, 3, 2, 26]));
+/// let mut tbs_request = TBSRequest {
+///     requestor_name: None,
+///     request_list: vec![Request {
+///         req_cert: CertID {
+///             hash_algorithm: sha1,
+///             issuer_name_hash: vec![0; 20],
+///             issuer_key_hash: vec![0; 20],
+///             serial_number: 0x1234.into(),
+///         },
+///         single_request_extensions: vec![],
+///     }],
+///     request_extensions: vec![],
+/// };
+/// tbs_request.set_nonce(&[1; 16]);
+/// let request = OCSPRequest::new(tbs_request);
+/// let der = yasna::encode_der(&request);
+/// let decoded : OCSPRequest = yasna::decode_der(&der).unwrap();
+/// let tbs_request = decoded.tbs_request.value();
+/// assert_eq!(tbs_request.nonce().unwrap(), Some(vec![1; 16]));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct OCSPRequest {
+    /// The signed part, with its encoding
+    pub tbs_request: WithRaw<TBSRequest>,
+    /// The signature
+    pub optional_signature: Option<OCSPSignature>,
+}
+
+impl OCSPRequest {
+    /// Constructs an unsigned request.
+    pub fn new(tbs_request: TBSRequest) -> Self {
+        OCSPRequest {
+            tbs_request: WithRaw::new(tbs_request),
+            optional_signature: None,
+        }
+    }
+
+    /// Constructs a signed request by encoding `tbs_request` in DER and
+    /// signing the encoding with `sign`.
+    ///
+    /// `tbs_request.requestor_name` should be set for a signed request.
+    pub fn sign<F, E>(tbs_request: TBSRequest,
+            signature_algorithm: AlgorithmIdentifier, certs: Vec<Certificate>,
+            sign: F) -> Result<Self, E>
+            where F: FnOnce(&[u8]) -> Result<Vec<u8>, E> {
+        let tbs_request = WithRaw::new(tbs_request);
+        let signature = BitString::from_bytes(sign(tbs_request.raw())?);
+        return Ok(OCSPRequest {
+            tbs_request,
+            optional_signature: Some(OCSPSignature {
+                signature_algorithm,
+                signature,
+                certs,
+            }),
+        });
+    }
+
+    /// Returns the encoding of the TBSRequest, over which the signature
+    /// is computed.
+    pub fn tbs_bytes(&self) -> &[u8] {
+        self.tbs_request.raw()
+    }
+}
+
+impl BERDecodable for OCSPRequest {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let tbs_request = WithRaw::decode_ber(reader.next())?;
+            let optional_signature = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0), OCSPSignature::decode_ber)
+            })?;
+            return Ok(OCSPRequest {
+                tbs_request,
+                optional_signature,
+            });
+        })
+    }
+}
+
+impl DEREncodable for OCSPRequest {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.tbs_request.encode_der(writer.next());
+            if let Some(ref optional_signature) = self.optional_signature {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    optional_signature.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// The processing status of an OCSP request (RFC 6960 4.2.1).
+///
+/// ```text
+/// OCSPResponseStatus ::= ENUMERATED {
+///     successful            (0),  -- Response has valid confirmations
+///     malformedRequest      (1),  -- Illegal confirmation request
+///     internalError         (2),  -- Internal error in issuer
+///     tryLater              (3),  -- Try again later
+///                                 -- (4) is not used
+///     sigRequired           (5),  -- Must sign the request
+///     unauthorized          (6)   -- Request unauthorized
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum OCSPResponseStatus {
+    /// `successful`
+    Successful = 0,
+    /// `malformedRequest`
+    MalformedRequest = 1,
+    /// `internalError`
+    InternalError = 2,
+    /// `tryLater`
+    TryLater = 3,
+    /// `sigRequired`
+    SigRequired = 5,
+    /// `unauthorized`
+    Unauthorized = 6,
+}
+
+impl BERDecodable for OCSPResponseStatus {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        match reader.read_enum()? {
+            0 => Ok(OCSPResponseStatus::Successful),
+            1 => Ok(OCSPResponseStatus::MalformedRequest),
+            2 => Ok(OCSPResponseStatus::InternalError),
+            3 => Ok(OCSPResponseStatus::TryLater),
+            5 => Ok(OCSPResponseStatus::SigRequired),
+            6 => Ok(OCSPResponseStatus::Unauthorized),
+            _ => Err(ASN1Error::new(ASN1ErrorKind::Invalid)),
+        }
+    }
+}
+
+impl DEREncodable for OCSPResponseStatus {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_enum(*self as i64)
+    }
+}
+
+/// The body of a successful OCSP response (RFC 6960 4.2.1).
+///
+/// ```text
+/// ResponseBytes ::=       SEQUENCE {
+///     responseType   OBJECT IDENTIFIER,
+///     response       OCTET STRING }
+/// ```
+///
+/// The response of type `id-pkix-ocsp-basic` is decoded by
+/// [`OCSPResponse::basic_response`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ResponseBytes {
+    /// The type of the response
+    pub response_type: ObjectIdentifier,
+    /// The DER encoding of the response
+    pub response: Vec<u8>,
+}
+
+impl BERDecodable for ResponseBytes {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let response_type = ObjectIdentifier::decode_ber(reader.next())?;
+            let response = reader.next().read_bytes()?;
+            return Ok(ResponseBytes {
+                response_type,
+                response,
+            });
+        })
+    }
+}
+
+impl DEREncodable for ResponseBytes {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.response_type.encode_der(writer.next());
+            writer.next().write_bytes(&self.response);
+        })
+    }
+}
+
+/// An OCSP response (RFC 6960 4.2.1).
+///
+/// ```text
+/// OCSPResponse ::= SEQUENCE {
+///    responseStatus         OCSPResponseStatus,
+///    responseBytes          [0] EXPLICIT ResponseBytes OPTIONAL }
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::{OCSPResponse, OCSPResponseStatus};
+/// let response : OCSPResponse =
+///     yasna::decode_der(&[48, 3, 10, 1, 3]).unwrap();
+/// assert_eq!(response.response_status, OCSPResponseStatus::TryLater);
+/// assert_eq!(response.basic_response().unwrap(), None);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct OCSPResponse {
+    /// The processing status
+    pub response_status: OCSPResponseStatus,
+    /// The body, present if the status is `Successful`
+    pub response_bytes: Option<ResponseBytes>,
+}
+
+impl OCSPResponse {
+    /// Constructs a successful response with a basic response.
+    pub fn from_basic_response(basic_response: &BasicOCSPResponse) -> Self {
+        OCSPResponse {
+            response_status: OCSPResponseStatus::Successful,
+            response_bytes: Some(ResponseBytes {
+                response_type: ID_PKIX_OCSP_BASIC.into(),
+                response: encode_der(basic_response),
+            }),
+        }
+    }
+
+    /// Decodes the basic response, or returns `None` if there is no body
+    /// or the body is of another type.
+    pub fn basic_response(&self) -> ASN1Result<Option<BasicOCSPResponse>> {
+        let response_bytes = match self.response_bytes {
+            Some(ref response_bytes) => response_bytes,
+            None => return Ok(None),
+        };
+        if response_bytes.response_type != ID_PKIX_OCSP_BASIC {
+            return Ok(None);
+        }
+        return Ok(Some(decode_der(&response_bytes.response)?));
+    }
+}
+
+impl BERDecodable for OCSPResponse {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let response_status = OCSPResponseStatus::decode_ber(reader.next())?;
+            let response_bytes = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0), ResponseBytes::decode_ber)
+            })?;
+            return Ok(OCSPResponse {
+                response_status,
+                response_bytes,
+            });
+        })
+    }
+}
+
+impl DEREncodable for OCSPResponse {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.response_status.encode_der(writer.next());
+            if let Some(ref response_bytes) = self.response_bytes {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    response_bytes.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// The basic OCSP response (RFC 6960 4.2.1).
+///
+/// ```text
+/// BasicOCSPResponse       ::= SEQUENCE {
+///    tbsResponseData      ResponseData,
+///    signatureAlgorithm   AlgorithmIdentifier,
+///    signature            BIT STRING,
+///    certs            [0] EXPLICIT SEQUENCE OF Certificate OPTIONAL }
+/// ```
+///
+/// The ResponseData is kept with its original encoding, which is written
+/// back verbatim when encoded. The signature is computed over
+/// [`tbs_bytes`](Self::tbs_bytes). The certificates are omitted when
+/// encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct BasicOCSPResponse {
+    /// The signed part, with its encoding
+    pub tbs_response_data: WithRaw<ResponseData>,
+    /// The signature algorithm
+    pub signature_algorithm: AlgorithmIdentifier,
+    /// The signature
+    pub signature: BitString,
+    /// The certificates to help verify the signature, such as the
+    /// certificate of a delegated responder
+    pub certs: Vec<Certificate>,
+}
+
+impl BasicOCSPResponse {
+    /// Constructs a basic response by encoding `tbs_response_data` in DER
+    /// and signing the encoding with `sign`.
+    pub fn sign<F, E>(tbs_response_data: ResponseData,
+            signature_algorithm: AlgorithmIdentifier, certs: Vec<Certificate>,
+            sign: F) -> Result<Self, E>
+            where F: FnOnce(&[u8]) -> Result<Vec<u8>, E> {
+        let tbs_response_data = WithRaw::new(tbs_response_data);
+        let signature = BitString::from_bytes(sign(tbs_response_data.raw())?);
+        return Ok(BasicOCSPResponse {
+            tbs_response_data,
+            signature_algorithm,
+            signature,
+            certs,
+        });
+    }
+
+    /// Returns the encoding of the ResponseData, over which the signature
+    /// is computed.
+    pub fn tbs_bytes(&self) -> &[u8] {
+        self.tbs_response_data.raw()
+    }
+}
+
+impl BERDecodable for BasicOCSPResponse {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let tbs_response_data = WithRaw::decode_ber(reader.next())?;
+            let signature_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let signature = BitString::decode_ber(reader.next())?;
+            let certs = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0), Vec::decode_ber)
+            })?;
+            return Ok(BasicOCSPResponse {
+                tbs_response_data,
+                signature_algorithm,
+                signature,
+                certs: certs.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for BasicOCSPResponse {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.tbs_response_data.encode_der(writer.next());
+            self.signature_algorithm.encode_der(writer.next());
+            self.signature.encode_der(writer.next());
+            if !self.certs.is_empty() {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    self.certs.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// The identifier of an OCSP responder (RFC 6960 4.2.1).
+///
+/// ```text
+/// ResponderID ::= CHOICE {
+///    byName   [1] Name,
+///    byKey    [2] KeyHash }
+///
+/// KeyHash ::= OCTET STRING -- SHA-1 hash of responder's public key
+///                          -- (excluding the tag and length fields)
+/// ```
+///
+/// The tags are EXPLICIT, as the ASN.1 module uses EXPLICIT TAGS.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ResponderID {
+    /// The name of the responder
+    ByName(Name),
+    /// The SHA-1 hash of the responder's public key
+    ByKey(Vec<u8>),
+}
+
+impl BERDecodable for ResponderID {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_choice([
+            ChoiceAlternative::new(Tag::context(1), PCBit::Constructed,
+                |reader| Ok(ResponderID::ByName(
+                    reader.read_tagged(Tag::context(1), Name::decode_ber)?))),
+            ChoiceAlternative::new(Tag::context(2), PCBit::Constructed,
+                |reader| Ok(ResponderID::ByKey(
+                    reader.read_tagged(Tag::context(2),
+                        |reader| reader.read_bytes())?))),
+        ])
+    }
+}
+
+impl DEREncodable for ResponderID {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            ResponderID::ByName(ref name) =>
+                writer.write_tagged(Tag::context(1), |writer| {
+                    name.encode_der(writer)
+                }),
+            ResponderID::ByKey(ref key_hash) =>
+                writer.write_tagged(Tag::context(2), |writer| {
+                    writer.write_bytes(key_hash)
+                }),
+        }
+    }
+}
+
+/// The signed part of a basic OCSP response (RFC 6960 4.2.1).
+///
+/// ```text
+/// ResponseData ::= SEQUENCE {
+///    version              [0] EXPLICIT Version DEFAULT v1,
+///    responderID              ResponderID,
+///    producedAt               GeneralizedTime,
+///    responses                SEQUENCE OF SingleResponse,
+///    responseExtensions   [1] EXPLICIT Extensions OPTIONAL }
+/// ```
+///
+/// The version is not stored, since v1 is the only version. The extensions
+/// are omitted when encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ResponseData {
+    /// The responder
+    pub responder_id: ResponderID,
+    /// When the response was signed
+    pub produced_at: GeneralizedTime,
+    /// The statuses of the requested certificates
+    pub responses: Vec<SingleResponse>,
+    /// The extensions, such as the nonce
+    pub response_extensions: Vec<Extension>,
+}
+
+impl ResponseData {
+    /// Returns the response extension with the given OID.
+    pub fn extension(&self, extn_id: &ObjectIdentifier) -> Option<&Extension> {
+        self.response_extensions.iter().find(|extension| {
+            extension.extn_id == *extn_id
+        })
+    }
+
+    /// Returns the status of the certificate identified by `cert_id`.
+    pub fn response(&self, cert_id: &CertID) -> Option<&SingleResponse> {
+        self.responses.iter().find(|response| response.cert_id == *cert_id)
+    }
+
+    /// Decodes the nonce extension (RFC 6960 4.4.1), which should be the
+    /// same as the one in the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the extension is not an OCTET STRING.
+    pub fn nonce(&self) -> ASN1Result<Option<Vec<u8>>> {
+        find_nonce(&self.response_extensions)
+    }
+
+    /// Sets the nonce extension, replacing the existing one if any.
+    pub fn set_nonce(&mut self, nonce: &[u8]) {
+        replace_nonce(&mut self.response_extensions, nonce)
+    }
+}
+
+impl BERDecodable for ResponseData {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            read_version(reader)?;
+            let responder_id = ResponderID::decode_ber(reader.next())?;
+            let produced_at = reader.next().read_generalized_time()?;
+            let responses = Vec::decode_ber(reader.next())?;
+            let response_extensions = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(1), Vec::decode_ber)
+            })?;
+            return Ok(ResponseData {
+                responder_id,
+                produced_at,
+                responses,
+                response_extensions: response_extensions.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for ResponseData {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.responder_id.encode_der(writer.next());
+            writer.next().write_generalized_time(&self.produced_at);
+            self.responses.encode_der(writer.next());
+            if !self.response_extensions.is_empty() {
+                writer.next().write_tagged(Tag::context(1), |writer| {
+                    self.response_extensions.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// The status of a single certificate (RFC 6960 4.2.1).
+///
+/// ```text
+/// SingleResponse ::= SEQUENCE {
+///    certID                       CertID,
+///    certStatus                   CertStatus,
+///    thisUpdate                   GeneralizedTime,
+///    nextUpdate         [0]       EXPLICIT GeneralizedTime OPTIONAL,
+///    singleExtensions   [1]       EXPLICIT Extensions OPTIONAL }
+/// ```
+///
+/// The extensions are omitted when encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SingleResponse {
+    /// The certificate
+    pub cert_id: CertID,
+    /// The status of the certificate
+    pub cert_status: CertStatus,
+    /// When the status was known to be correct
+    pub this_update: GeneralizedTime,
+    /// When newer information will be available
+    pub next_update: Option<GeneralizedTime>,
+    /// The extensions for this certificate
+    pub single_extensions: Vec<Extension>,
+}
+
+impl BERDecodable for SingleResponse {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let cert_id = CertID::decode_ber(reader.next())?;
+            let cert_status = CertStatus::decode_ber(reader.next())?;
+            let this_update = reader.next().read_generalized_time()?;
+            let next_update = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0),
+                    |reader| reader.read_generalized_time())
+            })?;
+            let single_extensions = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(1), Vec::decode_ber)
+            })?;
+            return Ok(SingleResponse {
+                cert_id,
+                cert_status,
+                this_update,
+                next_update,
+                single_extensions: single_extensions.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for SingleResponse {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.cert_id.encode_der(writer.next());
+            self.cert_status.encode_der(writer.next());
+            writer.next().write_generalized_time(&self.this_update);
+            if let Some(ref next_update) = self.next_update {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    writer.write_generalized_time(next_update)
+                });
+            }
+            if !self.single_extensions.is_empty() {
+                writer.next().write_tagged(Tag::context(1), |writer| {
+                    self.single_extensions.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// The revocation status of a certificate (RFC 6960 4.2.1).
+///
+/// ```text
+/// CertStatus ::= CHOICE {
+///     good        [0]     IMPLICIT NULL,
+///     revoked     [1]     IMPLICIT RevokedInfo,
+///     unknown     [2]     IMPLICIT UnknownInfo }
+///
+/// UnknownInfo ::= NULL
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum CertStatus {
+    /// The certificate is not revoked
+    Good,
+    /// The certificate is revoked
+    Revoked(RevokedInfo),
+    /// The responder doesn't know about the certificate
+    Unknown,
+}
+
+impl BERDecodable for CertStatus {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_choice([
+            ChoiceAlternative::new(Tag::context(0), PCBit::Primitive,
+                |reader| {
+                    reader.read_tagged_implicit(Tag::context(0),
+                        |reader| reader.read_null())?;
+                    return Ok(CertStatus::Good);
+                }),
+            ChoiceAlternative::new(Tag::context(1), PCBit::Constructed,
+                |reader| Ok(CertStatus::Revoked(reader.read_tagged_implicit(
+                    Tag::context(1), RevokedInfo::decode_ber)?))),
+            ChoiceAlternative::new(Tag::context(2), PCBit::Primitive,
+                |reader| {
+                    reader.read_tagged_implicit(Tag::context(2),
+                        |reader| reader.read_null())?;
+                    return Ok(CertStatus::Unknown);
+                }),
+        ])
+    }
+}
+
+impl DEREncodable for CertStatus {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            CertStatus::Good =>
+                writer.write_tagged_implicit(Tag::context(0), |writer| {
+                    writer.write_null()
+                }),
+            CertStatus::Revoked(ref revoked_info) =>
+                writer.write_tagged_implicit(Tag::context(1), |writer| {
+                    revoked_info.encode_der(writer)
+                }),
+            CertStatus::Unknown =>
+                writer.write_tagged_implicit(Tag::context(2), |writer| {
+                    writer.write_null()
+                }),
+        }
+    }
+}
+
+/// The details of a revocation (RFC 6960 4.2.1).
+///
+/// ```text
+/// RevokedInfo ::= SEQUENCE {
+///     revocationTime              GeneralizedTime,
+///     revocationReason    [0]     EXPLICIT CRLReason OPTIONAL }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RevokedInfo {
+    /// When the certificate was revoked
+    pub revocation_time: GeneralizedTime,
+    /// The reason for revocation
+    pub revocation_reason: Option<CrlReason>,
+}
+
+impl BERDecodable for RevokedInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let revocation_time = reader.next().read_generalized_time()?;
+            let revocation_reason = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0), CrlReason::decode_ber)
+            })?;
+            return Ok(RevokedInfo {
+                revocation_time,
+                revocation_reason,
+            });
+        })
+    }
+}
+
+impl DEREncodable for RevokedInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_generalized_time(&self.revocation_time);
+            if let Some(ref revocation_reason) = self.revocation_reason {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    revocation_reason.encode_der(writer)
+                });
+            }
+        })
+    }
+}
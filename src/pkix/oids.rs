@@ -0,0 +1,163 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Provides object identifiers used in PKIX.
+//!
+//! The constants are named after the ASN.1 value names, e.g.
+//! `id-ce-basicConstraints` as `ID_CE_BASIC_CONSTRAINTS`.
+//!
+//! # Examples
+//!
+//! ```
+//! use yasna::models::ObjectIdentifier;
+//! use yasna::pkix::oids::ID_CE_KEY_USAGE;
+//! let oid = ObjectIdentifier::from_slice(&[2, 5, 29, 15]);
+//! assert!(oid == ID_CE_KEY_USAGE);
+//! ```
+
+use super::super::models::OidBuf;
+use super::super::oid;
+
+/// id-ce-subjectKeyIdentifier (RFC 5280 4.2.1.2)
+pub const ID_CE_SUBJECT_KEY_IDENTIFIER : OidBuf = oid!("2.5.29.14");
+/// id-ce-keyUsage (RFC 5280 4.2.1.3)
+pub const ID_CE_KEY_USAGE : OidBuf = oid!("2.5.29.15");
+/// id-ce-subjectAltName (RFC 5280 4.2.1.6)
+pub const ID_CE_SUBJECT_ALT_NAME : OidBuf = oid!("2.5.29.17");
+/// id-ce-issuerAltName (RFC 5280 4.2.1.7)
+pub const ID_CE_ISSUER_ALT_NAME : OidBuf = oid!("2.5.29.18");
+/// id-ce-basicConstraints (RFC 5280 4.2.1.9)
+pub const ID_CE_BASIC_CONSTRAINTS : OidBuf = oid!("2.5.29.19");
+/// id-ce-cRLNumber (RFC 5280 5.2.3)
+pub const ID_CE_CRL_NUMBER : OidBuf = oid!("2.5.29.20");
+/// id-ce-cRLReasons (RFC 5280 5.3.1)
+pub const ID_CE_CRL_REASONS : OidBuf = oid!("2.5.29.21");
+/// id-ce-invalidityDate (RFC 5280 5.3.2)
+pub const ID_CE_INVALIDITY_DATE : OidBuf = oid!("2.5.29.24");
+/// id-ce-deltaCRLIndicator (RFC 5280 5.2.4)
+pub const ID_CE_DELTA_CRL_INDICATOR : OidBuf = oid!("2.5.29.27");
+/// id-ce-issuingDistributionPoint (RFC 5280 5.2.5)
+pub const ID_CE_ISSUING_DISTRIBUTION_POINT : OidBuf = oid!("2.5.29.28");
+/// id-ce-certificateIssuer (RFC 5280 5.3.3)
+pub const ID_CE_CERTIFICATE_ISSUER : OidBuf = oid!("2.5.29.29");
+/// id-ce-nameConstraints (RFC 5280 4.2.1.10)
+pub const ID_CE_NAME_CONSTRAINTS : OidBuf = oid!("2.5.29.30");
+/// id-ce-cRLDistributionPoints (RFC 5280 4.2.1.13)
+pub const ID_CE_CRL_DISTRIBUTION_POINTS : OidBuf = oid!("2.5.29.31");
+/// id-ce-certificatePolicies (RFC 5280 4.2.1.4)
+pub const ID_CE_CERTIFICATE_POLICIES : OidBuf = oid!("2.5.29.32");
+/// anyPolicy (RFC 5280 4.2.1.4)
+pub const ANY_POLICY : OidBuf = oid!("2.5.29.32.0");
+/// id-ce-authorityKeyIdentifier (RFC 5280 4.2.1.1)
+pub const ID_CE_AUTHORITY_KEY_IDENTIFIER : OidBuf = oid!("2.5.29.35");
+/// id-ce-policyConstraints (RFC 5280 4.2.1.11)
+pub const ID_CE_POLICY_CONSTRAINTS : OidBuf = oid!("2.5.29.36");
+/// id-ce-extKeyUsage (RFC 5280 4.2.1.12)
+pub const ID_CE_EXT_KEY_USAGE : OidBuf = oid!("2.5.29.37");
+/// anyExtendedKeyUsage (RFC 5280 4.2.1.12)
+pub const ANY_EXTENDED_KEY_USAGE : OidBuf = oid!("2.5.29.37.0");
+
+/// id-pe-authorityInfoAccess (RFC 5280 4.2.2.1)
+pub const ID_PE_AUTHORITY_INFO_ACCESS : OidBuf = oid!("1.3.6.1.5.5.7.1.1");
+
+/// id-qt-cps (RFC 5280 4.2.1.4)
+pub const ID_QT_CPS : OidBuf = oid!("1.3.6.1.5.5.7.2.1");
+/// id-qt-unotice (RFC 5280 4.2.1.4)
+pub const ID_QT_UNOTICE : OidBuf = oid!("1.3.6.1.5.5.7.2.2");
+
+/// id-kp-serverAuth (RFC 5280 4.2.1.12)
+pub const ID_KP_SERVER_AUTH : OidBuf = oid!("1.3.6.1.5.5.7.3.1");
+/// id-kp-clientAuth (RFC 5280 4.2.1.12)
+pub const ID_KP_CLIENT_AUTH : OidBuf = oid!("1.3.6.1.5.5.7.3.2");
+/// id-kp-codeSigning (RFC 5280 4.2.1.12)
+pub const ID_KP_CODE_SIGNING : OidBuf = oid!("1.3.6.1.5.5.7.3.3");
+/// id-kp-emailProtection (RFC 5280 4.2.1.12)
+pub const ID_KP_EMAIL_PROTECTION : OidBuf = oid!("1.3.6.1.5.5.7.3.4");
+/// id-kp-timeStamping (RFC 5280 4.2.1.12)
+pub const ID_KP_TIME_STAMPING : OidBuf = oid!("1.3.6.1.5.5.7.3.8");
+/// id-kp-OCSPSigning (RFC 5280 4.2.1.12)
+pub const ID_KP_OCSP_SIGNING : OidBuf = oid!("1.3.6.1.5.5.7.3.9");
+
+/// id-ad-ocsp (RFC 5280 4.2.2.1)
+pub const ID_AD_OCSP : OidBuf = oid!("1.3.6.1.5.5.7.48.1");
+/// id-ad-caIssuers (RFC 5280 4.2.2.1)
+pub const ID_AD_CA_ISSUERS : OidBuf = oid!("1.3.6.1.5.5.7.48.2");
+
+/// id-pkix-ocsp-basic (RFC 6960 4.2.1)
+pub const ID_PKIX_OCSP_BASIC : OidBuf = oid!("1.3.6.1.5.5.7.48.1.1");
+/// id-pkix-ocsp-nonce (RFC 6960 4.4.1)
+pub const ID_PKIX_OCSP_NONCE : OidBuf = oid!("1.3.6.1.5.5.7.48.1.2");
+
+/// pkcs-9-at-challengePassword (RFC 2985 5.4.1)
+pub const PKCS_9_AT_CHALLENGE_PASSWORD : OidBuf =
+    oid!("1.2.840.113549.1.9.7");
+/// pkcs-9-at-extensionRequest (RFC 2985 5.4.2)
+pub const PKCS_9_AT_EXTENSION_REQUEST : OidBuf =
+    oid!("1.2.840.113549.1.9.14");
+/// pkcs-9-at-friendlyName (RFC 2985 5.5.1)
+pub const PKCS_9_AT_FRIENDLY_NAME : OidBuf = oid!("1.2.840.113549.1.9.20");
+/// pkcs-9-at-localKeyId (RFC 2985 5.5.2)
+pub const PKCS_9_AT_LOCAL_KEY_ID : OidBuf = oid!("1.2.840.113549.1.9.21");
+
+/// rsaEncryption (RFC 8017 A.1)
+pub const RSA_ENCRYPTION : OidBuf = oid!("1.2.840.113549.1.1.1");
+/// id-ecPublicKey (RFC 5480 2.1.1)
+pub const ID_EC_PUBLIC_KEY : OidBuf = oid!("1.2.840.10045.2.1");
+/// secp256r1, or prime256v1 (RFC 5480 2.1.1.1)
+pub const SECP256R1 : OidBuf = oid!("1.2.840.10045.3.1.7");
+/// secp384r1 (RFC 5480 2.1.1.1)
+pub const SECP384R1 : OidBuf = oid!("1.3.132.0.34");
+/// secp521r1 (RFC 5480 2.1.1.1)
+pub const SECP521R1 : OidBuf = oid!("1.3.132.0.35");
+/// id-Ed25519 (RFC 8410 3)
+pub const ID_ED25519 : OidBuf = oid!("1.3.101.112");
+
+/// id-data (RFC 5652 4)
+pub const ID_DATA : OidBuf = oid!("1.2.840.113549.1.7.1");
+/// id-signedData (RFC 5652 5.1)
+pub const ID_SIGNED_DATA : OidBuf = oid!("1.2.840.113549.1.7.2");
+/// id-envelopedData (RFC 5652 6.1)
+pub const ID_ENVELOPED_DATA : OidBuf = oid!("1.2.840.113549.1.7.3");
+/// id-digestedData (RFC 5652 7)
+pub const ID_DIGESTED_DATA : OidBuf = oid!("1.2.840.113549.1.7.5");
+/// id-encryptedData (RFC 5652 8)
+pub const ID_ENCRYPTED_DATA : OidBuf = oid!("1.2.840.113549.1.7.6");
+/// id-contentType (RFC 5652 11.1)
+pub const ID_CONTENT_TYPE : OidBuf = oid!("1.2.840.113549.1.9.3");
+/// id-messageDigest (RFC 5652 11.2)
+pub const ID_MESSAGE_DIGEST : OidBuf = oid!("1.2.840.113549.1.9.4");
+/// id-signingTime (RFC 5652 11.3)
+pub const ID_SIGNING_TIME : OidBuf = oid!("1.2.840.113549.1.9.5");
+/// id-countersignature (RFC 5652 11.4)
+pub const ID_COUNTERSIGNATURE : OidBuf = oid!("1.2.840.113549.1.9.6");
+
+/// id-ct-TSTInfo (RFC 3161 2.4.2)
+pub const ID_CT_TSTINFO : OidBuf = oid!("1.2.840.113549.1.9.16.1.4");
+/// id-aa-timeStampToken, the signature time-stamp attribute
+/// (RFC 3161 Appendix A)
+pub const ID_AA_TIME_STAMP_TOKEN : OidBuf =
+    oid!("1.2.840.113549.1.9.16.2.14");
+
+/// keyBag (RFC 7292 4.2)
+pub const KEY_BAG : OidBuf = oid!("1.2.840.113549.1.12.10.1.1");
+/// pkcs8ShroudedKeyBag (RFC 7292 4.2)
+pub const PKCS8_SHROUDED_KEY_BAG : OidBuf = oid!("1.2.840.113549.1.12.10.1.2");
+/// certBag (RFC 7292 4.2)
+pub const CERT_BAG : OidBuf = oid!("1.2.840.113549.1.12.10.1.3");
+/// crlBag (RFC 7292 4.2)
+pub const CRL_BAG : OidBuf = oid!("1.2.840.113549.1.12.10.1.4");
+/// secretBag (RFC 7292 4.2)
+pub const SECRET_BAG : OidBuf = oid!("1.2.840.113549.1.12.10.1.5");
+/// safeContentsBag (RFC 7292 4.2)
+pub const SAFE_CONTENTS_BAG : OidBuf = oid!("1.2.840.113549.1.12.10.1.6");
+/// x509Certificate, a certificate type in a certBag (RFC 7292 4.2.3)
+pub const X509_CERTIFICATE : OidBuf = oid!("1.2.840.113549.1.9.22.1");
+/// sdsiCertificate, a certificate type in a certBag (RFC 7292 4.2.3)
+pub const SDSI_CERTIFICATE : OidBuf = oid!("1.2.840.113549.1.9.22.2");
+/// x509CRL, a CRL type in a crlBag (RFC 7292 4.2.4)
+pub const X509_CRL : OidBuf = oid!("1.2.840.113549.1.9.23.1");
@@ -0,0 +1,717 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::super::{ASN1Error, ASN1ErrorKind, ASN1Result, Tag};
+use super::super::{BERDecodable, BERReader, DEREncodable, DERWriter};
+use super::super::{decode_ber, encode_der, parse_der};
+use super::super::models::{ObjectIdentifier, TaggedDerValue};
+use super::super::tags::{TAG_BMPSTRING, TAG_OCTETSTRING};
+use super::{AlgorithmIdentifier, Attribute, Certificate, CertificateList};
+use super::{ContentInfo, EncryptedPrivateKeyInfo, PrivateKeyInfo};
+use super::attribute::single_value;
+use super::oids::*;
+
+/// A PKCS#12 file, or a PFX (RFC 7292 4).
+///
+/// ```text
+/// PFX ::= SEQUENCE {
+///     version     INTEGER {v3(3)}(v3,...),
+///     authSafe    ContentInfo,
+///     macData     MacData OPTIONAL
+/// }
+///
+/// AuthenticatedSafe ::= SEQUENCE OF ContentInfo
+///     -- Data if unencrypted
+///     -- EncryptedData if password-encrypted
+///     -- EnvelopedData if public key-encrypted
+/// ```
+///
+/// The version is not stored, since v3 is the only version. `authSafe` is
+/// a Data in the password integrity mode, protected by `macData`, and a
+/// SignedData in the public-key integrity mode. Either way, it contains
+/// the DER encoding of an `AuthenticatedSafe`, which is decoded by
+/// [`authenticated_safe`](Self::authenticated_safe).
+///
+/// The cryptography is left to the caller: the MAC is computed over
+/// [`auth_safe_data`](Self::auth_safe_data), and the encrypted contents
+/// are decrypted by the callback of [`safe_contents`](Self::safe_contents).
+///
+/// # Examples
+///
+/// ```
+/// use yasna::ASN1Error;
+/// use yasna::models::ObjectIdentifier;
+/// use yasna::pkix::{AlgorithmIdentifier, ContentInfo, EncryptedContentInfo};
+/// use yasna::pkix::{EncryptedData, PFX, SafeBag, SafeBagValue, SecretBag};
+/// use yasna::pkix::oids;
+/// use yasna::tags::TAG_OCTETSTRING;
+/// use yasna::models::TaggedDerValue;
+/// let mut bag = SafeBag::new(SafeBagValue::SecretBag(SecretBag {
+///     secret_type_id: ObjectIdentifier::from_slice(&[1, 2, 3]),
+///     secret_value: TaggedDerValue::from_tag_and_bytes(
+///         TAG_OCTETSTRING, b"secret".to_vec()),
+/// }));
+/// bag.set_friendly_name("my secret");
+/// let pbes2 = AlgorithmIdentifier::new(
+///     ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 5, 13]));
+/// let encrypted_content_info = EncryptedContentInfo::encrypt(
+///     oids::ID_DATA.into(), pbes2, &yasna::encode_der(&vec![bag.clone()]),
+///     |plaintext| {
+///         // Encrypt `plaintext` here.
+///         Ok::<_, ASN1Error>(plaintext.iter().map(|b| b ^ 0x55).collect())
+///     }).unwrap();
+/// let pfx = PFX::new(&[
+///     ContentInfo::EncryptedData(EncryptedData::new(encrypted_content_info)),
+/// ]);
+/// let der = yasna::encode_der(&pfx);
+///
+/// let pfx : PFX = yasna::decode_ber(&der).unwrap();
+/// let bags = pfx.safe_contents(|_algorithm, ciphertext| {
+///     // Decrypt `ciphertext` here.
+///     Ok::<_, ASN1Error>(ciphertext.iter().map(|b| b ^ 0x55).collect())
+/// }).unwrap();
+/// assert_eq!(bags, vec![bag]);
+/// assert_eq!(bags[0].friendly_name().unwrap().unwrap(), "my secret");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PFX {
+    /// The authenticated safe, usually a Data
+    pub auth_safe: ContentInfo,
+    /// The MAC in the password integrity mode
+    pub mac_data: Option<MacData>,
+}
+
+impl PFX {
+    /// Constructs a PFX without a MAC from the contents of an
+    /// `AuthenticatedSafe`.
+    pub fn new(authenticated_safe: &[ContentInfo]) -> Self {
+        PFX {
+            auth_safe: ContentInfo::Data(encode_der(authenticated_safe)),
+            mac_data: None,
+        }
+    }
+
+    /// Constructs a PFX from the contents of an `AuthenticatedSafe`,
+    /// computing the MAC over its DER encoding with `mac`.
+    pub fn with_mac<F, E>(authenticated_safe: &[ContentInfo], mac: F)
+            -> Result<Self, E>
+            where F: FnOnce(&[u8]) -> Result<MacData, E> {
+        let data = encode_der(authenticated_safe);
+        let mac_data = mac(&data)?;
+        return Ok(PFX {
+            auth_safe: ContentInfo::Data(data),
+            mac_data: Some(mac_data),
+        });
+    }
+
+    /// Returns the encoding of the `AuthenticatedSafe`, over which the MAC
+    /// or the signature is computed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `auth_safe` is neither a Data nor a SignedData
+    /// of a Data.
+    pub fn auth_safe_data(&self) -> ASN1Result<&[u8]> {
+        match self.auth_safe {
+            ContentInfo::Data(ref data) => return Ok(data),
+            ContentInfo::SignedData(ref signed_data) => {
+                let encap_content_info = &signed_data.encap_content_info;
+                if encap_content_info.e_content_type == ID_DATA {
+                    if let Some(ref e_content) = encap_content_info.e_content {
+                        return Ok(e_content);
+                    }
+                }
+            },
+            _ => {},
+        }
+        return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+    }
+
+    /// Decodes the `AuthenticatedSafe`.
+    ///
+    /// The encoding is decoded in BER mode, as BER is common in PKCS#12
+    /// files in the wild.
+    pub fn authenticated_safe(&self) -> ASN1Result<Vec<ContentInfo>> {
+        decode_ber(self.auth_safe_data()?)
+    }
+
+    /// Decodes the `SafeContents` in the `AuthenticatedSafe`, and returns
+    /// the bags in them.
+    ///
+    /// The `SafeContents` in an EncryptedData is decrypted with `decrypt`,
+    /// which is given the algorithm and the ciphertext. The bags in a
+    /// `safeContentsBag` are not expanded.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `decrypt`, or a decoding error converted into
+    /// `E`, which is the most likely result of a wrong password. An
+    /// EnvelopedData is also an error, since the key to decrypt it can't be
+    /// derived from a password; use
+    /// [`authenticated_safe`](Self::authenticated_safe) to handle it.
+    pub fn safe_contents<F, E>(&self, mut decrypt: F) -> Result<Vec<SafeBag>, E>
+            where F: FnMut(&AlgorithmIdentifier, &[u8]) -> Result<Vec<u8>, E>,
+                E: From<ASN1Error> {
+        let mut bags = Vec::new();
+        for content_info in self.authenticated_safe()? {
+            let safe_contents : Vec<SafeBag> = match content_info {
+                ContentInfo::Data(ref data) => decode_ber(data)?,
+                ContentInfo::EncryptedData(ref encrypted_data) => {
+                    let plaintext = encrypted_data.encrypted_content_info
+                        .decrypt(&mut decrypt)?;
+                    decode_ber(&plaintext)?
+                },
+                _ => return Err(ASN1Error::new(ASN1ErrorKind::Invalid).into()),
+            };
+            bags.extend(safe_contents);
+        }
+        return Ok(bags);
+    }
+}
+
+impl BERDecodable for PFX {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            if reader.next().read_u64()? != 3 {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            let auth_safe = ContentInfo::decode_ber(reader.next())?;
+            let mac_data = reader.read_optional(MacData::decode_ber)?;
+            return Ok(PFX {
+                auth_safe,
+                mac_data,
+            });
+        })
+    }
+}
+
+impl DEREncodable for PFX {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_u8(3);
+            self.auth_safe.encode_der(writer.next());
+            if let Some(ref mac_data) = self.mac_data {
+                mac_data.encode_der(writer.next());
+            }
+        })
+    }
+}
+
+/// A digest with its algorithm (RFC 8017 9.2).
+///
+/// ```text
+/// DigestInfo ::= SEQUENCE {
+///     digestAlgorithm DigestAlgorithm,
+///     digest OCTET STRING
+/// }
+/// ```
+///
+/// This is used for the MAC in a PFX, as well as in PKCS#1 v1.5
+/// signatures.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct DigestInfo {
+    /// The digest algorithm
+    pub digest_algorithm: AlgorithmIdentifier,
+    /// The digest
+    pub digest: Vec<u8>,
+}
+
+impl BERDecodable for DigestInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let digest_algorithm = AlgorithmIdentifier::decode_ber(reader.next())?;
+            let digest = reader.next().read_bytes()?;
+            return Ok(DigestInfo {
+                digest_algorithm,
+                digest,
+            });
+        })
+    }
+}
+
+impl DEREncodable for DigestInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.digest_algorithm.encode_der(writer.next());
+            writer.next().write_bytes(&self.digest);
+        })
+    }
+}
+
+/// The MAC of a PFX in the password integrity mode (RFC 7292 4).
+///
+/// ```text
+/// MacData ::= SEQUENCE {
+///     mac         DigestInfo,
+///     macSalt     OCTET STRING,
+///     iterations  INTEGER DEFAULT 1
+///     -- Note: The default is for historical reasons and its use is
+///     -- deprecated.
+/// }
+/// ```
+///
+/// The MAC key is derived from the password by the method in RFC 7292
+/// Appendix B with the salt and the iteration count, and the HMAC is
+/// computed over [`PFX::auth_safe_data`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MacData {
+    /// The hash algorithm and the MAC
+    pub mac: DigestInfo,
+    /// The salt for the key derivation
+    pub mac_salt: Vec<u8>,
+    /// The iteration count for the key derivation
+    pub iterations: u64,
+}
+
+impl BERDecodable for MacData {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let mac = DigestInfo::decode_ber(reader.next())?;
+            let mac_salt = reader.next().read_bytes()?;
+            let iterations = reader.read_default(1, |reader| {
+                reader.read_u64()
+            })?;
+            return Ok(MacData {
+                mac,
+                mac_salt,
+                iterations,
+            });
+        })
+    }
+}
+
+impl DEREncodable for MacData {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.mac.encode_der(writer.next());
+            writer.next().write_bytes(&self.mac_salt);
+            if self.iterations != 1 {
+                writer.next().write_u64(self.iterations);
+            }
+        })
+    }
+}
+
+/// An item in a PKCS#12 file (RFC 7292 4.2).
+///
+/// ```text
+/// SafeContents ::= SEQUENCE OF SafeBag
+///
+/// SafeBag ::= SEQUENCE {
+///     bagId          BAG-TYPE.&id ({PKCS12BagSet}),
+///     bagValue       [0] EXPLICIT BAG-TYPE.&Type({PKCS12BagSet}{@bagId}),
+///     bagAttributes  SET OF PKCS12Attribute OPTIONAL
+/// }
+/// ```
+///
+/// `SafeContents` is represented by `Vec<SafeBag>`. The bag is decoded
+/// according to its type. The attributes are omitted when encoded if
+/// empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SafeBag {
+    /// The bag
+    pub bag_value: SafeBagValue,
+    /// The attributes, such as friendlyName and localKeyId
+    pub bag_attributes: Vec<Attribute>,
+}
+
+impl SafeBag {
+    /// Constructs a bag without attributes.
+    pub fn new(bag_value: SafeBagValue) -> Self {
+        SafeBag {
+            bag_value,
+            bag_attributes: Vec::new(),
+        }
+    }
+
+    /// Returns the first attribute of the given type.
+    pub fn attribute(&self, attr_type: &ObjectIdentifier)
+            -> Option<&Attribute> {
+        self.bag_attributes.iter().find(|a| a.attr_type == *attr_type)
+    }
+
+    /// Decodes the friendlyName attribute (RFC 2985 5.5.1), which is a
+    /// BMPString.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the attribute is not a single BMPString.
+    pub fn friendly_name(&self) -> ASN1Result<Option<String>> {
+        let attribute = match self.attribute(&PKCS_9_AT_FRIENDLY_NAME.into()) {
+            Some(attribute) => attribute,
+            None => return Ok(None),
+        };
+        let value = single_value(attribute)?;
+        let name = parse_der(&encode_der(value),
+            |reader| reader.read_bmp_string())?;
+        return Ok(Some(name));
+    }
+
+    /// Decodes the localKeyId attribute (RFC 2985 5.5.2), which usually
+    /// associates a key with its certificate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the attribute is not a single OCTET STRING.
+    pub fn local_key_id(&self) -> ASN1Result<Option<Vec<u8>>> {
+        let attribute = match self.attribute(&PKCS_9_AT_LOCAL_KEY_ID.into()) {
+            Some(attribute) => attribute,
+            None => return Ok(None),
+        };
+        let value = single_value(attribute)?;
+        let local_key_id = parse_der(&encode_der(value),
+            |reader| reader.read_bytes())?;
+        return Ok(Some(local_key_id));
+    }
+
+    /// Sets the friendlyName attribute, replacing the existing one.
+    pub fn set_friendly_name(&mut self, friendly_name: &str) {
+        let bytes = friendly_name.encode_utf16()
+            .flat_map(|c| c.to_be_bytes())
+            .collect();
+        self.set_attribute(Attribute {
+            attr_type: PKCS_9_AT_FRIENDLY_NAME.into(),
+            values: vec![TaggedDerValue::from_tag_and_bytes(TAG_BMPSTRING, bytes)],
+        });
+    }
+
+    /// Sets the localKeyId attribute, replacing the existing one.
+    pub fn set_local_key_id(&mut self, local_key_id: &[u8]) {
+        self.set_attribute(Attribute {
+            attr_type: PKCS_9_AT_LOCAL_KEY_ID.into(),
+            values: vec![TaggedDerValue::from_tag_and_bytes(TAG_OCTETSTRING,
+                local_key_id.to_vec())],
+        });
+    }
+
+    fn set_attribute(&mut self, attribute: Attribute) {
+        self.bag_attributes.retain(|a| a.attr_type != attribute.attr_type);
+        self.bag_attributes.push(attribute);
+    }
+}
+
+impl BERDecodable for SafeBag {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let bag_id = ObjectIdentifier::decode_ber(reader.next())?;
+            let bag_value = reader.next().read_tagged(Tag::context(0),
+                |reader| SafeBagValue::read_value(bag_id, reader))?;
+            let bag_attributes = reader.read_optional(|reader| {
+                reader.collect_set_of(Attribute::decode_ber)
+            })?;
+            return Ok(SafeBag {
+                bag_value,
+                bag_attributes: bag_attributes.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for SafeBag {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.bag_value.bag_id().encode_der(writer.next());
+            writer.next().write_tagged(Tag::context(0), |writer| {
+                self.bag_value.write_value(writer)
+            });
+            if !self.bag_attributes.is_empty() {
+                writer.next().write_set_of(|writer| {
+                    for attribute in &self.bag_attributes {
+                        attribute.encode_der(writer.next());
+                    }
+                });
+            }
+        })
+    }
+}
+
+/// The value of a [`SafeBag`] (RFC 7292 4.2).
+///
+/// ```text
+/// KeyBag ::= PrivateKeyInfo
+///
+/// PKCS8ShroudedKeyBag ::= EncryptedPrivateKeyInfo
+///
+/// SafeContents ::= SEQUENCE OF SafeBag
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum SafeBagValue {
+    /// `keyBag`, an unencrypted private key
+    KeyBag(PrivateKeyInfo),
+    /// `pkcs8ShroudedKeyBag`, an encrypted private key
+    Pkcs8ShroudedKeyBag(EncryptedPrivateKeyInfo),
+    /// `certBag`
+    CertBag(CertBag),
+    /// `crlBag`
+    CrlBag(CrlBag),
+    /// `secretBag`
+    SecretBag(SecretBag),
+    /// `safeContentsBag`, nested bags
+    SafeContentsBag(Vec<SafeBag>),
+    /// Any other type
+    Other {
+        /// The type of the bag
+        bag_id: ObjectIdentifier,
+        /// The bag, without the `[0]` tag
+        bag_value: TaggedDerValue,
+    },
+}
+
+impl SafeBagValue {
+    /// Returns the type of the bag.
+    pub fn bag_id(&self) -> ObjectIdentifier {
+        match *self {
+            SafeBagValue::KeyBag(_) => KEY_BAG.into(),
+            SafeBagValue::Pkcs8ShroudedKeyBag(_) =>
+                PKCS8_SHROUDED_KEY_BAG.into(),
+            SafeBagValue::CertBag(_) => CERT_BAG.into(),
+            SafeBagValue::CrlBag(_) => CRL_BAG.into(),
+            SafeBagValue::SecretBag(_) => SECRET_BAG.into(),
+            SafeBagValue::SafeContentsBag(_) => SAFE_CONTENTS_BAG.into(),
+            SafeBagValue::Other { ref bag_id, .. } => bag_id.clone(),
+        }
+    }
+
+    fn read_value(bag_id: ObjectIdentifier, reader: BERReader)
+            -> ASN1Result<Self> {
+        if bag_id == KEY_BAG {
+            return Ok(SafeBagValue::KeyBag(PrivateKeyInfo::decode_ber(reader)?));
+        } else if bag_id == PKCS8_SHROUDED_KEY_BAG {
+            return Ok(SafeBagValue::Pkcs8ShroudedKeyBag(
+                EncryptedPrivateKeyInfo::decode_ber(reader)?));
+        } else if bag_id == CERT_BAG {
+            return Ok(SafeBagValue::CertBag(CertBag::decode_ber(reader)?));
+        } else if bag_id == CRL_BAG {
+            return Ok(SafeBagValue::CrlBag(CrlBag::decode_ber(reader)?));
+        } else if bag_id == SECRET_BAG {
+            return Ok(SafeBagValue::SecretBag(SecretBag::decode_ber(reader)?));
+        } else if bag_id == SAFE_CONTENTS_BAG {
+            return Ok(SafeBagValue::SafeContentsBag(Vec::decode_ber(reader)?));
+        }
+        return Ok(SafeBagValue::Other {
+            bag_id,
+            bag_value: reader.read_tagged_der()?,
+        });
+    }
+
+    fn write_value(&self, writer: DERWriter) {
+        match *self {
+            SafeBagValue::KeyBag(ref key) => key.encode_der(writer),
+            SafeBagValue::Pkcs8ShroudedKeyBag(ref key) => key.encode_der(writer),
+            SafeBagValue::CertBag(ref cert_bag) => cert_bag.encode_der(writer),
+            SafeBagValue::CrlBag(ref crl_bag) => crl_bag.encode_der(writer),
+            SafeBagValue::SecretBag(ref secret_bag) =>
+                secret_bag.encode_der(writer),
+            SafeBagValue::SafeContentsBag(ref bags) => bags.encode_der(writer),
+            SafeBagValue::Other { ref bag_value, .. } =>
+                writer.write_tagged_der(bag_value),
+        }
+    }
+}
+
+/// A certificate in a [`SafeBag`] (RFC 7292 4.2.3).
+///
+/// ```text
+/// CertBag ::= SEQUENCE {
+///     certId      BAG-TYPE.&id   ({CertTypes}),
+///     certValue   [0] EXPLICIT BAG-TYPE.&Type ({CertTypes}{@certId})
+/// }
+///
+/// x509Certificate BAG-TYPE ::=
+///     {OCTET STRING IDENTIFIED BY {certTypes 1}}
+///     -- DER-encoded X.509 certificate stored in OCTET STRING
+/// sdsiCertificate BAG-TYPE ::=
+///     {IA5String IDENTIFIED BY {certTypes 2}}
+///     -- Base64-encoded SDSI certificate stored in IA5String
+/// ```
+///
+/// The X.509 certificate in the OCTET STRING is decoded in BER mode, and
+/// its encoding is kept as is.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum CertBag {
+    /// `x509Certificate`
+    X509Certificate(Box<Certificate>),
+    /// `sdsiCertificate`, in Base64
+    SdsiCertificate(String),
+    /// Any other type
+    Other {
+        /// The type of the certificate
+        cert_id: ObjectIdentifier,
+        /// The certificate, without the `[0]` tag
+        cert_value: TaggedDerValue,
+    },
+}
+
+impl CertBag {
+    /// Returns the type of the certificate.
+    pub fn cert_id(&self) -> ObjectIdentifier {
+        match *self {
+            CertBag::X509Certificate(_) => X509_CERTIFICATE.into(),
+            CertBag::SdsiCertificate(_) => SDSI_CERTIFICATE.into(),
+            CertBag::Other { ref cert_id, .. } => cert_id.clone(),
+        }
+    }
+}
+
+impl BERDecodable for CertBag {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let cert_id = ObjectIdentifier::decode_ber(reader.next())?;
+            reader.next().read_tagged(Tag::context(0), |reader| {
+                if cert_id == X509_CERTIFICATE {
+                    let certificate = decode_ber(&reader.read_bytes()?)?;
+                    return Ok(CertBag::X509Certificate(Box::new(certificate)));
+                } else if cert_id == SDSI_CERTIFICATE {
+                    return Ok(CertBag::SdsiCertificate(
+                        reader.read_ia5_string()?));
+                }
+                return Ok(CertBag::Other {
+                    cert_id,
+                    cert_value: reader.read_tagged_der()?,
+                });
+            })
+        })
+    }
+}
+
+impl DEREncodable for CertBag {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.cert_id().encode_der(writer.next());
+            writer.next().write_tagged(Tag::context(0), |writer| {
+                match *self {
+                    CertBag::X509Certificate(ref certificate) =>
+                        writer.write_bytes(&encode_der(&**certificate)),
+                    CertBag::SdsiCertificate(ref certificate) =>
+                        writer.write_ia5_string(certificate),
+                    CertBag::Other { ref cert_value, .. } =>
+                        writer.write_tagged_der(cert_value),
+                }
+            });
+        })
+    }
+}
+
+/// A CRL in a [`SafeBag`] (RFC 7292 4.2.4).
+///
+/// ```text
+/// CRLBag ::= SEQUENCE {
+///     crlId      BAG-TYPE.&id  ({CRLTypes}),
+///     crlValue  [0] EXPLICIT BAG-TYPE.&Type ({CRLTypes}{@crlId})
+/// }
+///
+/// x509CRL BAG-TYPE ::=
+///     {OCTET STRING IDENTIFIED BY {crlTypes 1}}
+///     -- DER-encoded X.509 CRL stored in OCTET STRING
+/// ```
+///
+/// The X.509 CRL in the OCTET STRING is decoded in BER mode, and its
+/// encoding is kept as is.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum CrlBag {
+    /// `x509CRL`
+    X509Crl(Box<CertificateList>),
+    /// Any other type
+    Other {
+        /// The type of the CRL
+        crl_id: ObjectIdentifier,
+        /// The CRL, without the `[0]` tag
+        crl_value: TaggedDerValue,
+    },
+}
+
+impl CrlBag {
+    /// Returns the type of the CRL.
+    pub fn crl_id(&self) -> ObjectIdentifier {
+        match *self {
+            CrlBag::X509Crl(_) => X509_CRL.into(),
+            CrlBag::Other { ref crl_id, .. } => crl_id.clone(),
+        }
+    }
+}
+
+impl BERDecodable for CrlBag {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let crl_id = ObjectIdentifier::decode_ber(reader.next())?;
+            reader.next().read_tagged(Tag::context(0), |reader| {
+                if crl_id == X509_CRL {
+                    let crl = decode_ber(&reader.read_bytes()?)?;
+                    return Ok(CrlBag::X509Crl(Box::new(crl)));
+                }
+                return Ok(CrlBag::Other {
+                    crl_id,
+                    crl_value: reader.read_tagged_der()?,
+                });
+            })
+        })
+    }
+}
+
+impl DEREncodable for CrlBag {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.crl_id().encode_der(writer.next());
+            writer.next().write_tagged(Tag::context(0), |writer| {
+                match *self {
+                    CrlBag::X509Crl(ref crl) =>
+                        writer.write_bytes(&encode_der(&**crl)),
+                    CrlBag::Other { ref crl_value, .. } =>
+                        writer.write_tagged_der(crl_value),
+                }
+            });
+        })
+    }
+}
+
+/// A secret of any type in a [`SafeBag`] (RFC 7292 4.2.5).
+///
+/// ```text
+/// SecretBag ::= SEQUENCE {
+///     secretTypeId   BAG-TYPE.&id ({SecretTypes}),
+///     secretValue    [0] EXPLICIT BAG-TYPE.&Type ({SecretTypes}
+///                        {@secretTypeId})
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SecretBag {
+    /// The type of the secret
+    pub secret_type_id: ObjectIdentifier,
+    /// The secret, without the `[0]` tag
+    pub secret_value: TaggedDerValue,
+}
+
+impl BERDecodable for SecretBag {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let secret_type_id = ObjectIdentifier::decode_ber(reader.next())?;
+            let secret_value = reader.next().read_tagged(Tag::context(0),
+                |reader| reader.read_tagged_der())?;
+            return Ok(SecretBag {
+                secret_type_id,
+                secret_value,
+            });
+        })
+    }
+}
+
+impl DEREncodable for SecretBag {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.secret_type_id.encode_der(writer.next());
+            writer.next().write_tagged(Tag::context(0), |writer| {
+                writer.write_tagged_der(&self.secret_value)
+            });
+        })
+    }
+}
@@ -0,0 +1,298 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::super::{ASN1Error, ASN1ErrorKind, ASN1Result, Tag};
+use super::super::{BERDecodable, BERReader, DEREncodable, DERWriter};
+use super::super::{decode_der, encode_der};
+use super::super::models::{BitString, DirectoryString, ObjectIdentifier};
+use super::super::models::{PrintableString, TaggedDerValue, WithRaw};
+use super::super::tags::{TAG_PRINTABLESTRING, TAG_SEQUENCE, TAG_UTF8STRING};
+use super::{AlgorithmIdentifier, Attribute, Extension, Name};
+use super::attribute::single_value;
+use super::SubjectPublicKeyInfo;
+use super::oids::{PKCS_9_AT_CHALLENGE_PASSWORD, PKCS_9_AT_EXTENSION_REQUEST};
+
+/// The signed part of a certificate signing request (RFC 2986 4.1).
+///
+/// ```text
+/// CertificationRequestInfo ::= SEQUENCE {
+///      version       INTEGER { v1(0) } (v1,...),
+///      subject       Name,
+///      subjectPKInfo SubjectPublicKeyInfo{{ PKInfoAlgorithms }},
+///      attributes    [0] Attributes{{ CRIAttributes }}
+/// }
+///
+/// Attributes { ATTRIBUTE:IOSet } ::= SET OF Attribute{{ IOSet }}
+/// ```
+///
+/// The version is always v1 and is not stored; other versions are
+/// rejected when decoded.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::{CertificationRequestInfo, Name};
+/// let spki = [48, 42, 48, 5, 6, 3, 43, 101, 112, 3, 33, 0,
+///     25, 191, 68, 9, 105, 132, 205, 254, 133, 65, 186, 193, 103, 220, 59,
+///     150, 200, 80, 134, 170, 48, 181, 107, 203, 7, 98, 88, 182, 250, 26,
+///     71, 225];
+/// let subject : Name = "CN=example.com".parse().unwrap();
+/// let mut info = CertificationRequestInfo::new(subject, &spki).unwrap();
+/// info.set_challenge_password("secret");
+/// assert_eq!(info.challenge_password().unwrap().unwrap(), "secret");
+/// assert_eq!(info.extension_request().unwrap(), None);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CertificationRequestInfo {
+    /// The subject of the requested certificate
+    pub subject: Name,
+    /// The public key of the subject
+    pub subject_pk_info: SubjectPublicKeyInfo,
+    /// The attributes, such as extensionRequest and challengePassword
+    pub attributes: Vec<Attribute>,
+}
+
+impl CertificationRequestInfo {
+    /// Constructs a request without attributes from a subject and the
+    /// DER encoding of a SubjectPublicKeyInfo.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `subject_pk_info` is not a valid DER encoding
+    /// of a SubjectPublicKeyInfo.
+    pub fn new(subject: Name, subject_pk_info: &[u8]) -> ASN1Result<Self> {
+        return Ok(CertificationRequestInfo {
+            subject,
+            subject_pk_info: decode_der(subject_pk_info)?,
+            attributes: Vec::new(),
+        });
+    }
+
+    /// Returns the first attribute of the given type.
+    pub fn attribute(&self, attr_type: &ObjectIdentifier)
+            -> Option<&Attribute> {
+        self.attributes.iter().find(|a| a.attr_type == *attr_type)
+    }
+
+    /// Decodes the extensionRequest attribute (RFC 2985 5.4.2), which
+    /// lists the extensions to be included in the certificate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the attribute is malformed or does not have
+    /// exactly one value.
+    pub fn extension_request(&self) -> ASN1Result<Option<Vec<Extension>>> {
+        let attribute = match self.attribute(&PKCS_9_AT_EXTENSION_REQUEST.into()) {
+            Some(attribute) => attribute,
+            None => return Ok(None),
+        };
+        let value = single_value(attribute)?;
+        return Ok(Some(decode_der(&encode_der(value))?));
+    }
+
+    /// Decodes the challengePassword attribute (RFC 2985 5.4.1).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the attribute is not a single DirectoryString.
+    pub fn challenge_password(&self) -> ASN1Result<Option<String>> {
+        let attribute = match self.attribute(&PKCS_9_AT_CHALLENGE_PASSWORD.into()) {
+            Some(attribute) => attribute,
+            None => return Ok(None),
+        };
+        let value = single_value(attribute)?;
+        let password : DirectoryString = decode_der(&encode_der(value))?;
+        return Ok(Some(String::from(password.as_str())));
+    }
+
+    /// Sets the extensionRequest attribute, replacing the existing one.
+    pub fn set_extension_request(&mut self, extensions: &[Extension]) {
+        let mut contents = Vec::new();
+        for extension in extensions {
+            contents.extend(encode_der(extension));
+        }
+        self.set_attribute(Attribute {
+            attr_type: PKCS_9_AT_EXTENSION_REQUEST.into(),
+            values: vec![
+                TaggedDerValue::from_tag_and_bytes(TAG_SEQUENCE, contents),
+            ],
+        });
+    }
+
+    /// Sets the challengePassword attribute, replacing the existing one.
+    ///
+    /// The password is encoded as PrintableString if possible, and as
+    /// UTF8String otherwise.
+    pub fn set_challenge_password(&mut self, password: &str) {
+        let tag = if PrintableString::try_from(password).is_ok() {
+            TAG_PRINTABLESTRING
+        } else {
+            TAG_UTF8STRING
+        };
+        self.set_attribute(Attribute {
+            attr_type: PKCS_9_AT_CHALLENGE_PASSWORD.into(),
+            values: vec![TaggedDerValue::from_tag_and_bytes(
+                tag, password.as_bytes().to_vec())],
+        });
+    }
+
+    fn set_attribute(&mut self, attribute: Attribute) {
+        self.attributes.retain(|a| a.attr_type != attribute.attr_type);
+        self.attributes.push(attribute);
+    }
+}
+
+impl BERDecodable for CertificationRequestInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            if reader.next().read_u64()? != 0 {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            let subject = Name::decode_ber(reader.next())?;
+            let subject_pk_info =
+                SubjectPublicKeyInfo::decode_ber(reader.next())?;
+            let attributes = reader.next().read_tagged_implicit(
+                Tag::context(0), |reader| {
+                    reader.collect_set_of(Attribute::decode_ber)
+                })?;
+            return Ok(CertificationRequestInfo {
+                subject,
+                subject_pk_info,
+                attributes,
+            });
+        })
+    }
+}
+
+impl DEREncodable for CertificationRequestInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_u64(0);
+            self.subject.encode_der(writer.next());
+            self.subject_pk_info.encode_der(writer.next());
+            writer.next().write_tagged_implicit(Tag::context(0), |writer| {
+                writer.write_set_of(|writer| {
+                    for attribute in &self.attributes {
+                        attribute.encode_der(writer.next());
+                    }
+                })
+            });
+        })
+    }
+}
+
+/// A certificate signing request in PKCS#10 (RFC 2986 4.2).
+///
+/// ```text
+/// CertificationRequest ::= SEQUENCE {
+///      certificationRequestInfo CertificationRequestInfo,
+///      signatureAlgorithm AlgorithmIdentifier{{ SignatureAlgorithms }},
+///      signature          BIT STRING
+/// }
+/// ```
+///
+/// The original encoding of `certificationRequestInfo` is kept so that the
+/// signature can be verified over [`info_bytes`](Self::info_bytes).
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::ObjectIdentifier;
+/// use yasna::pkix::{AlgorithmIdentifier, BasicConstraints};
+/// use yasna::pkix::{CertificationRequest, CertificationRequestInfo};
+/// use yasna::pkix::{Extension, ExtensionValue, Name};
+/// let spki = [48, 42, 48, 5, 6, 3, 43, 101, 112, 3, 33, 0,
+///     25, 191, 68, 9, 105, 132, 205, 254, 133, 65, 186, 193, 103, 220, 59,
+///     150, 200, 80, 134, 170, 48, 181, 107, 203, 7, 98, 88, 182, 250, 26,
+///     71, 225];
+/// let subject : Name = "CN=example.com".parse().unwrap();
+/// let mut info = CertificationRequestInfo::new(subject, &spki).unwrap();
+/// info.set_extension_request(&[Extension::new(
+///     &ExtensionValue::BasicConstraints(BasicConstraints::default()),
+///     true)]);
+/// let ed25519 = AlgorithmIdentifier::new(
+///     ObjectIdentifier::from_slice(&[1, 3, 101, 112]));
+/// let request = CertificationRequest::sign(info, ed25519, |info_bytes| {
+///     // Sign `info_bytes` with Ed25519 here.
+///     Ok::<_, ()>(vec![0; 64])
+/// }).unwrap();
+/// let der = yasna::encode_der(&request);
+///
+/// let decoded : CertificationRequest = yasna::decode_der(&der).unwrap();
+/// assert_eq!(decoded, request);
+/// // Verify the signature over these bytes.
+/// assert_eq!(decoded.info_bytes(), &der[3..decoded.info_bytes().len() + 3]);
+/// let extensions = decoded.certification_request_info.value()
+///     .extension_request().unwrap().unwrap();
+/// assert_eq!(extensions[0].extn_id.to_string(), "2.5.29.19");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CertificationRequest {
+    /// The signed part, with its encoding
+    pub certification_request_info: WithRaw<CertificationRequestInfo>,
+    /// The signature algorithm
+    pub signature_algorithm: AlgorithmIdentifier,
+    /// The signature
+    pub signature: BitString,
+}
+
+impl CertificationRequest {
+    /// Constructs a request by encoding `certification_request_info` in
+    /// DER and signing the encoding with `sign`.
+    pub fn sign<F, E>(certification_request_info: CertificationRequestInfo,
+            signature_algorithm: AlgorithmIdentifier, sign: F)
+            -> Result<Self, E>
+            where F: FnOnce(&[u8]) -> Result<Vec<u8>, E> {
+        let certification_request_info =
+            WithRaw::new(certification_request_info);
+        let signature = BitString::from_bytes(
+            sign(certification_request_info.raw())?);
+        return Ok(CertificationRequest {
+            certification_request_info,
+            signature_algorithm,
+            signature,
+        });
+    }
+
+    /// Returns the encoding of the CertificationRequestInfo, over which the
+    /// signature is computed.
+    pub fn info_bytes(&self) -> &[u8] {
+        self.certification_request_info.raw()
+    }
+}
+
+impl BERDecodable for CertificationRequest {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let certification_request_info =
+                WithRaw::decode_ber(reader.next())?;
+            let signature_algorithm =
+                AlgorithmIdentifier::decode_ber(reader.next())?;
+            let signature = BitString::decode_ber(reader.next())?;
+            return Ok(CertificationRequest {
+                certification_request_info,
+                signature_algorithm,
+                signature,
+            });
+        })
+    }
+}
+
+impl DEREncodable for CertificationRequest {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.certification_request_info.encode_der(writer.next());
+            self.signature_algorithm.encode_der(writer.next());
+            self.signature.encode_der(writer.next());
+        })
+    }
+}
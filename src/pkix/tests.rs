@@ -0,0 +1,1806 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::*;
+use super::super::{decode_ber, decode_der, encode_der, ASN1Error, ASN1ErrorKind};
+use super::super::{PCBit, Tag};
+use super::super::models::{BitString, DirectoryString, GeneralizedTime};
+use super::super::models::ObjectIdentifier;
+use super::super::models::{RawDateTime, TaggedDerValue, Utf8String, WithRaw};
+use super::super::tags::{TAG_IA5STRING, TAG_PRINTABLESTRING, TAG_UTF8STRING};
+use super::super::text::decode_base64;
+
+/// A self-signed ECDSA certificate generated by OpenSSL.
+const V3_CERTIFICATE: &str = "
+MIIBbjCCAROgAwIBAgICEjQwCgYIKoZIzj0EAwIwFTETMBEGA1UEAwwKeWFzbmEg
+dGVzdDAeFw0yNjEwMTkwMzQwNTlaFw0zNjEwMTYwMzQwNTlaMBUxEzARBgNVBAMM
+Cnlhc25hIHRlc3QwWTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAARqbYGR/kr1mDKF
+cwsMFY8no84X2EhfiKrS6xhK2Qv5GOim9MunHv3PJrLZ5HqsoYwn3j2AqLfJ3ceG
+54YlUDFHo1MwUTAdBgNVHQ4EFgQUbJkAgmk2a234gusI4/cg3nGyMxcwHwYDVR0j
+BBgwFoAUbJkAgmk2a234gusI4/cg3nGyMxcwDwYDVR0TAQH/BAUwAwEB/zAKBggq
+hkjOPQQDAgNJADBGAiEAjEuBoFU955ZrwwUyI5e44/xkgElnP4hZ8XK6ldk+wU4C
+IQDTe5zc47K0boFOuXJ6UgaB/Z2DUQDEm+XVo2L5II6rgw==
+";
+
+/// A PKCS#10 request with an Ed25519 key generated by OpenSSL, with a
+/// challengePassword and an extensionRequest.
+const CERTIFICATION_REQUEST: &str = "
+MIHjMIGWAgEAMBUxEzARBgNVBAMMCnlhc25hIHRlc3QwKjAFBgMrZXADIQBaBze7
+0Dr001TZ5gXPZC4/2SGB3MuUW78/UBDQdI1s06BOMBUGCSqGSIb3DQEJBzEIDAZz
+ZWNyZXQwNQYJKoZIhvcNAQkOMSgwJjAWBgNVHREEDzANggtleGFtcGxlLmNvbTAM
+BgNVHRMBAf8EAjAAMAUGAytlcANBAHxM4qIIGjyOOqncw6eSlJ3dQ9raNjYrPbfb
+wA6PT8bsdI/8nNUuhiFLFImvhF3o+R095DDfCxTOzn8ruPRwNw0=
+";
+
+/// A v2 CRL generated by OpenSSL, with three entries and CRL extensions.
+const CERTIFICATE_LIST: &str = "
+MIIBdDCCARoCAQEwCgYIKoZIzj0EAwIwEzERMA8GA1UEAwwIeWFzbmEgQ0EXDTI2
+MTAxOTA0MDI0OFoXDTI2MTAyNjA0MDI0OFowdDAgAgEBFw0yNjEwMTkwNDAyNDha
+MAwwCgYDVR0VBAMKAQEwEwICAP8XDTI2MTAxOTA0MDI0OFowOwICAgMXDTI2MTAx
+OTA0MDI0OFowJjAKBgNVHRUEAwoBATAYBgNVHRgEERgPMjAyNjAxMDEwMDAwMDBa
+oGAwXjAfBgNVHSMEGDAWgBQMgPGCJUaz3VyPgsrxXnw76CtEpDAuBgNVHRwBAf8E
+JDAioB2gG4YZaHR0cDovL2V4YW1wbGUuY29tL2NhLmNybIEB/zALBgNVHRQEBAIC
+EAAwCgYIKoZIzj0EAwIDSAAwRQIgS6RGZ5/wOJFtr6p4tRO96Eyc79gv7FWaCXLz
+CPvvEgECIQDJLZkAnIInu0NZkVci4Frcoo65DD4n0bBLFZ8kf5QPwQ==
+";
+
+/// A 512-bit RSA key in PKCS#8, generated by OpenSSL.
+const RSA_PRIVATE_KEY_INFO: &str = "
+MIIBUwIBADANBgkqhkiG9w0BAQEFAASCAT0wggE5AgEAAkEAux07TGrcv98CpyMA
+Ad6zZ/nh2LQ/YfqRMfEXGwN3bJQUiiidjUqPryI3FWnNdUIkkU7/r6p8kEpDc1pp
+hFfmJQIDAQABAkASvabGzJ03inqViufhSBDoBRsdvU/r3FDWk7MgvYQkl7RubAtK
+WPbb5K94epTl0tX9jG1FbtKwgjoxj2gfWH0xAiEA3bLTZA/WApup2RgG063+2qgi
+nm2pvpWrkbWePoeTEYMCIQDYEJE3/iW5CQkphw6GUh2LGtgHU/CDWR8iigkq4Unh
+NwIgMGhprAGhMzSWI6cRZospiMkJthov5EN+EPTdNr4XqM0CICauiKEiofRHltlV
+cZdAmkNuMeDV+Ug6q2VOmyePH9/vAiBGQGGFGW6a2+p35I7QcP0EtPdFkZX2gKYP
+e9Mm6xhQ6g==
+";
+
+/// The public key of `RSA_PRIVATE_KEY_INFO`, in a SubjectPublicKeyInfo.
+const RSA_SUBJECT_PUBLIC_KEY_INFO: &str = "
+MFwwDQYJKoZIhvcNAQEBBQADSwAwSAJBALsdO0xq3L/fAqcjAAHes2f54di0P2H6
+kTHxFxsDd2yUFIoonY1Kj68iNxVpzXVCJJFO/6+qfJBKQ3NaaYRX5iUCAwEAAQ==
+";
+
+/// The public key of `RSA_PRIVATE_KEY_INFO`, in PKCS#1.
+const RSA_PUBLIC_KEY: &str = "
+MEgCQQC7HTtMaty/3wKnIwAB3rNn+eHYtD9h+pEx8RcbA3dslBSKKJ2NSo+vIjcV
+ac11QiSRTv+vqnyQSkNzWmmEV+YlAgMBAAE=
+";
+
+/// A P-256 key in PKCS#8, generated by OpenSSL.
+const EC_PRIVATE_KEY_INFO: &str = "
+MIGHAgEAMBMGByqGSM49AgEGCCqGSM49AwEHBG0wawIBAQQgmuPgaQH51f9fjiYs
+sIYIjjNgTLdGSykkZUIZl+rEAXahRANCAATkADX8ZlY4OP5Ew8O8QWkB0b/5hnFc
+YpgLkRMuatFonEbAg3ytYm5jXgn+y4FWoAci44ViYZsO1qvZ/xriDA6U
+";
+
+/// The key of `EC_PRIVATE_KEY_INFO` in SEC1, converted by OpenSSL.
+const EC_PRIVATE_KEY: &str = "
+MHcCAQEEIJrj4GkB+dX/X44mLLCGCI4zYEy3RkspJGVCGZfqxAF2oAoGCCqGSM49
+AwEHoUQDQgAE5AA1/GZWODj+RMPDvEFpAdG/+YZxXGKYC5ETLmrRaJxGwIN8rWJu
+Y14J/suBVqAHIuOFYmGbDtar2f8a4gwOlA==
+";
+
+/// The key of `EC_PRIVATE_KEY_INFO`, encrypted by OpenSSL with PBES2.
+const ENCRYPTED_PRIVATE_KEY_INFO: &str = "
+MIHzMF4GCSqGSIb3DQEFDTBRMDAGCSqGSIb3DQEFDDAjBBCBfGvts+94VaFj3czB
+XTe7AgEBMAwGCCqGSIb3DQIJBQAwHQYJYIZIAWUDBAECBBBSSyvk7sXW0TbXc/K3
+fKKwBIGQbjV/RQw8UbhsmjqBG+QTcFr0k7P9BW+jp/1Rk/abK5acE/4EPCUr9Jod
+kK6CYT9ZnRANTb6TTyp+TgXE2q2mZmrMS0XZVD+odpEyDshxXkNB5jcHqjAdj9fz
+Rla5yFqxOyUhO77onRRIeSHm8i5Sffjr2QvqUPdi044s0ryHhb78FfMGHJIX32xA
+tDusRPr/
+";
+
+/// The v2 Ed25519 key from RFC 8410 10.3, with an attribute and the
+/// public key.
+const ONE_ASYMMETRIC_KEY_V2: &str = "
+MHICAQEwBQYDK2VwBCIEINTuctv5E1hK1bbY8fdp+K06/nwoy/HU++CXqI9EdVhC
+oB8wHQYKKoZIhvcNAQkJFDEPDA1DdXJkbGUgQ2hhaXJzgSEAGb9ECWmEzf6FQbrB
+Z9w7lshQhqowtrbLDwJiWLb6Gkc=
+";
+
+/// A SignedData signed with Ed25519 by OpenSSL in streaming mode, which
+/// uses indefinite lengths and a constructed OCTET STRING.
+const SIGNED_DATA_BER: &str = "
+MIAGCSqGSIb3DQEHAqCAMIACAQExDTALBglghkgBZQMEAgEwgAYJKoZIhvcNAQcB
+oIAkgAQOSGVsbG8sIHlhc25hIQoAAAAAAACgggE0MIIBMDCB46ADAgECAgISNDAF
+BgMrZXAwFzEVMBMGA1UEAwwMeWFzbmEgc2lnbmVyMB4XDTI2MTAxOTA0MTgzNloX
+DTM2MTAxNjA0MTgzNlowFzEVMBMGA1UEAwwMeWFzbmEgc2lnbmVyMCowBQYDK2Vw
+AyEAxGgaILyTgVzRYwnkjnf2oN/83Ucl2qBx2XxGt0fvgl6jUzBRMB0GA1UdDgQW
+BBRnV9aq2FbtxCSdcy4ho2Y2bYnAUTAfBgNVHSMEGDAWgBRnV9aq2FbtxCSdcy4h
+o2Y2bYnAUTAPBgNVHRMBAf8EBTADAQH/MAUGAytlcANBAAKf0mpBG9Thjm5M+yQS
+s3pdM7BCwG7kNeH5Hu8pil3FeRFuAWVZ2y/erzPC8GNFXjf8cEj/ya7rrbvFYY2l
+7AoxgeYwgeMCAQEwHTAXMRUwEwYDVQQDDAx5YXNuYSBzaWduZXICAhI0MAsGCWCG
+SAFlAwQCAaBpMBgGCSqGSIb3DQEJAzELBgkqhkiG9w0BBwEwHAYJKoZIhvcNAQkF
+MQ8XDTI2MTAxOTA0MTg0MFowLwYJKoZIhvcNAQkEMSIEIG6bn5Xl5ftW+gPE1RLu
+vb2eEiL10FpC0vLeGfeQ6ZI3MAUGAytlcARA/rX/3xdd6sEXBDjzzG518BeXHGAs
+9Q4Xa/+Cx1zK1Iulpi5KpQ5+hCgcwpIQiAlMkhQGCuAHaJQeEtYD1S8PAAAAAAAA
+AA==
+";
+
+/// `SIGNED_DATA_BER` converted to DER by OpenSSL.
+const SIGNED_DATA: &str = "
+MIICZQYJKoZIhvcNAQcCoIICVjCCAlICAQExDTALBglghkgBZQMEAgEwHQYJKoZI
+hvcNAQcBoBAEDkhlbGxvLCB5YXNuYSEKoIIBNDCCATAwgeOgAwIBAgICEjQwBQYD
+K2VwMBcxFTATBgNVBAMMDHlhc25hIHNpZ25lcjAeFw0yNjEwMTkwNDE4MzZaFw0z
+NjEwMTYwNDE4MzZaMBcxFTATBgNVBAMMDHlhc25hIHNpZ25lcjAqMAUGAytlcAMh
+AMRoGiC8k4Fc0WMJ5I539qDf/N1HJdqgcdl8RrdH74Jeo1MwUTAdBgNVHQ4EFgQU
+Z1fWqthW7cQknXMuIaNmNm2JwFEwHwYDVR0jBBgwFoAUZ1fWqthW7cQknXMuIaNm
+Nm2JwFEwDwYDVR0TAQH/BAUwAwEB/zAFBgMrZXADQQACn9JqQRvU4Y5uTPskErN6
+XTOwQsBu5DXh+R7vKYpdxXkRbgFlWdsv3q8zwvBjRV43/HBI/8mu6627xWGNpewK
+MYHmMIHjAgEBMB0wFzEVMBMGA1UEAwwMeWFzbmEgc2lnbmVyAgISNDALBglghkgB
+ZQMEAgGgaTAYBgkqhkiG9w0BCQMxCwYJKoZIhvcNAQcBMBwGCSqGSIb3DQEJBTEP
+Fw0yNjEwMTkwNDE4NDBaMC8GCSqGSIb3DQEJBDEiBCBum5+V5eX7VvoDxNUS7r29
+nhIi9dBaQtLy3hn3kOmSNzAFBgMrZXAEQP61/98XXerBFwQ488xudfAXlxxgLPUO
+F2v/gsdcytSLpaYuSqUOfoQoHMKSEIgJTJIUBgrgB2iUHhLWA9UvDwA=
+";
+
+/// An EnvelopedData generated by OpenSSL, with ktri, kari, kekri and pwri
+/// recipients.
+const ENVELOPED_DATA: &str = "
+MIICYwYJKoZIhvcNAQcDoIICVDCCAlACAQMxggILMIGwAgEAMBkwFDESMBAGA1UE
+AwwJeWFzbmEgcnNhAgEBMA0GCSqGSIb3DQEBAQUABIGAC5GjPNlIkW3SoTD8J0SQ
+13xinn6EfbxU5ckUzgpA3sGSx609UOrU42mv9xIr+uWAbGnBaw039BAr29pUrnSs
+redteK+21N91vbPrpTQGUWTToQ73oMe7J6S+igIU5iG1o78Pv0arpvK2ndjAG0Rw
+xcl09YmqbmgKCAjhZp7lg9ihgagCAQOgUaFPMAkGByqGSM49AgEDQgAEyeIb6DlN
+jXAnOoTvOOLkj8oucsuxRV8P80O4+GCZQQXLX2XgTdlDJY4M9PVtLLRnSBRerz9Q
+Edb52KZQemx0pTAYBgkrgQUQhkg/AAIwCwYJYIZIAWUDBAEFMDYwNDAYMBMxETAP
+BgNVBAMMCHlhc25hIGVjAgECBBhaXoHPRQjGAU4IxPeYOTw0z/oAPYxNVueiMQIB
+BDAFBAMKCwwwCwYJYIZIAWUDBAEFBBiciTdpQMkdMMs0ieV3hQsGygdGKHbI5hqj
+eAIBAKAjBgkqhkiG9w0BBQwwFgQQpTLUxIrIazAeBeyKpaZksAICCAAwLAYLKoZI
+hvcNAQkQAwkwHQYJYIZIAWUDBAECBBD4TI9pKIgMElEd8yu3IdUHBCCIngqdSTen
+TWkRArp6f5tqlHaiNX7gzHfgcH25A1EkNDA8BgkqhkiG9w0BBwEwHQYJYIZIAWUD
+BAECBBCpD2MCLuFdKpYF8NAvSKBIgBDFtkpQyhdWAaJTBN1gcNrp
+";
+
+/// An OCSP request for two certificates with a nonce, generated by OpenSSL.
+const OCSP_REQUEST: &str = "
+MIGoMIGlMH4wPTA7MAkGBSsOAwIaBQAEFM/Q7jAG+HHd57v65CWXccmiIR06BBS7
+k8uGdNzJipCRZMuArJeM/9u9gwICEjQwPTA7MAkGBSsOAwIaBQAEFM/Q7jAG+HHd
+57v65CWXccmiIR06BBS7k8uGdNzJipCRZMuArJeM/9u9gwICEjWiIzAhMB8GCSsG
+AQUFBzABAgQSBBB2ZtvMZj1zavw7ZU2bWNXU
+";
+
+/// The OCSP response to `OCSP_REQUEST` by `openssl ocsp -index`, with
+/// a revoked and an unknown certificate.
+const OCSP_RESPONSE: &str = "
+MIIDPwoBAKCCAzgwggM0BgkrBgEFBQcwAQEEggMlMIIDITCCATWhGjAYMRYwFAYD
+VQQDDA15YXNuYSB0ZXN0IENBGA8yMDI2MTAxOTA0Mjc0NFowgeAweTA7MAkGBSsO
+AwIaBQAEFM/Q7jAG+HHd57v65CWXccmiIR06BBS7k8uGdNzJipCRZMuArJeM/9u9
+gwICEjShFhgPMjAyNjEwMTkwMDAwMDBaoAMKAQEYDzIwMjYxMDE5MDQyNzQ0WqAR
+GA8yMDI2MTAyNjA0Mjc0NFowYzA7MAkGBSsOAwIaBQAEFM/Q7jAG+HHd57v65CWX
+ccmiIR06BBS7k8uGdNzJipCRZMuArJeM/9u9gwICEjWCABgPMjAyNjEwMTkwNDI3
+NDRaoBEYDzIwMjYxMDI2MDQyNzQ0WqEjMCEwHwYJKwYBBQUHMAECBBIEEHZm28xm
+PXNq/DtlTZtY1dQwCgYIKoZIzj0EAwIDSAAwRQIhAKxw74wk7veGAl0wAaJ2pj0C
+lfaa8LOXX/SD1Fd7OWEMAiAsGqBjD4ZFlEuu4rkv4TSdVmW1osa9j8wCPARO6bcI
++qCCAY4wggGKMIIBhjCCASugAwIBAgIUIh3Gxflv3Vb+hkf3p8GX5UU3Fe4wCgYI
+KoZIzj0EAwIwGDEWMBQGA1UEAwwNeWFzbmEgdGVzdCBDQTAeFw0yNjEwMTkwNDI3
+NDFaFw0zNjEwMTYwNDI3NDFaMBgxFjAUBgNVBAMMDXlhc25hIHRlc3QgQ0EwWTAT
+BgcqhkjOPQIBBggqhkjOPQMBBwNCAAT5awBkJW0zK5krD+ki5wQvCIRJbEVKs/V+
+8o68ULbvZptXgm4Qb2B/HopsVaU/HdpfVuvQpDugnekurzjeycmFo1MwUTAdBgNV
+HQ4EFgQUu5PLhnTcyYqQkWTLgKyXjP/bvYMwHwYDVR0jBBgwFoAUu5PLhnTcyYqQ
+kWTLgKyXjP/bvYMwDwYDVR0TAQH/BAUwAwEB/zAKBggqhkjOPQQDAgNJADBGAiEA
+yrU2z5VNKwj8KQvuYBKgu2SU+dpER7JgEGLnhqRGkzMCIQDL+TZy40VFU2K0l+5r
+RGIwrSvzoTUs/eHL+433wBrllQ==
+";
+
+/// A time-stamp request with a nonce and certReq, generated by OpenSSL.
+const TIME_STAMP_REQ: &str = "
+MEMCAQEwMTANBglghkgBZQMEAgEFAAQgWJG1tSLV3whtD/CxEPvZ0hu0/HFjrzTQ
+goai6Eb2vgMCCEuokX/KFCJGAQH/
+";
+
+/// The response to `TIME_STAMP_REQ` by `openssl ts -reply`, with the
+/// accuracy, the ordering and the TSA name.
+const TIME_STAMP_RESP: &str = "
+MIIDszADAgEAMIIDqgYJKoZIhvcNAQcCoIIDmzCCA5cCAQMxDzANBglghkgBZQME
+AgEFADCBngYLKoZIhvcNAQkQAQSggY4EgYswgYgCAQEGBCoDBAEwMTANBglghkgB
+ZQMEAgEFAAQgWJG1tSLV3whtD/CxEPvZ0hu0/HFjrzTQgoai6Eb2vgMCAhACGBMy
+MDI2MTAxOTA0MzM1Ny4wNzJaMAoCAQGAAgH0gQFkAQH/AghLqJF/yhQiRqAYpBYw
+FDESMBAGA1UEAwwJeWFzbmEgdHNhoIIBmTCCAZUwggE7oAMCAQICFDUgj4TiYw65
+AzOHbS32muOA8UcIMAoGCCqGSM49BAMCMBQxEjAQBgNVBAMMCXlhc25hIHRzYTAe
+Fw0yNjEwMTkwNDMzNTFaFw0zNjEwMTYwNDMzNTFaMBQxEjAQBgNVBAMMCXlhc25h
+IHRzYTBZMBMGByqGSM49AgEGCCqGSM49AwEHA0IABHPy7lCMVvxcs1TyxQR3l1Qu
+cZaiK4f/MnlgGdz8BxvBuL3+RBI4OOpbeaAfOcK4juHsKu9oI9cfc/Y5EbwTzACj
+azBpMB0GA1UdDgQWBBQqAb6yUsY9mCyZW7Cm2ZVncNENUDAfBgNVHSMEGDAWgBQq
+Ab6yUsY9mCyZW7Cm2ZVncNENUDAPBgNVHRMBAf8EBTADAQH/MBYGA1UdJQEB/wQM
+MAoGCCsGAQUFBwMIMAoGCCqGSM49BAMCA0gAMEUCIQDmPyoMGt21Bbp2KKVMwl/T
+Hne9gP2UArBFItFiBbKgRQIgUaunrqGdd89LR6ZLGoizqXb6OvkDgKkP7Bc9H7ak
+eaoxggFBMIIBPQIBATAsMBQxEjAQBgNVBAMMCXlhc25hIHRzYQIUNSCPhOJjDrkD
+M4dtLfaa44DxRwgwDQYJYIZIAWUDBAIBBQCggaQwGgYJKoZIhvcNAQkDMQ0GCyqG
+SIb3DQEJEAEEMBwGCSqGSIb3DQEJBTEPFw0yNjEwMTkwNDMzNTdaMC8GCSqGSIb3
+DQEJBDEiBCCDqEX3mgO41bRzi+t4jHNJat5kHX4hALJOGVny14aNvjA3BgsqhkiG
+9w0BCRACLzEoMCYwJDAiBCCGDg23YJmfLujljq17gTvwDSOycixN3ab2Q/eSrbFs
+aTAKBggqhkjOPQQDAgRIMEYCIQC6BaGm5TapMTx9tD/DW2flaQcAZPr2jxYBF7q2
+o8q8PwIhALQ9Evk0tplMJHzV7yU9erZetkR2qWtwX6x4nS7UMsTh
+";
+
+/// A rejection by `openssl ts -reply` of a request with SHA-512.
+const TIME_STAMP_REJECTION: &str = "
+MDcwNQIBAjAsDCpNZXNzYWdlIGRpZ2VzdCBhbGdvcml0aG0gaXMgbm90IHN1cHBv
+cnRlZC4DAgeA
+";
+
+/// `openssl pkcs12 -export -keypbe NONE -certpbe NONE -name "yasna key"`
+/// with an EC key and its self-signed certificate, and the password "yasna".
+const PKCS12: &str = "
+MIIDcwIBAzCCAykGCSqGSIb3DQEHAaCCAxoEggMWMIIDEjCCAhAGCSqGSIb3DQEH
+AaCCAgEEggH9MIIB+TCCAfUGCyqGSIb3DQEMCgEDoIIBmjCCAZYGCiqGSIb3DQEJ
+FgGgggGGBIIBgjCCAX4wggEjoAMCAQICFCgQB71Lxm43pZ0CLjwkaVanToipMAoG
+CCqGSM49BAMCMBQxEjAQBgNVBAMMCXlhc25hIHAxMjAeFw0yNjEwMTkwNDM5MzBa
+Fw0zNjEwMTYwNDM5MzBaMBQxEjAQBgNVBAMMCXlhc25hIHAxMjBZMBMGByqGSM49
+AgEGCCqGSM49AwEHA0IABENzoWqORSD0rXnluj8j/2vgWn523YFFyjVULqQ4hO2J
+Tdt81TlnwJ7BMHr13h/+zIwj6p6mncsW1GWJ50uh9gyjUzBRMB0GA1UdDgQWBBTJ
+LcoTIaHYURAxddjGFrJcuCHP2jAfBgNVHSMEGDAWgBTJLcoTIaHYURAxddjGFrJc
+uCHP2jAPBgNVHRMBAf8EBTADAQH/MAoGCCqGSM49BAMCA0kAMEYCIQCTJybxO2ut
+UJxX5OTa4AVrsFZThd8MD0cc4mH1FjwnyAIhAOWmBktAyLY2Vx2+Gm4CKL66AvOb
+t1hS6Ku87I2uqLrFMUgwIQYJKoZIhvcNAQkUMRQeEgB5AGEAcwBuAGEAIABrAGUA
+eTAjBgkqhkiG9w0BCRUxFgQUgbboryGoRbZG/p46iZNVKMEo5HAwgfsGCSqGSIb3
+DQEHAaCB7QSB6jCB5zCB5AYLKoZIhvcNAQwKAQGggYowgYcCAQAwEwYHKoZIzj0C
+AQYIKoZIzj0DAQcEbTBrAgEBBCAhiJSSZKUXJIlfuPVdAwBkVETtBecvPXgMucsK
+vQQBaaFEA0IABENzoWqORSD0rXnluj8j/2vgWn523YFFyjVULqQ4hO2JTdt81Tln
+wJ7BMHr13h/+zIwj6p6mncsW1GWJ50uh9gwxSDAhBgkqhkiG9w0BCRQxFB4SAHkA
+YQBzAG4AYQAgAGsAZQB5MCMGCSqGSIb3DQEJFTEWBBSBtuivIahFtkb+njqJk1Uo
+wSjkcDBBMDEwDQYJYIZIAWUDBAIBBQAEIAcPTore+E6813E2FdkzdOE2cpIK7YUH
+xmUlfDOKr2i5BAhuTO2c7dw5twICCAA=
+";
+
+/// The same key and certificate exported with the default encryption
+/// (PBES2 with AES-256-CBC) and the password "yasna".
+const PKCS12_ENCRYPTED: &str = "
+MIIEXwIBAzCCBBUGCSqGSIb3DQEHAaCCBAYEggQCMIID/jCCAooGCSqGSIb3DQEH
+BqCCAnswggJ3AgEAMIICcAYJKoZIhvcNAQcBMF8GCSqGSIb3DQEFDTBSMDEGCSqG
+SIb3DQEFDDAkBBAill98ha009meb7PyMBsSlAgIIADAMBggqhkiG9w0CCQUAMB0G
+CWCGSAFlAwQBKgQQcFTyDb1ni4HAhOswxwsqoICCAgDp2Ahfg2KFHrjUUZ+wCJG3
+mxsUUDhYY8oiNtrh8xKEamM3Gm0rBopt7Ah7W2QsIUPCXJh1La92SIFSpY4bhHLA
+0chkFRW4rHjEvy1soptmfHTeLs1swhefAyEmcUhPsVNpiAxcedfOWmL3T+dFElOe
+cd1AyYRyJ1RfGJrgsA68zHKrt5EfXw8xhVyvkoFyeNtilcS66WyXotqcM4GM63KS
+OdCyNbGXQ5WZ8vRyMfkX0XNF8clFEFM2/wFW75lCqf7Fd3znvtV5QncegC9rhjFn
+ZK36P6442zVtoq8caLBu/Q5RZv4xuJP77X0fWeYy1LbSXubpVDFUnSYXJm+QxGtj
+3xqKbTg1iEJZgiDdATjqCIOteZLSrQiFaQKJO+qDaWvmUN1eFzQ+CWV+gAYQVw8r
+d4MvrPg8kGrkTx+FjVWbP4gVJE7pyI6o/OdQbg5EHdeONCRkZzwre9LDT7PK3A1J
+eNunni5h0vMr+1phiCB1Ztq2TlJSrlxsJLPxDuUXCwYgObp7XEqq29ZPxrXX5fhl
+iuoj7uxOjDdTs/ONdMJLhCy4vnqCfo5IL2ZSWFdCUGEKA8qir4DWgqNO1m1807Eg
+2RqFA6Eszv6c/NW/ikeak75GuqmZFgDSkd6OZ7Q+0wVoTVpqobo9oZymbK/D8rY9
+m8f1ay5ug6YTTE+vcQnQBTCCAWwGCSqGSIb3DQEHAaCCAV0EggFZMIIBVTCCAVEG
+CyqGSIb3DQEMCgECoIH3MIH0MF8GCSqGSIb3DQEFDTBSMDEGCSqGSIb3DQEFDDAk
+BBAYOyiafiy3i3RSUKDbqTl/AgIIADAMBggqhkiG9w0CCQUAMB0GCWCGSAFlAwQB
+KgQQHKgDnBK3WBRxMHoHKt610gSBkCpS6xVqsiqv6GE5z7lN3Icy0KYFL28gs3UR
+yU1GJwP/ujoD56U/TSN5y2WJGN+nKxP2SjEPHwhLLHrDr3beOrlbJNejtaKVnCZE
++RhwC5lNHNRQordmTryXVpYnx2lluU0Fc747VY1khrRCcsa3oXgefRvDkET808N2
+4lYxlyT0iWMItEiePj1eSTJ1J2CNZTFIMCEGCSqGSIb3DQEJFDEUHhIAeQBhAHMA
+bgBhACAAawBlAHkwIwYJKoZIhvcNAQkVMRYEFIG26K8hqEW2Rv6eOomTVSjBKORw
+MEEwMTANBglghkgBZQMEAgEFAAQgsnca2Ap9ifIlgb5EP7i1uuEDSpyBVLGUr8mh
+qvnmExoECJJaZi/QFT6sAgIIAA==
+";
+
+fn ecdsa_with_sha256() -> AlgorithmIdentifier {
+    AlgorithmIdentifier::new(
+        ObjectIdentifier::from_slice(&[1, 2, 840, 10045, 4, 3, 2]))
+}
+
+fn sample_tbs_certificate(version: Version) -> TBSCertificate {
+    TBSCertificate {
+        version,
+        serial_number: SerialNumber::from_unsigned_bytes(&[255; 20]),
+        signature: ecdsa_with_sha256(),
+        issuer: Name::default(),
+        validity: Validity {
+            not_before: RawDateTime::new(1949, 12, 31, 0, 0, 0, 0).unwrap(),
+            not_after: RawDateTime::new(9999, 12, 31, 23, 59, 59, 0).unwrap(),
+        },
+        subject: Name::default(),
+        subject_public_key_info: SubjectPublicKeyInfo {
+            algorithm: ecdsa_with_sha256(),
+            subject_public_key: BitString::from_bytes(vec![4, 1, 2]),
+        },
+        issuer_unique_id: None,
+        subject_unique_id: None,
+        extensions: vec![],
+    }
+}
+
+#[test]
+fn test_certificate_v3() {
+    let der = decode_base64(V3_CERTIFICATE).unwrap();
+    let certificate : Certificate = decode_der(&der).unwrap();
+    assert_eq!(certificate.tbs_bytes(), &der[4..283]);
+    assert_eq!(certificate.signature_algorithm, ecdsa_with_sha256());
+    assert_eq!(certificate.signature_value.len(), 72 * 8);
+
+    let tbs_certificate = certificate.tbs_certificate.value();
+    assert_eq!(tbs_certificate.version, Version::V3);
+    assert_eq!(tbs_certificate.serial_number.to_u64(), Some(0x1234));
+    assert_eq!(tbs_certificate.signature, ecdsa_with_sha256());
+    assert_eq!(tbs_certificate.issuer.to_string(), "CN=yasna test");
+    assert_eq!(encode_der(&tbs_certificate.issuer), &der[29..52]);
+    assert_eq!(tbs_certificate.subject, tbs_certificate.issuer);
+    assert_eq!(tbs_certificate.validity.not_before,
+        RawDateTime::new(2026, 10, 19, 3, 40, 59, 0).unwrap());
+    assert!(tbs_certificate.validity.contains(
+        &RawDateTime::new(2036, 10, 16, 3, 40, 59, 0).unwrap()));
+    assert!(!tbs_certificate.validity.contains(
+        &RawDateTime::new(2036, 10, 16, 3, 41, 0, 0).unwrap()));
+    let spki = &tbs_certificate.subject_public_key_info;
+    assert_eq!(spki.algorithm.algorithm,
+        ObjectIdentifier::from_slice(&[1, 2, 840, 10045, 2, 1]));
+    assert_eq!(spki.algorithm.parameters.as_ref().unwrap().value(),
+        &[42, 134, 72, 206, 61, 3, 1, 7]);
+    assert_eq!(spki.subject_public_key.as_octets().unwrap().len(), 65);
+    assert_eq!(tbs_certificate.issuer_unique_id, None);
+    assert_eq!(tbs_certificate.extensions.len(), 3);
+    let basic_constraints = tbs_certificate.extension(
+        &ObjectIdentifier::from_slice(&[2, 5, 29, 19])).unwrap();
+    assert!(basic_constraints.critical);
+    assert_eq!(basic_constraints.extn_value, &[48, 3, 1, 1, 255]);
+    assert!(!tbs_certificate.extensions[0].critical);
+
+    assert_eq!(encode_der(&certificate), der);
+    assert_eq!(encode_der(tbs_certificate), certificate.tbs_bytes());
+}
+
+#[test]
+fn test_certificate_versions() {
+    for &version in &[Version::V1, Version::V2, Version::V3] {
+        let mut tbs_certificate = sample_tbs_certificate(version);
+        if version >= Version::V2 {
+            tbs_certificate.issuer_unique_id =
+                Some(BitString::from_bytes_and_len(vec![1, 128], 9));
+            tbs_certificate.subject_unique_id =
+                Some(BitString::from_bytes(vec![]));
+        }
+        if version >= Version::V3 {
+            tbs_certificate.extensions.push(Extension {
+                extn_id: ObjectIdentifier::from_slice(&[2, 5, 29, 14]),
+                critical: false,
+                extn_value: vec![4, 0],
+            });
+        }
+        let certificate = Certificate::sign(tbs_certificate.clone(),
+            |tbs_bytes| Ok::<_, ()>(tbs_bytes.to_vec())).unwrap();
+        assert_eq!(certificate.signature_value.as_octets().unwrap(),
+            encode_der(&tbs_certificate));
+        let der = encode_der(&certificate);
+        let decoded : Certificate = decode_der(&der).unwrap();
+        assert_eq!(decoded, certificate);
+        assert_eq!(decoded.tbs_certificate.into_value(), tbs_certificate);
+    }
+
+    let tbs_certificate = sample_tbs_certificate(Version::V1);
+    let der = encode_der(&tbs_certificate);
+    // version: absent, serialNumber: 21 bytes
+    assert_eq!(&der[2..6], &[2, 21, 0, 255]);
+    // UTCTime can't express 1949.
+    assert_eq!(&der[39..43], &[48, 34, 24, 15]);
+}
+
+#[test]
+fn test_certificate_version_mismatch() {
+    let mut tbs_certificate = sample_tbs_certificate(Version::V2);
+    tbs_certificate.extensions.push(Extension {
+        extn_id: ObjectIdentifier::from_slice(&[2, 5, 29, 14]),
+        critical: false,
+        extn_value: vec![4, 0],
+    });
+    let der = encode_der(&tbs_certificate);
+    assert_eq!(decode_der::<TBSCertificate>(&der).unwrap_err().kind(),
+        ASN1ErrorKind::Invalid);
+
+    let mut tbs_certificate = sample_tbs_certificate(Version::V1);
+    tbs_certificate.subject_unique_id = Some(BitString::from_bytes(vec![1]));
+    let der = encode_der(&tbs_certificate);
+    assert_eq!(decode_der::<TBSCertificate>(&der).unwrap_err().kind(),
+        ASN1ErrorKind::Invalid);
+
+    // An explicit v1 is not DER.
+    let mut der = encode_der(&sample_tbs_certificate(Version::V1));
+    der.splice(2..2, vec![160, 3, 2, 1, 0]);
+    der[1] += 5;
+    assert!(decode_der::<TBSCertificate>(&der).is_err());
+    let tbs_certificate : WithRaw<TBSCertificate> = decode_ber(&der).unwrap();
+    assert_eq!(tbs_certificate.value().version, Version::V1);
+    assert_eq!(tbs_certificate.raw(), &der[..]);
+}
+
+#[test]
+fn test_serial_number() {
+    let tests : &[(&[u8], &[u8], Option<u64>)] = &[
+        (&[], &[0], Some(0)),
+        (&[0, 0], &[0], Some(0)),
+        (&[127], &[127], Some(127)),
+        (&[0, 128], &[0, 128], Some(128)),
+        (&[255; 8], &[0, 255, 255, 255, 255, 255, 255, 255, 255],
+            Some(u64::MAX)),
+        (&[1, 0, 0, 0, 0, 0, 0, 0, 0], &[1, 0, 0, 0, 0, 0, 0, 0, 0], None),
+    ];
+    for &(bytes, contents, value) in tests {
+        let serial = SerialNumber::from_unsigned_bytes(bytes);
+        assert_eq!(serial.as_bytes(), contents);
+        assert_eq!(serial.to_u64(), value);
+        let der = encode_der(&serial);
+        assert_eq!(&der[2..], contents);
+        assert_eq!(decode_der::<SerialNumber>(&der).unwrap(), serial);
+    }
+    assert_eq!(SerialNumber::from(0x1234).as_bytes(), &[18, 52]);
+    let negative : SerialNumber = decode_der(&[2, 2, 255, 1]).unwrap();
+    assert!(negative.is_negative());
+    assert_eq!(negative.to_u64(), None);
+    assert_eq!(encode_der(&negative), &[2, 2, 255, 1]);
+    let contents : Vec<u8> = negative.as_bytes().to_vec();
+    assert_eq!(contents, &[255, 1]);
+}
+
+#[test]
+fn test_name_der() {
+    let name : Name = "CN=Alice+UID=alice+C=JP,DC=example,DC=com".parse().unwrap();
+    assert_eq!(name.rdns.len(), 3);
+    assert_eq!(name.rdns[2].attributes.len(), 3);
+    let der = encode_der(&name);
+    let decoded : Name = decode_der(&der).unwrap();
+    // Attributes in the SET OF are sorted by their encodings.
+    let types : Vec<String> = decoded.rdns[2].attributes.iter()
+        .map(|attribute| attribute.attr_type.to_string()).collect();
+    assert_eq!(types, &["2.5.4.6", "2.5.4.3", "0.9.2342.19200300.100.1.1"]);
+    assert_eq!(decoded.to_string(), "C=JP+CN=Alice+UID=alice,DC=example,DC=com");
+    assert!(decoded.matches(&name));
+    assert_ne!(decoded, name);
+    let tags : Vec<Tag> = decoded.attributes()
+        .map(|attribute| attribute.value.tag()).collect();
+    assert_eq!(tags, &[TAG_IA5STRING, TAG_IA5STRING, TAG_PRINTABLESTRING,
+        TAG_UTF8STRING, TAG_UTF8STRING]);
+
+    // An unsorted SET OF is not DER.
+    let unsorted = &[48, 24, 49, 22, 48, 9, 6, 3, 85, 4, 6, 19, 2, 74, 80,
+        48, 9, 6, 3, 85, 4, 3, 12, 2, 74, 80];
+    assert!(decode_der::<Name>(unsorted).is_err());
+    let name : Name = decode_ber(unsorted).unwrap();
+    assert_eq!(name.to_string(), "C=JP+CN=JP");
+    assert_eq!(encode_der(&name)[4..15], unsorted[15..26]);
+}
+
+#[test]
+fn test_name_string() {
+    let tests : &[(&str, &str)] = &[
+        ("", ""),
+        ("CN=", "CN="),
+        (r#"CN=\ a\,b\+c\;\<\>\"\\\#\=\ "#, r#"CN=\ a\,b\+c\;\<\>\"\\#=\ "#),
+        (r"CN=\#a#", r"CN=\#a#"),
+        (r"CN=\E6\9D\B1\E4\BA\AC", "CN=\u{6771}\u{4eac}"),
+        (r"CN=a\00b", r"CN=a\00b"),
+        ("cn=x", "CN=x"),
+        ("CN=a  ,  O=b  +  OU=c", "CN=a,O=b+OU=c"),
+        ("2.5.4.3=x", "CN=x"),
+        ("2.5.4.45=#030200FF", "2.5.4.45=#030200FF"),
+        ("L=#0C03666F6F", "L=foo"),
+        ("ST=#1E020041", "ST=A"),
+        ("STREET=#1C0400000041", "STREET=A"),
+        ("O=#1403E9E9E9", "O=\u{e9}\u{e9}\u{e9}"),
+    ];
+    for &(s, expected) in tests {
+        let name : Name = s.parse().unwrap();
+        assert_eq!(name.to_string(), expected, "{}", s);
+        let reparsed : Name = expected.parse().unwrap();
+        assert!(reparsed.matches(&name), "{}", s);
+    }
+
+    let tests : &[(&str, usize)] = &[
+        ("CN", 2),
+        ("CN =a", 2),
+        ("X=a", 0),
+        ("1.40=a", 0),
+        ("CN=a;O=b", 4),
+        (r"CN=a\", 5),
+        (r"CN=a\x", 5),
+        (r"CN=a\4", 6),
+        (r"CN=\FF", 3),
+        ("CN=#0", 5),
+        ("CN=#0401", 4),
+        ("CN=#0400 x", 9),
+        ("CN=a,", 5),
+    ];
+    for &(s, position) in tests {
+        let err = s.parse::<Name>().unwrap_err();
+        assert_eq!(err.position(), position, "{}", s);
+    }
+}
+
+#[test]
+fn test_name_matches() {
+    let tests : &[(&str, &str, bool)] = &[
+        ("CN=Foo  Bar", "cn=  foo bar ", true),
+        ("C=JP", "C=jp", true),
+        ("C=JP,O=a", "O=a,C=JP", false),
+        ("C=JP+O=a", "O=a+C=JP", true),
+        ("C=JP+O=a", "C=JP", false),
+        ("CN=a", "UID=a", false),
+        ("2.5.4.45=#030200FF", "2.5.4.45=#030200FF", true),
+        ("2.5.4.45=#030200FF", "2.5.4.45=#030200FE", false),
+        ("CN=\u{c9}", "CN=\u{e9}", true),
+    ];
+    for &(a, b, expected) in tests {
+        let a : Name = a.parse().unwrap();
+        let b : Name = b.parse().unwrap();
+        assert_eq!(a.matches(&b), expected, "{} {}", a, b);
+    }
+}
+
+#[test]
+fn test_extension_values_v3() {
+    let der = decode_base64(V3_CERTIFICATE).unwrap();
+    let certificate : Certificate = decode_der(&der).unwrap();
+    let key_identifier = vec![
+        108, 153, 0, 130, 105, 54, 107, 109, 248, 130,
+        235, 8, 227, 247, 32, 222, 113, 178, 51, 23];
+    let values = certificate.tbs_certificate.value().extensions.iter()
+        .map(|extension| extension.parse_value().unwrap().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![
+        ExtensionValue::SubjectKeyIdentifier(key_identifier.clone()),
+        ExtensionValue::AuthorityKeyIdentifier(AuthorityKeyIdentifier {
+            key_identifier: Some(key_identifier),
+            ..AuthorityKeyIdentifier::default()
+        }),
+        ExtensionValue::BasicConstraints(BasicConstraints {
+            ca: true,
+            path_len_constraint: None,
+        }),
+    ]);
+    for (value, extension) in values.iter()
+            .zip(&certificate.tbs_certificate.value().extensions) {
+        assert_eq!(&Extension::new(value, extension.critical), extension);
+    }
+
+    let unknown = Extension {
+        extn_id: ObjectIdentifier::from_slice(&[1, 2, 3]),
+        critical: false,
+        extn_value: vec![5, 0],
+    };
+    assert_eq!(unknown.parse_value().unwrap(), None);
+    let broken = Extension {
+        extn_id: ObjectIdentifier::from(oids::ID_CE_KEY_USAGE),
+        critical: true,
+        extn_value: vec![4, 0],
+    };
+    assert!(broken.parse_value().is_err());
+}
+
+#[test]
+fn test_general_name() {
+    let tests : &[(GeneralName, &[u8])] = &[
+        (GeneralName::OtherName(OtherName {
+            type_id: ObjectIdentifier::from_slice(&[1, 2, 3]),
+            value: TaggedDerValue::from_tag_and_bytes(
+                TAG_UTF8STRING, b"a".to_vec()),
+        }), &[160, 9, 6, 2, 42, 3, 160, 3, 12, 1, 97]),
+        (GeneralName::Rfc822Name("a@b".to_string()),
+            &[129, 3, 97, 64, 98]),
+        (GeneralName::DnsName("a".to_string()), &[130, 1, 97]),
+        (GeneralName::X400Address(vec![
+            TaggedDerValue::from_tag_and_bytes(
+                TAG_PRINTABLESTRING, b"JP".to_vec()),
+        ]), &[163, 4, 19, 2, 74, 80]),
+        (GeneralName::DirectoryName("CN=a".parse().unwrap()),
+            &[164, 14, 48, 12, 49, 10, 48, 8, 6, 3, 85, 4, 3, 12, 1, 97]),
+        (GeneralName::EdiPartyName(EdiPartyName {
+            name_assigner: None,
+            party_name: DirectoryString::Utf8String(
+                Utf8String::try_from("a").unwrap()),
+        }), &[165, 5, 161, 3, 12, 1, 97]),
+        (GeneralName::UniformResourceIdentifier("http://a/".to_string()),
+            &[134, 9, 104, 116, 116, 112, 58, 47, 47, 97, 47]),
+        (GeneralName::IpAddress(vec![127, 0, 0, 1]),
+            &[135, 4, 127, 0, 0, 1]),
+        (GeneralName::RegisteredId(ObjectIdentifier::from_slice(&[1, 2, 3])),
+            &[136, 2, 42, 3]),
+    ];
+    for &(ref name, der) in tests {
+        assert_eq!(encode_der(name), der, "{:?}", name);
+        assert_eq!(&decode_der::<GeneralName>(der).unwrap(), name);
+    }
+
+    let invalid : &[&[u8]] = &[
+        &[22, 1, 97],
+        &[137, 1, 97],
+        &[130, 1, 255],
+        &[164, 2, 49, 0],
+    ];
+    for &der in invalid {
+        assert!(decode_der::<GeneralName>(der).is_err(), "{:?}", der);
+    }
+}
+
+#[test]
+fn test_extension_values() {
+    let uri = |s: &str| GeneralName::UniformResourceIdentifier(s.to_string());
+    let values = vec![
+        ExtensionValue::BasicConstraints(BasicConstraints::default()),
+        ExtensionValue::BasicConstraints(BasicConstraints {
+            ca: true,
+            path_len_constraint: Some(1),
+        }),
+        ExtensionValue::KeyUsage(KeyUsage::default()),
+        ExtensionValue::KeyUsage(KeyUsage {
+            digital_signature: true,
+            decipher_only: true,
+            ..KeyUsage::default()
+        }),
+        ExtensionValue::ExtendedKeyUsage(vec![
+            ObjectIdentifier::from(oids::ID_KP_SERVER_AUTH),
+            ObjectIdentifier::from(oids::ID_KP_CLIENT_AUTH),
+        ]),
+        ExtensionValue::SubjectAltName(vec![
+            GeneralName::DnsName("example.com".to_string()),
+            GeneralName::IpAddress(vec![0; 16]),
+        ]),
+        ExtensionValue::IssuerAltName(vec![
+            GeneralName::Rfc822Name("ca@example.com".to_string()),
+        ]),
+        ExtensionValue::AuthorityKeyIdentifier(AuthorityKeyIdentifier {
+            key_identifier: None,
+            authority_cert_issuer: Some(vec![
+                GeneralName::DirectoryName("CN=CA".parse().unwrap()),
+            ]),
+            authority_cert_serial_number: Some(SerialNumber::from(1)),
+        }),
+        ExtensionValue::SubjectKeyIdentifier(vec![1, 2, 3]),
+        ExtensionValue::CrlDistributionPoints(vec![
+            DistributionPoint {
+                distribution_point: Some(DistributionPointName::FullName(
+                    vec![uri("http://example.com/ca.crl")])),
+                ..DistributionPoint::default()
+            },
+            DistributionPoint {
+                distribution_point: Some(
+                    DistributionPointName::NameRelativeToCrlIssuer(
+                        "CN=CRL1".parse::<Name>().unwrap().rdns[0].clone())),
+                reasons: Some(BitString::from_set_bits(&[1, 2])),
+                crl_issuer: Some(vec![
+                    GeneralName::DirectoryName("CN=CA".parse().unwrap()),
+                ]),
+            },
+        ]),
+        ExtensionValue::AuthorityInfoAccess(vec![
+            AccessDescription {
+                access_method: ObjectIdentifier::from(oids::ID_AD_OCSP),
+                access_location: uri("http://ocsp.example.com/"),
+            },
+            AccessDescription {
+                access_method: ObjectIdentifier::from(oids::ID_AD_CA_ISSUERS),
+                access_location: uri("http://example.com/ca.crt"),
+            },
+        ]),
+        ExtensionValue::CertificatePolicies(vec![
+            PolicyInformation {
+                policy_identifier: ObjectIdentifier::from(oids::ANY_POLICY),
+                policy_qualifiers: vec![],
+            },
+            PolicyInformation {
+                policy_identifier: ObjectIdentifier::from_slice(
+                    &[2, 23, 140, 1, 2, 1]),
+                policy_qualifiers: vec![PolicyQualifierInfo {
+                    policy_qualifier_id: ObjectIdentifier::from(
+                        oids::ID_QT_CPS),
+                    qualifier: TaggedDerValue::from_tag_and_bytes(
+                        TAG_IA5STRING, b"http://example.com/cps".to_vec()),
+                }],
+            },
+        ]),
+        ExtensionValue::NameConstraints(NameConstraints {
+            permitted_subtrees: vec![GeneralSubtree {
+                base: GeneralName::DnsName(".example.com".to_string()),
+                minimum: 0,
+                maximum: None,
+            }],
+            excluded_subtrees: vec![GeneralSubtree {
+                base: GeneralName::IpAddress(vec![10, 0, 0, 0, 255, 0, 0, 0]),
+                minimum: 1,
+                maximum: Some(2),
+            }],
+        }),
+        ExtensionValue::NameConstraints(NameConstraints::default()),
+        ExtensionValue::PolicyConstraints(PolicyConstraints {
+            require_explicit_policy: Some(0),
+            inhibit_policy_mapping: None,
+        }),
+    ];
+    for value in &values {
+        let extension = Extension::new(value, false);
+        assert_eq!(extension.extn_id, value.extn_id());
+        assert_eq!(extension.parse_value().unwrap().as_ref(), Some(value));
+    }
+
+    assert_eq!(encode_der(&BasicConstraints::default()), &[48, 0]);
+    assert_eq!(encode_der(&KeyUsage::default()), &[3, 1, 0]);
+    assert_eq!(encode_der(&KeyUsage {
+        digital_signature: true,
+        decipher_only: true,
+        ..KeyUsage::default()
+    }), &[3, 3, 7, 128, 128]);
+    assert_eq!(encode_der(&PolicyConstraints {
+        require_explicit_policy: None,
+        inhibit_policy_mapping: Some(2),
+    }), &[48, 3, 129, 1, 2]);
+    // cA is DEFAULT FALSE and must not be encoded.
+    assert_eq!(decode_der::<BasicConstraints>(&[48, 3, 1, 1, 0])
+        .unwrap_err().kind(), ASN1ErrorKind::Invalid);
+}
+
+#[test]
+fn test_certification_request() {
+    let der = decode_base64(CERTIFICATION_REQUEST).unwrap();
+    let request : CertificationRequest = decode_der(&der).unwrap();
+    assert_eq!(request.info_bytes(), &der[3..156]);
+    assert_eq!(request.signature_algorithm.algorithm,
+        ObjectIdentifier::from_slice(&[1, 3, 101, 112]));
+    assert_eq!(request.signature.as_octets().unwrap().len(), 64);
+    assert_eq!(encode_der(&request), der);
+
+    let info = request.certification_request_info.value();
+    assert_eq!(info.subject.to_string(), "CN=yasna test");
+    assert_eq!(encode_der(&info.subject_pk_info), &der[32..76]);
+    assert_eq!(info.attributes.len(), 2);
+    assert_eq!(info.challenge_password().unwrap().unwrap(), "secret");
+    let extensions = info.extension_request().unwrap().unwrap();
+    assert_eq!(extensions.len(), 2);
+    assert_eq!(extensions[0].parse_value().unwrap().unwrap(),
+        ExtensionValue::SubjectAltName(vec![
+            GeneralName::DnsName("example.com".to_string()),
+        ]));
+    assert!(extensions[1].critical);
+    assert_eq!(extensions[1].parse_value().unwrap().unwrap(),
+        ExtensionValue::BasicConstraints(BasicConstraints::default()));
+
+    let mut rebuilt = CertificationRequestInfo::new(
+        info.subject.clone(), &der[32..76]).unwrap();
+    rebuilt.set_extension_request(&extensions);
+    rebuilt.attributes.push(info.attributes[0].clone());
+    let rebuilt = CertificationRequest::sign(rebuilt,
+        request.signature_algorithm.clone(), |info_bytes| {
+            assert_eq!(info_bytes, &der[3..156]);
+            Ok::<_, ()>(request.signature.as_bytes().to_vec())
+        }).unwrap();
+    assert_eq!(encode_der(&rebuilt), der);
+}
+
+#[test]
+fn test_certification_request_attributes() {
+    let der = decode_base64(CERTIFICATION_REQUEST).unwrap();
+    let request : CertificationRequest = decode_der(&der).unwrap();
+    let mut info = request.certification_request_info.value().clone();
+
+    info.set_challenge_password("secret");
+    info.set_challenge_password("\u{e9}");
+    assert_eq!(info.attributes.len(), 2);
+    assert_eq!(info.attributes[1].values[0].tag(), TAG_UTF8STRING);
+    assert_eq!(info.challenge_password().unwrap().unwrap(), "\u{e9}");
+    info.set_challenge_password("secret");
+    assert_eq!(info.attributes[1].values[0].tag(), TAG_PRINTABLESTRING);
+
+    let value = info.attributes[1].values[0].clone();
+    info.attributes[1].values.push(value);
+    assert!(info.challenge_password().is_err());
+    info.attributes.clear();
+    assert_eq!(info.challenge_password().unwrap(), None);
+    assert_eq!(info.extension_request().unwrap(), None);
+    assert!(encode_der(&info).ends_with(&[160, 0]));
+
+    // Only v1 is defined.
+    let mut der = der;
+    der[8] = 1;
+    assert!(decode_der::<CertificationRequest>(&der).is_err());
+    // An invalid SubjectPublicKeyInfo
+    assert!(CertificationRequestInfo::new(Name::default(), &[48, 0])
+        .is_err());
+}
+
+#[test]
+fn test_certificate_list() {
+    let der = decode_base64(CERTIFICATE_LIST).unwrap();
+    let crl : CertificateList = decode_der(&der).unwrap();
+    assert_eq!(crl.tbs_bytes(), &der[4..290]);
+    assert_eq!(crl.signature_algorithm, ecdsa_with_sha256());
+    assert_eq!(encode_der(&crl), der);
+
+    let tbs_cert_list = crl.tbs_cert_list.value();
+    assert_eq!(tbs_cert_list.version, Version::V2);
+    assert_eq!(tbs_cert_list.issuer.to_string(), "CN=yasna CA");
+    assert_eq!(tbs_cert_list.this_update,
+        RawDateTime::new(2026, 10, 19, 4, 2, 48, 0).unwrap());
+    assert_eq!(tbs_cert_list.next_update,
+        Some(RawDateTime::new(2026, 10, 26, 4, 2, 48, 0).unwrap()));
+    let values = tbs_cert_list.crl_extensions.iter()
+        .map(|extension| extension.parse_value().unwrap().unwrap())
+        .collect::<Vec<_>>();
+    assert!(matches!(values[0], ExtensionValue::AuthorityKeyIdentifier(_)));
+    assert_eq!(values[1], ExtensionValue::IssuingDistributionPoint(
+        IssuingDistributionPoint {
+            distribution_point: Some(DistributionPointName::FullName(vec![
+                GeneralName::UniformResourceIdentifier(
+                    "http://example.com/ca.crl".to_string()),
+            ])),
+            only_contains_user_certs: true,
+            ..IssuingDistributionPoint::default()
+        }));
+    assert_eq!(values[2],
+        ExtensionValue::CrlNumber(SerialNumber::from(4096)));
+
+    let entries = &tbs_cert_list.revoked_certificates;
+    let serials = entries.iter()
+        .map(|entry| entry.user_certificate.to_u64().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(serials, vec![0x01, 0xFF, 0x0203]);
+    assert_eq!(entries[0].crl_entry_extensions[0].parse_value().unwrap(),
+        Some(ExtensionValue::ReasonCode(CrlReason::KeyCompromise)));
+    assert!(entries[1].crl_entry_extensions.is_empty());
+    let invalidity_date = entries[2].extension(
+        &ObjectIdentifier::from(oids::ID_CE_INVALIDITY_DATE)).unwrap();
+    assert_eq!(invalidity_date.parse_value().unwrap(),
+        Some(ExtensionValue::InvalidityDate(
+            GeneralizedTime::parse(b"20260101000000Z").unwrap())));
+    assert_eq!(Extension::new(&invalidity_date.parse_value().unwrap()
+        .unwrap(), false), *invalidity_date);
+
+    let rebuilt = CertificateList::sign(tbs_cert_list.clone(), |tbs_bytes| {
+        assert_eq!(tbs_bytes, &der[4..290]);
+        Ok::<_, ()>(crl.signature_value.as_bytes().to_vec())
+    }).unwrap();
+    assert_eq!(rebuilt, crl);
+}
+
+#[test]
+fn test_certificate_list_ref() {
+    let der = decode_base64(CERTIFICATE_LIST).unwrap();
+    let crl : CertificateList = decode_der(&der).unwrap();
+    let crl_ref = CertificateListRef::parse_der(&der).unwrap();
+    assert_eq!(crl_ref.tbs_bytes, crl.tbs_bytes());
+    assert_eq!(crl_ref.signature_algorithm, crl.signature_algorithm);
+    assert_eq!(crl_ref.signature_value, crl.signature_value);
+    let mut tbs_cert_list = crl_ref.tbs_cert_list.clone();
+    assert!(tbs_cert_list.revoked_certificates.is_empty());
+    tbs_cert_list.revoked_certificates = crl_ref.revoked_certificates.clone()
+        .collect::<Result<_, _>>().unwrap();
+    assert_eq!(&tbs_cert_list, crl.tbs_cert_list.value());
+
+    let entry = crl_ref.find(&SerialNumber::from(0xFF)).unwrap().unwrap();
+    assert_eq!(entry, crl.tbs_cert_list.value().revoked_certificates[1]);
+    assert_eq!(crl_ref.find(&SerialNumber::from(2)).unwrap(), None);
+    assert!(CertificateListRef::parse_ber(&der).is_ok());
+
+    // The second entry has an invalid revocation date.
+    let mut der = der;
+    der[120] = b'X';
+    assert!(decode_der::<CertificateList>(&der).is_err());
+    let crl_ref = CertificateListRef::parse_der(&der).unwrap();
+    assert!(crl_ref.find(&SerialNumber::from(1)).unwrap().is_some());
+    assert!(crl_ref.find(&SerialNumber::from(0xFF)).is_err());
+}
+
+#[test]
+fn test_certificate_list_versions() {
+    let entry = RevokedCertificate {
+        user_certificate: SerialNumber::from(1),
+        revocation_date: RawDateTime::new(2026, 1, 1, 0, 0, 0, 0).unwrap(),
+        crl_entry_extensions: vec![],
+    };
+    let mut tbs_cert_list = TBSCertList {
+        version: Version::V1,
+        signature: ecdsa_with_sha256(),
+        issuer: Name::default(),
+        this_update: RawDateTime::new(2026, 1, 1, 0, 0, 0, 0).unwrap(),
+        next_update: None,
+        revoked_certificates: vec![],
+        crl_extensions: vec![],
+    };
+    let der = encode_der(&tbs_cert_list);
+    assert_eq!(der[2], 48);
+    assert_eq!(decode_der::<TBSCertList>(&der).unwrap(), tbs_cert_list);
+
+    tbs_cert_list.revoked_certificates.push(entry.clone());
+    tbs_cert_list.revoked_certificates[0].crl_entry_extensions.push(
+        Extension::new(&ExtensionValue::ReasonCode(CrlReason::Superseded),
+            false));
+    let der = encode_der(&tbs_cert_list);
+    assert!(decode_der::<TBSCertList>(&der).is_err());
+    let crl = CertificateList::sign(tbs_cert_list.clone(),
+        |_| Ok::<_, ()>(vec![0; 64])).unwrap();
+    let crl_der = encode_der(&crl);
+    let crl_ref = CertificateListRef::parse_der(&crl_der).unwrap();
+    let mut entries = crl_ref.revoked_certificates.clone();
+    assert!(entries.next().unwrap().is_err());
+    assert!(entries.next().is_none());
+    assert!(crl_ref.find(&SerialNumber::from(1)).is_err());
+    tbs_cert_list.version = Version::V2;
+    let der = encode_der(&tbs_cert_list);
+    assert_eq!(&der[2..5], &[2, 1, 1]);
+    assert_eq!(decode_der::<TBSCertList>(&der).unwrap(), tbs_cert_list);
+
+    tbs_cert_list.version = Version::V1;
+    tbs_cert_list.revoked_certificates = vec![entry];
+    tbs_cert_list.crl_extensions.push(Extension::new(
+        &ExtensionValue::CrlNumber(SerialNumber::from(1)), false));
+    let der = encode_der(&tbs_cert_list);
+    assert!(decode_der::<TBSCertList>(&der).is_err());
+    tbs_cert_list.version = Version::V3;
+    let der = encode_der(&tbs_cert_list);
+    assert!(decode_der::<TBSCertList>(&der).is_err());
+}
+
+#[test]
+fn test_rsa_private_key() {
+    let der = decode_base64(RSA_PRIVATE_KEY_INFO).unwrap();
+    let key_info : PrivateKeyInfo = decode_der(&der).unwrap();
+    assert!(key_info.private_key_algorithm.algorithm == oids::RSA_ENCRYPTION);
+    assert_eq!(encode_der(&key_info), der);
+
+    let key = key_info.to_rsa_private_key().unwrap();
+    assert_eq!(key.modulus.len(), 64);
+    assert_eq!(key.modulus[0], 0xBB);
+    assert_eq!(key.public_exponent, &[1, 0, 1]);
+    assert_eq!(key.prime1.len(), 32);
+    assert!(key.other_prime_infos.is_empty());
+    assert_eq!(encode_der(&key), key_info.private_key);
+    assert_eq!(OneAsymmetricKey::from_rsa_private_key(&key), key_info);
+
+    let public_key = key.public_key();
+    assert_eq!(encode_der(&public_key),
+        decode_base64(RSA_PUBLIC_KEY).unwrap());
+    let spki_der = decode_base64(RSA_SUBJECT_PUBLIC_KEY_INFO).unwrap();
+    let spki = SubjectPublicKeyInfo::from_rsa_public_key(&public_key);
+    assert_eq!(encode_der(&spki), spki_der);
+    let spki : SubjectPublicKeyInfo = decode_der(&spki_der).unwrap();
+    assert_eq!(spki.to_rsa_public_key().unwrap(), public_key);
+
+    let ec_der = decode_base64(EC_PRIVATE_KEY_INFO).unwrap();
+    let ec_key_info : PrivateKeyInfo = decode_der(&ec_der).unwrap();
+    assert!(ec_key_info.to_rsa_private_key().is_err());
+    assert!(key_info.to_ec_private_key().is_err());
+}
+
+#[test]
+fn test_rsa_private_key_multi_prime() {
+    let mut key = RSAPrivateKey {
+        modulus: vec![0x80, 1],
+        public_exponent: vec![3],
+        private_exponent: vec![5],
+        prime1: vec![7],
+        prime2: vec![11],
+        exponent1: vec![13],
+        exponent2: vec![17],
+        coefficient: vec![31],
+        other_prime_infos: vec![OtherPrimeInfo {
+            prime: vec![19],
+            exponent: vec![23],
+            coefficient: vec![29],
+        }],
+    };
+    let der = encode_der(&key);
+    assert_eq!(&der[..10], &[48, 42, 2, 1, 1, 2, 3, 0, 0x80, 1]);
+    assert_eq!(&der[31..], &[48, 11, 48, 9, 2, 1, 19, 2, 1, 23, 2, 1, 29]);
+    assert_eq!(decode_der::<RSAPrivateKey>(&der).unwrap(), key);
+
+    let mut der = der;
+    der[4] = 0;
+    assert!(decode_der::<RSAPrivateKey>(&der).is_err());
+    key.other_prime_infos.clear();
+    let mut der = encode_der(&key);
+    assert_eq!(der[4], 0);
+    der[4] = 1;
+    assert!(decode_der::<RSAPrivateKey>(&der).is_err());
+
+    // Negative integers are rejected.
+    let der = [48, 6, 2, 1, 0xFF, 2, 1, 3];
+    assert!(decode_der::<RSAPublicKey>(&der).is_err());
+}
+
+#[test]
+fn test_ec_private_key() {
+    let der = decode_base64(EC_PRIVATE_KEY_INFO).unwrap();
+    let sec1_der = decode_base64(EC_PRIVATE_KEY).unwrap();
+    let key_info : PrivateKeyInfo = decode_der(&der).unwrap();
+    assert!(key_info.private_key_algorithm.algorithm == oids::ID_EC_PUBLIC_KEY);
+    assert_eq!(encode_der(&key_info), der);
+
+    // The parameters are only in the algorithm.
+    let inner : ECPrivateKey = decode_der(&key_info.private_key).unwrap();
+    assert_eq!(inner.parameters, None);
+    assert_eq!(inner.named_curve(), None);
+
+    let key = key_info.to_ec_private_key().unwrap();
+    assert!(key.named_curve().unwrap() == oids::SECP256R1);
+    assert_eq!(key.private_key.len(), 32);
+    let public_key = key.public_key.as_ref().unwrap();
+    assert_eq!(public_key.as_octets().unwrap().len(), 65);
+    assert_eq!(public_key.as_octets().unwrap()[0], 4);
+    assert_eq!(encode_der(&key), sec1_der);
+
+    let key : ECPrivateKey = decode_der(&sec1_der).unwrap();
+    assert_eq!(encode_der(&OneAsymmetricKey::from_ec_private_key(&key)
+        .unwrap()), der);
+    assert!(OneAsymmetricKey::from_ec_private_key(&inner).is_err());
+
+    // The tags of the parameters and the public key are explicit.
+    assert_eq!(&sec1_der[39..43], &[160, 10, 6, 8]);
+    assert_eq!(&sec1_der[51..56], &[161, 68, 3, 66, 0]);
+
+    let mut sec1_der = sec1_der;
+    sec1_der[4] = 0;
+    assert!(decode_der::<ECPrivateKey>(&sec1_der).is_err());
+}
+
+#[test]
+fn test_one_asymmetric_key_v2() {
+    let der = decode_base64(ONE_ASYMMETRIC_KEY_V2).unwrap();
+    let key : OneAsymmetricKey = decode_der(&der).unwrap();
+    assert!(key.private_key_algorithm.algorithm == oids::ID_ED25519);
+    assert_eq!(key.private_key.len(), 34);
+    assert_eq!(key.attributes.len(), 1);
+    assert_eq!(key.attributes[0].attr_type, ObjectIdentifier::from_slice(
+        &[1, 2, 840, 113549, 1, 9, 9, 20]));
+    assert_eq!(key.attributes[0].values[0].value(), b"Curdle Chairs");
+    assert_eq!(key.public_key.as_ref().unwrap().as_octets().unwrap().len(),
+        32);
+    assert_eq!(encode_der(&key), der);
+
+    let mut der_v1 = der.clone();
+    der_v1[4] = 0;
+    assert!(decode_der::<OneAsymmetricKey>(&der_v1).is_err());
+    der_v1[4] = 2;
+    assert!(decode_der::<OneAsymmetricKey>(&der_v1).is_err());
+
+    let key = OneAsymmetricKey {
+        public_key: None,
+        attributes: vec![],
+        ..key
+    };
+    let der = encode_der(&key);
+    assert_eq!(&der[..5], &[48, 46, 2, 1, 0]);
+    assert_eq!(decode_der::<OneAsymmetricKey>(&der).unwrap(), key);
+
+    // Unknown extensions are skipped.
+    let mut der_ext = der.clone();
+    der_ext[1] += 4;
+    der_ext.extend_from_slice(&[0xa2, 0x02, 0x05, 0x00]);
+    assert_eq!(decode_der::<OneAsymmetricKey>(&der_ext).unwrap(), key);
+}
+
+#[test]
+fn test_encrypted_private_key_info() {
+    let der = decode_base64(ENCRYPTED_PRIVATE_KEY_INFO).unwrap();
+    let encrypted : EncryptedPrivateKeyInfo = decode_der(&der).unwrap();
+    assert_eq!(encrypted.encryption_algorithm.algorithm,
+        ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 5, 13]));
+    assert_eq!(encrypted.encrypted_data.len(), 144);
+    assert_eq!(encode_der(&encrypted), der);
+
+    // Decrypting with a wrong key yields garbage.
+    let result = encrypted.decrypt(|algorithm, ciphertext| {
+        assert!(algorithm == &encrypted.encryption_algorithm);
+        Ok::<_, ASN1Error>(ciphertext.to_vec())
+    });
+    assert_eq!(result.unwrap_err().kind(), ASN1ErrorKind::Invalid);
+
+    let key_info : PrivateKeyInfo =
+        decode_der(&decode_base64(EC_PRIVATE_KEY_INFO).unwrap()).unwrap();
+    let algorithm = encrypted.encryption_algorithm.clone();
+    let encrypted = EncryptedPrivateKeyInfo::encrypt(&key_info, algorithm,
+        |plaintext| Ok::<_, ()>(plaintext.iter().rev().cloned().collect()))
+        .unwrap();
+    assert_eq!(encrypted.encrypted_data.len(), 138);
+    let decrypted = encrypted.decrypt(|_, ciphertext| {
+        Ok::<_, ASN1Error>(ciphertext.iter().rev().cloned().collect())
+    }).unwrap();
+    assert_eq!(decrypted, key_info);
+    assert_eq!(encrypted.decrypt(|_, _| Err(ASN1Error::new(
+        ASN1ErrorKind::Eof))).unwrap_err().kind(), ASN1ErrorKind::Eof);
+}
+
+#[test]
+fn test_signed_data() {
+    let ber = decode_base64(SIGNED_DATA_BER).unwrap();
+    let der = decode_base64(SIGNED_DATA).unwrap();
+    assert!(decode_der::<ContentInfo>(&ber).is_err());
+    let content_info : ContentInfo = decode_ber(&ber).unwrap();
+    assert_eq!(decode_der::<ContentInfo>(&der).unwrap(), content_info);
+    assert_eq!(encode_der(&content_info), der);
+    assert!(content_info.content_type() == oids::ID_SIGNED_DATA);
+
+    let signed_data = match content_info {
+        ContentInfo::SignedData(signed_data) => signed_data,
+        _ => panic!("not a SignedData"),
+    };
+    assert_eq!(signed_data.version, CMSVersion::V1);
+    assert_eq!(signed_data.required_version(), CMSVersion::V1);
+    assert_eq!(signed_data.digest_algorithms, vec![AlgorithmIdentifier::new(
+        ObjectIdentifier::from_slice(&[2, 16, 840, 1, 101, 3, 4, 2, 1]))]);
+    assert!(signed_data.encap_content_info.e_content_type == oids::ID_DATA);
+    assert_eq!(signed_data.encap_content_info.e_content.as_ref().unwrap(),
+        b"Hello, yasna!\n");
+    assert_eq!(signed_data.certificates.len(), 1);
+    assert!(signed_data.crls.is_empty());
+    let certificate = match signed_data.certificates[0] {
+        CertificateChoices::Certificate(ref certificate) => certificate,
+        _ => panic!("not a certificate"),
+    };
+    let tbs_certificate = certificate.tbs_certificate.value();
+    assert_eq!(tbs_certificate.subject.to_string(), "CN=yasna signer");
+
+    assert_eq!(signed_data.signer_infos.len(), 1);
+    let signer_info = &signed_data.signer_infos[0];
+    assert_eq!(signer_info.version(), CMSVersion::V1);
+    assert_eq!(signer_info.sid, SignerIdentifier::IssuerAndSerialNumber(
+        IssuerAndSerialNumber {
+            issuer: tbs_certificate.issuer.clone(),
+            serial_number: tbs_certificate.serial_number.clone(),
+        }));
+    assert_eq!(signer_info.signed_attrs.len(), 3);
+    assert!(signer_info.signed_attribute(&oids::ID_SIGNING_TIME.into())
+        .is_some());
+    assert_eq!(signer_info.message_digest().unwrap().unwrap(), &[
+        0x6E, 0x9B, 0x9F, 0x95, 0xE5, 0xE5, 0xFB, 0x56,
+        0xFA, 0x03, 0xC4, 0xD5, 0x12, 0xEE, 0xBD, 0xBD,
+        0x9E, 0x12, 0x22, 0xF5, 0xD0, 0x5A, 0x42, 0xD2,
+        0xF2, 0xDE, 0x19, 0xF7, 0x90, 0xE9, 0x92, 0x37]);
+    assert_eq!(signer_info.signature.len(), 64);
+    assert!(signer_info.unsigned_attrs.is_empty());
+
+    // The signature is computed over the signed attributes tagged as a SET.
+    let signed_attrs = signer_info.signed_attrs_der().unwrap();
+    assert_eq!(ber[439], 160);
+    assert_eq!(signed_attrs[0], 49);
+    assert_eq!(&signed_attrs[1..], &ber[440..546]);
+}
+
+#[test]
+fn test_signed_data_versions() {
+    let mut signed_data = SignedData {
+        version: CMSVersion::V1,
+        digest_algorithms: vec![],
+        encap_content_info: EncapsulatedContentInfo {
+            e_content_type: oids::ID_DATA.into(),
+            e_content: None,
+        },
+        certificates: vec![],
+        crls: vec![],
+        signer_infos: vec![],
+    };
+    assert_eq!(signed_data.required_version(), CMSVersion::V1);
+    let signer_info = SignerInfo {
+        sid: SignerIdentifier::SubjectKeyIdentifier(vec![1, 2, 3]),
+        digest_algorithm: ecdsa_with_sha256(),
+        signed_attrs: vec![],
+        signature_algorithm: ecdsa_with_sha256(),
+        signature: vec![0; 8],
+        unsigned_attrs: vec![],
+    };
+    assert_eq!(signer_info.signed_attrs_der(), None);
+    signed_data.signer_infos.push(signer_info.clone());
+    assert_eq!(signed_data.required_version(), CMSVersion::V3);
+    signed_data.signer_infos.clear();
+    signed_data.encap_content_info.e_content_type =
+        ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 9, 16, 1, 4]);
+    assert_eq!(signed_data.required_version(), CMSVersion::V3);
+    signed_data.certificates.push(CertificateChoices::Other(
+        TaggedDerValue::from_tag_pc_and_bytes(Tag::context(2),
+            PCBit::Constructed, vec![])));
+    assert_eq!(signed_data.required_version(), CMSVersion::V4);
+    signed_data.crls.push(RevocationInfoChoice::Other(
+        TaggedDerValue::from_tag_pc_and_bytes(Tag::context(1),
+            PCBit::Constructed, vec![])));
+    assert_eq!(signed_data.required_version(), CMSVersion::V5);
+
+    // The version is kept as is.
+    let der = encode_der(&signed_data);
+    assert_eq!(&der[2..5], &[2, 1, 1]);
+    assert_eq!(decode_der::<SignedData>(&der).unwrap(), signed_data);
+
+    // The version of a SignerInfo must match the sid.
+    let mut der = encode_der(&signer_info);
+    assert_eq!(&der[2..5], &[2, 1, 3]);
+    assert_eq!(decode_der::<SignerInfo>(&der).unwrap(), signer_info);
+    der[4] = 1;
+    assert!(decode_der::<SignerInfo>(&der).is_err());
+}
+
+#[test]
+fn test_enveloped_data() {
+    let der = decode_base64(ENVELOPED_DATA).unwrap();
+    let content_info : ContentInfo = decode_der(&der).unwrap();
+    assert_eq!(encode_der(&content_info), der);
+    let mut enveloped_data = match content_info {
+        ContentInfo::EnvelopedData(enveloped_data) => enveloped_data,
+        _ => panic!("not an EnvelopedData"),
+    };
+    assert_eq!(enveloped_data.version, CMSVersion::V3);
+    assert_eq!(enveloped_data.required_version(), CMSVersion::V3);
+    assert_eq!(enveloped_data.originator_info, None);
+    let encrypted_content_info = &enveloped_data.encrypted_content_info;
+    assert!(encrypted_content_info.content_type == oids::ID_DATA);
+    assert_eq!(encrypted_content_info.encrypted_content.as_ref().unwrap()
+        .len(), 16);
+
+    let recipient_infos = &enveloped_data.recipient_infos;
+    assert_eq!(recipient_infos.len(), 4);
+    match recipient_infos[0] {
+        RecipientInfo::Ktri(ref ktri) => {
+            assert_eq!(ktri.version(), CMSVersion::V0);
+            match ktri.rid {
+                RecipientIdentifier::IssuerAndSerialNumber(ref id) => {
+                    assert_eq!(id.issuer.to_string(), "CN=yasna rsa");
+                    assert_eq!(id.serial_number.to_u64(), Some(1));
+                },
+                _ => panic!("not an issuerAndSerialNumber"),
+            }
+            assert!(ktri.key_encryption_algorithm.algorithm
+                == oids::RSA_ENCRYPTION);
+            assert_eq!(ktri.encrypted_key.len(), 128);
+        },
+        _ => panic!("not a ktri"),
+    }
+    match recipient_infos[1] {
+        RecipientInfo::Kari(ref kari) => {
+            match kari.originator {
+                OriginatorIdentifierOrKey::OriginatorKey(ref key) => {
+                    assert!(key.algorithm.algorithm == oids::ID_EC_PUBLIC_KEY);
+                },
+                _ => panic!("not an originatorKey"),
+            }
+            assert_eq!(kari.ukm, None);
+            assert_eq!(kari.recipient_encrypted_keys.len(), 1);
+            let key = &kari.recipient_encrypted_keys[0];
+            match key.rid {
+                KeyAgreeRecipientIdentifier::IssuerAndSerialNumber(ref id) => {
+                    assert_eq!(id.serial_number.to_u64(), Some(2));
+                },
+                _ => panic!("not an issuerAndSerialNumber"),
+            }
+            assert_eq!(key.encrypted_key.len(), 24);
+        },
+        _ => panic!("not a kari"),
+    }
+    match recipient_infos[2] {
+        RecipientInfo::Kekri(ref kekri) => {
+            assert_eq!(kekri.kekid, KEKIdentifier {
+                key_identifier: vec![10, 11, 12],
+                date: None,
+                other: None,
+            });
+            assert_eq!(kekri.encrypted_key.len(), 24);
+        },
+        _ => panic!("not a kekri"),
+    }
+    match recipient_infos[3] {
+        RecipientInfo::Pwri(ref pwri) => {
+            assert_eq!(pwri.key_derivation_algorithm.as_ref().unwrap()
+                .algorithm, ObjectIdentifier::from_slice(
+                    &[1, 2, 840, 113549, 1, 5, 12]));
+            assert_eq!(pwri.encrypted_key.len(), 32);
+        },
+        _ => panic!("not a pwri"),
+    }
+    assert_eq!(recipient_infos.iter().map(|r| r.version())
+        .collect::<Vec<_>>(), vec![Some(CMSVersion::V0),
+            Some(CMSVersion::V3), Some(CMSVersion::V4), Some(CMSVersion::V0)]);
+
+    enveloped_data.recipient_infos.truncate(3);
+    assert_eq!(enveloped_data.required_version(), CMSVersion::V2);
+    enveloped_data.recipient_infos.truncate(1);
+    assert_eq!(enveloped_data.required_version(), CMSVersion::V0);
+    enveloped_data.originator_info = Some(OriginatorInfo::default());
+    assert_eq!(enveloped_data.required_version(), CMSVersion::V2);
+    let der = encode_der(&enveloped_data);
+    assert_eq!(decode_der::<EnvelopedData>(&der).unwrap(), enveloped_data);
+
+    enveloped_data.recipient_infos.clear();
+    let der = encode_der(&enveloped_data);
+    assert!(decode_der::<EnvelopedData>(&der).is_err());
+}
+
+#[test]
+fn test_recipient_infos() {
+    let kekri = RecipientInfo::Kekri(KEKRecipientInfo {
+        kekid: KEKIdentifier {
+            key_identifier: vec![1],
+            date: GeneralizedTime::parse(b"20260101000000Z"),
+            other: Some(OtherKeyAttribute {
+                key_attr_id: ObjectIdentifier::from_slice(&[1, 2, 3]),
+                key_attr: None,
+            }),
+        },
+        key_encryption_algorithm: ecdsa_with_sha256(),
+        encrypted_key: vec![2],
+    });
+    let der = encode_der(&kekri);
+    assert_eq!(&der[..11], &[162, 46, 2, 1, 4, 48, 26, 4, 1, 1, 24]);
+    assert_eq!(decode_der::<RecipientInfo>(&der).unwrap(), kekri);
+
+    let kari = RecipientInfo::Kari(KeyAgreeRecipientInfo {
+        originator: OriginatorIdentifierOrKey::SubjectKeyIdentifier(vec![1]),
+        ukm: Some(vec![2]),
+        key_encryption_algorithm: ecdsa_with_sha256(),
+        recipient_encrypted_keys: vec![RecipientEncryptedKey {
+            rid: KeyAgreeRecipientIdentifier::RKeyId(RecipientKeyIdentifier {
+                subject_key_identifier: vec![3],
+                date: None,
+                other: None,
+            }),
+            encrypted_key: vec![4],
+        }],
+    });
+    let der = encode_der(&kari);
+    assert_eq!(&der[..15], &[161, 37, 2, 1, 3, 160, 3, 128, 1, 1,
+        161, 3, 4, 1, 2]);
+    assert_eq!(decode_der::<RecipientInfo>(&der).unwrap(), kari);
+
+    let ori = RecipientInfo::Ori(OtherRecipientInfo {
+        ori_type: ObjectIdentifier::from_slice(&[1, 2, 3]),
+        ori_value: TaggedDerValue::from_tag_and_bytes(TAG_IA5STRING,
+            b"x".to_vec()),
+    });
+    let der = encode_der(&ori);
+    assert_eq!(der, &[164, 7, 6, 2, 42, 3, 22, 1, 120]);
+    assert_eq!(decode_der::<RecipientInfo>(&der).unwrap(), ori);
+    assert_eq!(ori.version(), None);
+}
+
+const OCSP_NONCE: [u8; 16] = [118, 102, 219, 204, 102, 61, 115, 106,
+    252, 59, 101, 77, 155, 88, 213, 212];
+
+#[test]
+fn test_ocsp_request() {
+    let der = decode_base64(OCSP_REQUEST).unwrap();
+    let request : OCSPRequest = decode_der(&der).unwrap();
+    assert_eq!(encode_der(&request), der);
+    assert_eq!(request.tbs_bytes(), &der[3..171]);
+    assert_eq!(request.optional_signature, None);
+    let tbs_request = request.tbs_request.value();
+    assert_eq!(tbs_request.requestor_name, None);
+    assert_eq!(tbs_request.request_list.len(), 2);
+    let cert_id = &tbs_request.request_list[0].req_cert;
+    assert!(cert_id.hash_algorithm.algorithm ==
+        ObjectIdentifier::from_slice(&[1, 3, 14, 3, 2, 26]));
+    assert_eq!(cert_id.issuer_name_hash.len(), 20);
+    assert_eq!(cert_id.serial_number.to_u64(), Some(0x1234));
+    assert_eq!(tbs_request.request_list[1].req_cert.serial_number.to_u64(),
+        Some(0x1235));
+    assert_eq!(tbs_request.nonce().unwrap(), Some(OCSP_NONCE.to_vec()));
+    assert_eq!(OCSPRequest::new(tbs_request.clone()), request);
+
+    let mut tbs_request = tbs_request.clone();
+    tbs_request.set_nonce(&[1, 2, 3]);
+    assert_eq!(tbs_request.request_extensions.len(), 1);
+    assert_eq!(tbs_request.nonce().unwrap(), Some(vec![1, 2, 3]));
+    tbs_request.request_extensions[0].extn_value = vec![5, 0];
+    assert!(tbs_request.nonce().is_err());
+    tbs_request.request_extensions.clear();
+    assert_eq!(tbs_request.nonce().unwrap(), None);
+
+    // The CertID is computed from the issuer in the response.
+    let response : OCSPResponse =
+        decode_der(&decode_base64(OCSP_RESPONSE).unwrap()).unwrap();
+    let basic_response = response.basic_response().unwrap().unwrap();
+    let issuer = &basic_response.certs[0];
+    let mut inputs = Vec::new();
+    let mut hashes = vec![cert_id.issuer_key_hash.clone(),
+        cert_id.issuer_name_hash.clone()];
+    let computed = CertID::new(cert_id.hash_algorithm.clone(), issuer,
+        SerialNumber::from(0x1234), |bytes| {
+            inputs.push(bytes.to_vec());
+            hashes.pop().unwrap()
+        });
+    assert_eq!(&computed, cert_id);
+    let subject = &issuer.tbs_certificate.value().subject;
+    assert_eq!(inputs[0], encode_der(subject));
+    assert_eq!(inputs[1].len(), 65);
+    assert_eq!(inputs[1][0], 4);
+
+    let signed = OCSPRequest::sign(TBSRequest {
+        requestor_name: Some(GeneralName::DirectoryName(subject.clone())),
+        request_list: vec![Request {
+            req_cert: computed,
+            single_request_extensions: vec![],
+        }],
+        request_extensions: vec![],
+    }, ecdsa_with_sha256(), vec![issuer.clone()], |tbs_bytes| {
+        assert_eq!(tbs_bytes[0], 48);
+        Ok::<_, ()>(vec![0; 8])
+    }).unwrap();
+    let der = encode_der(&signed);
+    assert_eq!(&der[signed.tbs_bytes().len() + 4..][..2], &[160, 130]);
+    assert_eq!(decode_der::<OCSPRequest>(&der).unwrap(), signed);
+}
+
+#[test]
+fn test_ocsp_response() {
+    let der = decode_base64(OCSP_RESPONSE).unwrap();
+    let response : OCSPResponse = decode_der(&der).unwrap();
+    assert_eq!(encode_der(&response), der);
+    assert_eq!(response.response_status, OCSPResponseStatus::Successful);
+    let response_bytes = response.response_bytes.as_ref().unwrap();
+    assert!(response_bytes.response_type == oids::ID_PKIX_OCSP_BASIC);
+    assert_eq!(response_bytes.response, &der[30..]);
+
+    let basic_response = response.basic_response().unwrap().unwrap();
+    assert_eq!(basic_response.tbs_bytes(), &der[34..347]);
+    assert_eq!(basic_response.signature_algorithm, ecdsa_with_sha256());
+    assert_eq!(basic_response.certs.len(), 1);
+    assert_eq!(OCSPResponse::from_basic_response(&basic_response), response);
+
+    let response_data = basic_response.tbs_response_data.value();
+    match response_data.responder_id {
+        ResponderID::ByName(ref name) =>
+            assert_eq!(name.to_string(), "CN=yasna test CA"),
+        _ => panic!("not byName"),
+    }
+    assert_eq!(response_data.produced_at,
+        GeneralizedTime::parse(b"20261019042744Z").unwrap());
+    assert_eq!(response_data.nonce().unwrap(), Some(OCSP_NONCE.to_vec()));
+    assert_eq!(response_data.responses.len(), 2);
+
+    let request : OCSPRequest =
+        decode_der(&decode_base64(OCSP_REQUEST).unwrap()).unwrap();
+    let request_list = &request.tbs_request.value().request_list;
+    let revoked = response_data.response(&request_list[0].req_cert).unwrap();
+    assert_eq!(revoked.cert_status, CertStatus::Revoked(RevokedInfo {
+        revocation_time: GeneralizedTime::parse(b"20261019000000Z").unwrap(),
+        revocation_reason: Some(CrlReason::KeyCompromise),
+    }));
+    assert_eq!(revoked.this_update, response_data.produced_at);
+    assert_eq!(revoked.next_update,
+        GeneralizedTime::parse(b"20261026042744Z"));
+    let unknown = response_data.response(&request_list[1].req_cert).unwrap();
+    assert_eq!(unknown.cert_status, CertStatus::Unknown);
+    let mut cert_id = request_list[1].req_cert.clone();
+    cert_id.serial_number = SerialNumber::from(0x1236);
+    assert_eq!(response_data.response(&cert_id), None);
+
+    let signed = BasicOCSPResponse::sign(response_data.clone(),
+        ecdsa_with_sha256(), basic_response.certs.clone(), |tbs_bytes| {
+            assert_eq!(tbs_bytes, basic_response.tbs_bytes());
+            Ok::<_, ()>(basic_response.signature.as_bytes().to_vec())
+        }).unwrap();
+    assert_eq!(signed, basic_response);
+}
+
+#[test]
+fn test_ocsp_response_fields() {
+    assert_eq!(encode_der(&CertStatus::Good), &[128, 0]);
+    assert_eq!(encode_der(&CertStatus::Unknown), &[130, 0]);
+    assert_eq!(decode_der::<CertStatus>(&[128, 0]).unwrap(), CertStatus::Good);
+    assert!(decode_der::<CertStatus>(&[131, 0]).is_err());
+    let revoked = CertStatus::Revoked(RevokedInfo {
+        revocation_time: GeneralizedTime::parse(b"20260101000000Z").unwrap(),
+        revocation_reason: None,
+    });
+    let der = encode_der(&revoked);
+    assert_eq!(&der[..4], &[161, 17, 24, 15]);
+    assert_eq!(decode_der::<CertStatus>(&der).unwrap(), revoked);
+
+    let responder_id = ResponderID::ByKey(vec![1, 2]);
+    let der = encode_der(&responder_id);
+    assert_eq!(der, &[162, 4, 4, 2, 1, 2]);
+    assert_eq!(decode_der::<ResponderID>(&der).unwrap(), responder_id);
+
+    // An error status without a body.
+    let response : OCSPResponse = decode_der(&[48, 3, 10, 1, 6]).unwrap();
+    assert_eq!(response.response_status, OCSPResponseStatus::Unauthorized);
+    assert_eq!(response.response_bytes, None);
+    assert!(decode_der::<OCSPResponse>(&[48, 3, 10, 1, 4]).is_err());
+
+    // Only v1 is defined.
+    let tbs_request = TBSRequest {
+        requestor_name: None,
+        request_list: vec![],
+        request_extensions: vec![],
+    };
+    assert_eq!(encode_der(&tbs_request), &[48, 2, 48, 0]);
+    let v1 = &[48, 7, 160, 3, 2, 1, 0, 48, 0];
+    assert_eq!(decode_ber::<TBSRequest>(v1).unwrap(), tbs_request);
+    assert!(decode_der::<TBSRequest>(v1).is_err());
+    let v2 = &[48, 7, 160, 3, 2, 1, 1, 48, 0];
+    assert!(decode_ber::<TBSRequest>(v2).is_err());
+}
+
+#[test]
+fn test_time_stamp_req() {
+    let der = decode_base64(TIME_STAMP_REQ).unwrap();
+    let request : TimeStampReq = decode_der(&der).unwrap();
+    assert_eq!(encode_der(&request), der);
+    let hash_algorithm = &request.message_imprint.hash_algorithm;
+    assert!(hash_algorithm.algorithm ==
+        ObjectIdentifier::from_slice(&[2, 16, 840, 1, 101, 3, 4, 2, 1]));
+    assert_eq!(request.message_imprint.hashed_message.len(), 32);
+    assert_eq!(request.req_policy, None);
+    assert_eq!(request.nonce.as_ref().unwrap().to_u64(),
+        Some(0x4BA8917FCA142246));
+    assert!(request.cert_req);
+    assert_eq!(request.extensions, vec![]);
+
+    let mut v2 = der.clone();
+    v2[4] = 2;
+    assert!(decode_der::<TimeStampReq>(&v2).is_err());
+}
+
+#[test]
+fn test_time_stamp_resp() {
+    let der = decode_base64(TIME_STAMP_RESP).unwrap();
+    let response : TimeStampResp = decode_der(&der).unwrap();
+    assert_eq!(encode_der(&response), der);
+    assert_eq!(response.status.status, PKIStatus::Granted);
+    assert!(response.status.is_granted());
+    assert_eq!(response.status.status_string, Vec::<String>::new());
+    assert_eq!(response.status.fail_info, None);
+    let signed_data = match response.time_stamp_token {
+        Some(ContentInfo::SignedData(ref signed_data)) => signed_data,
+        _ => panic!("not a SignedData"),
+    };
+    assert_eq!(signed_data.certificates.len(), 1);
+    assert!(signed_data.signer_infos[0].message_digest().unwrap().is_some());
+
+    let tst_info = response.tst_info().unwrap().unwrap();
+    assert_eq!(encode_der(&tst_info), &der[74..213]);
+    assert_eq!(tst_info.policy, ObjectIdentifier::from_slice(&[1, 2, 3, 4, 1]));
+    let request : TimeStampReq =
+        decode_der(&decode_base64(TIME_STAMP_REQ).unwrap()).unwrap();
+    assert_eq!(tst_info.message_imprint, request.message_imprint);
+    assert_eq!(tst_info.serial_number.to_u64(), Some(0x1002));
+    assert_eq!(tst_info.gen_time,
+        GeneralizedTime::parse(b"20261019043357.072Z").unwrap());
+    let accuracy = tst_info.accuracy.unwrap();
+    assert_eq!(accuracy, Accuracy {
+        seconds: Some(1),
+        millis: Some(500),
+        micros: Some(100),
+    });
+    assert_eq!(accuracy.as_micros(), 1_500_100);
+    assert!(tst_info.ordering);
+    assert_eq!(tst_info.nonce, request.nonce);
+    match tst_info.tsa {
+        Some(GeneralName::DirectoryName(ref name)) =>
+            assert_eq!(name.to_string(), "CN=yasna tsa"),
+        _ => panic!("not a directoryName"),
+    }
+    assert_eq!(tst_info.extensions, vec![]);
+    assert_eq!(TSTInfo::from_token(
+        response.time_stamp_token.as_ref().unwrap()).unwrap(), tst_info);
+    assert!(TSTInfo::from_token(&ContentInfo::Data(vec![])).is_err());
+
+    let der = decode_base64(TIME_STAMP_REJECTION).unwrap();
+    let response : TimeStampResp = decode_der(&der).unwrap();
+    assert_eq!(encode_der(&response), der);
+    assert_eq!(response.status.status, PKIStatus::Rejection);
+    assert!(!response.status.is_granted());
+    assert_eq!(response.status.status_string,
+        vec!["Message digest algorithm is not supported.".to_string()]);
+    assert_eq!(response.status.fail_info, Some(BitString::from_set_bits(&[0])));
+    assert_eq!(response.tst_info().unwrap(), None);
+}
+
+#[test]
+fn test_tst_info_fields() {
+    // Fractions finer than nanoseconds
+    let mut der = vec![48, 48, 2, 1, 1, 6, 2, 42, 3,
+        48, 8, 48, 4, 6, 2, 42, 3, 4, 0, 2, 1, 1];
+    der.extend_from_slice(b"\x18\x1a20260101000000.1234567891Z");
+    let tst_info = decode_der::<TSTInfo>(&der).unwrap();
+    assert_eq!(tst_info.gen_time,
+        GeneralizedTime::parse(b"20260101000000.1234567891Z").unwrap());
+    assert_eq!(tst_info.gen_time.sub_nano(), b"1");
+    assert!(tst_info.accuracy.is_none() && !tst_info.ordering);
+    assert_eq!(encode_der(&tst_info), der);
+
+    assert_eq!(encode_der(&Accuracy::default()), &[48, 0]);
+    let accuracy : Accuracy = decode_der(&[48, 6, 128, 1, 1, 129, 1, 2])
+        .unwrap();
+    assert_eq!(accuracy.as_micros(), 1002);
+    assert!(decode_der::<Accuracy>(&[48, 3, 128, 1, 0]).is_err());
+    assert!(decode_der::<Accuracy>(&[48, 4, 129, 2, 3, 232]).is_err());
+
+    assert!(decode_der::<PKIStatusInfo>(&[48, 5, 2, 1, 0, 48, 0]).is_err());
+    assert!(decode_der::<PKIStatusInfo>(&[48, 3, 2, 1, 6]).is_err());
+}
+
+#[test]
+fn test_pkcs12() {
+    let der = decode_base64(PKCS12).unwrap();
+    let pfx : PFX = decode_der(&der).unwrap();
+    let mac_data = pfx.mac_data.clone().unwrap();
+    assert_eq!(mac_data.mac.digest_algorithm.algorithm,
+        ObjectIdentifier::from_slice(&[2, 16, 840, 1, 101, 3, 4, 2, 1]));
+    assert_eq!(mac_data.mac.digest.len(), 32);
+    assert_eq!(mac_data.mac_salt.len(), 8);
+    assert_eq!(mac_data.iterations, 2048);
+    assert_eq!(pfx.auth_safe_data().unwrap(), &der[30..820]);
+
+    let authenticated_safe = pfx.authenticated_safe().unwrap();
+    assert_eq!(authenticated_safe.len(), 2);
+    let safe_contents = authenticated_safe.iter().map(|content_info| {
+        match *content_info {
+            ContentInfo::Data(ref data) =>
+                decode_der::<Vec<SafeBag>>(data).unwrap(),
+            _ => panic!("not a Data"),
+        }
+    }).collect::<Vec<_>>();
+    let bags = pfx.safe_contents(|_, _| -> Result<Vec<u8>, ASN1Error> {
+        panic!("not encrypted")
+    }).unwrap();
+    assert_eq!(bags, safe_contents.concat());
+    assert_eq!(bags.len(), 2);
+    match bags[0].bag_value {
+        SafeBagValue::CertBag(CertBag::X509Certificate(ref certificate)) => {
+            assert_eq!(certificate.tbs_certificate.value().subject.to_string(),
+                "CN=yasna p12");
+        },
+        _ => panic!("not an X.509 certificate"),
+    }
+    match bags[1].bag_value {
+        SafeBagValue::KeyBag(ref key) => {
+            assert_eq!(key.private_key_algorithm.algorithm,
+                ObjectIdentifier::from_slice(&[1, 2, 840, 10045, 2, 1]));
+        },
+        _ => panic!("not a keyBag"),
+    }
+    for bag in &bags {
+        assert_eq!(bag.friendly_name().unwrap().unwrap(), "yasna key");
+        assert_eq!(bag.local_key_id().unwrap().unwrap().len(), 20);
+    }
+    assert_eq!(bags[0].local_key_id().unwrap(), bags[1].local_key_id().unwrap());
+
+    let contents = safe_contents.iter()
+        .map(|bags| ContentInfo::Data(encode_der(bags)))
+        .collect::<Vec<_>>();
+    let pfx2 = PFX::with_mac(&contents, |data| {
+        assert_eq!(data, &der[30..820]);
+        Ok::<_, ASN1Error>(mac_data.clone())
+    }).unwrap();
+    assert_eq!(pfx2, pfx);
+    assert_eq!(encode_der(&pfx2), der);
+
+    // Indefinite lengths and a constructed OCTET STRING
+    let mut ber = vec![48, 128];
+    ber.extend_from_slice(&der[4..7]);
+    ber.extend_from_slice(&[48, 128]);
+    ber.extend_from_slice(&der[11..22]);
+    ber.extend_from_slice(&[160, 128, 36, 128, 4, 130, 1, 144]);
+    ber.extend_from_slice(&der[30..430]);
+    ber.extend_from_slice(&[4, 130, 1, 134]);
+    ber.extend_from_slice(&der[430..820]);
+    ber.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+    ber.extend_from_slice(&der[820..]);
+    ber.extend_from_slice(&[0, 0]);
+    assert!(decode_der::<PFX>(&ber).is_err());
+    let pfx3 : PFX = decode_ber(&ber).unwrap();
+    assert_eq!(pfx3, pfx);
+
+    assert!(decode_der::<PFX>(&[48, 5, 2, 1, 2, 4, 0]).is_err());
+}
+
+#[test]
+fn test_pkcs12_encrypted() {
+    let plain : PFX = decode_der(&decode_base64(PKCS12).unwrap()).unwrap();
+    let plain_contents = plain.authenticated_safe().unwrap();
+    let plain_bags = plain.safe_contents(|_, _| -> Result<Vec<u8>, ASN1Error> {
+        panic!("not encrypted")
+    }).unwrap();
+
+    let der = decode_base64(PKCS12_ENCRYPTED).unwrap();
+    let pfx : PFX = decode_der(&der).unwrap();
+    assert_eq!(encode_der(&pfx), der);
+    let pbes2 = ObjectIdentifier::from_slice(&[1, 2, 840, 113549, 1, 5, 13]);
+    let bags = pfx.safe_contents(|algorithm, ciphertext| {
+        assert_eq!(algorithm.algorithm, pbes2);
+        assert_eq!(ciphertext.len(), 512);
+        match plain_contents[0] {
+            ContentInfo::Data(ref data) => Ok::<_, ASN1Error>(data.clone()),
+            _ => panic!("not a Data"),
+        }
+    }).unwrap();
+    assert_eq!(bags.len(), 2);
+    assert_eq!(bags[0], plain_bags[0]);
+    match bags[1].bag_value {
+        SafeBagValue::Pkcs8ShroudedKeyBag(ref key) =>
+            assert_eq!(key.encryption_algorithm.algorithm, pbes2),
+        _ => panic!("not a pkcs8ShroudedKeyBag"),
+    }
+    assert_eq!(bags[1].bag_attributes, plain_bags[1].bag_attributes);
+
+    let error = pfx.safe_contents(|_, ciphertext| {
+        Ok::<_, ASN1Error>(ciphertext.to_vec())
+    }).unwrap_err();
+    assert_eq!(error.kind(), ASN1ErrorKind::Invalid);
+
+    // Round trip of an EncryptedData
+    let content = encode_der(&plain_bags);
+    let encrypted_content_info = EncryptedContentInfo::encrypt(
+        oids::ID_DATA.into(), AlgorithmIdentifier::new(pbes2), &content,
+        |plaintext| {
+            Ok::<_, ASN1Error>(plaintext.iter().map(|b| b ^ 0x5a).collect())
+        }).unwrap();
+    let mut encrypted_data = EncryptedData::new(encrypted_content_info);
+    assert_eq!(encrypted_data.required_version(), CMSVersion::V0);
+    let pfx2 = PFX::new(&[ContentInfo::EncryptedData(encrypted_data.clone())]);
+    let pfx2 : PFX = decode_der(&encode_der(&pfx2)).unwrap();
+    let bags2 = pfx2.safe_contents(|_, ciphertext| {
+        Ok::<_, ASN1Error>(ciphertext.iter().map(|b| b ^ 0x5a).collect())
+    }).unwrap();
+    assert_eq!(bags2, plain_bags);
+
+    encrypted_data.unprotected_attrs.push(Attribute {
+        attr_type: ObjectIdentifier::from_slice(&[1, 2, 3]),
+        values: vec![TaggedDerValue::from_octetstring(vec![1])],
+    });
+    assert_eq!(encrypted_data.required_version(), CMSVersion::V2);
+    encrypted_data.version = CMSVersion::V2;
+    let content_info = ContentInfo::EncryptedData(encrypted_data);
+    assert_eq!(decode_der::<ContentInfo>(&encode_der(&content_info)).unwrap(),
+        content_info);
+    let pfx3 = PFX::new(&[ContentInfo::Other {
+        content_type: ObjectIdentifier::from_slice(&[1, 2, 3]),
+        content: TaggedDerValue::from_octetstring(vec![]),
+    }]);
+    assert!(pfx3.safe_contents(|_, _| -> Result<Vec<u8>, ASN1Error> {
+        panic!("not encrypted")
+    }).is_err());
+}
+
+#[test]
+fn test_safe_bags() {
+    let mut bag = SafeBag::new(SafeBagValue::SecretBag(SecretBag {
+        secret_type_id: ObjectIdentifier::from_slice(&[1, 2, 3]),
+        secret_value: TaggedDerValue::from_octetstring(b"secret".to_vec()),
+    }));
+    assert_eq!(bag.friendly_name().unwrap(), None);
+    assert_eq!(bag.local_key_id().unwrap(), None);
+    let mut der = vec![48, 31,
+        6, 11, 42, 134, 72, 134, 247, 13, 1, 12, 10, 1, 5,
+        160, 16, 48, 14, 6, 2, 42, 3, 160, 8, 4, 6];
+    der.extend_from_slice(b"secret");
+    assert_eq!(encode_der(&bag), der);
+    assert_eq!(decode_der::<SafeBag>(&der).unwrap(), bag);
+    bag.set_friendly_name("\u{3042}");
+    bag.set_local_key_id(&[1, 2]);
+    bag.set_friendly_name("a\u{1F600}");
+    assert_eq!(bag.bag_attributes.len(), 2);
+    assert_eq!(bag.friendly_name().unwrap().unwrap(), "a\u{1F600}");
+    assert_eq!(bag.local_key_id().unwrap().unwrap(), vec![1, 2]);
+    assert_eq!(bag.bag_attributes[1].values[0].value(),
+        &[0, 97, 216, 61, 222, 0]);
+
+    let nested = SafeBag::new(SafeBagValue::SafeContentsBag(vec![
+        bag.clone(),
+        SafeBag::new(SafeBagValue::CertBag(CertBag::SdsiCertificate(
+            "c2RzaQ==".to_string()))),
+        SafeBag::new(SafeBagValue::CertBag(CertBag::Other {
+            cert_id: ObjectIdentifier::from_slice(&[1, 2, 4]),
+            cert_value: TaggedDerValue::from_octetstring(vec![]),
+        })),
+        SafeBag::new(SafeBagValue::CrlBag(CrlBag::Other {
+            crl_id: ObjectIdentifier::from_slice(&[1, 2, 5]),
+            crl_value: TaggedDerValue::from_octetstring(vec![]),
+        })),
+        SafeBag::new(SafeBagValue::Other {
+            bag_id: ObjectIdentifier::from_slice(&[1, 2, 6]),
+            bag_value: TaggedDerValue::from_octetstring(vec![3]),
+        }),
+    ]));
+    let der = encode_der(&nested);
+    assert_eq!(decode_der::<SafeBag>(&der).unwrap(), nested);
+
+    let invalid = SafeBag {
+        bag_attributes: vec![Attribute {
+            attr_type: oids::PKCS_9_AT_FRIENDLY_NAME.into(),
+            values: vec![TaggedDerValue::from_octetstring(vec![0, 97])],
+        }],
+        ..nested
+    };
+    assert!(invalid.friendly_name().is_err());
+}
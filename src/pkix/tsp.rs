@@ -0,0 +1,598 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::super::{ASN1Error, ASN1ErrorKind, ASN1Result, Tag};
+use super::super::{BERDecodable, BERReader, DEREncodable, DERWriter};
+use super::super::decode_der;
+use super::super::models::{BitString, GeneralizedTime, ObjectIdentifier};
+use super::{AlgorithmIdentifier, ContentInfo, Extension, GeneralName};
+use super::SerialNumber;
+use super::oids::ID_CT_TSTINFO;
+
+/// The hash of the data to be time-stamped (RFC 3161 2.4.1).
+///
+/// ```text
+/// MessageImprint ::= SEQUENCE  {
+///      hashAlgorithm                AlgorithmIdentifier,
+///      hashedMessage                OCTET STRING  }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MessageImprint {
+    /// The hash algorithm
+    pub hash_algorithm: AlgorithmIdentifier,
+    /// The hash of the data
+    pub hashed_message: Vec<u8>,
+}
+
+impl BERDecodable for MessageImprint {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let hash_algorithm = AlgorithmIdentifier::decode_ber(reader.next())?;
+            let hashed_message = reader.next().read_bytes()?;
+            return Ok(MessageImprint {
+                hash_algorithm,
+                hashed_message,
+            });
+        })
+    }
+}
+
+impl DEREncodable for MessageImprint {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.hash_algorithm.encode_der(writer.next());
+            writer.next().write_bytes(&self.hashed_message);
+        })
+    }
+}
+
+/// A time-stamp request (RFC 3161 2.4.1).
+///
+/// ```text
+/// TimeStampReq ::= SEQUENCE  {
+///    version                      INTEGER  { v1(1) },
+///    messageImprint               MessageImprint,
+///      --a hash algorithm OID and the hash value of the data to be
+///      --time-stamped
+///    reqPolicy             TSAPolicyId              OPTIONAL,
+///    nonce                 INTEGER                  OPTIONAL,
+///    certReq               BOOLEAN                  DEFAULT FALSE,
+///    extensions            [0] IMPLICIT Extensions  OPTIONAL  }
+///
+/// TSAPolicyId ::= OBJECT IDENTIFIER
+/// ```
+///
+/// The version is not stored, since v1 is the only version. The nonce is
+/// a large random INTEGER, so it is represented by [`SerialNumber`]. The
+/// extensions are omitted when encoded if empty.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::models::ObjectIdentifier;
+/// use yasna::pkix::{AlgorithmIdentifier, MessageImprint, TimeStampReq};
+/// let request = TimeStampReq {
+///     message_imprint: MessageImprint {
+///         hash_algorithm: AlgorithmIdentifier::new(
+///             ObjectIdentifier::from_slice(&[2, 16, 840, 1, 101, 3, 4, 2, 1])),
+///         hashed_message: vec![0; 32],
+///     },
+///     req_policy: None,
+///     nonce: Some(0x1234.into()),
+///     cert_req: true,
+///     extensions: vec![],
+/// };
+/// let der = yasna::encode_der(&request);
+/// assert_eq!(&der[..5], &[48, 59, 2, 1, 1]);
+/// assert_eq!(yasna::decode_der::<TimeStampReq>(&der).unwrap(), request);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TimeStampReq {
+    /// The hash of the data to be time-stamped
+    pub message_imprint: MessageImprint,
+    /// The policy under which the token should be issued
+    pub req_policy: Option<ObjectIdentifier>,
+    /// The nonce, to be returned in the token
+    pub nonce: Option<SerialNumber>,
+    /// Whether the certificate of the TSA should be in the response
+    pub cert_req: bool,
+    /// The extensions
+    pub extensions: Vec<Extension>,
+}
+
+impl BERDecodable for TimeStampReq {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            if reader.next().read_u64()? != 1 {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            let message_imprint = MessageImprint::decode_ber(reader.next())?;
+            let req_policy = reader.read_optional(ObjectIdentifier::decode_ber)?;
+            let nonce = reader.read_optional(SerialNumber::decode_ber)?;
+            let cert_req = reader.read_default(false, |reader| {
+                reader.read_bool()
+            })?;
+            let extensions = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(0), Vec::decode_ber)
+            })?;
+            return Ok(TimeStampReq {
+                message_imprint,
+                req_policy,
+                nonce,
+                cert_req,
+                extensions: extensions.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for TimeStampReq {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_u8(1);
+            self.message_imprint.encode_der(writer.next());
+            if let Some(ref req_policy) = self.req_policy {
+                req_policy.encode_der(writer.next());
+            }
+            if let Some(ref nonce) = self.nonce {
+                nonce.encode_der(writer.next());
+            }
+            if self.cert_req {
+                writer.next().write_bool(true);
+            }
+            if !self.extensions.is_empty() {
+                writer.next().write_tagged_implicit(Tag::context(0), |writer| {
+                    self.extensions.encode_der(writer)
+                });
+            }
+        })
+    }
+}
+
+/// The status of a time-stamp response (RFC 3161 2.4.2).
+///
+/// ```text
+/// PKIStatus ::= INTEGER {
+///    granted                (0),
+///    -- when the PKIStatus contains the value zero a TimeStampToken, as
+///    -- requested, is present.
+///    grantedWithMods        (1),
+///    -- when the PKIStatus contains the value one a TimeStampToken,
+///    -- with modifications, is present.
+///    rejection              (2),
+///    waiting                (3),
+///    revocationWarning      (4),
+///    -- this message contains a warning that a revocation is
+///    -- imminent
+///    revocationNotification (5)
+///    -- notification that a revocation has occurred   }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PKIStatus {
+    /// `granted`
+    Granted = 0,
+    /// `grantedWithMods`
+    GrantedWithMods = 1,
+    /// `rejection`
+    Rejection = 2,
+    /// `waiting`
+    Waiting = 3,
+    /// `revocationWarning`
+    RevocationWarning = 4,
+    /// `revocationNotification`
+    RevocationNotification = 5,
+}
+
+impl BERDecodable for PKIStatus {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        match reader.read_i64()? {
+            0 => Ok(PKIStatus::Granted),
+            1 => Ok(PKIStatus::GrantedWithMods),
+            2 => Ok(PKIStatus::Rejection),
+            3 => Ok(PKIStatus::Waiting),
+            4 => Ok(PKIStatus::RevocationWarning),
+            5 => Ok(PKIStatus::RevocationNotification),
+            _ => Err(ASN1Error::new(ASN1ErrorKind::Invalid)),
+        }
+    }
+}
+
+impl DEREncodable for PKIStatus {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_i64(*self as i64)
+    }
+}
+
+/// The status of a time-stamp response with the details (RFC 3161 2.4.2).
+///
+/// ```text
+/// PKIStatusInfo ::= SEQUENCE {
+///    status        PKIStatus,
+///    statusString  PKIFreeText     OPTIONAL,
+///    failInfo      PKIFailureInfo  OPTIONAL  }
+///
+/// PKIFreeText ::= SEQUENCE SIZE (1..MAX) OF UTF8String
+///
+/// PKIFailureInfo ::= BIT STRING {
+///     badAlg               (0),
+///     badRequest           (2),
+///     badDataFormat        (5),
+///     timeNotAvailable    (14),
+///     unacceptedPolicy    (15),
+///     unacceptedExtension (16),
+///     addInfoNotAvailable (17),
+///     systemFailure       (25)  }
+/// ```
+///
+/// `PKIFreeText` is represented by `Vec<String>`, which is omitted when
+/// encoded if empty, and rejected when decoded if present but empty.
+/// `PKIFailureInfo` is represented by `BitString`, as the `ReasonFlags` in
+/// [`DistributionPoint`](super::DistributionPoint).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PKIStatusInfo {
+    /// The status
+    pub status: PKIStatus,
+    /// The human-readable description of the status
+    pub status_string: Vec<String>,
+    /// The reasons for the failure
+    pub fail_info: Option<BitString>,
+}
+
+impl PKIStatusInfo {
+    /// Returns `true` if the status is `Granted` or `GrantedWithMods`,
+    /// in which case a token is present.
+    pub fn is_granted(&self) -> bool {
+        self.status == PKIStatus::Granted ||
+            self.status == PKIStatus::GrantedWithMods
+    }
+}
+
+impl BERDecodable for PKIStatusInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let status = PKIStatus::decode_ber(reader.next())?;
+            let status_string = reader.read_optional(Vec::decode_ber)?;
+            if status_string.as_ref().map_or(false, Vec::is_empty) {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            let fail_info = reader.read_optional(BitString::decode_ber)?;
+            return Ok(PKIStatusInfo {
+                status,
+                status_string: status_string.unwrap_or_default(),
+                fail_info,
+            });
+        })
+    }
+}
+
+impl DEREncodable for PKIStatusInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.status.encode_der(writer.next());
+            if !self.status_string.is_empty() {
+                self.status_string.encode_der(writer.next());
+            }
+            if let Some(ref fail_info) = self.fail_info {
+                fail_info.encode_der(writer.next());
+            }
+        })
+    }
+}
+
+/// A time-stamp response (RFC 3161 2.4.2).
+///
+/// ```text
+/// TimeStampResp ::= SEQUENCE  {
+///      status                  PKIStatusInfo,
+///      timeStampToken          TimeStampToken     OPTIONAL  }
+///
+/// TimeStampToken ::= ContentInfo
+///      -- contentType is id-signedData ([CMS])
+///      -- content is SignedData ([CMS])
+/// ```
+///
+/// The signature of the token is verified as that of any other
+/// [`SignedData`](super::SignedData). The signed content is decoded by
+/// [`tst_info`](Self::tst_info).
+///
+/// # Examples
+///
+/// ```
+/// use yasna::pkix::{PKIStatus, TimeStampResp};
+/// let response : TimeStampResp =
+///     yasna::decode_der(&[48, 9, 48, 7, 2, 1, 2, 3, 2, 7, 128]).unwrap();
+/// assert_eq!(response.status.status, PKIStatus::Rejection);
+/// // badAlg
+/// assert_eq!(response.status.fail_info.unwrap().get(0), Some(true));
+/// assert_eq!(response.time_stamp_token, None);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TimeStampResp {
+    /// The status
+    pub status: PKIStatusInfo,
+    /// The token, present if the status is granted
+    pub time_stamp_token: Option<ContentInfo>,
+}
+
+impl TimeStampResp {
+    /// Decodes the TSTInfo in the token, or returns `None` if there is no
+    /// token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token is not a SignedData of a TSTInfo.
+    pub fn tst_info(&self) -> ASN1Result<Option<TSTInfo>> {
+        match self.time_stamp_token {
+            Some(ref token) => return Ok(Some(TSTInfo::from_token(token)?)),
+            None => return Ok(None),
+        }
+    }
+}
+
+impl BERDecodable for TimeStampResp {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let status = PKIStatusInfo::decode_ber(reader.next())?;
+            let time_stamp_token = reader.read_optional(ContentInfo::decode_ber)?;
+            return Ok(TimeStampResp {
+                status,
+                time_stamp_token,
+            });
+        })
+    }
+}
+
+impl DEREncodable for TimeStampResp {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            self.status.encode_der(writer.next());
+            if let Some(ref time_stamp_token) = self.time_stamp_token {
+                time_stamp_token.encode_der(writer.next());
+            }
+        })
+    }
+}
+
+/// The accuracy of the time in a time-stamp token (RFC 3161 2.4.2).
+///
+/// ```text
+/// Accuracy ::= SEQUENCE {
+///       seconds        INTEGER              OPTIONAL,
+///       millis     [0] INTEGER  (1..999)    OPTIONAL,
+///       micros     [1] INTEGER  (1..999)    OPTIONAL  }
+/// ```
+///
+/// The missing fields are zero. `millis` and `micros` out of range are
+/// rejected when decoded.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub struct Accuracy {
+    /// The seconds
+    pub seconds: Option<u64>,
+    /// The milliseconds, between 1 and 999
+    pub millis: Option<u16>,
+    /// The microseconds, between 1 and 999
+    pub micros: Option<u16>,
+}
+
+impl Accuracy {
+    /// Returns the accuracy in microseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::pkix::Accuracy;
+    /// let accuracy = Accuracy {
+    ///     seconds: Some(1),
+    ///     millis: None,
+    ///     micros: Some(100),
+    /// };
+    /// assert_eq!(accuracy.as_micros(), 1_000_100);
+    /// ```
+    pub fn as_micros(&self) -> u128 {
+        self.seconds.unwrap_or(0) as u128 * 1_000_000 +
+            self.millis.unwrap_or(0) as u128 * 1_000 +
+            self.micros.unwrap_or(0) as u128
+    }
+}
+
+/// Reads `INTEGER (1..999)`.
+fn read_sub_second(reader: BERReader) -> ASN1Result<u16> {
+    let value = reader.read_u16()?;
+    if !(1..=999).contains(&value) {
+        return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+    }
+    return Ok(value);
+}
+
+impl BERDecodable for Accuracy {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            let seconds = reader.read_optional(|reader| reader.read_u64())?;
+            let millis = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(0), read_sub_second)
+            })?;
+            let micros = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1), read_sub_second)
+            })?;
+            return Ok(Accuracy {
+                seconds,
+                millis,
+                micros,
+            });
+        })
+    }
+}
+
+impl DEREncodable for Accuracy {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            if let Some(seconds) = self.seconds {
+                writer.next().write_u64(seconds);
+            }
+            if let Some(millis) = self.millis {
+                writer.next().write_tagged_implicit(Tag::context(0), |writer| {
+                    writer.write_u16(millis)
+                });
+            }
+            if let Some(micros) = self.micros {
+                writer.next().write_tagged_implicit(Tag::context(1), |writer| {
+                    writer.write_u16(micros)
+                });
+            }
+        })
+    }
+}
+
+/// The content of a time-stamp token (RFC 3161 2.4.2).
+///
+/// ```text
+/// TSTInfo ::= SEQUENCE  {
+///    version                      INTEGER  { v1(1) },
+///    policy                       TSAPolicyId,
+///    messageImprint               MessageImprint,
+///      -- MUST have the same value as the similar field in
+///      -- TimeStampReq
+///    serialNumber                 INTEGER,
+///     -- Time-Stamping users MUST be ready to accommodate integers
+///     -- up to 160 bits.
+///    genTime                      GeneralizedTime,
+///    accuracy                     Accuracy                 OPTIONAL,
+///    ordering                     BOOLEAN             DEFAULT FALSE,
+///    nonce                        INTEGER                  OPTIONAL,
+///      -- MUST be present if the similar field was present
+///      -- in TimeStampReq.  In that case it MUST have the same value.
+///    tsa                          [0] GeneralName          OPTIONAL,
+///    extensions                   [1] IMPLICIT Extensions   OPTIONAL  }
+/// ```
+///
+/// The version is not stored, since v1 is the only version. The serial
+/// number and the nonce are represented by [`SerialNumber`].
+///
+/// `genTime` may have fractional seconds of any precision, which
+/// [`GeneralizedTime`] keeps in full, so that they are written back as
+/// they were read.
+///
+/// `tsa` is EXPLICIT, since GeneralName is a CHOICE. The extensions are
+/// omitted when encoded if empty.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TSTInfo {
+    /// The policy under which the token was issued
+    pub policy: ObjectIdentifier,
+    /// The hash of the time-stamped data
+    pub message_imprint: MessageImprint,
+    /// The serial number, unique for each token issued by a TSA
+    pub serial_number: SerialNumber,
+    /// When the token was issued
+    pub gen_time: GeneralizedTime,
+    /// The accuracy of `gen_time`
+    pub accuracy: Option<Accuracy>,
+    /// Whether the tokens from the TSA can be ordered by `gen_time`,
+    /// regardless of the accuracy
+    pub ordering: bool,
+    /// The nonce in the request
+    pub nonce: Option<SerialNumber>,
+    /// The name of the TSA
+    pub tsa: Option<GeneralName>,
+    /// The extensions
+    pub extensions: Vec<Extension>,
+}
+
+impl TSTInfo {
+    /// Decodes the TSTInfo in a time-stamp token.
+    ///
+    /// This can also be used for the tokens in the signature time-stamp
+    /// attribute (RFC 3161 Appendix A) of a CMS signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token is not a SignedData of a TSTInfo.
+    pub fn from_token(token: &ContentInfo) -> ASN1Result<Self> {
+        let encap_content_info = match *token {
+            ContentInfo::SignedData(ref signed_data) =>
+                &signed_data.encap_content_info,
+            _ => return Err(ASN1Error::new(ASN1ErrorKind::Invalid)),
+        };
+        if encap_content_info.e_content_type != ID_CT_TSTINFO {
+            return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+        }
+        match encap_content_info.e_content {
+            Some(ref e_content) => return decode_der(e_content),
+            None => return Err(ASN1Error::new(ASN1ErrorKind::Invalid)),
+        }
+    }
+}
+
+impl BERDecodable for TSTInfo {
+    fn decode_ber(reader: BERReader) -> ASN1Result<Self> {
+        reader.read_sequence(|reader| {
+            if reader.next().read_u64()? != 1 {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            let policy = ObjectIdentifier::decode_ber(reader.next())?;
+            let message_imprint = MessageImprint::decode_ber(reader.next())?;
+            let serial_number = SerialNumber::decode_ber(reader.next())?;
+            let gen_time = reader.next().read_generalized_time()?;
+            let accuracy = reader.read_optional(Accuracy::decode_ber)?;
+            let ordering = reader.read_default(false, |reader| {
+                reader.read_bool()
+            })?;
+            let nonce = reader.read_optional(SerialNumber::decode_ber)?;
+            let tsa = reader.read_optional(|reader| {
+                reader.read_tagged(Tag::context(0), GeneralName::decode_ber)
+            })?;
+            let extensions = reader.read_optional(|reader| {
+                reader.read_tagged_implicit(Tag::context(1), Vec::decode_ber)
+            })?;
+            return Ok(TSTInfo {
+                policy,
+                message_imprint,
+                serial_number,
+                gen_time,
+                accuracy,
+                ordering,
+                nonce,
+                tsa,
+                extensions: extensions.unwrap_or_default(),
+            });
+        })
+    }
+}
+
+impl DEREncodable for TSTInfo {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_sequence(|writer| {
+            writer.next().write_u8(1);
+            self.policy.encode_der(writer.next());
+            self.message_imprint.encode_der(writer.next());
+            self.serial_number.encode_der(writer.next());
+            writer.next().write_generalized_time(&self.gen_time);
+            if let Some(ref accuracy) = self.accuracy {
+                accuracy.encode_der(writer.next());
+            }
+            if self.ordering {
+                writer.next().write_bool(true);
+            }
+            if let Some(ref nonce) = self.nonce {
+                nonce.encode_der(writer.next());
+            }
+            if let Some(ref tsa) = self.tsa {
+                writer.next().write_tagged(Tag::context(0), |writer| {
+                    tsa.encode_der(writer)
+                });
+            }
+            if !self.extensions.is_empty() {
+                writer.next().write_tagged_implicit(Tag::context(1), |writer| {
+                    self.extensions.encode_der(writer)
+                });
+            }
+        })
+    }
+}
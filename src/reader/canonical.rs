@@ -0,0 +1,271 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{BERReader,BERReaderImpl,BERMode,TlvEvent,walk_ber};
+use super::{ASN1Error,ASN1ErrorKind,ASN1Result};
+use super::super::{PCBit,Tag,TagClass,DERWriter,construct_der};
+use super::super::models::TaggedDerValue;
+use super::super::tags::{TAG_BOOLEAN,TAG_BITSTRING,TAG_OCTETSTRING};
+use super::super::tags::{TAG_OBJECT_DESCRIPTOR,TAG_SET};
+
+/// A schema-less representation of a BER value.
+enum Node {
+    Primitive(Tag, Vec<u8>),
+    Constructed(Tag, Vec<Node>),
+}
+
+/// Tells whether values with the universal tag may be encoded in
+/// constructed form in BER, in which case they are flattened.
+fn is_string_tag(tag: Tag) -> bool {
+    if tag.tag_class != TagClass::Universal {
+        return false;
+    }
+    return tag == TAG_OCTETSTRING || tag == TAG_OBJECT_DESCRIPTOR ||
+        tag.tag_number == 12 || (18..=28).contains(&tag.tag_number) ||
+        tag.tag_number == 30;
+}
+
+/// Reads a value whose encoding is normalized as a whole: a BOOLEAN,
+/// a BIT STRING or a constructed string. Returns `None` for other values.
+fn read_normalized(inner: &mut BERReaderImpl, tag: Tag, pcbit: PCBit)
+        -> ASN1Result<Option<Vec<u8>>> {
+    if tag == TAG_BOOLEAN {
+        let value = BERReader::new(inner).read_bool()?;
+        return Ok(Some(vec![if value { 255 } else { 0 }]));
+    }
+    if tag == TAG_BITSTRING {
+        let (mut bytes, len) = BERReader::new(inner).read_bitvec_bytes()?;
+        let unused_bits = bytes.len() * 8 - len;
+        if let Some(last) = bytes.last_mut() {
+            *last &= !((1u16 << unused_bits) - 1) as u8;
+        }
+        let mut contents = Vec::with_capacity(bytes.len() + 1);
+        contents.push(unused_bits as u8);
+        contents.extend_from_slice(&bytes);
+        return Ok(Some(contents));
+    }
+    if pcbit == PCBit::Constructed && is_string_tag(tag) {
+        let bytes = BERReader {
+            inner,
+            implicit_tag: Some(tag),
+        }.read_bytes()?;
+        return Ok(Some(bytes));
+    }
+    return Ok(None);
+}
+
+/// Appends `node` to the innermost open constructed value, or to `roots`
+/// at the top level.
+fn push_node(stack: &mut [(Tag, Vec<Node>)], roots: &mut Vec<Node>,
+        node: Node) {
+    match stack.last_mut() {
+        Some(&mut (_, ref mut children)) => children.push(node),
+        None => roots.push(node),
+    }
+}
+
+fn write_node(node: &Node, writer: DERWriter) {
+    match *node {
+        Node::Primitive(tag, ref contents) => {
+            writer.write_tagged_der(&TaggedDerValue::from_tag_pc_and_bytes(
+                tag, PCBit::Primitive, contents.clone()));
+        },
+        Node::Constructed(TAG_SET, ref children) => {
+            let bufs = children.iter().map(|child| {
+                construct_der(|writer| write_node(child, writer))
+            }).collect::<Vec<_>>();
+            let mut tags = children.iter().map(|child| {
+                match *child {
+                    Node::Primitive(tag, _) => tag,
+                    Node::Constructed(tag, _) => tag,
+                }
+            }).collect::<Vec<_>>();
+            tags.sort();
+            tags.dedup();
+            let callback = |writer: &mut super::super::DERWriterSet| {
+                for buf in bufs.iter() {
+                    writer.next().write_der(buf);
+                }
+            };
+            if tags.len() == children.len() {
+                writer.write_set(callback);
+            } else {
+                writer.write_set_of(callback);
+            }
+        },
+        Node::Constructed(tag, ref children) => {
+            writer.write_tagged_implicit(tag, |writer| {
+                writer.write_sequence(|writer| {
+                    for child in children.iter() {
+                        write_node(child, writer.next());
+                    }
+                })
+            });
+        },
+    }
+}
+
+/// Re-encodes BER-encoded data into DER, without knowing its schema.
+///
+/// The input must consist of exactly one BER value. This function:
+///
+/// - uses definite lengths in the minimal form,
+/// - flattens constructed OCTET STRINGs, BIT STRINGs and the universal
+///   character string types (as well as UTCTime/GeneralizedTime),
+/// - encodes BOOLEAN true as `0xFF`,
+/// - clears the unused bits of BIT STRINGs, and
+/// - sorts the elements of universal SETs: in the order of their tags if
+///   the tags are all distinct (SET), otherwise in the order of their
+///   encodings (SET OF), following
+///   [`write_set`](crate::DERWriter::write_set) and
+///   [`write_set_of`](crate::DERWriter::write_set_of).
+///
+/// # Limitations
+///
+/// Since no schema is available, the result is not always valid DER:
+///
+/// - Implicitly tagged SETs, such as `[0] IMPLICIT SET OF Attribute`
+///   in CMS, cannot be told from other constructed values and their
+///   elements are kept in the original order.
+///   Implicitly tagged strings, BOOLEANs and BIT STRINGs are not
+///   normalized either.
+/// - A SET OF whose elements happen to have distinct tags is sorted
+///   as a SET. The two orders differ only if the elements mix primitive
+///   and constructed encodings.
+/// - The contents of other primitive values, such as INTEGERs and times,
+///   are copied as is.
+///
+/// # Examples
+///
+/// ```
+/// use yasna;
+/// let ber = &[49, 128, 36, 128, 4, 1, 72, 4, 1, 105, 0, 0, 1, 1, 1, 0, 0];
+/// let der = yasna::ber_to_der(ber).unwrap();
+/// assert_eq!(der, &[49, 7, 1, 1, 255, 4, 2, 72, 105]);
+/// ```
+pub fn ber_to_der(src: &[u8]) -> ASN1Result<Vec<u8>> {
+    // Constructed values being converted, with their converted children.
+    let mut stack: Vec<(Tag, Vec<Node>)> = Vec::new();
+    let mut roots = Vec::new();
+    // The depth of the value read as a whole, whose contents are skipped.
+    let mut skip_depth = None;
+    walk_ber(src, BERMode::Ber, |event| {
+        match event {
+            TlvEvent::Header { depth, tag, pcbit, pos, contents_pos, length,
+                    .. } => {
+                if skip_depth.map_or(false, |skip_depth| depth > skip_depth) {
+                    return Ok(());
+                }
+                let mut inner = BERReaderImpl::with_pos(src, pos, BERMode::Ber);
+                let node = match read_normalized(&mut inner, tag, pcbit)? {
+                    Some(contents) => {
+                        if pcbit == PCBit::Constructed {
+                            skip_depth = Some(depth);
+                        }
+                        Node::Primitive(tag, contents)
+                    },
+                    None if pcbit == PCBit::Constructed => {
+                        stack.push((tag, Vec::new()));
+                        return Ok(());
+                    },
+                    None => {
+                        // Primitive values always have definite lengths.
+                        let length = length.unwrap();
+                        Node::Primitive(tag,
+                            src[contents_pos..contents_pos + length].to_vec())
+                    },
+                };
+                push_node(&mut stack, &mut roots, node);
+            },
+            TlvEvent::End { depth, .. } => {
+                if let Some(skip) = skip_depth {
+                    if depth == skip {
+                        skip_depth = None;
+                    }
+                    return Ok(());
+                }
+                let (tag, children) = stack.pop().unwrap();
+                push_node(&mut stack, &mut roots,
+                    Node::Constructed(tag, children));
+            },
+        }
+        return Ok(());
+    })?;
+    if roots.len() > 1 {
+        return Err(ASN1Error::new(ASN1ErrorKind::Extra));
+    }
+    let node = roots.pop().ok_or(ASN1Error::new(ASN1ErrorKind::Eof))?;
+    return Ok(construct_der(|writer| write_node(&node, writer)));
+}
+
+#[test]
+fn test_ber_to_der_ok() {
+    let tests : &[(&[u8], &[u8])] = &[
+        // Already in DER
+        (&[48, 6, 2, 1, 10, 1, 1, 255], &[48, 6, 2, 1, 10, 1, 1, 255]),
+        // Indefinite and non-minimal lengths
+        (&[48, 128, 2, 129, 1, 10, 48, 128, 0, 0, 0, 0],
+            &[48, 5, 2, 1, 10, 48, 0]),
+        (&[160, 130, 0, 3, 2, 1, 10], &[160, 3, 2, 1, 10]),
+        // BOOLEAN
+        (&[1, 1, 1], &[1, 1, 255]),
+        (&[48, 3, 1, 1, 0], &[48, 3, 1, 1, 0]),
+        // Constructed strings
+        (&[36, 128, 4, 1, 72, 36, 3, 4, 1, 105, 0, 0], &[4, 2, 72, 105]),
+        (&[44, 8, 4, 1, 72, 4, 3, 105, 226, 130],
+            &[12, 4, 72, 105, 226, 130]),
+        (&[35, 128, 3, 2, 0, 255, 3, 2, 4, 255, 0, 0], &[3, 3, 4, 255, 240]),
+        // Unused bits
+        (&[3, 2, 1, 255], &[3, 2, 1, 254]),
+        // SET ordering by tags
+        (&[49, 6, 2, 1, 10, 1, 1, 255], &[49, 6, 1, 1, 255, 2, 1, 10]),
+        (&[49, 7, 129, 0, 160, 3, 2, 1, 10], &[49, 7, 160, 3, 2, 1, 10, 129, 0]),
+        // SET OF ordering by encodings
+        (&[49, 9, 2, 1, 10, 2, 1, 3, 2, 1, 10],
+            &[49, 9, 2, 1, 3, 2, 1, 10, 2, 1, 10]),
+        // Implicitly tagged SETs are not reordered
+        (&[160, 6, 2, 1, 10, 2, 1, 3], &[160, 6, 2, 1, 10, 2, 1, 3]),
+    ];
+    for &(ber, der) in tests {
+        assert_eq!(ber_to_der(ber).unwrap(), der);
+    }
+}
+
+#[test]
+fn test_ber_to_der_err() {
+    let tests : &[&[u8]] = &[
+        &[],
+        &[0, 0],
+        &[2, 1, 10, 0],
+        &[48, 128, 2, 1, 10],
+        &[48, 128, 2, 1, 10, 0],
+        &[1, 2, 0, 0],
+        &[36, 128, 2, 1, 10, 0, 0],
+        &[2, 128, 0, 0],
+        &[48, 0, 2, 1, 10],
+    ];
+    for &ber in tests {
+        ber_to_der(ber).unwrap_err();
+    }
+}
+
+#[test]
+fn test_ber_to_der_depth() {
+    let mut ber = Vec::new();
+    for _ in 0..200 {
+        ber.extend_from_slice(&[48, 128]);
+    }
+    for _ in 0..200 {
+        ber.extend_from_slice(&[0, 0]);
+    }
+    let err = ber_to_der(&ber).unwrap_err();
+    assert_eq!(err.kind(), ASN1ErrorKind::StackOverflow);
+}
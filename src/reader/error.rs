@@ -16,7 +16,8 @@ pub struct ASN1Error {
 }
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum ASN1ErrorKind {
-    Eof, Extra, IntegerOverflow, StackOverflow, Invalid,
+    Eof, Extra, IntegerOverflow, StackOverflow, Invalid, NoAlternative,
+    NonCanonicalTime,
 }
 
 pub type ASN1Result<T> = Result<T, ASN1Error>;
@@ -49,6 +50,8 @@ impl Error for ASN1Error {
             ASN1ErrorKind::IntegerOverflow => "Integer overflow",
             ASN1ErrorKind::StackOverflow => "Stack overflow",
             ASN1ErrorKind::Invalid => "Invalid data",
+            ASN1ErrorKind::NoAlternative => "No matching alternative",
+            ASN1ErrorKind::NonCanonicalTime => "Non-canonical time in DER",
         }
     }
 }
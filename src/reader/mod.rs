@@ -12,8 +12,13 @@
 use alloc::vec::Vec;
 use alloc::string::String;
 use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+use core::marker::PhantomData;
 
 mod error;
+mod canonical;
 
 #[cfg(feature = "num-bigint")]
 use num_bigint::{BigInt,BigUint,Sign};
@@ -24,10 +29,15 @@ use super::{PCBit,Tag,TAG_CLASSES};
 use super::tags::{TAG_EOC,TAG_BOOLEAN,TAG_INTEGER,TAG_OCTETSTRING};
 use super::tags::{TAG_NULL,TAG_OID,TAG_UTF8STRING,TAG_SEQUENCE,TAG_SET,TAG_ENUM};
 use super::tags::{TAG_NUMERICSTRING,TAG_PRINTABLESTRING,TAG_VISIBLESTRING,TAG_IA5STRING,TAG_BMPSTRING};
-use super::models::{ObjectIdentifier,TaggedDerValue};
+use super::models::{ObjectIdentifier,TaggedDerValue,WithRawRef};
+use super::models::{Oid,OidBytesError};
+use super::models::{Date,TimeOfDay,DateTime,Duration,Time};
+use super::models::GeneralizedTimeFields;
+use super::deserializer::BERDecodable;
 #[cfg(feature = "time")]
-use super::models::{UTCTime,GeneralizedTime};
+use super::models::{UTCTime,GeneralizedTime,TimeStrictness,UTCTimeWindow};
 pub use self::error::*;
+pub use self::canonical::ber_to_der;
 
 /// Parses DER/BER-encoded data.
 ///
@@ -110,6 +120,46 @@ pub enum BERMode {
     Der,
 }
 
+/// A step of the traversal by [`walk_ber`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum TlvEvent {
+    /// The identifier and the length of a value. The identifier starts at
+    /// `pos`, the length at `length_pos` and the contents at `contents_pos`.
+    /// `length` is `None` for the indefinite length.
+    Header {
+        depth: usize,
+        tag: Tag,
+        pcbit: PCBit,
+        pos: usize,
+        length_pos: usize,
+        contents_pos: usize,
+        length: Option<usize>,
+    },
+    /// The end of the contents of a constructed value. For the indefinite
+    /// length, `pos` is where the end-of-contents octets start.
+    End {
+        depth: usize,
+        pos: usize,
+        indefinite: bool,
+    },
+}
+
+/// Traverses all the values in `buf`, including the contents of
+/// constructed values, reporting them to `visit`.
+///
+/// It stops at the first error, either in the encoding or from `visit`.
+/// As in [`BERReader`], values nested too deeply fail with
+/// [`ASN1ErrorKind::StackOverflow`].
+pub(crate) fn walk_ber<V>(buf: &[u8], mode: BERMode, mut visit: V)
+        -> ASN1Result<()>
+        where V: FnMut(TlvEvent) -> ASN1Result<()> {
+    let mut reader_impl = BERReaderImpl::new(buf, mode);
+    while reader_impl.pos < buf.len() {
+        reader_impl.walk_general(true, &mut visit)?;
+    }
+    return Ok(());
+}
+
 #[derive(Debug)]
 struct BERReaderImpl<'a> {
     buf: &'a [u8],
@@ -126,7 +176,7 @@ enum Contents<'a, 'b> where 'a: 'b {
     Constructed(&'b mut BERReaderImpl<'a>),
 }
 
-const BER_READER_STACK_DEPTH : usize = 100;
+pub(crate) const BER_READER_STACK_DEPTH : usize = 100;
 
 impl<'a> BERReaderImpl<'a> {
     fn new(buf: &'a [u8], mode: BERMode) -> Self {
@@ -204,41 +254,9 @@ impl<'a> BERReaderImpl<'a> {
         return Ok((tag, pcbit));
     }
 
-    fn lookahead_tag(&self) -> ASN1Result<Tag> {
-        let mut pos = self.pos;
-        let mut read_u8 = || {
-            if pos < self.buf.len() {
-                let ret = self.buf[pos];
-                pos += 1;
-                return Ok(ret);
-            } else {
-                return Err(ASN1Error::new(ASN1ErrorKind::Eof));
-            }
-        };
-        let tagbyte = read_u8()?;
-        let tag_class = TAG_CLASSES[(tagbyte >> 6) as usize];
-        let mut tag_number = (tagbyte & 31) as u64;
-        if tag_number == 31 {
-            tag_number = 0;
-            loop {
-                let b = read_u8()? as u64;
-                let x =
-                    tag_number.checked_mul(128).ok_or(
-                        ASN1Error::new(ASN1ErrorKind::IntegerOverflow))?;
-                tag_number = x + (b & 127);
-                if (b & 128) == 0 {
-                    break;
-                }
-            }
-            if tag_number < 31 {
-                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
-            }
-        }
-        let tag = Tag {
-            tag_class,
-            tag_number,
-        };
-        return Ok(tag);
+    fn lookahead_identifier(&self) -> ASN1Result<(Tag, PCBit)> {
+        let mut lookahead = BERReaderImpl::with_pos(self.buf, self.pos, self.mode);
+        lookahead.read_identifier()
     }
 
     fn read_length(&mut self) -> ASN1Result<Option<usize>> {
@@ -321,44 +339,94 @@ impl<'a> BERReaderImpl<'a> {
     }
 
     fn skip_general(&mut self) -> ASN1Result<(Tag, PCBit, usize)> {
-        let mut skip_depth = 0;
+        return self.walk_general(false, |_| Ok(()));
+    }
+
+    /// Skips a value like `skip_general`, reporting each identifier-length
+    /// header and the end of each constructed value to `visit`. If `descend`
+    /// is true, the contents of constructed values of definite length are
+    /// traversed as well, up to the same depth as `read_general`.
+    fn walk_general<V>(&mut self, descend: bool, mut visit: V)
+            -> ASN1Result<(Tag, PCBit, usize)>
+            where V: FnMut(TlvEvent) -> ASN1Result<()> {
+        // The ends of the enclosing constructed values: `Some` for definite
+        // lengths (only when descending), `None` for indefinite lengths.
+        let mut stack : Vec<Option<usize>> = Vec::new();
         let mut skip_tag = None;
         let mut data_pos = None;
-        while skip_depth > 0 || skip_tag == None {
+        while !stack.is_empty() || skip_tag == None {
+            if let Some(&Some(limit)) = stack.last() {
+                if limit <= self.pos {
+                    if limit < self.pos {
+                        return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+                    }
+                    stack.pop();
+                    visit(TlvEvent::End {
+                        depth: stack.len(),
+                        pos: limit,
+                        indefinite: false,
+                    })?;
+                    continue;
+                }
+            }
             let old_pos = self.pos;
             let (tag, pcbit) = self.read_identifier()?;
             if tag == TAG_EOC {
-                if skip_depth == 0 {
+                if stack.last() != Some(&None) {
                     self.pos = old_pos;
                     return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
                 }
-                skip_depth -= 1;
+                stack.pop();
                 // EOC is a pair of zero bytes, consume the second.
                 if self.read_u8()? != 0 {
                     return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
                 }
+                visit(TlvEvent::End {
+                    depth: stack.len(),
+                    pos: old_pos,
+                    indefinite: true,
+                })?;
                 continue;
             }
-            if skip_depth == 0 {
+            if stack.is_empty() {
                 skip_tag = Some((tag, pcbit));
+            } else if descend && stack.len() > BER_READER_STACK_DEPTH {
+                return Err(ASN1Error::new(ASN1ErrorKind::StackOverflow));
             }
-            if let Some(length) = self.read_length()? {
-                if skip_depth == 0 {
-                    data_pos = Some(self.pos);
-                }
-                let limit = self.pos+length;
+            let length_pos = self.pos;
+            let length = self.read_length()?;
+            let contents_pos = self.pos;
+            if stack.is_empty() {
+                data_pos = Some(contents_pos);
+            }
+            if let Some(length) = length {
+                let limit = contents_pos+length;
                 if self.buf.len() < limit {
                     return Err(ASN1Error::new(ASN1ErrorKind::Eof));
                 }
-                self.pos = limit;
-            } else {
-                if skip_depth == 0 {
-                    data_pos = Some(self.pos);
-                }
-                if pcbit != PCBit::Constructed || self.mode == BERMode::Der {
-                    return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+                if let Some(&Some(parent_limit)) = stack.last() {
+                    if parent_limit < limit {
+                        return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+                    }
                 }
-                skip_depth += 1;
+            } else if pcbit != PCBit::Constructed || self.mode == BERMode::Der {
+                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            }
+            visit(TlvEvent::Header {
+                depth: stack.len(),
+                tag,
+                pcbit,
+                pos: old_pos,
+                length_pos,
+                contents_pos,
+                length,
+            })?;
+            match length {
+                Some(length) if descend && pcbit == PCBit::Constructed => {
+                    stack.push(Some(contents_pos+length));
+                },
+                Some(length) => self.pos = contents_pos+length,
+                None => stack.push(None),
             }
         }
         return Ok((skip_tag.unwrap().0, skip_tag.unwrap().1, data_pos.unwrap()));
@@ -986,14 +1054,18 @@ impl<'a, 'b> BERReader<'a, 'b> {
             if buf.len() == 0 || buf[buf.len()-1] >= 128 {
                 return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
             }
-            let mut subid : u64 = 0;
+            let mut subid : u128 = 0;
+            let mut subid_start = true;
             for &b in buf.iter() {
-                if b == 128 {
+                if subid_start && b == 128 {
+                    // Non-minimal encoding of a subidentifier
                     return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
                 }
-                subid = subid.checked_mul(128)
-                    .ok_or(ASN1Error::new(
-                        ASN1ErrorKind::IntegerOverflow))? + ((b & 127) as u64);
+                if subid >> 121 != 0 {
+                    return Err(ASN1Error::new(ASN1ErrorKind::IntegerOverflow));
+                }
+                subid = subid * 128 + ((b & 127) as u128);
+                subid_start = (b & 128) == 0;
                 if (b & 128) == 0 {
                     if components.len() == 0 {
                         let id0 = if subid < 40 {
@@ -1012,7 +1084,45 @@ impl<'a, 'b> BERReader<'a, 'b> {
                     subid = 0;
                 }
             }
-            return Ok(ObjectIdentifier::new(components));
+            return Ok(ObjectIdentifier::from_arcs_unchecked(components));
+        })
+    }
+
+    /// Reads an ASN.1 object identifier without allocation, borrowing its
+    /// encoding from the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::oid;
+    /// let data = &[6, 8, 42, 134, 72, 134, 247, 13, 1, 1];
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_oid_ref()
+    /// }).unwrap();
+    /// assert!(asn == oid!("1.2.840.113549.1.1"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// It fails with [`Invalid`](ASN1ErrorKind::Invalid) if the contents are
+    /// not a correct OBJECT IDENTIFIER, or with
+    /// [`IntegerOverflow`](ASN1ErrorKind::IntegerOverflow) if an arc doesn't
+    /// fit in `u128`, as [`read_oid`](BERReader::read_oid) does.
+    pub fn read_oid_ref(self) -> ASN1Result<Oid<'a>> {
+        self.read_general(TAG_OID, |contents| {
+            let buf = match contents {
+                Contents::Primitive(buf) => buf,
+                Contents::Constructed(_) => {
+                    return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+                },
+            };
+            return Oid::check_der_bytes(buf).map_err(|e| match e {
+                OidBytesError::Invalid =>
+                    ASN1Error::new(ASN1ErrorKind::Invalid),
+                OidBytesError::Overflow =>
+                    ASN1Error::new(ASN1ErrorKind::IntegerOverflow),
+            });
         })
     }
 
@@ -1144,6 +1254,41 @@ impl<'a, 'b> BERReader<'a, 'b> {
         return Ok(collection);
     }
 
+    /// Reads an ASN.1 SEQUENCE OF value without decoding its elements.
+    ///
+    /// The elements are decoded one by one as the returned
+    /// [`LazySequenceOf`] is iterated. It borrows the input, so large
+    /// sequences can be searched without collecting them into a `Vec`.
+    /// The encoding of the elements is checked upfront, but their contents
+    /// are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// let data = &[48, 7, 2, 1, 10, 2, 2, 255, 127];
+    /// let numbers = yasna::parse_der(data, |reader| {
+    ///     reader.read_sequence_of_lazy::<i64>()
+    /// }).unwrap();
+    /// assert_eq!(numbers.clone().count(), 2);
+    /// assert!(numbers.map(|n| n.unwrap()).any(|n| n == -129));
+    /// ```
+    pub fn read_sequence_of_lazy<T>(self)
+            -> ASN1Result<LazySequenceOf<'a, T>>
+            where T: BERDecodable {
+        let mode = self.mode();
+        let buf = self.read_sequence(|reader| {
+            return Ok(reader.read_with_buffer(|reader| {
+                reader.skip_remaining()
+            })?.1);
+        })?;
+        return Ok(LazySequenceOf {
+            buf,
+            mode,
+            _marker: PhantomData,
+        });
+    }
+
     /// Reads an ASN.1 SET value.
     ///
     /// This function uses the loan pattern: `callback` is called back with
@@ -1180,12 +1325,11 @@ impl<'a, 'b> BERReader<'a, 'b> {
             let mut elements = Vec::new();
             loop {
                 let old_pos = inner.pos;
-                if let Some(tag) = inner.read_optional(|inner| {
-                    inner.skip_general().map(|t| t.0)
+                if let Some(identifier) = inner.read_optional(|inner| {
+                    inner.skip_general().map(|t| (t.0, t.1))
                 })? {
                     let new_pos = inner.pos;
-                    // TODO: this should store the P/C bit as well
-                    elements.push((tag, &inner.buf[..new_pos], old_pos));
+                    elements.push((identifier, &inner.buf[..new_pos], old_pos));
                 } else {
                     break;
                 }
@@ -1340,7 +1484,7 @@ impl<'a, 'b> BERReader<'a, 'b> {
                 if !(
                     byte == b' ' ||
                     (b'\'' <= byte && byte <= b':' && byte != b'*') ||
-                    byte == b'=' ||
+                    byte == b'=' || byte == b'?' ||
                     (b'A' <= byte && byte <= b'Z') ||
                     (b'a' <= byte && byte <= b'z')) {
                     return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
@@ -1420,6 +1564,13 @@ impl<'a, 'b> BERReader<'a, 'b> {
     /// assert_eq!(asn.datetime().unix_timestamp(), 378820800);
     /// ```
     ///
+    /// # Errors
+    ///
+    /// In DER mode, it fails with
+    /// [`NonCanonicalTime`](ASN1ErrorKind::NonCanonicalTime) if the value
+    /// is a correct UTCTime but not in the canonical form
+    /// (see [`TimeStrictness::Der`]).
+    ///
     /// # Features
     ///
     /// This method is enabled by `time` feature.
@@ -1435,8 +1586,58 @@ impl<'a, 'b> BERReader<'a, 'b> {
             let bytes = reader.read_bytes()?;
             let datetime = UTCTime::parse(&bytes).ok_or_else(
                 || ASN1Error::new(ASN1ErrorKind::Invalid))?;
-            if mode == BERMode::Der && &datetime.to_bytes() != &bytes {
-                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            if mode == BERMode::Der && UTCTime::parse_with_strictness(
+                    &bytes, TimeStrictness::Der).is_none() {
+                return Err(ASN1Error::new(ASN1ErrorKind::NonCanonicalTime));
+            }
+            return Ok(datetime);
+        })
+    }
+
+    #[cfg(feature = "time")]
+    /// Reads an ASN.1 UTCTime, interpreting the two-digit year in the given
+    /// window.
+    ///
+    /// As with [`read_utctime`](BERReader::read_utctime), the seconds may be
+    /// omitted and the time may have an offset like `+0900` in BER mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::models::UTCTimeWindow;
+    /// let data = b"\x17\x0b6001010000Z";
+    /// let asn = yasna::parse_ber(data, |reader| {
+    ///     reader.read_utctime_with_window(UTCTimeWindow::starting_at(1970))
+    /// }).unwrap();
+    /// assert_eq!(asn.datetime().year(), 2060);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// In DER mode, it fails with
+    /// [`NonCanonicalTime`](ASN1ErrorKind::NonCanonicalTime) if the value
+    /// is a correct UTCTime but not in the canonical form
+    /// (see [`TimeStrictness::Der`]).
+    ///
+    /// # Features
+    ///
+    /// This method is enabled by `time` feature.
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// yasna = { version = "*", features = ["time"] }
+    /// ```
+    pub fn read_utctime_with_window(self, window: UTCTimeWindow)
+            -> ASN1Result<UTCTime> {
+        use super::tags::TAG_UTCTIME;
+        let mode = self.inner.mode;
+        self.read_tagged_implicit(TAG_UTCTIME, |reader| {
+            let bytes = reader.read_bytes()?;
+            let datetime = UTCTime::parse_with_window(&bytes, window)
+                .ok_or_else(|| ASN1Error::new(ASN1ErrorKind::Invalid))?;
+            if mode == BERMode::Der && datetime.to_bytes() != bytes {
+                return Err(ASN1Error::new(ASN1ErrorKind::NonCanonicalTime));
             }
             return Ok(datetime);
         })
@@ -1458,6 +1659,13 @@ impl<'a, 'b> BERReader<'a, 'b> {
     /// assert_eq!(asn.datetime().unix_timestamp(), 500159309);
     /// ```
     ///
+    /// # Errors
+    ///
+    /// In DER mode, it fails with
+    /// [`NonCanonicalTime`](ASN1ErrorKind::NonCanonicalTime) if the value
+    /// is a correct GeneralizedTime but not in the canonical form
+    /// (see [`TimeStrictness::Der`]).
+    ///
     /// # Features
     ///
     /// This method is enabled by `time` feature.
@@ -1473,13 +1681,177 @@ impl<'a, 'b> BERReader<'a, 'b> {
             let bytes = reader.read_bytes()?;
             let datetime = GeneralizedTime::parse(&bytes).ok_or_else(
                 || ASN1Error::new(ASN1ErrorKind::Invalid))?;
-            if mode == BERMode::Der && &datetime.to_bytes() != &bytes {
-                return Err(ASN1Error::new(ASN1ErrorKind::Invalid));
+            if mode == BERMode::Der && GeneralizedTime::parse_with_strictness(
+                    &bytes, TimeStrictness::Der).is_none() {
+                return Err(ASN1Error::new(ASN1ErrorKind::NonCanonicalTime));
             }
             return Ok(datetime);
         })
     }
 
+    /// Reads an ASN.1 GeneralizedTime, keeping its calendar fields as
+    /// written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// let data = b"\x18\x0f20161231235960Z";
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_generalized_time_fields()
+    /// }).unwrap();
+    /// assert!(asn.is_leap_second());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// In DER mode, it fails with
+    /// [`NonCanonicalTime`](ASN1ErrorKind::NonCanonicalTime) if the value
+    /// is a correct GeneralizedTime but not in the canonical form
+    /// (see [`GeneralizedTimeFields::is_canonical`]).
+    pub fn read_generalized_time_fields(self)
+            -> ASN1Result<GeneralizedTimeFields> {
+        use super::tags::TAG_GENERALIZEDTIME;
+        let mode = self.inner.mode;
+        self.read_tagged_implicit(TAG_GENERALIZEDTIME, |reader| {
+            let bytes = reader.read_bytes()?;
+            let fields = GeneralizedTimeFields::parse(&bytes).ok_or_else(
+                || ASN1Error::new(ASN1ErrorKind::Invalid))?;
+            if mode == BERMode::Der && !fields.is_canonical() {
+                return Err(ASN1Error::new(ASN1ErrorKind::NonCanonicalTime));
+            }
+            return Ok(fields);
+        })
+    }
+
+    /// Reads an ASN.1 DATE.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// let data = b"\x1f\x1f\x0a2019-10-19";
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_date()
+    /// }).unwrap();
+    /// assert_eq!((asn.year(), asn.month(), asn.day()), (2019, 10, 19));
+    /// ```
+    pub fn read_date(self) -> ASN1Result<Date> {
+        use super::tags::TAG_DATE;
+        self.read_tagged_implicit(TAG_DATE, |reader| {
+            let bytes = reader.read_bytes()?;
+            return Date::parse(&bytes).ok_or_else(
+                || ASN1Error::new(ASN1ErrorKind::Invalid));
+        })
+    }
+
+    /// Reads an ASN.1 TIME-OF-DAY.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// let data = b"\x1f\x20\x0809:30:00";
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_time_of_day()
+    /// }).unwrap();
+    /// assert_eq!((asn.hour(), asn.minute(), asn.second()), (9, 30, 0));
+    /// ```
+    pub fn read_time_of_day(self) -> ASN1Result<TimeOfDay> {
+        use super::tags::TAG_TIME_OF_DAY;
+        self.read_tagged_implicit(TAG_TIME_OF_DAY, |reader| {
+            let bytes = reader.read_bytes()?;
+            return TimeOfDay::parse(&bytes).ok_or_else(
+                || ASN1Error::new(ASN1ErrorKind::Invalid));
+        })
+    }
+
+    /// Reads an ASN.1 DATE-TIME.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// let data = b"\x1f\x21\x132019-10-19T09:30:00";
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_date_time()
+    /// }).unwrap();
+    /// assert_eq!(&asn.to_string(), "2019-10-19T09:30:00");
+    /// ```
+    pub fn read_date_time(self) -> ASN1Result<DateTime> {
+        use super::tags::TAG_DATE_TIME;
+        self.read_tagged_implicit(TAG_DATE_TIME, |reader| {
+            let bytes = reader.read_bytes()?;
+            return DateTime::parse(&bytes).ok_or_else(
+                || ASN1Error::new(ASN1ErrorKind::Invalid));
+        })
+    }
+
+    /// Reads an ASN.1 DURATION.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// let data = b"\x1f\x22\x04PT1H";
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_duration()
+    /// }).unwrap();
+    /// assert_eq!(asn.hours(), Some(1));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Except parse errors, it can raise
+    /// [`NonCanonicalTime`](ASN1ErrorKind::NonCanonicalTime) if the value
+    /// is not in the canonical form in DER mode.
+    pub fn read_duration(self) -> ASN1Result<Duration> {
+        use super::tags::TAG_DURATION;
+        let mode = self.inner.mode;
+        self.read_tagged_implicit(TAG_DURATION, |reader| {
+            let bytes = reader.read_bytes()?;
+            let duration = Duration::parse(&bytes).ok_or_else(
+                || ASN1Error::new(ASN1ErrorKind::Invalid))?;
+            if mode == BERMode::Der && !Duration::is_canonical_bytes(&bytes) {
+                return Err(ASN1Error::new(ASN1ErrorKind::NonCanonicalTime));
+            }
+            return Ok(duration);
+        })
+    }
+
+    /// Reads an ASN.1 TIME.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::models::TimeBasic;
+    /// let data = b"\x0e\x072019-10";
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_time()
+    /// }).unwrap();
+    /// assert_eq!(asn.settings().basic(), TimeBasic::Date);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Except parse errors, it can raise
+    /// [`NonCanonicalTime`](ASN1ErrorKind::NonCanonicalTime) if the value
+    /// is not in the canonical form in DER mode.
+    pub fn read_time(self) -> ASN1Result<Time> {
+        use super::tags::TAG_TIME;
+        let mode = self.inner.mode;
+        self.read_tagged_implicit(TAG_TIME, |reader| {
+            let bytes = reader.read_bytes()?;
+            let time = Time::parse(&bytes).ok_or_else(
+                || ASN1Error::new(ASN1ErrorKind::Invalid))?;
+            if mode == BERMode::Der && !time.is_canonical() {
+                return Err(ASN1Error::new(ASN1ErrorKind::NonCanonicalTime));
+            }
+            return Ok(time);
+        })
+    }
+
     /// Reads an ASN.1 VisibleString.
     ///
     /// # Examples
@@ -1579,9 +1951,189 @@ impl<'a, 'b> BERReader<'a, 'b> {
     /// assert_eq!(&asn, &[Some(10), None]);
     /// ```
     pub fn lookahead_tag(&self) -> ASN1Result<Tag> {
-        self.inner.lookahead_tag()
+        self.inner.lookahead_identifier().map(|(tag, _)| tag)
+    }
+
+    /// Lookaheads the tag and the primitive/constructed bit in the next
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::{self,PCBit};
+    /// use yasna::tags::TAG_SEQUENCE;
+    /// let data = &[48, 3, 2, 1, 10];
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     let identifier = reader.lookahead_identifier()?;
+    ///     reader.read_der()?;
+    ///     return Ok(identifier);
+    /// }).unwrap();
+    /// assert_eq!(asn, (TAG_SEQUENCE, PCBit::Constructed));
+    /// ```
+    pub fn lookahead_identifier(&self) -> ASN1Result<(Tag, PCBit)> {
+        self.inner.lookahead_identifier()
+    }
+
+    /// Reads an ASN.1 CHOICE value.
+    ///
+    /// This is a shorthand for
+    /// [`read_choice_or_else`](Self::read_choice_or_else), which describes
+    /// how the alternatives are matched.
+    ///
+    /// # Errors
+    ///
+    /// If no alternative matches, it fails with
+    /// [`ASN1ErrorKind::NoAlternative`] without consuming the input.
+    /// The error doesn't carry the expected identifiers, since [`ASN1Error`]
+    /// is kept small and `Copy`. Use
+    /// [`read_choice_or_else`](Self::read_choice_or_else) to report them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::{self,ChoiceAlternative,PCBit,Tag};
+    /// use yasna::tags::{TAG_INTEGER,TAG_UTF8STRING};
+    /// #[derive(Debug, PartialEq)]
+    /// enum Choice {
+    ///     Number(i64),
+    ///     Name(String),
+    ///     Flag(bool),
+    /// }
+    /// let data = &[48, 11, 2, 1, 10, 12, 3, 102, 111, 111, 128, 1, 255];
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.collect_sequence_of(|reader| {
+    ///         reader.read_choice([
+    ///             ChoiceAlternative::new(TAG_INTEGER, PCBit::Primitive,
+    ///                 |reader| Ok(Choice::Number(reader.read_i64()?))),
+    ///             ChoiceAlternative::new(TAG_UTF8STRING, PCBit::Primitive,
+    ///                 |reader| Ok(Choice::Name(reader.read_utf8string()?))),
+    ///             ChoiceAlternative::new(Tag::context(0), PCBit::Primitive,
+    ///                 |reader| Ok(Choice::Flag(reader.read_tagged_implicit(
+    ///                     Tag::context(0), |reader| reader.read_bool())?))),
+    ///         ])
+    ///     })
+    /// }).unwrap();
+    /// assert_eq!(asn, vec![
+    ///     Choice::Number(10),
+    ///     Choice::Name("foo".to_string()),
+    ///     Choice::Flag(true),
+    /// ]);
+    /// ```
+    pub fn read_choice<'t, T, I>(self, alternatives: I) -> ASN1Result<T>
+            where I: IntoIterator<Item = ChoiceAlternative<'t, 'a, T>> {
+        self.read_choice_or_else(alternatives, |_, _| {
+            Err(ASN1Error::new(ASN1ErrorKind::NoAlternative))
+        })
+    }
+
+    /// Reads an ASN.1 CHOICE value, calling back `or_else` if no
+    /// alternative matches.
+    ///
+    /// The identifier (tag and primitive/constructed bit) of the next value
+    /// is looked ahead, and the first alternative accepting the identifier
+    /// is called back with this reader.
+    ///
+    /// Untagged nested CHOICEs can be expressed by
+    /// [`ChoiceAlternative::nested`], which accepts several identifiers.
+    ///
+    /// Note that in BER, values of string types may be encoded either in
+    /// primitive or constructed form. Register both identifiers if you want
+    /// to accept both.
+    ///
+    /// If no alternative matches, `or_else` is given this reader and the
+    /// identifiers of all the alternatives, in order. It may report them
+    /// in an error of its own, or read the value in another way, which is
+    /// useful for a CHOICE with an extension marker, or for a field whose
+    /// identifier is open.
+    ///
+    /// # Examples
+    ///
+    /// Reporting the expected identifiers:
+    ///
+    /// ```
+    /// use yasna::{self,ChoiceAlternative,PCBit,Tag};
+    /// use yasna::tags::{TAG_INTEGER,TAG_NULL};
+    /// let data = &[1, 1, 255];
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_choice_or_else([
+    ///         ChoiceAlternative::new(TAG_INTEGER, PCBit::Primitive,
+    ///             |reader| Ok(Ok(reader.read_i64()?))),
+    ///         ChoiceAlternative::new(TAG_NULL, PCBit::Primitive,
+    ///             |reader| { reader.read_null()?; Ok(Ok(0)) }),
+    ///     ], |reader, expected| {
+    ///         let found = reader.lookahead_identifier()?;
+    ///         reader.read_der()?;
+    ///         Ok(Err((found, expected.to_vec())))
+    ///     })
+    /// }).unwrap();
+    /// assert_eq!(asn, Err(((yasna::tags::TAG_BOOLEAN, PCBit::Primitive), vec![
+    ///     (TAG_INTEGER, PCBit::Primitive),
+    ///     (TAG_NULL, PCBit::Primitive),
+    /// ])));
+    /// ```
+    ///
+    /// Falling back to another decoder:
+    ///
+    /// ```
+    /// use yasna::{self,ChoiceAlternative,PCBit};
+    /// use yasna::models::TaggedDerValue;
+    /// use yasna::tags::{TAG_INTEGER,TAG_NULL};
+    /// let data = &[1, 1, 255];
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_choice_or_else([
+    ///         ChoiceAlternative::new(TAG_INTEGER, PCBit::Primitive,
+    ///             |reader| Ok(Ok(reader.read_i64()?))),
+    ///         ChoiceAlternative::new(TAG_NULL, PCBit::Primitive,
+    ///             |reader| { reader.read_null()?; Ok(Ok(0)) }),
+    ///     ], |reader, _| Ok(Err(reader.read_tagged_der()?)))
+    /// }).unwrap();
+    /// assert_eq!(asn, Err(TaggedDerValue::from_tag_and_bytes(
+    ///     yasna::tags::TAG_BOOLEAN, vec![255])));
+    /// ```
+    pub fn read_choice_or_else<'t, T, I, F>(self, alternatives: I,
+            or_else: F) -> ASN1Result<T>
+            where I: IntoIterator<Item = ChoiceAlternative<'t, 'a, T>>,
+                F: FnOnce(BERReader<'a, 'b>, &[(Tag, PCBit)])
+                    -> ASN1Result<T> {
+        let identifier = self.lookahead_identifier()?;
+        let mut expected = Vec::new();
+        for alternative in alternatives {
+            if alternative.identifiers.contains(&identifier) {
+                return (alternative.handler)(self);
+            }
+            expected.extend(alternative.identifiers);
+        }
+        return or_else(self, &expected);
     }
 
+    /// Reads a value with `callback`, and returns the result together
+    /// with the encoding it was read from (including the tag and the
+    /// length), borrowed from the input.
+    ///
+    /// Useful for verifying signatures, which are computed over the exact
+    /// encoding of a sub-structure. See also
+    /// [`read_with_raw_ref`](BERReader::read_with_raw_ref) for types
+    /// implementing `BERDecodable`, and [`WithRaw`](crate::models::WithRaw)
+    /// for the owned form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// let data = &[48, 9, 48, 3, 2, 1, 10, 3, 2, 0, 255];
+    /// let (tbs, raw, signature) = yasna::parse_der(data, |reader| {
+    ///     reader.read_sequence(|reader| {
+    ///         let (tbs, raw) = reader.next().read_with_buffer(|reader| {
+    ///             reader.read_sequence(|reader| reader.next().read_i64())
+    ///         })?;
+    ///         let signature = reader.next().read_bitvec_bytes()?;
+    ///         return Ok((tbs, raw, signature));
+    ///     })
+    /// }).unwrap();
+    /// assert_eq!(tbs, 10);
+    /// assert_eq!(raw, &[48, 3, 2, 1, 10]);
+    /// assert_eq!(signature, (vec![255], 8));
+    /// ```
     pub fn read_with_buffer<T, F>(self, callback: F)
             -> ASN1Result<(T, &'a [u8])>
             where F: for<'c> FnOnce(BERReader<'a, 'c>) -> ASN1Result<T> {
@@ -1594,6 +2146,14 @@ impl<'a, 'b> BERReader<'a, 'b> {
         })
     }
 
+    /// Reads a `T` together with the encoding it was read from, borrowed
+    /// from the input. See [`WithRawRef`] for an example.
+    pub fn read_with_raw_ref<T: BERDecodable>(self)
+            -> ASN1Result<WithRawRef<'a, T>> {
+        let (value, raw) = self.read_with_buffer(T::decode_ber)?;
+        return Ok(WithRawRef::from_parts(value, raw));
+    }
+
     /// Read an arbitrary (tag, value) pair as a TaggedDerValue.
     /// The length is not included in the returned payload. If the
     /// payload has indefinite-length encoding, the EOC bytes are
@@ -1636,6 +2196,77 @@ impl<'a, 'b> BERReader<'a, 'b> {
     }
 }
 
+/// An alternative of a CHOICE type, used by
+/// [`BERReader::read_choice_or_else`] and [`BERReader::read_choice`].
+///
+/// An alternative consists of the identifiers (pairs of a tag and a
+/// primitive/constructed bit) it accepts, and a handler to read the value.
+pub struct ChoiceAlternative<'t, 'a, T> {
+    identifiers: Vec<(Tag, PCBit)>,
+    handler: Box<dyn for<'c> FnOnce(BERReader<'a, 'c>) -> ASN1Result<T> + 't>,
+}
+
+impl<'t, 'a, T> ChoiceAlternative<'t, 'a, T> {
+    /// Constructs an alternative accepting a single identifier.
+    pub fn new<F>(tag: Tag, pcbit: PCBit, handler: F) -> Self
+            where F: for<'c> FnOnce(BERReader<'a, 'c>) -> ASN1Result<T> + 't {
+        return ChoiceAlternative {
+            identifiers: vec![(tag, pcbit)],
+            handler: Box::new(handler),
+        };
+    }
+
+    /// Constructs an alternative accepting any of `identifiers`.
+    ///
+    /// This is mainly used for an untagged CHOICE nested in another CHOICE,
+    /// in which case `identifiers` are the union of the identifiers of
+    /// the inner alternatives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::{self,ChoiceAlternative,PCBit};
+    /// use yasna::tags::{TAG_INTEGER,TAG_BOOLEAN,TAG_NULL};
+    /// let data = &[1, 1, 255];
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_choice([
+    ///         ChoiceAlternative::new(TAG_NULL, PCBit::Primitive,
+    ///             |reader| { reader.read_null()?; Ok(None) }),
+    ///         ChoiceAlternative::nested(
+    ///             &[(TAG_INTEGER, PCBit::Primitive),
+    ///                 (TAG_BOOLEAN, PCBit::Primitive)],
+    ///             |reader| reader.read_choice([
+    ///                 ChoiceAlternative::new(TAG_INTEGER, PCBit::Primitive,
+    ///                     |reader| Ok(Some(reader.read_i64()?))),
+    ///                 ChoiceAlternative::new(TAG_BOOLEAN, PCBit::Primitive,
+    ///                     |reader| Ok(Some(reader.read_bool()? as i64))),
+    ///             ])),
+    ///     ])
+    /// }).unwrap();
+    /// assert_eq!(asn, Some(1));
+    /// ```
+    pub fn nested<F>(identifiers: &[(Tag, PCBit)], handler: F) -> Self
+            where F: for<'c> FnOnce(BERReader<'a, 'c>) -> ASN1Result<T> + 't {
+        return ChoiceAlternative {
+            identifiers: identifiers.to_vec(),
+            handler: Box::new(handler),
+        };
+    }
+
+    /// Returns the identifiers this alternative accepts.
+    pub fn identifiers(&self) -> &[(Tag, PCBit)] {
+        &self.identifiers
+    }
+}
+
+impl<'t, 'a, T> fmt::Debug for ChoiceAlternative<'t, 'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("ChoiceAlternative")
+            .field("identifiers", &self.identifiers)
+            .finish()
+    }
+}
+
 /// A reader object for a sequence of BER/DER-encoded ASN.1 data.
 ///
 /// The main source of this object is the [`read_sequence`] method from
@@ -1750,6 +2381,137 @@ impl<'a, 'b> BERReaderSeq<'a, 'b> {
             callback(&mut BERReaderSeq { inner, })
         })
     }
+
+    /// Reads all the remaining elements as [`TaggedDerValue`]s.
+    ///
+    /// Used to parse extensible SEQUENCEs (those with an extension marker
+    /// `...`): the unknown extension additions can be preserved
+    /// and re-encoded later with
+    /// [`write_tagged_der`](crate::DERWriter::write_tagged_der).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::models::TaggedDerValue;
+    /// use yasna::tags::TAG_BOOLEAN;
+    /// let data = &[48, 6, 2, 1, 10, 1, 1, 255];
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_sequence(|reader| {
+    ///         let i = reader.next().read_i64()?;
+    ///         let extensions = reader.remaining_elements()?;
+    ///         return Ok((i, extensions));
+    ///     })
+    /// }).unwrap();
+    /// assert_eq!(asn, (10, vec![
+    ///     TaggedDerValue::from_tag_and_bytes(TAG_BOOLEAN, vec![255]),
+    /// ]));
+    /// ```
+    pub fn remaining_elements(&mut self)
+            -> ASN1Result<Vec<TaggedDerValue>> {
+        let mut elements = Vec::new();
+        while let Some(element) = self.read_optional(|reader| {
+            reader.read_tagged_der()
+        })? {
+            elements.push(element);
+        }
+        return Ok(elements);
+    }
+
+    /// Skips all the remaining elements.
+    ///
+    /// Used to parse extensible SEQUENCEs (those with an extension marker
+    /// `...`) when the unknown extension additions are not needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// let data = &[48, 6, 2, 1, 10, 1, 1, 255];
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_sequence(|reader| {
+    ///         let i = reader.next().read_i64()?;
+    ///         reader.skip_remaining()?;
+    ///         return Ok(i);
+    ///     })
+    /// }).unwrap();
+    /// assert_eq!(asn, 10);
+    /// ```
+    pub fn skip_remaining(&mut self) -> ASN1Result<()> {
+        while self.inner.read_optional(|inner| {
+            inner.skip_general()
+        })?.is_some() {}
+        return Ok(());
+    }
+}
+
+/// An iterator over the elements of a SEQUENCE OF, decoded lazily.
+///
+/// This is returned by [`BERReader::read_sequence_of_lazy`]. Each item is
+/// the result of decoding an element; the iteration stops after an error.
+/// Cloning it is cheap, so it can be iterated more than once.
+pub struct LazySequenceOf<'a, T> {
+    buf: &'a [u8],
+    mode: BERMode,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> LazySequenceOf<'a, T> {
+    /// Returns the encoding of the elements.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.buf
+    }
+
+    /// Returns `true` if there are no more elements.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+}
+
+impl<'a, T> Clone for LazySequenceOf<'a, T> {
+    fn clone(&self) -> Self {
+        LazySequenceOf {
+            buf: self.buf,
+            mode: self.mode,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Default for LazySequenceOf<'a, T> {
+    fn default() -> Self {
+        LazySequenceOf {
+            buf: &[],
+            mode: BERMode::Der,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> fmt::Debug for LazySequenceOf<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LazySequenceOf")
+            .field("buf", &self.buf)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl<'a, T: BERDecodable> Iterator for LazySequenceOf<'a, T> {
+    type Item = ASN1Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+        let mut inner = BERReaderImpl::new(self.buf, self.mode);
+        let result = T::decode_ber(BERReader::new(&mut inner));
+        self.buf = match result {
+            Ok(_) => &self.buf[inner.pos..],
+            Err(_) => &[],
+        };
+        return Some(result);
+    }
 }
 
 /// A reader object for a set of BER/DER-encoded ASN.1 data.
@@ -1775,7 +2537,7 @@ impl<'a, 'b> BERReaderSeq<'a, 'b> {
 #[derive(Debug)]
 pub struct BERReaderSet<'a, 'b> where 'a: 'b {
     impl_ref: &'b mut BERReaderImpl<'a>,
-    elements: &'b mut Vec<(Tag, &'a [u8], usize)>,
+    elements: &'b mut Vec<((Tag, PCBit), &'a [u8], usize)>,
 }
 
 impl<'a, 'b> BERReaderSet<'a, 'b> {
@@ -1789,7 +2551,7 @@ impl<'a, 'b> BERReaderSet<'a, 'b> {
     /// This method needs `tag_hint` to determine the position of the data.
     pub fn next<'c>(&'c mut self, tag_hint: &[Tag])
             -> ASN1Result<BERReader<'a, 'c>> {
-        if let Some(elem_pos) = self.elements.iter().position(|&(tag,_,_)| {
+        if let Some(elem_pos) = self.elements.iter().position(|&((tag,_),_,_)| {
             tag_hint.contains(&tag)
         }) {
             let (_, buf, pos) = self.elements.remove(elem_pos);
@@ -1827,7 +2589,7 @@ impl<'a, 'b> BERReaderSet<'a, 'b> {
     pub fn read_optional<T, F>(&mut self, tag_hint: &[Tag], callback: F)
             -> ASN1Result<Option<T>>
             where F: for<'c> FnOnce(BERReader<'a, 'c>) -> ASN1Result<T> {
-        if let Some(elem_pos) = self.elements.iter().position(|&(tag,_,_)| {
+        if let Some(elem_pos) = self.elements.iter().position(|&((tag,_),_,_)| {
             tag_hint.contains(&tag)
         }) {
             let (_, buf, pos) = self.elements.remove(elem_pos);
@@ -1883,6 +2645,94 @@ impl<'a, 'b> BERReaderSet<'a, 'b> {
             None => Ok(default),
         }
     }
+
+    /// Returns the identifiers (tag and primitive/constructed bit) of the
+    /// elements not read yet, in the order of the encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::{self,PCBit,Tag};
+    /// use yasna::tags::TAG_INTEGER;
+    /// let data = &[49, 8, 2, 1, 10, 161, 3, 1, 1, 255];
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_set(|reader| {
+    ///         let identifiers = reader.remaining_identifiers();
+    ///         reader.skip_remaining()?;
+    ///         return Ok(identifiers);
+    ///     })
+    /// }).unwrap();
+    /// assert_eq!(asn, vec![
+    ///     (TAG_INTEGER, PCBit::Primitive),
+    ///     (Tag::context(1), PCBit::Constructed),
+    /// ]);
+    /// ```
+    pub fn remaining_identifiers(&self) -> Vec<(Tag, PCBit)> {
+        return self.elements.iter()
+            .map(|&(identifier, _, _)| identifier).collect();
+    }
+
+    /// Reads all the remaining elements as [`TaggedDerValue`]s,
+    /// in the order of the encoding.
+    ///
+    /// Used to parse extensible SETs (those with an extension marker
+    /// `...`): the unknown extension additions can be preserved
+    /// and re-encoded later with
+    /// [`write_tagged_der`](crate::DERWriter::write_tagged_der).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna::{self,Tag};
+    /// use yasna::models::TaggedDerValue;
+    /// use yasna::tags::TAG_INTEGER;
+    /// let data = &[49, 6, 2, 1, 10, 128, 1, 255];
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_set(|reader| {
+    ///         let i = reader.next(&[TAG_INTEGER])?.read_i64()?;
+    ///         let extensions = reader.remaining_elements()?;
+    ///         return Ok((i, extensions));
+    ///     })
+    /// }).unwrap();
+    /// assert_eq!(asn, (10, vec![
+    ///     TaggedDerValue::from_tag_and_bytes(Tag::context(0), vec![255]),
+    /// ]));
+    /// ```
+    pub fn remaining_elements(&mut self)
+            -> ASN1Result<Vec<TaggedDerValue>> {
+        let mode = self.impl_ref.mode;
+        let mut elements = Vec::new();
+        for (_, buf, pos) in self.elements.drain(..) {
+            let mut reader_impl = BERReaderImpl::with_pos(buf, pos, mode);
+            elements.push(BERReader::new(&mut reader_impl).read_tagged_der()?);
+        }
+        return Ok(elements);
+    }
+
+    /// Skips all the remaining elements.
+    ///
+    /// Used to parse extensible SETs (those with an extension marker
+    /// `...`) when the unknown extension additions are not needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::tags::TAG_INTEGER;
+    /// let data = &[49, 6, 2, 1, 10, 128, 1, 255];
+    /// let asn = yasna::parse_der(data, |reader| {
+    ///     reader.read_set(|reader| {
+    ///         let i = reader.next(&[TAG_INTEGER])?.read_i64()?;
+    ///         reader.skip_remaining()?;
+    ///         return Ok(i);
+    ///     })
+    /// }).unwrap();
+    /// assert_eq!(asn, 10);
+    /// ```
+    pub fn skip_remaining(&mut self) -> ASN1Result<()> {
+        self.elements.clear();
+        return Ok(());
+    }
 }
 
 
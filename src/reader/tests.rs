@@ -1667,3 +1667,369 @@ fn test_ber_read_overflow() {
     #[cfg(target_pointer_width = "64")]
     assert_eq!(err.kind(), ASN1ErrorKind::IntegerOverflow);
 }
+
+#[test]
+fn test_ber_read_choice_ok() {
+    use super::super::tags::{TAG_INTEGER, TAG_BOOLEAN, TAG_OCTETSTRING};
+    let tests : &[(i64, &[u8])] = &[
+        (10, &[2, 1, 10]),
+        (1, &[1, 1, 255]),
+        (3, &[4, 3, 1, 2, 3]),
+        (2, &[36, 128, 4, 1, 1, 4, 1, 2, 0, 0]),
+        (-1, &[128, 1, 255]),
+    ];
+    for &(evalue, data) in tests {
+        let value = parse_ber(data, |reader| {
+            reader.read_choice([
+                ChoiceAlternative::nested(
+                    &[(TAG_INTEGER, PCBit::Primitive),
+                        (TAG_BOOLEAN, PCBit::Primitive)],
+                    |reader| reader.read_choice([
+                        ChoiceAlternative::new(TAG_INTEGER, PCBit::Primitive,
+                            |reader| reader.read_i64()),
+                        ChoiceAlternative::new(TAG_BOOLEAN, PCBit::Primitive,
+                            |reader| Ok(reader.read_bool()? as i64)),
+                    ])),
+                ChoiceAlternative::nested(
+                    &[(TAG_OCTETSTRING, PCBit::Primitive),
+                        (TAG_OCTETSTRING, PCBit::Constructed)],
+                    |reader| Ok(reader.read_bytes()?.len() as i64)),
+                ChoiceAlternative::new(Tag::context(0), PCBit::Primitive,
+                    |reader| reader.read_tagged_implicit(
+                        Tag::context(0), |reader| reader.read_i64())),
+            ])
+        }).unwrap();
+        assert_eq!(value, evalue);
+    }
+}
+
+#[test]
+fn test_ber_read_choice_err() {
+    use super::super::tags::{TAG_INTEGER, TAG_NULL};
+    let data = &[48, 3, 2, 1, 10];
+    let err = parse_ber(data, |reader| {
+        reader.read_choice([
+            ChoiceAlternative::new(TAG_INTEGER, PCBit::Primitive,
+                |reader| reader.read_i64()),
+            ChoiceAlternative::new(TAG_NULL, PCBit::Primitive,
+                |reader| { reader.read_null()?; Ok(0) }),
+        ])
+    }).unwrap_err();
+    assert_eq!(err.kind(), ASN1ErrorKind::NoAlternative);
+
+    let expected = parse_ber(data, |reader| {
+        reader.read_choice_or_else([
+            ChoiceAlternative::new(TAG_INTEGER, PCBit::Primitive,
+                |reader| { reader.read_i64()?; Ok(vec![]) }),
+            ChoiceAlternative::new(TAG_NULL, PCBit::Primitive,
+                |reader| { reader.read_null()?; Ok(vec![]) }),
+        ], |reader, expected| {
+            reader.read_der()?;
+            Ok(expected.to_vec())
+        })
+    }).unwrap();
+    assert_eq!(expected, vec![
+        (TAG_INTEGER, PCBit::Primitive),
+        (TAG_NULL, PCBit::Primitive),
+    ]);
+
+    let value = parse_ber(data, |reader| {
+        reader.read_sequence(|reader| {
+            let identifier = reader.next().lookahead_identifier()?;
+            assert_eq!(identifier, (TAG_INTEGER, PCBit::Primitive));
+            reader.next().read_i64()
+        })
+    }).unwrap();
+    assert_eq!(value, 10);
+}
+
+#[test]
+fn test_ber_read_sequence_extensible() {
+    use super::super::models::TaggedDerValue;
+    use super::super::tags::{TAG_BOOLEAN, TAG_OCTETSTRING};
+    let tests : &[(i64, &[TaggedDerValue], &[u8])] = &[
+        (10, &[], &[48, 3, 2, 1, 10]),
+        (10, &[], &[48, 128, 2, 1, 10, 0, 0]),
+        (10, &[
+            TaggedDerValue::from_tag_and_bytes(TAG_BOOLEAN, vec![255]),
+            TaggedDerValue::from_tag_pc_and_bytes(
+                Tag::context(1), PCBit::Constructed, vec![4, 0]),
+        ], &[48, 10, 2, 1, 10, 1, 1, 255, 161, 2, 4, 0]),
+        (10, &[
+            TaggedDerValue::from_tag_pc_and_bytes(
+                TAG_OCTETSTRING, PCBit::Constructed,
+                vec![4, 1, 1, 0, 0]),
+        ], &[48, 128, 2, 1, 10, 36, 128, 4, 1, 1, 0, 0, 0, 0]),
+    ];
+    for &(evalue, eextensions, data) in tests {
+        let (value, extensions) = parse_ber(data, |reader| {
+            reader.read_sequence(|reader| {
+                let value = reader.next().read_i64()?;
+                let extensions = reader.remaining_elements()?;
+                return Ok((value, extensions));
+            })
+        }).unwrap();
+        assert_eq!(value, evalue);
+        assert_eq!(extensions, eextensions);
+        let value = parse_ber(data, |reader| {
+            reader.read_sequence(|reader| {
+                let value = reader.next().read_i64()?;
+                reader.skip_remaining()?;
+                return Ok(value);
+            })
+        }).unwrap();
+        assert_eq!(value, evalue);
+    }
+    let tests : &[&[u8]] = &[
+        &[48, 4, 2, 1, 10, 1],
+        &[48, 128, 2, 1, 10, 1, 1, 255, 0],
+    ];
+    for &data in tests {
+        parse_ber(data, |reader| {
+            reader.read_sequence(|reader| {
+                reader.next().read_i64()?;
+                reader.skip_remaining()
+            })
+        }).unwrap_err();
+    }
+}
+
+#[test]
+fn test_read_sequence_of_lazy() {
+    let tests : &[(BERMode, &[i64], &[u8])] = &[
+        (BERMode::Der, &[], &[48, 0]),
+        (BERMode::Der, &[10, -129], &[48, 7, 2, 1, 10, 2, 2, 255, 127]),
+        (BERMode::Ber, &[10, -129], &[48, 128, 2, 1, 10, 2, 2, 255, 127, 0, 0]),
+        (BERMode::Ber, &[10], &[48, 131, 0, 0, 3, 2, 1, 10]),
+    ];
+    for &(mode, evalue, data) in tests {
+        let lazy = parse_ber_general(data, mode, |reader| {
+            reader.read_sequence_of_lazy::<i64>()
+        }).unwrap();
+        let value = lazy.clone().collect::<ASN1Result<Vec<_>>>().unwrap();
+        assert_eq!(value, evalue);
+        assert_eq!(lazy.count(), evalue.len());
+    }
+
+    // Malformed elements are rejected upfront.
+    let tests : &[&[u8]] = &[
+        &[48, 3, 2, 2, 10],
+        &[48, 128, 2, 1, 10, 0, 0],
+    ];
+    for &data in tests {
+        parse_der(data, |reader| {
+            reader.read_sequence_of_lazy::<i64>()
+        }).unwrap_err();
+    }
+
+    // Invalid contents are reported while iterating, then it stops.
+    let mut lazy = parse_der(&[48, 9, 2, 1, 10, 1, 1, 255, 2, 1, 11],
+        |reader| reader.read_sequence_of_lazy::<i64>()).unwrap();
+    assert_eq!(lazy.next().unwrap().unwrap(), 10);
+    assert!(lazy.next().unwrap().is_err());
+    assert!(lazy.next().is_none());
+    assert!(lazy.is_empty());
+}
+
+#[test]
+fn test_ber_read_set_extensible() {
+    use super::super::models::TaggedDerValue;
+    use super::super::tags::{TAG_INTEGER, TAG_BOOLEAN};
+    let data = &[49, 9, 1, 1, 255, 2, 1, 10, 128, 1, 0];
+    let (value, extensions) = parse_der(data, |reader| {
+        reader.read_set(|reader| {
+            let value = reader.next(&[TAG_INTEGER])?.read_i64()?;
+            let extensions = reader.remaining_elements()?;
+            return Ok((value, extensions));
+        })
+    }).unwrap();
+    assert_eq!(value, 10);
+    assert_eq!(extensions, [
+        TaggedDerValue::from_tag_and_bytes(TAG_BOOLEAN, vec![255]),
+        TaggedDerValue::from_tag_and_bytes(Tag::context(0), vec![0]),
+    ]);
+    let value = parse_der(data, |reader| {
+        reader.read_set(|reader| {
+            let value = reader.next(&[TAG_INTEGER])?.read_i64()?;
+            reader.skip_remaining()?;
+            return Ok(value);
+        })
+    }).unwrap();
+    assert_eq!(value, 10);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_der_read_time_non_canonical() {
+    let tests : &[(&[u8], Option<ASN1ErrorKind>)] = &[
+        (b"\x18\x0f19851106210627Z", None),
+        (b"\x18\x1119851106210627.3Z", None),
+        (b"\x18\x1219851106210627.30Z", Some(ASN1ErrorKind::NonCanonicalTime)),
+        (b"\x18\x1119851106210627,3Z", Some(ASN1ErrorKind::NonCanonicalTime)),
+        (b"\x18\x0d198511062106Z", Some(ASN1ErrorKind::NonCanonicalTime)),
+        (b"\x18\x1319851106210627+0900",
+            Some(ASN1ErrorKind::NonCanonicalTime)),
+        (b"\x18\x0e19851106210627", Some(ASN1ErrorKind::Invalid)),
+    ];
+    for &(data, ekind) in tests {
+        let result = parse_der(data, |reader| reader.read_generalized_time());
+        assert_eq!(result.err().map(|e| e.kind()), ekind);
+        if ekind != Some(ASN1ErrorKind::Invalid) {
+            parse_ber(data, |reader| reader.read_generalized_time()).unwrap();
+        }
+    }
+    let tests : &[(&[u8], Option<ASN1ErrorKind>)] = &[
+        (b"\x17\x0d820102120000Z", None),
+        (b"\x17\x0b8201021200Z", Some(ASN1ErrorKind::NonCanonicalTime)),
+        (b"\x17\x11820102070000-0500", Some(ASN1ErrorKind::NonCanonicalTime)),
+        (b"\x17\x0b820102120000", Some(ASN1ErrorKind::Invalid)),
+    ];
+    for &(data, ekind) in tests {
+        let result = parse_der(data, |reader| reader.read_utctime());
+        assert_eq!(result.err().map(|e| e.kind()), ekind);
+    }
+}
+
+#[test]
+fn test_der_read_generalized_time_fields() {
+    let tests : &[(&[u8], Option<ASN1ErrorKind>)] = &[
+        (b"\x18\x0f19851106210660Z", None),
+        (b"\x18\x1119851106210627.3Z", None),
+        (b"\x18\x1219851106210627.30Z", Some(ASN1ErrorKind::NonCanonicalTime)),
+        (b"\x18\x0d198511062106Z", Some(ASN1ErrorKind::NonCanonicalTime)),
+        (b"\x18\x0e19851106210627", Some(ASN1ErrorKind::NonCanonicalTime)),
+        (b"\x18\x0f19851106210627Y", Some(ASN1ErrorKind::Invalid)),
+    ];
+    for &(data, ekind) in tests {
+        let result =
+            parse_der(data, |reader| reader.read_generalized_time_fields());
+        assert_eq!(result.as_ref().err().map(|e| e.kind()), ekind);
+        if ekind != Some(ASN1ErrorKind::Invalid) {
+            let fields = parse_ber(data, |reader| {
+                reader.read_generalized_time_fields()
+            }).unwrap();
+            assert_eq!(fields.to_bytes(), &data[2..]);
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_ber_read_utctime_variants() {
+    use super::super::models::UTCTimeWindow;
+    let tests : &[(&[u8], i64)] = &[
+        (b"\x17\x0d820102120000Z", 378820800),
+        (b"\x17\x0b8201021200Z", 378820800),
+        (b"\x17\x0f8201021700+0500", 378820800),
+        (b"\x17\x11820102070000-0500", 378820800),
+    ];
+    for &(data, timestamp) in tests {
+        let datetime = parse_ber(data, |reader| reader.read_utctime()).unwrap();
+        assert_eq!(datetime.datetime().unix_timestamp(), timestamp);
+        let datetime = parse_ber(data, |reader| {
+            reader.read_utctime_with_window(UTCTimeWindow::starting_at(1900))
+        }).unwrap();
+        assert_eq!(datetime.datetime().unix_timestamp(), timestamp);
+    }
+    let data = b"\x17\x0d490102120000Z";
+    let datetime = parse_der(data, |reader| {
+        reader.read_utctime_with_window(UTCTimeWindow::starting_at(1900))
+    }).unwrap();
+    assert_eq!(datetime.datetime().year(), 1949);
+    let err = parse_der(b"\x17\x0b4901021200Z", |reader| {
+        reader.read_utctime_with_window(UTCTimeWindow::starting_at(1900))
+    }).unwrap_err();
+    assert_eq!(err.kind(), ASN1ErrorKind::NonCanonicalTime);
+}
+
+#[test]
+fn test_der_read_oid_ref() {
+    use super::super::models::ObjectIdentifier;
+    let tests : &[(&[u8], &[u128])] = &[
+        (&[6, 1, 42], &[1, 2]),
+        (&[6, 8, 42, 134, 72, 134, 247, 13, 1, 1],
+            &[1, 2, 840, 113549, 1, 1]),
+        (&[6, 2, 136, 55], &[2, 999]),
+        (&[6, 20, 105, 131, 240, 157, 167, 235, 207, 222, 224, 199, 161, 167,
+            178, 192, 148, 140, 200, 249, 215, 118],
+            &[2, 25, 0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6]),
+    ];
+    for &(data, arcs) in tests {
+        let oid = parse_der(data, |reader| reader.read_oid_ref()).unwrap();
+        assert_eq!(oid, ObjectIdentifier::from_arcs(arcs).unwrap());
+        assert_eq!(oid.arcs().collect::<Vec<_>>(), arcs);
+        assert_eq!(oid.as_bytes(), &data[2..]);
+        let oid2 = parse_der(data, |reader| reader.read_oid()).unwrap();
+        assert_eq!(oid, oid2);
+    }
+    let tests : &[(&[u8], ASN1ErrorKind)] = &[
+        (&[6, 0], ASN1ErrorKind::Invalid),
+        (&[6, 2, 42, 134], ASN1ErrorKind::Invalid),
+        (&[6, 3, 42, 128, 1], ASN1ErrorKind::Invalid),
+        (&[38, 3, 6, 1, 42], ASN1ErrorKind::Invalid),
+        (&[6, 21, 42, 132, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+            128, 128, 128, 128, 128, 128, 128, 128, 0],
+            ASN1ErrorKind::IntegerOverflow),
+    ];
+    for &(data, kind) in tests {
+        let err = parse_der(data, |reader| reader.read_oid_ref()).unwrap_err();
+        assert_eq!(err.kind(), kind);
+        let err = parse_der(data, |reader| reader.read_oid()).unwrap_err();
+        assert_eq!(err.kind(), kind);
+    }
+}
+
+#[test]
+fn test_ber_read_oid() {
+    use super::super::models::ObjectIdentifier;
+    let tests : &[(&[u8], &[u128])] = &[
+        (&[6, 4, 85, 129, 128, 0], &[2, 5, 16384]),
+        (&[6, 20, 105, 131, 240, 157, 167, 235, 207, 222, 224, 199, 161, 167,
+            178, 192, 148, 140, 200, 249, 215, 118],
+            &[2, 25, 0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6]),
+    ];
+    for &(data, arcs) in tests {
+        let oid = parse_ber(data, |reader| reader.read_oid()).unwrap();
+        assert_eq!(oid, ObjectIdentifier::from_arcs(arcs).unwrap());
+        let oid = parse_der(data, |reader| reader.read_oid()).unwrap();
+        assert_eq!(oid, ObjectIdentifier::from_arcs(arcs).unwrap());
+    }
+    let tests : &[(&[u8], ASN1ErrorKind)] = &[
+        (&[6, 3, 42, 128, 1], ASN1ErrorKind::Invalid),
+        (&[6, 2, 128, 42], ASN1ErrorKind::Invalid),
+        (&[6, 21, 42, 132, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
+            128, 128, 128, 128, 128, 128, 128, 128, 0],
+            ASN1ErrorKind::IntegerOverflow),
+    ];
+    for &(data, kind) in tests {
+        let err = parse_ber(data, |reader| reader.read_oid()).unwrap_err();
+        assert_eq!(err.kind(), kind);
+        let err = parse_der(data, |reader| reader.read_oid()).unwrap_err();
+        assert_eq!(err.kind(), kind);
+    }
+}
+
+#[test]
+fn test_der_read_printable_string() {
+    let tests : &[(&str, &[u8])] = &[
+        ("", &[19, 0]),
+        ("Co., Ltd.", &[19, 9, 67, 111, 46, 44, 32, 76, 116, 100, 46]),
+        ("1+1=2?", &[19, 6, 49, 43, 49, 61, 50, 63]),
+    ];
+    for &(evalue, data) in tests {
+        let value = parse_der(data, |reader| {
+            reader.read_printable_string()
+        }).unwrap();
+        assert_eq!(value, evalue);
+    }
+    let tests : &[&[u8]] = &[
+        &[19, 1, 42],
+        &[19, 1, 64],
+    ];
+    for &data in tests {
+        let err = parse_der(data, |reader| {
+            reader.read_printable_string()
+        }).unwrap_err();
+        assert_eq!(err.kind(), ASN1ErrorKind::Invalid);
+    }
+}
@@ -10,6 +10,8 @@
 
 use alloc::vec::Vec;
 use alloc::string::String;
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+use core::convert::TryFrom;
 
 #[cfg(feature = "num-bigint")]
 use num_bigint::{BigInt,BigUint};
@@ -17,9 +19,14 @@ use num_bigint::{BigInt,BigUint};
 use bit_vec::BitVec;
 
 use super::{DERWriter,construct_der};
-use super::models::ObjectIdentifier;
+use super::models::{ObjectIdentifier,WithRaw,WithRawRef,RawDateTime,Oid,OidBuf};
+use super::models::{BitString,TaggedDerValue};
+use super::models::{NumericString,PrintableString,Ia5String,VisibleString};
+use super::models::{BmpString,Utf8String,DirectoryString};
+use super::models::{Date,TimeOfDay,DateTime,Duration,Time};
+use super::models::GeneralizedTimeFields;
 #[cfg(feature = "time")]
-use super::models::{UTCTime,GeneralizedTime};
+use super::models::{UTCTime,GeneralizedTime,X509Time};
 
 /// Types encodable in DER.
 ///
@@ -45,13 +52,26 @@ use super::models::{UTCTime,GeneralizedTime};
 /// - `()` as NULL encoder.
 /// - Tuples (except `()`) as SEQUENCE encoder.
 /// - `Vec<u8>`/`[u8]` as OCTETSTRING encoder.
-/// - `BitVec` as BITSTRING encoder.
+/// - `BitVec`/`BitString` as BITSTRING encoder.
 /// - `String`/`str` as UTF8String encoder.
 /// - `i64`, `u64`, `i32`, `u32`, `i16`, `u16`, `BigInt`, `BigUint`
 ///   as INTEGER encoder. (`u8` is avoided because of confliction.)
 /// - `bool` as BOOLEAN encoder.
 /// - `ObjectIdentifier` as OBJECTT IDENTIFIER encoder.
+/// - `Oid`/`OidBuf` as OBJECT IDENTIFIER encoder.
 /// - `UTCTime`/`GeneralizedTime` as UTCTime/GeneralizedTime encoder.
+/// - `X509Time` as UTCTime or GeneralizedTime, whichever it holds.
+/// - `GeneralizedTimeFields` as GeneralizedTime encoder.
+/// - `Date`, `TimeOfDay`, `DateTime`, `Duration`, `Time` as DATE,
+///   TIME-OF-DAY, DATE-TIME, DURATION, TIME encoder.
+/// - `NumericString`, `PrintableString`, `Ia5String`, `VisibleString`,
+///   `BmpString`, `Utf8String` as the corresponding string encoders.
+/// - `DirectoryString` as the string type of the chosen alternative.
+/// - `TaggedDerValue` as the value with its tag.
+/// - `WithRaw<T>`/`WithRawRef<T>` as the original encoding, written verbatim.
+/// - `RawDateTime`, `chrono::DateTime<Utc>` and `jiff::Timestamp` as
+///   UTCTime if the year is between 1950 and 2049, and as GeneralizedTime
+///   otherwise, without fractional seconds (RFC 5280).
 pub trait DEREncodable {
     /// Writes the value as an DER-encoded ASN.1 value.
     ///
@@ -180,6 +200,12 @@ impl DEREncodable for BitVec {
     }
 }
 
+impl DEREncodable for BitString {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_bitvec_bytes(self.as_bytes(), self.len())
+    }
+}
+
 impl DEREncodable for Vec<u8> {
     fn encode_der(&self, writer: DERWriter) {
         writer.write_bytes(self)
@@ -210,6 +236,118 @@ impl DEREncodable for ObjectIdentifier {
     }
 }
 
+impl<'a> DEREncodable for Oid<'a> {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_oid_ref(*self)
+    }
+}
+
+impl DEREncodable for OidBuf {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_oid_ref(self.as_oid())
+    }
+}
+
+impl DEREncodable for NumericString {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_numeric_string(self)
+    }
+}
+
+impl DEREncodable for PrintableString {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_printable_string(self)
+    }
+}
+
+impl DEREncodable for Ia5String {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_ia5_string(self)
+    }
+}
+
+impl DEREncodable for VisibleString {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_visible_string(self)
+    }
+}
+
+impl DEREncodable for BmpString {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_bmp_string(self)
+    }
+}
+
+impl DEREncodable for Utf8String {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_utf8string(self)
+    }
+}
+
+impl DEREncodable for DirectoryString {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            DirectoryString::NumericString(ref s) => s.encode_der(writer),
+            DirectoryString::PrintableString(ref s) => s.encode_der(writer),
+            DirectoryString::Ia5String(ref s) => s.encode_der(writer),
+            DirectoryString::VisibleString(ref s) => s.encode_der(writer),
+            DirectoryString::BmpString(ref s) => s.encode_der(writer),
+            DirectoryString::Utf8String(ref s) => s.encode_der(writer),
+        }
+    }
+}
+
+impl DEREncodable for TaggedDerValue {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_tagged_der(self)
+    }
+}
+
+impl DEREncodable for RawDateTime {
+    fn encode_der(&self, writer: DERWriter) {
+        let (tag, bytes) = self.to_x509_time();
+        writer.write_tagged_implicit(tag, |writer| {
+            writer.write_bytes(&bytes)
+        })
+    }
+}
+
+/// # Panics
+///
+/// Panics if the year is not between 0 and 9999.
+#[cfg(feature = "chrono")]
+impl DEREncodable for chrono::DateTime<chrono::Utc> {
+    fn encode_der(&self, writer: DERWriter) {
+        let datetime = RawDateTime::try_from(*self)
+            .expect("Can't express the datetime in ASN.1 time types");
+        datetime.encode_der(writer)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the year is before 0.
+#[cfg(feature = "jiff")]
+impl DEREncodable for jiff::Timestamp {
+    fn encode_der(&self, writer: DERWriter) {
+        let datetime = RawDateTime::try_from(*self)
+            .expect("Can't express the timestamp in ASN.1 time types");
+        datetime.encode_der(writer)
+    }
+}
+
+impl<T> DEREncodable for WithRaw<T> {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_der(self.raw())
+    }
+}
+
+impl<'a, T> DEREncodable for WithRawRef<'a, T> {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_der(self.raw())
+    }
+}
+
 #[cfg(feature = "time")]
 impl DEREncodable for UTCTime {
     fn encode_der(&self, writer: DERWriter) {
@@ -224,6 +362,53 @@ impl DEREncodable for GeneralizedTime{
     }
 }
 
+#[cfg(feature = "time")]
+impl DEREncodable for X509Time {
+    fn encode_der(&self, writer: DERWriter) {
+        match *self {
+            X509Time::UTCTime(ref time) => writer.write_utctime(time),
+            X509Time::GeneralizedTime(ref time) =>
+                writer.write_generalized_time(time),
+        }
+    }
+}
+
+impl DEREncodable for GeneralizedTimeFields {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_generalized_time_fields(self)
+    }
+}
+
+impl DEREncodable for Date {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_date(self)
+    }
+}
+
+impl DEREncodable for TimeOfDay {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_time_of_day(self)
+    }
+}
+
+impl DEREncodable for DateTime {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_date_time(self)
+    }
+}
+
+impl DEREncodable for Duration {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_duration(self)
+    }
+}
+
+impl DEREncodable for Time {
+    fn encode_der(&self, writer: DERWriter) {
+        writer.write_time(self)
+    }
+}
+
 impl DEREncodable for () {
     fn encode_der(&self, writer: DERWriter) {
         writer.write_null()
@@ -0,0 +1,334 @@
+// Copyright 2026 Masaki Hara
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Provides helpers for ASN.1 data written as text: hexadecimal and base64
+//! input, and annotated hex dumps.
+//!
+//! # Examples
+//!
+//! ```
+//! use yasna::text::{decode_base64, decode_hex};
+//! let der = decode_hex("30:06:02:01:0a:01:01:ff").unwrap();
+//! assert_eq!(der, decode_base64("MAYCAQoBAf8=").unwrap());
+//! let asn: (i64, bool) = yasna::decode_der(&der).unwrap();
+//! assert_eq!(asn, (10, true));
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Write};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use super::{BERMode, PCBit};
+use super::notation::write_tag_name;
+use super::reader::{walk_ber, TlvEvent};
+
+/// An error indicating failure to decode hexadecimal or base64 text.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::text::decode_hex;
+/// let err = decode_hex("30 0g").unwrap_err();
+/// assert_eq!(err.position(), 4);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TextDecodeError {
+    position: usize,
+    message: &'static str,
+}
+
+impl TextDecodeError {
+    fn new(position: usize, message: &'static str) -> Self {
+        TextDecodeError {
+            position,
+            message,
+        }
+    }
+
+    /// Returns the byte offset in the input where the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TextDecodeError {}
+
+impl Display for TextDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{} at byte {}", self.message, self.position)
+    }
+}
+
+/// Decodes hexadecimal text into bytes.
+///
+/// Both cases are accepted. Whitespace and colons are ignored, even inside
+/// a byte.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::text::decode_hex;
+/// assert_eq!(decode_hex("04 02\n  AB:cd").unwrap(), &[4, 2, 171, 205]);
+/// assert!(decode_hex("04 0").is_err());
+/// ```
+pub fn decode_hex(s: &str) -> Result<Vec<u8>, TextDecodeError> {
+    let mut bytes = Vec::with_capacity(s.len() / 2);
+    let mut high = None;
+    for (i, c) in s.char_indices() {
+        if c.is_ascii_whitespace() || c == ':' {
+            continue;
+        }
+        let digit = c.to_digit(16)
+            .ok_or(TextDecodeError::new(i, "invalid hexadecimal digit"))?;
+        match high.take() {
+            Some(high) => bytes.push((high * 16 + digit) as u8),
+            None => high = Some(digit),
+        }
+    }
+    if high.is_some() {
+        return Err(TextDecodeError::new(s.len(), "odd number of digits"));
+    }
+    return Ok(bytes);
+}
+
+/// Decodes base64 text (in the standard alphabet) into bytes.
+///
+/// Whitespace is ignored. The padding is optional, but unused bits in the
+/// last character must be zero.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::text::decode_base64;
+/// assert_eq!(decode_base64("BAKrzQ==").unwrap(), &[4, 2, 171, 205]);
+/// assert_eq!(decode_base64("BAKr\nzQ").unwrap(), &[4, 2, 171, 205]);
+/// assert!(decode_base64("BAKrzR==").is_err());
+/// ```
+pub fn decode_base64(s: &str) -> Result<Vec<u8>, TextDecodeError> {
+    let mut bytes = Vec::with_capacity(s.len() * 3 / 4);
+    let mut acc : u32 = 0;
+    let mut nbits = 0;
+    let mut padding = 0;
+    for (i, b) in s.bytes().enumerate() {
+        if b.is_ascii_whitespace() {
+            continue;
+        }
+        if b == b'=' {
+            padding += 1;
+            continue;
+        }
+        if padding > 0 {
+            return Err(TextDecodeError::new(i, "data after padding"));
+        }
+        let value = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return Err(TextDecodeError::new(i, "invalid base64 character")),
+        };
+        acc = acc << 6 | value as u32;
+        nbits += 6;
+        if nbits >= 8 {
+            nbits -= 8;
+            bytes.push((acc >> nbits) as u8);
+            acc &= (1 << nbits) - 1;
+        }
+    }
+    // 0, 2 or 4 bits may be left over, corresponding to 0, 1 or 2 padding
+    // characters.
+    if nbits == 6 || acc != 0 ||
+            (padding > 0 && padding != [0, 0, 1, 0, 2][nbits]) {
+        return Err(TextDecodeError::new(s.len(), "invalid base64 length"));
+    }
+    return Ok(bytes);
+}
+
+/// Dumps BER/DER data in hexadecimal, annotated with the structure.
+///
+/// Each line starts with the offset. The identifier and the length of
+/// each value are bracketed and followed by its type, and the contents of
+/// constructed values are enclosed in `{` and `}`. If the data is
+/// malformed, the dump ends with the error and the remaining bytes.
+///
+/// # Examples
+///
+/// ```
+/// use yasna::BERMode;
+/// use yasna::text::hex_dump;
+/// let dump = hex_dump(&[48, 128, 2, 1, 10, 160, 3, 1, 1, 255, 0, 0],
+///     BERMode::Ber);
+/// assert_eq!(dump, "\
+/// 0000  [30] [80]  SEQUENCE {
+/// 0002    [02] [01]  INTEGER
+/// 0004      0A
+/// 0005    [A0] [03]  [0] {
+/// 0007      [01] [01]  BOOLEAN
+/// 0009        FF
+/// 000A    }
+/// 000A  [00 00] }
+/// ");
+/// ```
+pub fn hex_dump(buf: &[u8], mode: BERMode) -> String {
+    let width = if buf.len() > 0xFFFF { 8 } else { 4 };
+    let mut out = String::new();
+    // Where the next value starts if the traversal fails.
+    let mut resume = 0;
+    let result = walk_ber(buf, mode, |event| {
+        match event {
+            TlvEvent::Header {
+                depth, tag, pcbit, pos, length_pos, contents_pos, length,
+            } => {
+                write_offset(&mut out, width, pos, depth);
+                write_bracketed(&mut out, &buf[pos..length_pos]);
+                out.push(' ');
+                write_bracketed(&mut out, &buf[length_pos..contents_pos]);
+                out.push_str("  ");
+                write_tag_name(&mut out, tag);
+                resume = contents_pos;
+                if pcbit == PCBit::Constructed {
+                    out.push_str(" {");
+                } else if let Some(length) = length {
+                    resume += length;
+                }
+                out.push('\n');
+                if pcbit == PCBit::Primitive {
+                    write_lines(&mut out, width, buf, contents_pos, resume,
+                        depth + 1);
+                }
+            },
+            TlvEvent::End { depth, pos, indefinite } => {
+                write_offset(&mut out, width, pos, depth);
+                resume = pos;
+                if indefinite {
+                    out.push_str("[00 00] ");
+                    resume += 2;
+                }
+                out.push_str("}\n");
+            },
+        }
+        return Ok(());
+    });
+    if let Err(e) = result {
+        write_offset(&mut out, width, resume, 0);
+        writeln!(out, "error: {:?}", e.kind()).unwrap();
+        write_lines(&mut out, width, buf, resume, buf.len(), 1);
+    }
+    return out;
+}
+
+fn write_offset(out: &mut String, width: usize, pos: usize, depth: usize) {
+    write!(out, "{:01$X}  ", pos, width).unwrap();
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+}
+
+fn write_bracketed(out: &mut String, bytes: &[u8]) {
+    out.push('[');
+    for (i, b) in bytes.iter().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        write!(out, "{:02X}", b).unwrap();
+    }
+    out.push(']');
+}
+
+fn write_lines(out: &mut String, width: usize, buf: &[u8],
+        start: usize, end: usize, depth: usize) {
+    for line_start in (start..end).step_by(16) {
+        let line_end = (line_start + 16).min(end);
+        write_offset(out, width, line_start, depth);
+        for (i, b) in buf[line_start..line_end].iter().enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            write!(out, "{:02X}", b).unwrap();
+        }
+        out.push('\n');
+    }
+}
+
+#[test]
+fn test_decode_hex() {
+    assert_eq!(decode_hex("").unwrap(), &[]);
+    assert_eq!(decode_hex(" 0\t1:fF ").unwrap(), &[1, 255]);
+    assert_eq!(decode_hex("0x01").unwrap_err().position(), 1);
+    assert_eq!(decode_hex("012").unwrap_err().position(), 3);
+}
+
+#[test]
+fn test_decode_base64() {
+    let tests : &[(&str, &[u8])] = &[
+        ("", &[]),
+        ("AA==", &[0]),
+        ("AAA=", &[0, 0]),
+        ("AAAA", &[0, 0, 0]),
+        ("+/+/", &[251, 255, 191]),
+        ("Zm9v\r\nYmFy", b"foobar"),
+        ("Zm9vYg", b"foob"),
+    ];
+    for &(s, expected) in tests {
+        assert_eq!(decode_base64(s).unwrap(), expected);
+    }
+    let tests : &[(&str, usize)] = &[
+        ("A", 1),
+        ("AAAAA", 5),
+        ("AB==", 4),
+        ("AA=", 3),
+        ("AA=A", 3),
+        ("AA-_", 2),
+    ];
+    for &(s, position) in tests {
+        assert_eq!(decode_base64(s).unwrap_err().position(), position);
+    }
+}
+
+#[test]
+fn test_hex_dump() {
+    let data = decode_hex("
+        30 1a
+          06 03 55 04 03
+          04 11 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f 10
+          05 00
+    ").unwrap();
+    assert_eq!(hex_dump(&data, BERMode::Der), "\
+0000  [30] [1A]  SEQUENCE {
+0002    [06] [03]  OBJECT IDENTIFIER
+0004      55 04 03
+0007    [04] [11]  OCTET STRING
+0009      00 01 02 03 04 05 06 07 08 09 0A 0B 0C 0D 0E 0F
+0019      10
+001A    [05] [00]  NULL
+001C  }
+");
+    assert_eq!(hex_dump(&[2, 1, 1, 5, 0], BERMode::Der), "\
+0000  [02] [01]  INTEGER
+0002    01
+0003  [05] [00]  NULL
+");
+    assert_eq!(hex_dump(&[48, 4, 2, 3, 1, 2], BERMode::Der), "\
+0000  [30] [04]  SEQUENCE {
+0002  error: Eof
+0002    02 03 01 02
+");
+    assert_eq!(hex_dump(&[48, 128, 0, 0], BERMode::Der), "\
+0000  error: Invalid
+0000    30 80 00 00
+");
+    assert_eq!(hex_dump(&[48, 3, 2, 2, 1, 2], BERMode::Ber), "\
+0000  [30] [03]  SEQUENCE {
+0002  error: Invalid
+0002    02 02 01 02
+");
+}
@@ -21,6 +21,9 @@ use super::tags::{TAG_BOOLEAN,TAG_INTEGER,TAG_OCTETSTRING};
 use super::tags::{TAG_NULL,TAG_OID,TAG_UTF8STRING,TAG_SEQUENCE,TAG_SET,TAG_ENUM,TAG_IA5STRING,TAG_BMPSTRING};
 use super::tags::{TAG_NUMERICSTRING,TAG_PRINTABLESTRING,TAG_VISIBLESTRING};
 use super::models::{ObjectIdentifier,TaggedDerValue};
+use super::models::Oid;
+use super::models::{Date,TimeOfDay,DateTime,Duration,Time};
+use super::models::GeneralizedTimeFields;
 #[cfg(feature = "time")]
 use super::models::{UTCTime,GeneralizedTime};
 
@@ -734,21 +737,20 @@ impl<'a> DERWriter<'a> {
     ///
     /// It panics when the OID cannot be canonically encoded in BER.
     pub fn write_oid(mut self, oid: &ObjectIdentifier) {
-        assert!(oid.components().len() >= 2, "Invalid OID: too short");
-        let id0 = oid.components()[0];
-        let id1 = oid.components()[1];
+        let mut arcs = oid.arcs();
+        let (id0, id1) = match (arcs.next(), arcs.next()) {
+            (Some(id0), Some(id1)) => (id0, id1),
+            _ => panic!("Invalid OID: too short"),
+        };
         assert!(
-            (id0 < 3) && (id1 < 18446744073709551535) &&
+            (id0 < 3) && (id1 <= u128::MAX - 80) &&
             (id0 >= 2 || id1 < 40),
             "Invalid OID {{{} {} ...}}", id0, id1);
         let subid0 = id0 * 40 + id1;
+        let subids = || core::iter::once(subid0).chain(oid.arcs().skip(2));
         let mut length = 0;
-        for i in 1..oid.components().len() {
-            let mut subid = if i == 1 {
-                subid0
-            } else {
-                oid.components()[i]
-            } | 1;
+        for subid in subids() {
+            let mut subid = subid | 1;
             while subid > 0 {
                 length += 1;
                 subid >>= 7;
@@ -756,13 +758,8 @@ impl<'a> DERWriter<'a> {
         }
         self.write_identifier(TAG_OID, PCBit::Primitive);
         self.write_length(length);
-        for i in 1..oid.components().len() {
-            let subid = if i == 1 {
-                subid0
-            } else {
-                oid.components()[i]
-            };
-            let mut shiftnum = 63; // ceil(64 / 7) * 7 - 7
+        for subid in subids() {
+            let mut shiftnum = 126; // ceil(128 / 7) * 7 - 7
             while ((subid|1) >> shiftnum) == 0 {
                 shiftnum -= 7;
             }
@@ -774,6 +771,24 @@ impl<'a> DERWriter<'a> {
         }
     }
 
+    /// Writes an ASN.1 object identifier from its encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::oid;
+    /// let der = yasna::construct_der(|writer| {
+    ///     writer.write_oid_ref(oid!("1.2.840.113549.1.1").as_oid())
+    /// });
+    /// assert_eq!(&der, &[6, 8, 42, 134, 72, 134, 247, 13, 1, 1]);
+    /// ```
+    pub fn write_oid_ref(mut self, oid: Oid) {
+        self.write_identifier(TAG_OID, PCBit::Primitive);
+        self.write_length(oid.as_bytes().len());
+        self.buf.extend_from_slice(oid.as_bytes());
+    }
+
     /// Writes an ASN.1 UTF8String.
     ///
     /// # Examples
@@ -984,7 +999,7 @@ impl<'a> DERWriter<'a> {
             assert!(
                 byte == b' ' ||
                 (b'\'' <= byte && byte <= b':' && byte != b'*') ||
-                byte == b'=' ||
+                byte == b'=' || byte == b'?' ||
                 (b'A' <= byte && byte <= b'Z') ||
                 (b'a' <= byte && byte <= b'z'),
                 "Invalid PrintableString: {:?} appeared", byte);
@@ -1066,6 +1081,132 @@ impl<'a> DERWriter<'a> {
         });
     }
 
+    /// Writes an ASN.1 GeneralizedTime from its calendar fields, exactly
+    /// as they are.
+    ///
+    /// Note that the output is not valid DER unless
+    /// [`GeneralizedTimeFields::is_canonical`] holds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::models::GeneralizedTimeFields;
+    /// let der = yasna::construct_der(|writer| {
+    ///     writer.write_generalized_time_fields(
+    ///         &GeneralizedTimeFields::new(2016, 12, 31, 23, 59, 60).unwrap())
+    /// });
+    /// assert_eq!(&der, b"\x18\x0f20161231235960Z");
+    /// ```
+    pub fn write_generalized_time_fields(self, fields: &GeneralizedTimeFields) {
+        use super::tags::TAG_GENERALIZEDTIME;
+        self.write_tagged_implicit(TAG_GENERALIZEDTIME, |writer| {
+            writer.write_bytes(&fields.to_bytes())
+        });
+    }
+
+    /// Writes an ASN.1 DATE.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::models::Date;
+    /// let der = yasna::construct_der(|writer| {
+    ///     writer.write_date(&Date::new(2019, 10, 19).unwrap())
+    /// });
+    /// assert_eq!(&der, b"\x1f\x1f\x0a2019-10-19");
+    /// ```
+    pub fn write_date(self, date: &Date) {
+        use super::tags::TAG_DATE;
+        self.write_tagged_implicit(TAG_DATE, |writer| {
+            writer.write_bytes(&date.to_bytes())
+        });
+    }
+
+    /// Writes an ASN.1 TIME-OF-DAY.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::models::TimeOfDay;
+    /// let der = yasna::construct_der(|writer| {
+    ///     writer.write_time_of_day(&TimeOfDay::new(9, 30, 0).unwrap())
+    /// });
+    /// assert_eq!(&der, b"\x1f\x20\x0809:30:00");
+    /// ```
+    pub fn write_time_of_day(self, time: &TimeOfDay) {
+        use super::tags::TAG_TIME_OF_DAY;
+        self.write_tagged_implicit(TAG_TIME_OF_DAY, |writer| {
+            writer.write_bytes(&time.to_bytes())
+        });
+    }
+
+    /// Writes an ASN.1 DATE-TIME.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::models::{Date,TimeOfDay,DateTime};
+    /// let datetime = DateTime::new(
+    ///     Date::new(2019, 10, 19).unwrap(),
+    ///     TimeOfDay::new(9, 30, 0).unwrap());
+    /// let der = yasna::construct_der(|writer| {
+    ///     writer.write_date_time(&datetime)
+    /// });
+    /// assert_eq!(&der, b"\x1f\x21\x132019-10-19T09:30:00");
+    /// ```
+    pub fn write_date_time(self, datetime: &DateTime) {
+        use super::tags::TAG_DATE_TIME;
+        self.write_tagged_implicit(TAG_DATE_TIME, |writer| {
+            writer.write_bytes(&datetime.to_bytes())
+        });
+    }
+
+    /// Writes an ASN.1 DURATION.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::models::{Duration,DurationComponents};
+    /// let duration = Duration::from_components(DurationComponents {
+    ///     hours: 1,
+    ///     ..Default::default()
+    /// });
+    /// let der = yasna::construct_der(|writer| {
+    ///     writer.write_duration(&duration)
+    /// });
+    /// assert_eq!(&der, b"\x1f\x22\x04PT1H");
+    /// ```
+    pub fn write_duration(self, duration: &Duration) {
+        use super::tags::TAG_DURATION;
+        self.write_tagged_implicit(TAG_DURATION, |writer| {
+            writer.write_bytes(&duration.to_bytes())
+        });
+    }
+
+    /// Writes an ASN.1 TIME.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use yasna;
+    /// use yasna::models::Time;
+    /// let der = yasna::construct_der(|writer| {
+    ///     writer.write_time(&Time::parse(b"2019-10").unwrap())
+    /// });
+    /// assert_eq!(&der, b"\x0e\x072019-10");
+    /// ```
+    pub fn write_time(self, time: &Time) {
+        use super::tags::TAG_TIME;
+        self.write_tagged_implicit(TAG_TIME, |writer| {
+            writer.write_bytes(&time.to_bytes())
+        });
+    }
+
     /// Writes an ASN.1 VisibleString.
     ///
     /// # Examples
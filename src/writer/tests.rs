@@ -477,3 +477,35 @@ fn test_der_write_tagged_implicit() {
     });
     assert_eq!(data, vec![131, 1, 10]);
 }
+
+#[test]
+fn test_der_write_oid() {
+    use super::super::models::ObjectIdentifier;
+    let tests : &[(&[u128], &[u8])] = &[
+        (&[1, 2], &[6, 1, 42]),
+        (&[2, 999, 3], &[6, 3, 136, 55, 3]),
+        (&[2, 25, 0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6],
+            &[6, 20, 105, 131, 240, 157, 167, 235, 207, 222, 224, 199, 161,
+            167, 178, 192, 148, 140, 200, 249, 215, 118]),
+    ];
+    for &(arcs, data) in tests {
+        let oid = ObjectIdentifier::from_arcs(arcs).unwrap();
+        let buf = construct_der(|writer| writer.write_oid(&oid));
+        assert_eq!(buf, data);
+    }
+}
+
+#[test]
+fn test_der_write_printable_string() {
+    let tests : &[(&str, &[u8])] = &[
+        ("", &[19, 0]),
+        ("Co., Ltd.", &[19, 9, 67, 111, 46, 44, 32, 76, 116, 100, 46]),
+        ("1+1=2?", &[19, 6, 49, 43, 49, 61, 50, 63]),
+    ];
+    for &(value, edata) in tests {
+        let data = construct_der(|writer| {
+            writer.write_printable_string(value)
+        });
+        assert_eq!(data, edata);
+    }
+}